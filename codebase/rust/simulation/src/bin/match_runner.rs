@@ -0,0 +1,203 @@
+//! Runs a bot-vs-bot match without rendering and prints a JSON summary of it.
+//!
+//! Usage: `cargo run --release --bin match_runner -- [config.ron|config.json] [--record replay.json]`
+//! Without a config, the match is played by the same players the game starts with.
+//!
+//! `cargo run --release --bin match_runner -- --replay replay.json` plays a recorded match back instead,
+//! it fails if the playback drifts away from the recording.
+//!
+//! `--coordinate-map coordinate_map.json` plays on the map baked by the game instead of the synthetic one,
+//! replays have to be played back on the same map they were recorded on.
+
+use std::{env, fs, process};
+
+use serde::Deserialize;
+use simulation::clock::SimulationClock;
+use simulation::globe::baked_map::BakedCoordinateMap;
+use simulation::replay::record::Replay;
+use simulation::setup::MatchSetup;
+use simulation::territories::territory::Territory;
+use simulation::headless::world::World;
+
+/// Runner only fields, they sit in the config file next to the match setup ones
+#[derive(Debug, Deserialize)]
+#[serde(default)]
+struct RunnerOptions {
+  /// The match stops there if no one has won yet
+  max_ticks: u64,
+  /// How often the territories of every player are recorded in the summary
+  sample_every_secs: f64,
+}
+
+impl Default for RunnerOptions {
+  fn default() -> Self {
+    let ticks_per_second = SimulationClock::TICKS_PER_SECOND as u64;
+
+    RunnerOptions {
+      max_ticks: 30 * 60 * ticks_per_second,
+      sample_every_secs: 10.,
+    }
+  }
+}
+
+#[derive(Debug, Default)]
+struct RunnerConfig {
+  setup: MatchSetup,
+  options: RunnerOptions,
+}
+
+/// RON or JSON depending on the extension, as the match setup files, check `MatchSetup::parse`
+fn read_config(path: &str) -> Result<RunnerConfig, String> {
+  let content = fs::read_to_string(path)
+    .map_err(|error| format!("Couldn't read {path}: {error}"))?;
+
+  let setup = MatchSetup::load(path, &content, &Territory::get_map())
+    .map_err(|error| format!("Invalid match setup at {path}: {error}"))?;
+
+  // `MatchSetup::load` already turned any other extension down
+  let options: RunnerOptions = if path.ends_with(".ron") {
+    ron::from_str(&content).map_err(|error| format!("Couldn't parse {path}: {error}"))?
+  } else {
+    serde_json::from_str(&content).map_err(|error| format!("Couldn't parse {path}: {error}"))?
+  };
+
+  Ok(RunnerConfig { setup, options })
+}
+
+#[derive(Debug, Default)]
+struct RunnerArgs {
+  config_path: Option<String>,
+  record_path: Option<String>,
+  replay_path: Option<String>,
+  coordinate_map_path: Option<String>,
+}
+
+fn read_args() -> Result<RunnerArgs, String> {
+  let mut runner_args = RunnerArgs::default();
+  let mut args = env::args().skip(1);
+
+  while let Some(arg) = args.next() {
+    match arg.as_str() {
+      "--record" => runner_args.record_path = Some(args.next().ok_or("--record expects a path")?),
+      "--replay" => runner_args.replay_path = Some(args.next().ok_or("--replay expects a path")?),
+      "--coordinate-map" => runner_args.coordinate_map_path = Some(args.next().ok_or("--coordinate-map expects a path")?),
+      _ if runner_args.config_path.is_none() => runner_args.config_path = Some(arg),
+      _ => return Err(format!("Unexpected argument {arg}")),
+    }
+  }
+
+  Ok(runner_args)
+}
+
+fn read_replay(path: &str) -> Result<Replay, String> {
+  let content = fs::read_to_string(path)
+    .map_err(|error| format!("Couldn't read {path}: {error}"))?;
+
+  Replay::load(&content, &Territory::get_map())
+    .map_err(|error| format!("Invalid replay at {path}: {error}"))
+}
+
+fn read_coordinate_map(path: &str) -> Result<BakedCoordinateMap, String> {
+  let content = fs::read_to_string(path)
+    .map_err(|error| format!("Couldn't read {path}: {error}"))?;
+
+  BakedCoordinateMap::load(&content, &Territory::get_map())
+    .map_err(|error| format!("Invalid coordinate map at {path}: {error}"))
+}
+
+fn exit_with_error(error: String) -> ! {
+  eprintln!("{error}");
+  process::exit(1);
+}
+
+fn main() {
+  let args = read_args().unwrap_or_else(|error| exit_with_error(error));
+  let baked_map = args.coordinate_map_path
+    .as_deref()
+    .map(|path| read_coordinate_map(path).unwrap_or_else(|error| exit_with_error(error)));
+
+  if let Some(replay_path) = &args.replay_path {
+    let replay = read_replay(replay_path).unwrap_or_else(|error| exit_with_error(error));
+    let sample_every_ticks = 10 * SimulationClock::TICKS_PER_SECOND as u64;
+
+    let mut world = World::from_replay_on_map(&replay, baked_map.as_ref());
+    let summary = world.run(replay.last_tick, sample_every_ticks);
+    println!("{}", serde_json::to_string_pretty(&summary).expect("Expected summary to be serializable"));
+
+    if let Some(desync) = world.get_replay_desync() {
+      exit_with_error(format!("Replay at {replay_path} couldn't be reproduced: {desync}"));
+    }
+    return;
+  }
+
+  let config = match &args.config_path {
+    Some(path) => read_config(path).unwrap_or_else(|error| exit_with_error(error)),
+    None => RunnerConfig::default(),
+  };
+
+  let sample_every_ticks = (config.options.sample_every_secs * SimulationClock::TICKS_PER_SECOND as f64) as u64;
+
+  let mut world = World::new_on_map(&config.setup, baked_map.as_ref());
+  let summary = world.run(config.options.max_ticks, sample_every_ticks);
+
+  println!("{}", serde_json::to_string_pretty(&summary).expect("Expected summary to be serializable"));
+
+  if let Some(record_path) = &args.record_path {
+    let replay = world.get_replay().expect("Expected a new match to be recorded");
+    fs::write(record_path, replay.to_json())
+      .unwrap_or_else(|error| exit_with_error(format!("Couldn't write {record_path}: {error}")));
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::{env, fs, process};
+
+  use simulation::setup::MatchSetup;
+
+  use super::{read_config, RunnerConfig, RunnerOptions};
+
+  const DEFAULT_MATCH: &str = include_str!("../../../../godot/matches/default_match.ron");
+
+  /// Writes the config at a temporary path of its own, tests run in parallel, and reads it back
+  fn read_config_from(name: &str, extension: &str, content: &str) -> Result<RunnerConfig, String> {
+    let path = env::temp_dir().join(format!("match_runner_{name}_{}.{extension}", process::id()));
+    let path = path.to_str().expect("Expected temporary path to be valid UTF-8");
+    fs::write(path, content).expect("Expected config to be written");
+
+    let config = read_config(path);
+    fs::remove_file(path).expect("Expected config to be removed");
+    config
+  }
+
+  #[test]
+  fn ron_configs_hold_the_setup_and_the_runner_options() {
+    let content = DEFAULT_MATCH.replacen("rules: (),", "rules: (),\n  max_ticks: 600,\n  sample_every_secs: 5.,", 1);
+
+    let config = read_config_from("ron_config", "ron", &content).expect("Expected RON config to be read");
+
+    assert_eq!(config.options.max_ticks, 600);
+    assert_eq!(config.options.sample_every_secs, 5.);
+    assert_eq!(config.setup.players.len(), 4);
+  }
+
+  #[test]
+  fn json_configs_hold_the_setup_and_the_runner_options() {
+    let mut content = serde_json::to_value(MatchSetup::default()).expect("Expected setup to be serializable");
+    content["max_ticks"] = serde_json::Value::from(900);
+
+    let config = read_config_from("json_config", "json", &content.to_string()).expect("Expected JSON config to be read");
+
+    assert_eq!(config.options.max_ticks, 900);
+    assert_eq!(config.options.sample_every_secs, RunnerOptions::default().sample_every_secs);
+    assert_eq!(config.setup.seed, MatchSetup::default().seed);
+  }
+
+  #[test]
+  fn configs_with_an_invalid_setup_are_turned_down() {
+    let content = DEFAULT_MATCH.replacen("\"baffin_bay\"", "\"atlantis\"", 1);
+
+    assert!(read_config_from("invalid_setup", "ron", &content).is_err());
+    assert!(read_config_from("unknown_extension", "toml", DEFAULT_MATCH).is_err());
+  }
+}
@@ -0,0 +1,117 @@
+/// Single clock of the match, every game rule advances in steps of `FIXED_DELTA`
+/// in the order: territories -> troops -> projectiles
+///
+/// Rendering may run at any frame rate, the frame deltas are accumulated
+/// and turned into a whole number of ticks, so two runs with the same seed and
+/// the same orders always go through the very same steps
+#[derive(Debug, Clone)]
+pub struct SimulationClock {
+  /// Number of ticks elapsed since the match started
+  pub tick: u64,
+  accumulator: f64,
+}
+
+impl Default for SimulationClock {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+impl SimulationClock {
+  /// Matches Godot's default `physics/common/physics_ticks_per_second`
+  pub const TICKS_PER_SECOND: u32 = 60;
+  pub const FIXED_DELTA: f64 = 1. / Self::TICKS_PER_SECOND as f64;
+
+  /// If a frame takes too long, the remaining time is dropped instead of
+  /// running more and more ticks each frame to catch up (aka spiral of death)
+  pub const MAX_TICKS_PER_FRAME: u32 = 8;
+
+  pub fn new() -> SimulationClock {
+    SimulationClock {
+      tick: 0,
+      accumulator: 0.,
+    }
+  }
+
+  /// Accumulates the frame delta and returns how many ticks should run in this frame,
+  /// each of them is counted by `step` once it runs
+  pub fn accumulate(&mut self, frame_delta: f64) -> u32 {
+    self.accumulator += frame_delta.max(0.);
+
+    let mut ticks_to_run = 0;
+    while self.accumulator >= Self::FIXED_DELTA && ticks_to_run < Self::MAX_TICKS_PER_FRAME {
+      self.accumulator -= Self::FIXED_DELTA;
+      ticks_to_run += 1;
+    }
+
+    if ticks_to_run == Self::MAX_TICKS_PER_FRAME {
+      self.accumulator = self.accumulator.min(Self::FIXED_DELTA);
+    }
+
+    ticks_to_run
+  }
+
+  /// Advances a single tick, right before the rules are stepped
+  pub fn step(&mut self) {
+    self.tick += 1;
+  }
+
+  pub fn get_elapsed_secs(&self) -> f64 {
+    self.tick as f64 * Self::FIXED_DELTA
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::SimulationClock;
+
+  #[test]
+  fn frame_deltas_are_turned_into_whole_ticks() {
+    let mut clock = SimulationClock::new();
+
+    assert_eq!(clock.accumulate(0.01), 0);
+    assert_eq!(clock.accumulate(0.01), 1);
+    assert_eq!(clock.accumulate(0.02), 1);
+    assert_eq!(clock.accumulate(SimulationClock::FIXED_DELTA * 2.5), 2);
+  }
+
+  #[test]
+  fn same_frames_run_the_same_ticks() {
+    let frame_deltas = [0.016, 0.033, 0.007, 0.05, 0.1, 0.0169, 0.2];
+    let mut clock = SimulationClock::new();
+    let mut other_clock = SimulationClock::new();
+
+    for frame_delta in frame_deltas {
+      assert_eq!(clock.accumulate(frame_delta), other_clock.accumulate(frame_delta));
+    }
+  }
+
+  #[test]
+  fn long_frames_drop_the_time_left_behind() {
+    let mut clock = SimulationClock::new();
+
+    assert_eq!(clock.accumulate(1.), SimulationClock::MAX_TICKS_PER_FRAME);
+    assert_eq!(clock.accumulate(0.), 1);
+    assert_eq!(clock.accumulate(0.), 0);
+  }
+
+  #[test]
+  fn negative_frame_deltas_are_ignored() {
+    let mut clock = SimulationClock::new();
+
+    assert_eq!(clock.accumulate(-1.), 0);
+    assert_eq!(clock.accumulate(SimulationClock::FIXED_DELTA), 1);
+  }
+
+  #[test]
+  fn elapsed_time_follows_the_ticks() {
+    let mut clock = SimulationClock::new();
+
+    for _ in 0..SimulationClock::TICKS_PER_SECOND * 3 {
+      clock.step();
+    }
+
+    assert_eq!(clock.tick, 180);
+    assert!((clock.get_elapsed_secs() - 3.).abs() < 1e-9);
+  }
+}
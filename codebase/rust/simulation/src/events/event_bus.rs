@@ -0,0 +1,161 @@
+use super::game_event::GameEvent;
+
+pub type SubscriptionId = usize;
+
+type Subscriber = Box<dyn FnMut(&GameEvent)>;
+
+/// Events are queued while the tick goes on and handed to the subscribers by `dispatch`,
+/// once the tick is over, so subscribers never run in the middle of a system step
+#[derive(Default)]
+pub struct EventBus {
+  queued: Vec<GameEvent>,
+  subscribers: Vec<(SubscriptionId, Subscriber)>,
+  next_subscription_id: SubscriptionId,
+}
+
+impl EventBus {
+  pub fn new() -> EventBus {
+    Self::default()
+  }
+
+  /// The subscriber gets every event, in the order they were emitted
+  pub fn subscribe(&mut self, subscriber: impl FnMut(&GameEvent) + 'static) -> SubscriptionId {
+    let subscription_id = self.next_subscription_id;
+    self.next_subscription_id += 1;
+    self.subscribers.push((subscription_id, Box::new(subscriber)));
+
+    subscription_id
+  }
+
+  pub fn unsubscribe(&mut self, subscription_id: SubscriptionId) {
+    self.subscribers.retain(|(id, _)| *id != subscription_id);
+  }
+
+  pub fn emit(&mut self, event: GameEvent) {
+    self.queued.push(event);
+  }
+
+  /// Hands the queued events to the subscribers and returns them
+  pub fn dispatch(&mut self) -> Vec<GameEvent> {
+    let events = std::mem::take(&mut self.queued);
+
+    for event in &events {
+      for (_, subscriber) in self.subscribers.iter_mut() {
+        subscriber(event);
+      }
+    }
+
+    events
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::cell::RefCell;
+  use std::rc::Rc;
+
+  use crate::events::game_event::GameEvent;
+  use crate::players::player::PlayerId;
+
+  use super::{EventBus, SubscriptionId};
+
+  type Received = Rc<RefCell<Vec<(&'static str, GameEvent)>>>;
+
+  fn get_event(player_id: PlayerId) -> GameEvent {
+    GameEvent::PlayerEliminated { player_id }
+  }
+
+  /// Every event the subscriber gets goes to `received`, tagged with `name`
+  fn subscribe(bus: &mut EventBus, received: &Received, name: &'static str) -> SubscriptionId {
+    let received = received.clone();
+    bus.subscribe(move |event| received.borrow_mut().push((name, event.clone())))
+  }
+
+  #[test]
+  fn subscribers_get_the_events_in_the_order_they_were_emitted() {
+    let mut bus = EventBus::new();
+    let received = Received::default();
+    subscribe(&mut bus, &received, "first");
+    subscribe(&mut bus, &received, "second");
+
+    bus.emit(get_event(1));
+    bus.emit(get_event(2));
+    let events = bus.dispatch();
+
+    assert_eq!(events, vec![get_event(1), get_event(2)]);
+    assert_eq!(*received.borrow(), vec![
+      ("first", get_event(1)),
+      ("second", get_event(1)),
+      ("first", get_event(2)),
+      ("second", get_event(2)),
+    ]);
+  }
+
+  #[test]
+  fn events_are_only_handed_out_on_dispatch() {
+    let mut bus = EventBus::new();
+    let received = Received::default();
+    subscribe(&mut bus, &received, "subscriber");
+
+    bus.emit(get_event(1));
+
+    assert!(received.borrow().is_empty());
+  }
+
+  #[test]
+  fn unsubscribed_subscribers_stop_getting_events() {
+    let mut bus = EventBus::new();
+    let received = Received::default();
+    let first = subscribe(&mut bus, &received, "first");
+    let second = subscribe(&mut bus, &received, "second");
+    assert_ne!(first, second);
+
+    bus.unsubscribe(first);
+    bus.emit(get_event(1));
+    bus.dispatch();
+
+    assert_eq!(*received.borrow(), vec![("second", get_event(1))]);
+
+    // Ids aren't reused, so unsubscribing an old id again doesn't drop anyone else
+    let third = subscribe(&mut bus, &received, "third");
+    assert_ne!(third, first);
+    bus.unsubscribe(first);
+    bus.emit(get_event(2));
+    bus.dispatch();
+
+    assert_eq!(received.borrow().len(), 3);
+  }
+
+  #[test]
+  fn dispatch_drains_the_queue() {
+    let mut bus = EventBus::new();
+    let received = Received::default();
+    subscribe(&mut bus, &received, "subscriber");
+
+    bus.emit(get_event(1));
+    bus.dispatch();
+
+    assert!(bus.dispatch().is_empty());
+    assert_eq!(received.borrow().len(), 1);
+  }
+
+  #[test]
+  fn events_emitted_while_a_dispatch_is_handed_out_wait_for_the_next_one() {
+    let mut bus = EventBus::new();
+    let received = Received::default();
+    subscribe(&mut bus, &received, "subscriber");
+
+    bus.emit(get_event(1));
+    // The way `GameEvents::dispatch` emits the signals, whose handlers may emit again
+    for event in bus.dispatch() {
+      let GameEvent::PlayerEliminated { player_id } = event else {
+        panic!("Expected only eliminations");
+      };
+      bus.emit(get_event(player_id + 10));
+    }
+
+    assert_eq!(*received.borrow(), vec![("subscriber", get_event(1))]);
+    assert_eq!(bus.dispatch(), vec![get_event(11)]);
+    assert_eq!(received.borrow().last(), Some(&("subscriber", get_event(11))));
+  }
+}
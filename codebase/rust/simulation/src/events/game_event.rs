@@ -0,0 +1,79 @@
+use serde::{Deserialize, Serialize};
+
+use crate::players::player::PlayerId;
+use crate::territories::territory::TerritoryId;
+use crate::troops::troop::TroopId;
+use crate::victory::standings::MatchOutcome;
+
+/// Something that happened in the match, emitted through the `EventBus`
+/// so HUD, audio, stats and bots can react without polling the territories every frame
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum GameEvent {
+  TroopSpawned {
+    troop_id: TroopId,
+    player_id: PlayerId,
+    territory_id: TerritoryId,
+  },
+  TroopDied {
+    troop_id: TroopId,
+    player_id: PlayerId,
+    territory_id: TerritoryId,
+    /// The troop that fired the lethal hit, it might be dead already
+    killed_by: TroopId,
+    /// Check `Projectile::fired_by_player`
+    killer_player_id: PlayerId,
+  },
+  /// The player started occupying an unoccupied territory or one it's taking over
+  OccupationStarted {
+    territory_id: TerritoryId,
+    player_id: PlayerId,
+  },
+  TerritoryConquered {
+    territory_id: TerritoryId,
+    player_id: PlayerId,
+    taken_from: Option<PlayerId>,
+  },
+  /// Always comes right after the `TerritoryConquered` of a territory that had a ruler
+  TerritoryLost {
+    territory_id: TerritoryId,
+    player_id: PlayerId,
+    lost_to: PlayerId,
+  },
+  /// The troop engaged a combat, emitted once until it leaves the combat
+  CombatStarted {
+    troop_id: TroopId,
+    player_id: PlayerId,
+    territory_id: TerritoryId,
+  },
+  /// No enemies left where the troop was fighting, it isn't emitted for troops dying mid combat
+  CombatEnded {
+    troop_id: TroopId,
+    player_id: PlayerId,
+    territory_id: TerritoryId,
+  },
+  /// The player has no territories nor troops left
+  PlayerEliminated {
+    player_id: PlayerId,
+  },
+  /// The last event of the match, the simulation isn't stepped anymore
+  MatchEnded {
+    outcome: MatchOutcome,
+  },
+}
+
+impl GameEvent {
+  /// Name of the event in snake_case, the Godot `GameEvents` signals are named after it
+  pub fn get_name(&self) -> &'static str {
+    match self {
+      GameEvent::TroopSpawned { .. } => "troop_spawned",
+      GameEvent::TroopDied { .. } => "troop_died",
+      GameEvent::OccupationStarted { .. } => "occupation_started",
+      GameEvent::TerritoryConquered { .. } => "territory_conquered",
+      GameEvent::TerritoryLost { .. } => "territory_lost",
+      GameEvent::CombatStarted { .. } => "combat_started",
+      GameEvent::CombatEnded { .. } => "combat_ended",
+      GameEvent::PlayerEliminated { .. } => "player_eliminated",
+      GameEvent::MatchEnded { .. } => "match_ended",
+    }
+  }
+}
@@ -0,0 +1,2 @@
+pub mod game_event;
+pub mod event_bus;
\ No newline at end of file
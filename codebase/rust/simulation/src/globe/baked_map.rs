@@ -0,0 +1,278 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+use serde::{Deserialize, Serialize};
+
+use crate::math::Vec3;
+use crate::territories::territory::{Territories, TerritoryId};
+
+use super::coordinates::{CoordinateMap, CoordinateMetadata, Latitude, Longitude, Planet};
+
+/// A coordinate of the grid as it's written on the file:
+/// latitude, longitude, cartesian x, y and z, and the index of its territory at `territory_ids`, -1 for the sea
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BakedCoordinate(pub Latitude, pub Longitude, pub f32, pub f32, pub f32, pub i32);
+
+/// The `CoordinateMap` `VirtualPlanet` matched against the territory meshes, written as JSON by the bake step,
+/// so the game, the headless tools and tests know where every territory is without Godot's physics
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BakedCoordinateMap {
+  /// Format of the file, check `BakedCoordinateMap::CURRENT_VERSION`
+  pub version: u32,
+  pub num_of_latitudes: i16,
+  pub num_of_longitudes: i16,
+  pub territory_ids: Vec<TerritoryId>,
+  /// Every coordinate of the grid, in `Planet::all_coordinates` order
+  pub coordinates: Vec<BakedCoordinate>,
+}
+
+/// Why a baked coordinate map can't be used, meant to be shown as is
+#[derive(Debug, Clone, PartialEq)]
+pub enum BakedMapError {
+  Parse(String),
+  UnsupportedVersion(u32),
+  /// The grid of `Planet` changed since the map was baked
+  WrongGrid(i16, i16),
+  /// The coordinate at this index isn't the one `Planet::all_coordinates` has there
+  MisplacedCoordinate(usize),
+  UnknownTerritoryIndex(i32),
+  UnknownTerritory(TerritoryId),
+  /// The territory has no coordinates, it couldn't be played
+  MissingTerritory(TerritoryId),
+}
+
+impl Display for BakedMapError {
+  fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+    match self {
+      BakedMapError::Parse(reason) =>
+        write!(f, "couldn't read the coordinate map: {reason}"),
+      BakedMapError::UnsupportedVersion(version) =>
+        write!(f, "version {version} isn't supported, bake the coordinate map again"),
+      BakedMapError::WrongGrid(num_of_latitudes, num_of_longitudes) =>
+        write!(f, "it was baked for a {num_of_latitudes}x{num_of_longitudes} grid, bake the coordinate map again"),
+      BakedMapError::MisplacedCoordinate(index) =>
+        write!(f, "coordinate #{index} is out of place"),
+      BakedMapError::UnknownTerritoryIndex(territory_index) =>
+        write!(f, "there's no territory #{territory_index}"),
+      BakedMapError::UnknownTerritory(territory_id) =>
+        write!(f, "'{territory_id}' isn't a known territory"),
+      BakedMapError::MissingTerritory(territory_id) =>
+        write!(f, "'{territory_id}' has no coordinates"),
+    }
+  }
+}
+
+impl std::error::Error for BakedMapError {}
+
+impl BakedCoordinateMap {
+  pub const CURRENT_VERSION: u32 = 1;
+
+  /// Expects every coordinate of the grid to be in the map, as `Planet::get_blank_coordinate_map` has them
+  pub fn from_coordinate_map(coordinate_map: &CoordinateMap) -> BakedCoordinateMap {
+    let territory_ids: Vec<TerritoryId> = coordinate_map
+      .values()
+      .filter_map(|coordinate_metadata| coordinate_metadata.territory_id.clone())
+      .collect::<BTreeSet<_>>()
+      .into_iter()
+      .collect();
+    let territory_indexes: BTreeMap<&TerritoryId, i32> = territory_ids
+      .iter()
+      .enumerate()
+      .map(|(index, territory_id)| (territory_id, index as i32))
+      .collect();
+
+    let coordinates = Planet::all_coordinates()
+      .map(|lat_long| {
+        let coordinate_metadata = coordinate_map.get(&lat_long).expect("Expected coordinate to exist");
+        let territory_index = coordinate_metadata.territory_id
+          .as_ref()
+          .map_or(-1, |territory_id| territory_indexes[territory_id]);
+        let Vec3 { x, y, z } = coordinate_metadata.cartesian;
+
+        BakedCoordinate(lat_long.0, lat_long.1, x, y, z, territory_index)
+      })
+      .collect();
+
+    BakedCoordinateMap {
+      version: Self::CURRENT_VERSION,
+      num_of_latitudes: Planet::get_num_of_latitudes(),
+      num_of_longitudes: Planet::get_num_of_longitudes(),
+      territory_ids,
+      coordinates,
+    }
+  }
+
+  pub fn to_json(&self) -> String {
+    serde_json::to_string(self).expect("Expected baked coordinate map to be serializable")
+  }
+
+  pub fn from_json(content: &str) -> Result<BakedCoordinateMap, BakedMapError> {
+    serde_json::from_str(content).map_err(|error| BakedMapError::Parse(error.to_string()))
+  }
+
+  /// Same as `from_json`, but also checks the map fits the grid and the territories
+  pub fn load(content: &str, territories: &Territories) -> Result<BakedCoordinateMap, BakedMapError> {
+    let baked_map = Self::from_json(content)?;
+    baked_map.validate(territories)?;
+    Ok(baked_map)
+  }
+
+  pub fn validate(&self, territories: &Territories) -> Result<(), BakedMapError> {
+    if self.version != Self::CURRENT_VERSION {
+      return Err(BakedMapError::UnsupportedVersion(self.version));
+    }
+
+    if self.num_of_latitudes != Planet::get_num_of_latitudes() ||
+      self.num_of_longitudes != Planet::get_num_of_longitudes() ||
+      self.coordinates.len() != Planet::all_coordinates().count() {
+      return Err(BakedMapError::WrongGrid(self.num_of_latitudes, self.num_of_longitudes));
+    }
+
+    if let Some(territory_id) = self.territory_ids.iter().find(|territory_id| !territories.contains_key(*territory_id)) {
+      return Err(BakedMapError::UnknownTerritory(territory_id.clone()));
+    }
+
+    let mut num_of_coordinates_by_territory = vec![0; self.territory_ids.len()];
+    for (index, (coordinate, lat_long)) in self.coordinates.iter().zip(Planet::all_coordinates()).enumerate() {
+      let BakedCoordinate(latitude, longitude, .., territory_index) = *coordinate;
+      if (latitude, longitude) != lat_long {
+        return Err(BakedMapError::MisplacedCoordinate(index));
+      }
+
+      if territory_index == -1 {
+        continue;
+      }
+      let Some(num_of_coordinates) = usize::try_from(territory_index)
+        .ok()
+        .and_then(|territory_index| num_of_coordinates_by_territory.get_mut(territory_index)) else {
+        return Err(BakedMapError::UnknownTerritoryIndex(territory_index));
+      };
+      *num_of_coordinates += 1;
+    }
+
+    if let Some(territory_id) = territories.keys().find(|territory_id| {
+      self.territory_ids
+        .iter()
+        .position(|baked_territory_id| baked_territory_id == *territory_id)
+        .is_none_or(|territory_index| num_of_coordinates_by_territory[territory_index] == 0)
+    }) {
+      return Err(BakedMapError::MissingTerritory(territory_id.clone()));
+    }
+
+    Ok(())
+  }
+
+  /// Expects a map already checked by `validate`
+  pub fn get_coordinate_map(&self) -> CoordinateMap {
+    self.coordinates
+      .iter()
+      .map(|BakedCoordinate(latitude, longitude, x, y, z, territory_index)| {
+        let territory_id = usize::try_from(*territory_index)
+          .ok()
+          .map(|territory_index| self.territory_ids[territory_index].clone());
+
+        ((*latitude, *longitude), CoordinateMetadata {
+          territory_id,
+          cartesian: Vec3::new(*x, *y, *z),
+        })
+      })
+      .collect()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::globe::coordinates::{CoordinateMap, Planet};
+  use crate::territories::territory::{Continent, Territories, Territory};
+
+  use super::{BakedCoordinateMap, BakedMapError};
+
+  fn get_territories() -> Territories {
+    ["west", "east"]
+      .into_iter()
+      .map(|territory_id| (territory_id.to_owned(), Territory::get_base_territory(territory_id, Continent::Europe, None)))
+      .collect()
+  }
+
+  fn get_coordinate_map() -> CoordinateMap {
+    let mut coordinate_map = Planet::get_blank_coordinate_map();
+    Planet::add_territory(&mut coordinate_map, "west", 100..110, 0..10);
+    Planet::add_territory(&mut coordinate_map, "east", 100..110, 10..20);
+    coordinate_map
+  }
+
+  fn get_baked_map() -> BakedCoordinateMap {
+    BakedCoordinateMap::from_coordinate_map(&get_coordinate_map())
+  }
+
+  #[test]
+  fn baked_maps_load_back_the_same_coordinate_map() {
+    let content = get_baked_map().to_json();
+
+    let baked_map = BakedCoordinateMap::load(&content, &get_territories()).expect("Expected baked map to be loaded");
+
+    assert_eq!(baked_map.territory_ids, vec!["east".to_owned(), "west".to_owned()]);
+    assert_eq!(baked_map.get_coordinate_map(), get_coordinate_map());
+  }
+
+  #[test]
+  fn maps_baked_for_another_grid_are_turned_down() {
+    let mut baked_map = get_baked_map();
+    baked_map.num_of_longitudes += 1;
+    assert_eq!(
+      baked_map.validate(&get_territories()),
+      Err(BakedMapError::WrongGrid(Planet::get_num_of_latitudes(), Planet::get_num_of_longitudes() + 1)),
+    );
+
+    let mut baked_map = get_baked_map();
+    baked_map.coordinates.pop();
+    assert!(matches!(baked_map.validate(&get_territories()), Err(BakedMapError::WrongGrid(..))));
+  }
+
+  #[test]
+  fn coordinates_out_of_place_are_turned_down() {
+    let mut baked_map = get_baked_map();
+    baked_map.coordinates.swap(3, 4);
+
+    assert_eq!(baked_map.validate(&get_territories()), Err(BakedMapError::MisplacedCoordinate(3)));
+  }
+
+  #[test]
+  fn territory_indexes_have_to_be_in_the_territory_ids() {
+    for territory_index in [2, -2] {
+      let mut baked_map = get_baked_map();
+      baked_map.coordinates[0].5 = territory_index;
+
+      assert_eq!(baked_map.validate(&get_territories()), Err(BakedMapError::UnknownTerritoryIndex(territory_index)));
+    }
+  }
+
+  #[test]
+  fn every_territory_needs_coordinates() {
+    let mut territories = get_territories();
+    territories.insert("north".to_owned(), Territory::get_base_territory("north", Continent::Europe, None));
+    assert_eq!(get_baked_map().validate(&territories), Err(BakedMapError::MissingTerritory("north".to_owned())));
+
+    // Listed, but none of the coordinates are its own
+    let mut baked_map = get_baked_map();
+    for coordinate in &mut baked_map.coordinates {
+      if coordinate.5 == 0 {
+        coordinate.5 = 1;
+      }
+    }
+    assert_eq!(baked_map.validate(&get_territories()), Err(BakedMapError::MissingTerritory("east".to_owned())));
+  }
+
+  #[test]
+  fn unknown_territories_and_versions_are_turned_down() {
+    let mut territories = get_territories();
+    territories.remove("east");
+    assert_eq!(get_baked_map().validate(&territories), Err(BakedMapError::UnknownTerritory("east".to_owned())));
+
+    let mut baked_map = get_baked_map();
+    baked_map.version = BakedCoordinateMap::CURRENT_VERSION + 1;
+    assert_eq!(baked_map.validate(&get_territories()), Err(BakedMapError::UnsupportedVersion(baked_map.version)));
+
+    assert!(matches!(BakedCoordinateMap::load("{", &get_territories()), Err(BakedMapError::Parse(_))));
+  }
+}
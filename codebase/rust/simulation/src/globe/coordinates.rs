@@ -0,0 +1,236 @@
+use std::collections::HashMap;
+use std::f64::consts::PI;
+#[cfg(test)]
+use std::ops::Range;
+
+use crate::math::Vec3;
+use crate::territories::territory::TerritoryId;
+
+pub type Latitude = i16;
+pub type Longitude = i16;
+pub type Coordinates = (Latitude, Longitude);
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CoordinateMetadata {
+  pub territory_id: Option<TerritoryId>,
+  pub cartesian: Vec3,
+}
+
+/// It maps the coordinates of the planet to the metadata of the coordinates
+/// Populated by the `VirtualPlanet::populate_surface_points_and_coordinate_map` method
+pub type CoordinateMap = HashMap<Coordinates, CoordinateMetadata>;
+
+/// Dimensions of the lat/long grid laid over the globe
+pub struct Planet {}
+
+impl Planet {
+  /// Following inline functions have pseudo-arbitrary numbers defined after checking the globe mesh size
+  /// that's the reason they all seem to be magic numbers
+  #[inline] pub fn get_planet_radius() -> f64 { 1.0795 * 3.0 }
+  #[inline] pub fn get_num_of_latitudes() -> i16 { (90. * 2.5) as i16 }
+  #[inline] pub fn get_num_of_longitudes() -> i16 { (180. * 2.5) as i16 }
+
+  /// Every lat/long of the grid, in the same order `VirtualPlanet` creates its `SurfacePoint`s
+  pub fn all_coordinates() -> impl Iterator<Item = Coordinates> {
+    (0..Self::get_num_of_latitudes()).flat_map(|lat| {
+      (0..Self::get_num_of_longitudes()).map(move |long| (lat, long))
+    })
+  }
+
+  /// Receives a latitude and longitude and returns the cartesian position on the planet surface
+  /// theta goes from 0 to pi along the latitudes, phi from 0 to 2pi along the longitudes
+  pub fn get_cartesian_from_coordinates(coordinates: Coordinates) -> Vec3 {
+    let (lat, long) = coordinates;
+    let planet_radius = Self::get_planet_radius();
+
+    let theta = (lat as f64) * PI / (Self::get_num_of_latitudes() as f64);
+    let phi = (long as f64) * 2.0 * PI / (Self::get_num_of_longitudes() as f64);
+
+    Vec3::new(
+      (planet_radius * theta.sin() * phi.cos()) as f32,
+      (planet_radius * theta.sin() * phi.sin()) as f32,
+      (planet_radius * theta.cos()) as f32,
+    )
+  }
+
+  /// Inverse of `get_cartesian_from_coordinates`: returns the lat/long of the grid closest to where
+  /// the line from the planet center to `position` crosses the surface, so `position` may be above or below it
+  /// Returns None for the planet center, as it has no direction
+  pub fn get_coordinates_from_cartesian(position: Vec3) -> Option<Coordinates> {
+    let direction = position.try_normalized()?;
+    let num_of_latitudes = Self::get_num_of_latitudes();
+    let num_of_longitudes = Self::get_num_of_longitudes();
+
+    let theta = (direction.z as f64).clamp(-1., 1.).acos();
+    let phi = (direction.y as f64).atan2(direction.x as f64).rem_euclid(2.0 * PI);
+
+    // theta == pi would be one latitude past the grid, the closest one is the last
+    let lat = ((theta * (num_of_latitudes as f64) / PI).round() as i16).min(num_of_latitudes - 1);
+    let long = ((phi * (num_of_longitudes as f64) / (2.0 * PI)).round() as i16).rem_euclid(num_of_longitudes);
+
+    Some((lat, long))
+  }
+
+  /// Finds the coordinate under `position` with math only, no physics query involved,
+  /// so it's cheap enough to be called per frame and from any thread
+  pub fn get_coordinate_metadata_from_cartesian(
+    coordinate_map: &CoordinateMap,
+    position: Vec3
+  ) -> Option<(Coordinates, &CoordinateMetadata)> {
+    let coordinates = Self::get_coordinates_from_cartesian(position)?;
+    let coordinate_metadata = coordinate_map.get(&coordinates)?;
+    Some((coordinates, coordinate_metadata))
+  }
+
+  /// Returns a coordinate map with every lat/long and no territory matched yet
+  pub fn get_blank_coordinate_map() -> CoordinateMap {
+    Self::all_coordinates()
+      .map(|lat_long| (lat_long, CoordinateMetadata {
+        territory_id: None,
+        cartesian: Self::get_cartesian_from_coordinates(lat_long),
+      }))
+      .collect()
+  }
+
+  /// Matches every lat/long of the rectangle to `territory_id` and returns them, lays out the maps of the tests
+  #[cfg(test)]
+  pub(crate) fn add_territory(
+    coordinate_map: &mut CoordinateMap,
+    territory_id: &str,
+    latitudes: Range<Latitude>,
+    longitudes: Range<Longitude>,
+  ) -> Vec<Coordinates> {
+    let coordinates: Vec<Coordinates> = latitudes
+      .flat_map(|lat| longitudes.clone().map(move |long| (lat, long)))
+      .collect();
+
+    for lat_long in &coordinates {
+      coordinate_map.get_mut(lat_long).expect("Expected coordinate to exist").territory_id = Some(territory_id.to_owned());
+    }
+
+    coordinates
+  }
+
+  /// Brings a lat/long that stepped off the grid back into it:
+  /// longitudes wrap around, and stepping past a pole lands on the opposite longitude,
+  /// on the other side of that pole
+  pub fn get_wrapped_coordinates(latitude: i16, longitude: i16) -> Coordinates {
+    let num_of_latitudes = Self::get_num_of_latitudes();
+    let num_of_longitudes = Self::get_num_of_longitudes();
+
+    let (latitude, longitude) = if latitude < 0 {
+      // Latitude 0 is the north pole itself
+      (-latitude, longitude + num_of_longitudes / 2)
+    } else if latitude >= num_of_latitudes {
+      // The south pole sits between the last latitude and the one past it
+      (2 * num_of_latitudes - 1 - latitude, longitude + num_of_longitudes / 2)
+    } else {
+      (latitude, longitude)
+    };
+
+    (latitude, longitude.rem_euclid(num_of_longitudes))
+  }
+
+  /// Returns the 8 neighbors of a coordinate on the grid, wrapping around the longitudes and over the poles
+  pub fn get_neighbors(current_coordinate: Coordinates) -> [Coordinates; 8] {
+    const BUFFER: i16 = 1;
+
+    let (latitude, longitude) = current_coordinate;
+
+    let latitude_east = latitude + BUFFER;
+    let latitude_west = latitude - BUFFER;
+    let longitude_north = longitude + BUFFER;
+    let longitude_south = longitude - BUFFER;
+
+    [
+      Self::get_wrapped_coordinates(latitude, longitude_north), // ↑ Northern Neighbor
+      Self::get_wrapped_coordinates(latitude, longitude_south), // ↓ Southern Neighbor
+      Self::get_wrapped_coordinates(latitude_east, longitude), // → Eastern Neighbor
+      Self::get_wrapped_coordinates(latitude_west, longitude), // ← Western Neighbor
+
+      Self::get_wrapped_coordinates(latitude_east, longitude_north), // ↑← Northeastern Neighbor
+      Self::get_wrapped_coordinates(latitude_west, longitude_north), // ↑← Northwestern Neighbor
+      Self::get_wrapped_coordinates(latitude_east, longitude_south), // ↓→ Southeastern Neighbor
+      Self::get_wrapped_coordinates(latitude_west, longitude_south), // ↓→ Southwestern Neighbor
+    ]
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::f64::consts::PI;
+
+  use crate::math::Vec3;
+
+  use super::Planet;
+
+  #[test]
+  fn cartesian_goes_back_to_the_same_coordinates() {
+    // Every longitude of latitude 0 is the north pole itself
+    for lat_long in Planet::all_coordinates().filter(|(lat, _)| *lat > 0) {
+      let position = Planet::get_cartesian_from_coordinates(lat_long);
+
+      assert_eq!(Planet::get_coordinates_from_cartesian(position), Some(lat_long));
+    }
+  }
+
+  #[test]
+  fn positions_above_or_below_the_surface_have_the_same_coordinates() {
+    let lat_long = (80, 123);
+    let position = Planet::get_cartesian_from_coordinates(lat_long);
+
+    assert_eq!(Planet::get_coordinates_from_cartesian(position * 1.5), Some(lat_long));
+    assert_eq!(Planet::get_coordinates_from_cartesian(position * 0.2), Some(lat_long));
+    assert_eq!(Planet::get_coordinates_from_cartesian(Vec3::ZERO), None);
+  }
+
+  #[test]
+  fn poles_stay_within_the_grid() {
+    let north_pole = Vec3::new(0., 0., 1.);
+    let south_pole = Vec3::new(0., 0., -1.);
+
+    assert_eq!(Planet::get_coordinates_from_cartesian(north_pole), Some((0, 0)));
+    assert_eq!(Planet::get_coordinates_from_cartesian(south_pole), Some((Planet::get_num_of_latitudes() - 1, 0)));
+  }
+
+  #[test]
+  fn longitudes_wrap_around_the_seam() {
+    let num_of_longitudes = Planet::get_num_of_longitudes();
+    let half_a_longitude = PI / num_of_longitudes as f64;
+    let near_the_seam = |phi: f64| Vec3::new(phi.cos() as f32, phi.sin() as f32, 0.);
+
+    // Right before the seam it rounds to the first longitude, not to one past the last
+    assert_eq!(Planet::get_coordinates_from_cartesian(near_the_seam(-half_a_longitude * 0.5)).map(|(_, long)| long), Some(0));
+    assert_eq!(
+      Planet::get_coordinates_from_cartesian(near_the_seam(-half_a_longitude * 1.5)).map(|(_, long)| long),
+      Some(num_of_longitudes - 1)
+    );
+    assert_eq!(Planet::get_coordinates_from_cartesian(near_the_seam(half_a_longitude * 0.5)).map(|(_, long)| long), Some(0));
+  }
+
+  #[test]
+  fn coordinates_off_the_grid_are_wrapped_into_it() {
+    let num_of_latitudes = Planet::get_num_of_latitudes();
+    let num_of_longitudes = Planet::get_num_of_longitudes();
+
+    assert_eq!(Planet::get_wrapped_coordinates(10, -1), (10, num_of_longitudes - 1));
+    assert_eq!(Planet::get_wrapped_coordinates(10, num_of_longitudes), (10, 0));
+    assert_eq!(Planet::get_wrapped_coordinates(-1, 10), (1, 10 + num_of_longitudes / 2));
+    assert_eq!(Planet::get_wrapped_coordinates(num_of_latitudes, 10), (num_of_latitudes - 1, 10 + num_of_longitudes / 2));
+  }
+
+  #[test]
+  fn neighbors_are_on_the_grid_and_close_by() {
+    let grid_step = Planet::get_cartesian_from_coordinates((112, 0)).distance_to(Planet::get_cartesian_from_coordinates((112, 1)));
+
+    for lat_long in [(0, 0), (1, 449), (224, 200), (112, 0)] {
+      let position = Planet::get_cartesian_from_coordinates(lat_long);
+
+      for (lat, long) in Planet::get_neighbors(lat_long) {
+        assert!((0..Planet::get_num_of_latitudes()).contains(&lat));
+        assert!((0..Planet::get_num_of_longitudes()).contains(&long));
+        assert!(position.distance_to(Planet::get_cartesian_from_coordinates((lat, long))) < grid_step * 2.5);
+      }
+    }
+  }
+}
@@ -0,0 +1,4 @@
+pub mod coordinates;
+pub mod trajectory;
+pub mod baked_map;
+pub mod pathfinding;
\ No newline at end of file
@@ -0,0 +1,408 @@
+use std::cmp::Ordering;
+use std::collections::{BTreeSet, BinaryHeap, HashMap, HashSet};
+
+use crate::territories::territory::TerritoryId;
+use crate::troops::surface::Surface;
+
+use super::coordinates::{CoordinateMap, CoordinateMetadata, Coordinates, Planet};
+
+/// Which coordinates a path is allowed to go through, the sea is always allowed
+#[derive(Debug, Clone, PartialEq)]
+pub enum PathConstraint {
+  Anywhere,
+  /// Can't step on any territory but this one
+  StayInside(TerritoryId),
+  /// Can't step on any of these territories, e.g. the enemy ones
+  Avoid(BTreeSet<TerritoryId>),
+}
+
+impl PathConstraint {
+  pub fn allows(&self, coordinate_metadata: &CoordinateMetadata) -> bool {
+    let Some(territory_id) = &coordinate_metadata.territory_id else {
+      return true;
+    };
+
+    match self {
+      PathConstraint::Anywhere => true,
+      PathConstraint::StayInside(allowed_territory_id) => territory_id == allowed_territory_id,
+      PathConstraint::Avoid(avoided_territory_ids) => !avoided_territory_ids.contains(territory_id),
+    }
+  }
+}
+
+/// A coordinate waiting to be expanded, ordered by its estimated cost so `BinaryHeap` pops the cheapest first
+struct OpenCoordinate {
+  estimated_cost: f32,
+  coordinates: Coordinates,
+}
+
+impl PartialEq for OpenCoordinate {
+  fn eq(&self, other: &Self) -> bool {
+    self.cmp(other) == Ordering::Equal
+  }
+}
+
+impl Eq for OpenCoordinate {}
+
+impl PartialOrd for OpenCoordinate {
+  fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+    Some(self.cmp(other))
+  }
+}
+
+impl Ord for OpenCoordinate {
+  /// Reversed, as `BinaryHeap` is a max-heap. Ties are broken by the coordinates so the search is deterministic
+  fn cmp(&self, other: &Self) -> Ordering {
+    other.estimated_cost
+      .total_cmp(&self.estimated_cost)
+      .then_with(|| other.coordinates.cmp(&self.coordinates))
+  }
+}
+
+/// A* over the lat/long grid of `Planet`, moving between the 8 neighbors of every coordinate
+pub struct Pathfinder {}
+
+impl Pathfinder {
+  /// Indexes at `Planet::get_neighbors` of the two neighbors every diagonal one sits between
+  const DIAGONAL_CORNERS: [[usize; 2]; 4] = [[2, 0], [3, 0], [2, 1], [3, 1]];
+
+  /// Returns the shortest path from `origin` to `destination`, both included,
+  /// walking only through the coordinates the constraint allows.
+  /// Both ends are always allowed, so a troop can leave or approach a forbidden coordinate
+  ///
+  /// The cost of every step is the angle between both coordinates, seen from the planet center,
+  /// and the estimate is the angle straight to the destination, so the path found is the shortest one
+  ///
+  /// Returns None if the constraint leaves no way through, or if any end isn't on the map
+  pub fn find_path(
+    coordinate_map: &CoordinateMap,
+    origin: Coordinates,
+    destination: Coordinates,
+    constraint: &PathConstraint,
+  ) -> Option<Vec<Coordinates>> {
+    Self::search(coordinate_map, origin, destination, constraint, None)
+  }
+
+  /// Same as `find_path`, but every step between the land and the sea costs `surface_change_cost` more,
+  /// so the path embarks and disembarks as few times as it can and sails around the land in between.
+  /// It switches surface only at the coast, and never cuts a corner of the other surface, check `cuts_a_corner`
+  pub fn find_naval_path(
+    coordinate_map: &CoordinateMap,
+    origin: Coordinates,
+    destination: Coordinates,
+    constraint: &PathConstraint,
+    surface_change_cost: f32,
+  ) -> Option<Vec<Coordinates>> {
+    Self::search(coordinate_map, origin, destination, constraint, Some(surface_change_cost))
+  }
+
+  /// The naval rules only apply with a `surface_change_cost`
+  fn search(
+    coordinate_map: &CoordinateMap,
+    origin: Coordinates,
+    destination: Coordinates,
+    constraint: &PathConstraint,
+    surface_change_cost: Option<f32>,
+  ) -> Option<Vec<Coordinates>> {
+    let destination_metadata = coordinate_map.get(&destination)?;
+    let origin_metadata = coordinate_map.get(&origin)?;
+
+    let mut open_coordinates = BinaryHeap::new();
+    let mut came_from: HashMap<Coordinates, Coordinates> = HashMap::new();
+    let mut cost_so_far: HashMap<Coordinates, f32> = HashMap::new();
+    let mut expanded: HashSet<Coordinates> = HashSet::new();
+
+    cost_so_far.insert(origin, 0.);
+    open_coordinates.push(OpenCoordinate {
+      estimated_cost: Self::estimate_cost(origin_metadata, destination_metadata, surface_change_cost),
+      coordinates: origin,
+    });
+
+    while let Some(OpenCoordinate { coordinates, .. }) = open_coordinates.pop() {
+      if coordinates == destination {
+        return Some(Self::back_trace(&came_from, origin, destination));
+      }
+      // A coordinate may be queued more than once, only its cheapest entry matters
+      if !expanded.insert(coordinates) {
+        continue;
+      }
+
+      let current_cost = cost_so_far[&coordinates];
+      let current_metadata = &coordinate_map[&coordinates];
+      let current_surface = Surface::from_coordinate_metadata(current_metadata);
+
+      let neighbors = Planet::get_neighbors(coordinates);
+      for (index, neighbor) in neighbors.iter().copied().enumerate() {
+        let Some(neighbor_metadata) = coordinate_map.get(&neighbor) else {
+          continue;
+        };
+        if neighbor != destination && !constraint.allows(neighbor_metadata) {
+          continue;
+        }
+
+        let mut neighbor_cost = current_cost + current_metadata.cartesian.angle_to(neighbor_metadata.cartesian);
+        if let Some(surface_change_cost) = surface_change_cost {
+          let neighbor_surface = Surface::from_coordinate_metadata(neighbor_metadata);
+          if Self::cuts_a_corner(coordinate_map, &neighbors, index, current_surface, neighbor_surface) {
+            continue;
+          }
+          if neighbor_surface != current_surface {
+            neighbor_cost += surface_change_cost;
+          }
+        }
+        if cost_so_far.get(&neighbor).is_some_and(|known_cost| *known_cost <= neighbor_cost) {
+          continue;
+        }
+
+        cost_so_far.insert(neighbor, neighbor_cost);
+        came_from.insert(neighbor, coordinates);
+        open_coordinates.push(OpenCoordinate {
+          estimated_cost: neighbor_cost + Self::estimate_cost(neighbor_metadata, destination_metadata, surface_change_cost),
+          coordinates: neighbor,
+        });
+      }
+    }
+
+    None
+  }
+
+  /// The angle straight to the destination, plus a surface change when the naval rules apply
+  /// and the destination is on the other surface, as there's no getting there without one
+  fn estimate_cost(
+    coordinate_metadata: &CoordinateMetadata,
+    destination_metadata: &CoordinateMetadata,
+    surface_change_cost: Option<f32>,
+  ) -> f32 {
+    let angle = coordinate_metadata.cartesian.angle_to(destination_metadata.cartesian);
+    match surface_change_cost {
+      Some(surface_change_cost) if
+        Surface::from_coordinate_metadata(coordinate_metadata) != Surface::from_coordinate_metadata(destination_metadata) =>
+        angle + surface_change_cost,
+      _ => angle,
+    }
+  }
+
+  /// Diagonal steps are only taken with the 4 coordinates around them on the same surface,
+  /// otherwise the great circle between both ends would graze the coast in between
+  fn cuts_a_corner(
+    coordinate_map: &CoordinateMap,
+    neighbors: &[Coordinates; 8],
+    neighbor_index: usize,
+    current_surface: Surface,
+    neighbor_surface: Surface,
+  ) -> bool {
+    let Some(corners) = neighbor_index.checked_sub(4).map(|diagonal_index| Self::DIAGONAL_CORNERS[diagonal_index]) else {
+      return false;
+    };
+
+    neighbor_surface != current_surface ||
+      corners.iter().any(|corner| {
+        coordinate_map
+          .get(&neighbors[*corner])
+          .is_none_or(|corner_metadata| Surface::from_coordinate_metadata(corner_metadata) != current_surface)
+      })
+  }
+
+  fn back_trace(
+    came_from: &HashMap<Coordinates, Coordinates>,
+    origin: Coordinates,
+    destination: Coordinates,
+  ) -> Vec<Coordinates> {
+    let mut path = vec![destination];
+    let mut coordinates = destination;
+
+    while coordinates != origin {
+      coordinates = came_from[&coordinates];
+      path.push(coordinates);
+    }
+
+    path.reverse();
+    path
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::collections::BTreeSet;
+  use crate::globe::coordinates::{CoordinateMap, Coordinates, Planet};
+  use crate::troops::surface::Surface;
+
+  use super::{PathConstraint, Pathfinder};
+
+  const SURFACE_CHANGE_COST: f32 = 0.1;
+
+  fn assert_is_walkable(path: &[Coordinates], origin: Coordinates, destination: Coordinates) {
+    assert_eq!(path.first(), Some(&origin));
+    assert_eq!(path.last(), Some(&destination));
+    assert!(path.windows(2).all(|step| Planet::get_neighbors(step[0]).contains(&step[1])));
+  }
+
+  fn get_surface(coordinate_map: &CoordinateMap, coordinates: Coordinates) -> Surface {
+    Surface::from_coordinate_metadata(&coordinate_map[&coordinates])
+  }
+
+  /// Far from the poles and the seam, so the coordinates in between don't need to be wrapped
+  fn get_corners(from: Coordinates, to: Coordinates) -> Option<[Coordinates; 2]> {
+    let is_diagonal = from.0 != to.0 && from.1 != to.1;
+    is_diagonal.then_some([(from.0, to.1), (to.0, from.1)])
+  }
+
+  #[test]
+  fn path_wraps_around_the_longitudes() {
+    let coordinate_map = Planet::get_blank_coordinate_map();
+    let (origin, destination) = ((112, 445), (112, 5));
+
+    let path = Pathfinder::find_path(&coordinate_map, origin, destination, &PathConstraint::Anywhere)
+      .expect("Expected a path");
+
+    assert_is_walkable(&path, origin, destination);
+    assert_eq!(path.len(), 11);
+  }
+
+  #[test]
+  fn path_goes_over_the_poles() {
+    let coordinate_map = Planet::get_blank_coordinate_map();
+    let half_of_the_longitudes = Planet::get_num_of_longitudes() / 2;
+    let (origin, destination) = ((2, 0), (2, half_of_the_longitudes));
+
+    let path = Pathfinder::find_path(&coordinate_map, origin, destination, &PathConstraint::Anywhere)
+      .expect("Expected a path");
+
+    assert_is_walkable(&path, origin, destination);
+    assert!(path.len() <= 6, "Expected the path to cross the north pole, it has {} steps", path.len());
+  }
+
+  #[test]
+  fn path_stays_inside_the_territory() {
+    let mut coordinate_map = Planet::get_blank_coordinate_map();
+    Planet::add_territory(&mut coordinate_map, "home", 100..110, 100..120);
+    Planet::add_territory(&mut coordinate_map, "other", 100..110, 120..140);
+    let (origin, destination) = ((105, 101), (105, 118));
+    let constraint = PathConstraint::StayInside("home".to_owned());
+
+    let path = Pathfinder::find_path(&coordinate_map, origin, destination, &constraint).expect("Expected a path");
+
+    assert_is_walkable(&path, origin, destination);
+    assert!(path.iter().all(|coordinates| constraint.allows(&coordinate_map[coordinates])));
+  }
+
+  #[test]
+  fn path_avoids_the_territories_in_the_way() {
+    let mut coordinate_map = Planet::get_blank_coordinate_map();
+    Planet::add_territory(&mut coordinate_map, "enemy", 90..130, 110..112);
+    let (origin, destination) = ((110, 100), (110, 120));
+    let constraint = PathConstraint::Avoid(BTreeSet::from(["enemy".to_owned()]));
+
+    let straight_path = Pathfinder::find_path(&coordinate_map, origin, destination, &PathConstraint::Anywhere)
+      .expect("Expected a path");
+    let path = Pathfinder::find_path(&coordinate_map, origin, destination, &constraint).expect("Expected a path");
+
+    assert_is_walkable(&path, origin, destination);
+    assert!(!straight_path.iter().all(|coordinates| constraint.allows(&coordinate_map[coordinates])));
+    assert!(path.iter().all(|coordinates| constraint.allows(&coordinate_map[coordinates])));
+    assert!(path.len() > straight_path.len());
+  }
+
+  #[test]
+  fn forbidden_ends_are_still_reached() {
+    let mut coordinate_map = Planet::get_blank_coordinate_map();
+    Planet::add_territory(&mut coordinate_map, "enemy", 100..110, 100..110);
+    // Both on the edge of the territory, the path goes around it instead of across
+    let (origin, destination) = ((109, 105), (100, 105));
+    let constraint = PathConstraint::Avoid(BTreeSet::from(["enemy".to_owned()]));
+
+    let path = Pathfinder::find_path(&coordinate_map, origin, destination, &constraint).expect("Expected a path");
+
+    assert_is_walkable(&path, origin, destination);
+    assert_eq!(path.iter().filter(|coordinates| !constraint.allows(&coordinate_map[*coordinates])).count(), 2);
+  }
+
+  #[test]
+  fn no_path_when_there_is_no_way_through() {
+    let mut coordinate_map = Planet::get_blank_coordinate_map();
+    Planet::add_territory(&mut coordinate_map, "island", 100..110, 100..110);
+    Planet::add_territory(&mut coordinate_map, "mainland", 100..110, 110..120);
+    let constraint = PathConstraint::StayInside("island".to_owned());
+
+    // The sea is always allowed, so the island is only cut off from the mainland once it's all land around it
+    let mut landlocked_map = coordinate_map.clone();
+    Planet::add_territory(&mut landlocked_map, "mainland", 95..115, 95..125);
+    Planet::add_territory(&mut landlocked_map, "island", 100..110, 100..110);
+
+    assert!(Pathfinder::find_path(&coordinate_map, (105, 105), (100, 115), &constraint).is_some());
+    assert_eq!(Pathfinder::find_path(&landlocked_map, (105, 105), (100, 115), &constraint), None);
+  }
+
+  #[test]
+  fn no_path_when_an_end_is_off_the_map() {
+    let coordinate_map = Planet::get_blank_coordinate_map();
+
+    assert_eq!(Pathfinder::find_path(&coordinate_map, (-1, 0), (10, 10), &PathConstraint::Anywhere), None);
+    assert_eq!(Pathfinder::find_path(&coordinate_map, (10, 10), (10, 999), &PathConstraint::Anywhere), None);
+  }
+
+  #[test]
+  fn naval_path_doesnt_slip_between_two_land_corners() {
+    let mut coordinate_map = Planet::get_blank_coordinate_map();
+    Planet::add_territory(&mut coordinate_map, "north", 100..101, 100..101);
+    Planet::add_territory(&mut coordinate_map, "south", 101..102, 101..102);
+    let (origin, destination) = ((101, 100), (100, 101));
+
+    let path = Pathfinder::find_path(&coordinate_map, origin, destination, &PathConstraint::Anywhere)
+      .expect("Expected a path");
+    let naval_path = Pathfinder::find_naval_path(&coordinate_map, origin, destination, &PathConstraint::Anywhere, SURFACE_CHANGE_COST)
+      .expect("Expected a path");
+
+    assert_eq!(path, vec![origin, destination]);
+    assert_is_walkable(&naval_path, origin, destination);
+    assert!(naval_path.len() > 2);
+    assert!(naval_path.iter().all(|coordinates| get_surface(&coordinate_map, *coordinates) == Surface::Sea));
+  }
+
+  #[test]
+  fn naval_path_only_takes_diagonal_steps_in_open_water_or_inland() {
+    let mut coordinate_map = Planet::get_blank_coordinate_map();
+    Planet::add_territory(&mut coordinate_map, "coast", 90..130, 100..110);
+    Planet::add_territory(&mut coordinate_map, "cape", 105..110, 110..116);
+    let (origin, destination) = ((95, 102), (125, 120));
+
+    let path = Pathfinder::find_naval_path(&coordinate_map, origin, destination, &PathConstraint::Anywhere, SURFACE_CHANGE_COST)
+      .expect("Expected a path");
+
+    assert_is_walkable(&path, origin, destination);
+    for step in path.windows(2) {
+      let surface = get_surface(&coordinate_map, step[0]);
+      let Some(corners) = get_corners(step[0], step[1]) else {
+        continue;
+      };
+
+      assert_eq!(get_surface(&coordinate_map, step[1]), surface, "Expected {step:?} to stay on the same surface");
+      assert!(
+        corners.iter().all(|corner| get_surface(&coordinate_map, *corner) == surface),
+        "Expected {step:?} not to cut a corner"
+      );
+    }
+  }
+
+  #[test]
+  fn naval_path_changes_surface_as_few_times_as_it_can() {
+    let mut coordinate_map = Planet::get_blank_coordinate_map();
+    // A bay between two capes of the same land, sailing across it would embark and disembark
+    Planet::add_territory(&mut coordinate_map, "land", 100..130, 100..104);
+    Planet::add_territory(&mut coordinate_map, "land", 100..104, 104..112);
+    Planet::add_territory(&mut coordinate_map, "land", 126..130, 104..112);
+    let (origin, destination) = ((101, 110), (128, 110));
+
+    let path = Pathfinder::find_naval_path(&coordinate_map, origin, destination, &PathConstraint::Anywhere, SURFACE_CHANGE_COST)
+      .expect("Expected a path");
+    let surface_changes = path
+      .windows(2)
+      .filter(|step| get_surface(&coordinate_map, step[0]) != get_surface(&coordinate_map, step[1]))
+      .count();
+
+    assert_is_walkable(&path, origin, destination);
+    assert_eq!(surface_changes, 0);
+    assert!(Pathfinder::find_path(&coordinate_map, origin, destination, &PathConstraint::Anywhere)
+      .is_some_and(|straight_path| straight_path.len() < path.len()));
+  }
+}
@@ -0,0 +1,189 @@
+use crate::math::Vec3;
+use crate::territories::territory::TerritoryId;
+
+use super::coordinates::{CoordinateMap, Planet};
+use super::pathfinding::{PathConstraint, Pathfinder};
+
+pub struct Trajectory {}
+
+impl Trajectory {
+  /// Even the shortest hop gets that many waypoints, so the moving point still follows the globe curvature
+  pub const MIN_NUM_OF_WAYPOINTS: usize = 10;
+
+  /// The longest trips are capped there, so half-planet deployments don't take forever to generate
+  pub const MAX_NUM_OF_WAYPOINTS: usize = 50;
+
+  /// Arc length in between two waypoints, before the count is clamped
+  pub const WAYPOINT_SPACING: f32 = 0.2;
+
+  /// How many waypoints the trajectory in between both positions has, depending on its arc length
+  /// and clamped between `MIN_NUM_OF_WAYPOINTS` and `MAX_NUM_OF_WAYPOINTS`
+  pub fn get_num_of_waypoints(origin: Vec3, destination: Vec3, radius: f32) -> usize {
+    let arc_length = origin.angle_to(destination) * radius;
+    let num_of_waypoints = (arc_length / Self::WAYPOINT_SPACING).ceil() as usize + 1;
+
+    num_of_waypoints.clamp(Self::MIN_NUM_OF_WAYPOINTS, Self::MAX_NUM_OF_WAYPOINTS)
+  }
+
+  /// Receives the origin and destination positions and
+  /// returns the positions of the trajectory where a moving point would pass by.
+  ///
+  /// The waypoints are calculated as they're iterated, check `get_num_of_waypoints`,
+  /// this way nothing is allocated unless the caller collects them.
+  pub fn get_geodesic_trajectory(
+    origin: Vec3,
+    destination: Vec3,
+    radius: f32
+  ) -> impl ExactSizeIterator<Item = Vec3> {
+    let origin = origin.normalized();
+    let destination = destination.normalized();
+    let num_of_waypoints = Self::get_num_of_waypoints(origin, destination, radius);
+
+    (0..num_of_waypoints).map(move |i| {
+      let t = i as f64 / (num_of_waypoints - 1) as f64;
+      origin.slerp(destination, t as f32) * radius
+    })
+  }
+
+  /// Finds the shortest way between both positions without stepping on any other territory,
+  /// check `Pathfinder::find_path`
+  ///
+  /// If it doesnt pass by other territories, or if there's no way around them,
+  /// it returns the geodesic trajectory.
+  pub fn get_in_the_frontiers_trajectory(
+    coordinate_map: &CoordinateMap,
+    origin: Vec3,
+    destination: Vec3,
+    radius: f32,
+    within_the_territory_id: &TerritoryId,
+  ) -> Vec<Vec3> {
+    let base_geodesic_trajectory: Vec<Vec3> = Self::get_geodesic_trajectory(origin, destination, radius).collect();
+
+    if !Self::passes_by_other_territories(coordinate_map, &base_geodesic_trajectory, within_the_territory_id) {
+      return base_geodesic_trajectory;
+    }
+
+    let (Some(origin_lat_long), Some(dest_lat_long)) = (
+      Planet::get_coordinates_from_cartesian(origin),
+      Planet::get_coordinates_from_cartesian(destination),
+    ) else {
+      return base_geodesic_trajectory;
+    };
+
+    let Some(in_the_frontiers_coordinates) = Pathfinder::find_path(
+      coordinate_map,
+      origin_lat_long,
+      dest_lat_long,
+      &PathConstraint::StayInside(within_the_territory_id.clone()),
+    ) else {
+      return base_geodesic_trajectory;
+    };
+
+    in_the_frontiers_coordinates
+      .iter()
+      .filter_map(|coordinates| coordinate_map.get(coordinates))
+      .map(|coordinate_metadata| coordinate_metadata.cartesian)
+      .collect()
+  }
+
+  fn passes_by_other_territories(
+    coordinate_map: &CoordinateMap,
+    base_geodesic_trajectory: &[Vec3],
+    within_the_territory_id: &TerritoryId,
+  ) -> bool {
+    base_geodesic_trajectory.iter().any(|trajectory_point| {
+      Planet::get_coordinate_metadata_from_cartesian(coordinate_map, *trajectory_point)
+        .and_then(|(_, coordinate_metadata)| coordinate_metadata.territory_id.as_ref())
+        .is_some_and(|territory_id| territory_id != within_the_territory_id)
+    })
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::globe::coordinates::{CoordinateMap, Planet};
+  use crate::math::Vec3;
+
+  use super::Trajectory;
+
+  const RADIUS: f32 = 3.;
+
+  /// Position at `arc_length` from `Vec3::new(RADIUS, 0., 0.)` along the equator
+  fn get_position_at(arc_length: f32) -> Vec3 {
+    let angle = arc_length / RADIUS;
+    Vec3::new(angle.cos(), angle.sin(), 0.) * RADIUS
+  }
+
+  #[test]
+  fn waypoints_follow_the_arc_length() {
+    let origin = get_position_at(0.);
+
+    assert_eq!(Trajectory::get_num_of_waypoints(origin, get_position_at(2.1), RADIUS), 12);
+    assert_eq!(Trajectory::get_num_of_waypoints(origin, get_position_at(4.1), RADIUS), 22);
+  }
+
+  #[test]
+  fn waypoints_are_clamped() {
+    let origin = get_position_at(0.);
+
+    assert_eq!(Trajectory::get_num_of_waypoints(origin, origin, RADIUS), Trajectory::MIN_NUM_OF_WAYPOINTS);
+    assert_eq!(Trajectory::get_num_of_waypoints(origin, get_position_at(0.5), RADIUS), Trajectory::MIN_NUM_OF_WAYPOINTS);
+    // The same angle on a bigger planet is a longer trip
+    assert_eq!(Trajectory::get_num_of_waypoints(origin, get_position_at(3.), RADIUS * 4.), Trajectory::MAX_NUM_OF_WAYPOINTS);
+  }
+
+  #[test]
+  fn geodesic_trajectory_is_evenly_spread_on_the_surface() {
+    let (origin, destination) = (get_position_at(0.), get_position_at(4.1));
+
+    let trajectory: Vec<Vec3> = Trajectory::get_geodesic_trajectory(origin, destination, RADIUS).collect();
+
+    assert_eq!(trajectory.len(), Trajectory::get_num_of_waypoints(origin, destination, RADIUS));
+    assert!(trajectory.first().is_some_and(|start| start.distance_to(origin) < 1e-4));
+    assert!(trajectory.last().is_some_and(|end| end.distance_to(destination) < 1e-4));
+    assert!(trajectory.iter().all(|point| (point.length() - RADIUS).abs() < 1e-4));
+
+    let steps: Vec<f32> = trajectory.windows(2).map(|step| step[0].distance_to(step[1])).collect();
+    assert!(steps.iter().all(|step| (step - steps[0]).abs() < 1e-4 && *step <= Trajectory::WAYPOINT_SPACING));
+  }
+
+  fn get_coordinate_map() -> CoordinateMap {
+    let mut coordinate_map = Planet::get_blank_coordinate_map();
+    for lat in 100..120 {
+      for long in 100..130 {
+        let territory_id = if (108..112).contains(&lat) && (110..120).contains(&long) { "lake" } else { "home" };
+        coordinate_map.get_mut(&(lat, long)).expect("Expected coordinate to exist").territory_id = Some(territory_id.to_owned());
+      }
+    }
+    coordinate_map
+  }
+
+  #[test]
+  fn trajectory_within_the_frontiers_stays_inside() {
+    let coordinate_map = get_coordinate_map();
+    let home = "home".to_owned();
+    let origin = Planet::get_cartesian_from_coordinates((110, 105));
+    let destination = Planet::get_cartesian_from_coordinates((110, 125));
+    let radius = Planet::get_planet_radius() as f32;
+
+    let trajectory = Trajectory::get_in_the_frontiers_trajectory(&coordinate_map, origin, destination, radius, &home);
+
+    assert!(trajectory.len() > 2);
+    assert!(trajectory.iter().all(|point| {
+      Planet::get_coordinate_metadata_from_cartesian(&coordinate_map, *point)
+        .is_some_and(|(_, coordinate_metadata)| coordinate_metadata.territory_id.as_ref() == Some(&home))
+    }));
+  }
+
+  #[test]
+  fn trajectory_within_the_frontiers_goes_straight_when_it_can() {
+    let coordinate_map = get_coordinate_map();
+    let origin = Planet::get_cartesian_from_coordinates((102, 105));
+    let destination = Planet::get_cartesian_from_coordinates((102, 125));
+    let radius = Planet::get_planet_radius() as f32;
+
+    let trajectory = Trajectory::get_in_the_frontiers_trajectory(&coordinate_map, origin, destination, radius, &"home".to_owned());
+
+    assert_eq!(trajectory, Trajectory::get_geodesic_trajectory(origin, destination, radius).collect::<Vec<_>>());
+  }
+}
@@ -0,0 +1,90 @@
+use crate::clock::SimulationClock;
+use crate::players::player::PlayerId;
+use crate::territories::territory::{Territory, TerritoryId, TerritoryState};
+use crate::troops::orders::{DeployOrder, OrderMode};
+use crate::troops::route::RouteOption;
+use crate::troops::troop::TroopId;
+
+use super::world::World;
+
+/// Simple expansionist: every now and then, sends the spare troops of each of its
+/// territories to the closest territory it doesn't rule yet or that is being invaded
+#[derive(Debug, Clone)]
+pub struct Bot {
+  pub player_id: PlayerId,
+}
+
+impl Bot {
+  /// Bots think on the clock instead of keeping their own timer,
+  /// so a loaded match gives its orders in the same ticks the saved one would
+  pub const THINKING_INTERVAL_IN_TICKS: u64 = 2 * SimulationClock::TICKS_PER_SECOND as u64;
+
+  /// Troops kept back in every territory to defend it
+  pub const TROOPS_KEPT_PER_TERRITORY: usize = 1;
+
+  pub fn new(player_id: PlayerId) -> Bot {
+    Bot { player_id }
+  }
+
+  /// Returns the orders the bot gives in this step, it only gives orders
+  /// once every `THINKING_INTERVAL_IN_TICKS`
+  pub fn think(&self, world: &World) -> Vec<DeployOrder> {
+    if !world.clock.tick.is_multiple_of(Self::THINKING_INTERVAL_IN_TICKS) {
+      return Vec::new();
+    }
+
+    let mut orders = Vec::new();
+    for territory in world.territories.values() {
+      if territory.current_ruler != Some(self.player_id) ||
+        territory.is_under_conflict() ||
+        territory.territory_states.contains(&TerritoryState::OccupationInProgress) {
+        continue;
+      }
+
+      let spare_troops = self.get_spare_troops(territory, world);
+      if spare_troops.is_empty() {
+        continue;
+      }
+
+      let Some(target_territory_id) = self.get_closest_territory_to_conquer_or_defend(territory, world) else {
+        continue;
+      };
+
+      orders.extend(spare_troops.into_iter().map(|troop_id| DeployOrder {
+        troop_id,
+        territory_id: target_territory_id.clone(),
+        destination: None,
+        route: RouteOption::Shortest,
+        pace: None,
+        mode: OrderMode::Now,
+      }));
+    }
+
+    orders
+  }
+
+  fn get_spare_troops(&self, territory: &Territory, world: &World) -> Vec<TroopId> {
+    let Some(troops) = territory.all_troops_deployed_and_arrived_by_player.get(&self.player_id) else {
+      return Vec::new();
+    };
+
+    troops
+      .iter()
+      .filter(|troop_id| world.troops.get(*troop_id).is_some_and(|troop| troop.is_available_for_orders()))
+      .skip(Self::TROOPS_KEPT_PER_TERRITORY)
+      .cloned()
+      .collect()
+  }
+
+  fn get_closest_territory_to_conquer_or_defend(&self, from: &Territory, world: &World) -> Option<TerritoryId> {
+    world.territories
+      .values()
+      .filter(|territory| territory.current_ruler != Some(self.player_id) || territory.is_under_conflict())
+      .map(|territory| (territory, from.spawner_location.distance_to(territory.spawner_location)))
+      .fold(None, |closest: Option<(&Territory, f32)>, (territory, distance)| match closest {
+        Some((_, closest_distance)) if distance >= closest_distance => closest,
+        _ => Some((territory, distance)),
+      })
+      .map(|(territory, _)| territory.territory_id.clone())
+  }
+}
@@ -0,0 +1,93 @@
+use std::f32::consts::PI;
+
+use crate::globe::coordinates::{CoordinateMap, Planet};
+use crate::math::Vec3;
+use crate::rules::Rules;
+use crate::territories::territory::Territories;
+
+/// A bit less than the real Earth
+const LAND_RATIO: f32 = 0.25;
+
+/// Without the Godot meshes there's no way to know which surface points belong to which territory,
+/// so headless matches lay the territories over the globe: every territory gets a center
+/// evenly spread on the sphere (in territory id order) and owns the coordinates closer to it.
+///
+/// Coordinates too far from every center are left as sea, so roughly `LAND_RATIO` of the globe is land.
+/// It isn't the real world map, territories are roughly the same size and round,
+/// but it's deterministic and good enough to compare rules between runs
+pub fn lay_out_territories(territories: &mut Territories, rules: &Rules) -> CoordinateMap {
+  let mut coordinate_map = Planet::get_blank_coordinate_map();
+  let centers = get_evenly_spread_directions(territories.len());
+  let territory_ids: Vec<_> = territories.keys().cloned().collect();
+
+  // Every territory is a spherical cap covering LAND_RATIO of the area around its center
+  let territory_angular_radius = (4. * LAND_RATIO / territories.len() as f32).sqrt();
+  let min_dot_to_be_land = territory_angular_radius.cos();
+
+  for lat_long in Planet::all_coordinates() {
+    let coordinate_metadata = coordinate_map
+      .get_mut(&lat_long)
+      .expect("Expected coordinate to exist");
+    let direction = coordinate_metadata.cartesian.normalized();
+
+    let (closest_center, closest_dot) = centers
+      .iter()
+      .enumerate()
+      .fold((0, f32::MIN), |(closest, closest_dot), (index, center)| {
+        let dot = center.dot(direction);
+        if dot > closest_dot { (index, dot) } else { (closest, closest_dot) }
+      });
+
+    if closest_dot < min_dot_to_be_land {
+      continue;
+    }
+
+    coordinate_metadata.territory_id = Some(territory_ids[closest_center].clone());
+  }
+
+  settle_territories(territories, &coordinate_map, rules);
+
+  coordinate_map
+}
+
+/// Gives every territory its coordinates, in `Planet::all_coordinates` order as `VirtualPlanet` matches them,
+/// along with its spawner location and the stats depending on them
+pub fn settle_territories(territories: &mut Territories, coordinate_map: &CoordinateMap, rules: &Rules) {
+  for lat_long in Planet::all_coordinates() {
+    let Some(territory_id) = coordinate_map
+      .get(&lat_long)
+      .and_then(|coordinate_metadata| coordinate_metadata.territory_id.as_ref()) else {
+      continue;
+    };
+
+    territories
+      .get_mut(territory_id)
+      .expect("Expected territory to exist")
+      .coordinates
+      .push(lat_long);
+  }
+
+  for territory in territories.values_mut() {
+    if let Some(spawner_coordinate) = territory.get_spawner_coordinate() {
+      territory.spawner_location = coordinate_map
+        .get(&spawner_coordinate)
+        .expect("Expected coordinate to exist")
+        .cartesian;
+    }
+    territory.set_coordinates_dependent_stats(rules);
+  }
+}
+
+/// Fibonacci sphere, `num_of_directions` unit vectors roughly at the same distance from each other
+fn get_evenly_spread_directions(num_of_directions: usize) -> Vec<Vec3> {
+  let golden_angle = PI * (3. - 5_f32.sqrt());
+
+  (0..num_of_directions)
+    .map(|i| {
+      let z = 1. - 2. * (i as f32 + 0.5) / num_of_directions as f32;
+      let ring_radius = (1. - z * z).sqrt();
+      let (sin, cos) = (golden_angle * i as f32).sin_cos();
+      Vec3::new(ring_radius * cos, ring_radius * sin, z)
+    })
+    .collect()
+}
@@ -0,0 +1,4 @@
+pub mod world;
+pub mod geography;
+pub mod bot;
+pub mod summary;
\ No newline at end of file
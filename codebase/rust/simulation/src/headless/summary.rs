@@ -0,0 +1,85 @@
+use std::collections::BTreeMap;
+
+use serde::Serialize;
+
+use crate::events::game_event::GameEvent;
+use crate::players::player::PlayerId;
+use crate::random::MatchSeed;
+use crate::territories::territory::TerritoryId;
+use crate::victory::conditions::VictoryReason;
+use crate::victory::standings::Standing;
+
+/// What happened in a headless match, printed as JSON by the `match_runner` binary
+#[derive(Debug, Clone, Serialize)]
+pub struct MatchSummary {
+  pub seed: MatchSeed,
+  pub ticks: u64,
+  pub elapsed_secs: f64,
+  /// None if the tick limit was reached before the match was over, or if no one was left
+  pub winner: Option<PlayerId>,
+  /// None if the tick limit was reached before the match was over
+  pub reason: Option<VictoryReason>,
+  pub standings: Vec<Standing>,
+  pub players: Vec<PlayerSummary>,
+  pub territories_over_time: Vec<TerritoriesSample>,
+  pub conquest_events: Vec<ConquestEvent>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct PlayerSummary {
+  pub player_id: PlayerId,
+  pub user_name: String,
+  pub troops_spawned: u32,
+  pub troops_lost: u32,
+  pub troops_killed: u32,
+  /// Troops killed by the player, by the player they belonged to
+  pub troops_killed_by_player: BTreeMap<PlayerId, u32>,
+  pub final_territories: u32,
+  pub final_troops: u32,
+}
+
+/// Number of territories ruled by every player at some point of the match
+#[derive(Debug, Clone, Serialize)]
+pub struct TerritoriesSample {
+  pub elapsed_secs: f64,
+  pub territories_by_player: BTreeMap<PlayerId, u32>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ConquestEvent {
+  pub elapsed_secs: f64,
+  pub territory_id: TerritoryId,
+  pub conquered_by: PlayerId,
+  /// None if the territory was unoccupied
+  pub taken_from: Option<PlayerId>,
+}
+
+impl MatchSummary {
+  pub fn get_player_mut(&mut self, player_id: PlayerId) -> &mut PlayerSummary {
+    self.players
+      .iter_mut()
+      .find(|player| player.player_id == player_id)
+      .expect("Expected player to be in the summary")
+  }
+
+  /// Keeps the counters and conquests up with the events of the match as they're emitted
+  pub fn record_event(&mut self, elapsed_secs: f64, event: &GameEvent) {
+    match event {
+      GameEvent::TroopSpawned { player_id, .. } => self.get_player_mut(*player_id).troops_spawned += 1,
+      GameEvent::TroopDied { player_id, killer_player_id, .. } => {
+        self.get_player_mut(*player_id).troops_lost += 1;
+
+        let killer_summary = self.get_player_mut(*killer_player_id);
+        killer_summary.troops_killed += 1;
+        *killer_summary.troops_killed_by_player.entry(*player_id).or_default() += 1;
+      },
+      GameEvent::TerritoryConquered { territory_id, player_id, taken_from } => self.conquest_events.push(ConquestEvent {
+        elapsed_secs,
+        territory_id: territory_id.clone(),
+        conquered_by: *player_id,
+        taken_from: *taken_from,
+      }),
+      _ => (),
+    }
+  }
+}
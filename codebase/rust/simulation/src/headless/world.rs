@@ -0,0 +1,684 @@
+use std::collections::BTreeMap;
+
+use crate::clock::SimulationClock;
+use crate::events::event_bus::EventBus;
+use crate::events::game_event::GameEvent;
+use crate::globe::baked_map::BakedCoordinateMap;
+use crate::globe::coordinates::{CoordinateMap, Coordinates};
+use crate::math::Vec3;
+use crate::players::player::{PlayerId, PlayerState};
+use crate::random::MatchRng;
+use crate::replay::playback::{ReplayDesync, ReplayPlayback};
+use crate::replay::record::{OrderSource, Replay, ReplayOrder, TroopSpawn};
+use crate::replay::recorder::ReplayRecorder;
+use crate::rules::Rules;
+use crate::save::match_save::{MatchSave, PlayerSave};
+use crate::save::migration::CURRENT_VERSION;
+use crate::setup::{MatchSetup, PlayerSetup};
+use crate::territories::adjacency::AdjacencyGraph;
+use crate::territories::realm::Realm;
+use crate::territories::territory::{Territories, Territory, TerritoryId};
+use crate::troops::battlefield::{Battlefield, TroopSighting};
+use crate::troops::combat::projectile::Projectile;
+use crate::troops::mesh_map::TroopMeshes;
+use crate::troops::orders::{DeployOrder, OrderMode, QueuedOrder};
+use crate::troops::steering::Steering;
+use crate::troops::troop::{Troop, TroopId};
+use crate::victory::standings::Standing;
+use crate::victory::tracker::VictoryTracker;
+
+use super::bot::Bot;
+use super::geography::{lay_out_territories, settle_territories};
+use super::summary::{MatchSummary, PlayerSummary, TerritoriesSample};
+
+/// A whole match without rendering, every player is a `Bot`.
+/// Steps in the same order `RootScene::tick` does: territories -> troops -> projectiles
+pub struct World {
+  pub rules: Rules,
+  pub clock: SimulationClock,
+  pub rng: MatchRng,
+
+  pub coordinate_map: CoordinateMap,
+  pub territories: Territories,
+  pub adjacency: AdjacencyGraph,
+  pub players: BTreeMap<PlayerId, PlayerState>,
+  /// Ordered by id, so every tick steps them in the same order
+  pub troops: BTreeMap<TroopId, Troop>,
+  pub projectiles: Vec<Projectile>,
+  /// Subscribers get the events of every tick once it's over
+  pub events: EventBus,
+  pub victory: VictoryTracker,
+
+  player_setups: Vec<PlayerSetup>,
+  bots: Vec<Bot>,
+  summary: MatchSummary,
+
+  /// None while playing a replay back
+  recorder: Option<ReplayRecorder>,
+  /// While it's set, the bots are quiet and the orders come from the replay
+  playback: Option<ReplayPlayback>,
+}
+
+impl World {
+  /// Expects a setup already checked by `MatchSetup::validate`
+  pub fn new(setup: &MatchSetup) -> World {
+    Self::new_on_map(setup, None)
+  }
+
+  /// Same as `new`, on the real world map when it's given, check `BakedCoordinateMap::load`
+  /// Without it, the territories are laid out by `lay_out_territories`
+  pub fn new_on_map(setup: &MatchSetup, baked_map: Option<&BakedCoordinateMap>) -> World {
+    let mut world = Self::get_world_without_progress(setup, baked_map);
+
+    world.start_match(&setup.players);
+
+    world.recorder = Some(ReplayRecorder::new(world.get_save()));
+    world
+  }
+
+  /// Picks up a saved match from its tick, expects a save already checked by `MatchSave::validate`
+  /// The summary only covers what happens after the save
+  pub fn from_save(save: &MatchSave) -> World {
+    Self::from_save_on_map(save, None)
+  }
+
+  /// Same as `from_save`, the save has to be played on the same map it was saved on
+  pub fn from_save_on_map(save: &MatchSave, baked_map: Option<&BakedCoordinateMap>) -> World {
+    let mut world = Self::get_world_without_progress(&save.get_setup(), baked_map);
+    world.clock.tick = save.tick;
+    world.rng.restore_state(save.rng_state);
+
+    for player in &save.players {
+      world.players.insert(player.state.player_id, player.state.clone());
+      // Troops spawned and killed before the save still count for the summary
+      let player_summary = world.summary.get_player_mut(player.state.player_id);
+      player_summary.troops_spawned = player.state.troops_spawned;
+      player_summary.troops_killed = player.state.troops_killed;
+    }
+
+    for territory_save in &save.territories {
+      world.territories
+        .get_mut(&territory_save.territory_id)
+        .expect("Expected territory to exist")
+        .restore_save(territory_save);
+    }
+
+    for troop_save in &save.troops {
+      let troop = Troop::from_save(troop_save, world.get_troop_meshes(troop_save.owner), &world.rules);
+      world.troops.insert(troop_save.troop_id.clone(), troop);
+    }
+    world.projectiles = save.projectiles.clone();
+    world.victory = save.victory.clone();
+
+    world.recorder = Some(ReplayRecorder::new(world.get_save()));
+    world
+  }
+
+  /// Plays a replay back from its start, expects a replay already checked by `Replay::load`
+  pub fn from_replay(replay: &Replay) -> World {
+    Self::from_replay_on_map(replay, None)
+  }
+
+  /// Same as `from_replay`, the replay has to be played on the same map it was recorded on
+  pub fn from_replay_on_map(replay: &Replay, baked_map: Option<&BakedCoordinateMap>) -> World {
+    let mut world = Self::from_save_on_map(&replay.start, baked_map);
+    world.recorder = None;
+    world.playback = Some(ReplayPlayback::new(replay.clone()));
+    world
+  }
+
+  /// Everything recorded since the match started or was loaded, None while playing a replay back
+  pub fn get_replay(&self) -> Option<Replay> {
+    self.recorder
+      .as_ref()
+      .map(|recorder| recorder.get_replay(self.clock.tick))
+  }
+
+  /// First difference found between the replay being played and this match
+  pub fn get_replay_desync(&self) -> Option<&ReplayDesync> {
+    self.playback
+      .as_ref()
+      .and_then(|playback| playback.get_desync())
+  }
+
+  pub fn get_save(&self) -> MatchSave {
+    MatchSave {
+      version: CURRENT_VERSION,
+      tick: self.clock.tick,
+      seed: self.rng.get_seed(),
+      rng_state: self.rng.get_state(),
+      rules: self.rules.clone(),
+
+      players: self.player_setups
+        .iter()
+        .map(|setup| PlayerSave {
+          setup: setup.clone(),
+          state: self.players.get(&setup.player_id).expect("Expected player to exist").clone(),
+        })
+        .collect(),
+      territories: self.territories.values().map(Territory::get_save).collect(),
+      troops: self.troops.values().map(Troop::get_save).collect(),
+      projectiles: self.projectiles.clone(),
+      victory: self.victory.clone(),
+    }
+  }
+
+  /// Players and territories as they are before anyone rules anything
+  fn get_world_without_progress(setup: &MatchSetup, baked_map: Option<&BakedCoordinateMap>) -> World {
+    let mut territories = Territory::get_map();
+    let coordinate_map = match baked_map {
+      Some(baked_map) => {
+        let coordinate_map = baked_map.get_coordinate_map();
+        settle_territories(&mut territories, &coordinate_map, &setup.rules);
+        coordinate_map
+      },
+      None => lay_out_territories(&mut territories, &setup.rules),
+    };
+    let adjacency = AdjacencyGraph::from_coordinate_map(&coordinate_map, setup.rules.max_sea_connection_distance);
+
+    World {
+      rules: setup.rules.clone(),
+      clock: SimulationClock::new(),
+      rng: MatchRng::new(setup.seed),
+
+      coordinate_map,
+      territories,
+      adjacency,
+      players: setup.players
+        .iter()
+        .map(|player_setup| (player_setup.player_id, PlayerState::new(player_setup.player_id)))
+        .collect(),
+      troops: BTreeMap::new(),
+      projectiles: Vec::new(),
+      events: EventBus::new(),
+      victory: VictoryTracker::new(),
+
+      player_setups: setup.players.clone(),
+      bots: setup.players.iter().map(|player_setup| Bot::new(player_setup.player_id)).collect(),
+      recorder: None,
+      playback: None,
+      summary: MatchSummary {
+        seed: setup.seed,
+        ticks: 0,
+        elapsed_secs: 0.,
+        winner: None,
+        reason: None,
+        standings: Vec::new(),
+        players: setup.players
+          .iter()
+          .map(|player_setup| PlayerSummary {
+            player_id: player_setup.player_id,
+            user_name: player_setup.user_name.clone(),
+            troops_spawned: 0,
+            troops_lost: 0,
+            troops_killed: 0,
+            troops_killed_by_player: BTreeMap::new(),
+            final_territories: 0,
+            final_troops: 0,
+          })
+          .collect(),
+        territories_over_time: Vec::new(),
+        conquest_events: Vec::new(),
+      },
+    }
+  }
+
+  /// Runs the match until it's over or `max_ticks` is reached,
+  /// sampling the territories of every player once every `sample_every_ticks`
+  pub fn run(&mut self, max_ticks: u64, sample_every_ticks: u64) -> MatchSummary {
+    let sample_every_ticks = sample_every_ticks.max(1);
+    self.record_territories_sample();
+
+    while self.clock.tick < max_ticks && !self.victory.is_game_over() {
+      self.tick();
+
+      if self.clock.tick.is_multiple_of(sample_every_ticks) {
+        self.record_territories_sample();
+      }
+    }
+
+    self.get_summary()
+  }
+
+  /// Steps the match by `SimulationClock::FIXED_DELTA`, unless it's over
+  /// Orders are carried out in between ticks, just like the clicks of a player in the game
+  pub fn tick(&mut self) {
+    if self.victory.is_game_over() {
+      return;
+    }
+
+    let delta = SimulationClock::FIXED_DELTA;
+    self.give_orders();
+    self.clock.step();
+
+    self.tick_territories(delta);
+    self.tick_troops(delta);
+    self.tick_projectiles(delta);
+
+    if let Some(playback) = self.playback.as_mut() {
+      playback.check_missing_spawns(self.clock.tick);
+    }
+
+    self.check_victory(delta);
+    self.events.dispatch();
+  }
+
+  /// None until the match is over, or if it ended with no one left
+  pub fn get_winner(&self) -> Option<PlayerId> {
+    self.victory.outcome
+      .as_ref()
+      .and_then(|outcome| outcome.winner)
+  }
+
+  /// Final standings once the match is over, the current ones otherwise
+  pub fn get_standings(&self) -> Vec<Standing> {
+    match &self.victory.outcome {
+      Some(outcome) => outcome.standings.clone(),
+      None => {
+        let players: Vec<PlayerState> = self.players.values().cloned().collect();
+        self.victory.get_standings(&players, None)
+      },
+    }
+  }
+
+  pub fn get_summary(&self) -> MatchSummary {
+    let mut summary = self.summary.clone();
+    summary.ticks = self.clock.tick;
+    summary.elapsed_secs = self.clock.get_elapsed_secs();
+    summary.winner = self.get_winner();
+    summary.reason = self.victory.outcome.as_ref().map(|outcome| outcome.reason.clone());
+    summary.standings = self.get_standings();
+
+    for player in self.players.values() {
+      let player_summary = summary.get_player_mut(player.player_id);
+      player_summary.final_territories = player.territory_counter;
+      player_summary.final_troops = player.troops_counter;
+    }
+
+    summary
+  }
+
+  pub fn get_cartesian_from_coordinates(&self, coordinates: &Coordinates) -> Vec3 {
+    self.coordinate_map
+      .get(coordinates)
+      .expect("Expected coordinates to exist")
+      .cartesian
+  }
+
+  /// Meshes the troops of the player use on each surface, which pick their archetype
+  pub fn get_troop_meshes(&self, player_id: PlayerId) -> &TroopMeshes {
+    &self.player_setups
+      .iter()
+      .find(|setup| setup.player_id == player_id)
+      .expect("Expected player to exist")
+      .troop_meshes
+  }
+
+  /// Sends the troop to the order destination, or to a random coordinate of the territory if it has none.
+  /// Depending on `DeployOrder::mode` it goes right away or once it's done with its previous orders
+  pub fn deploy_troop(&mut self, order: &DeployOrder) {
+    let Some(mut troop) = self.troops.remove(&order.troop_id) else {
+      return;
+    };
+
+    self.give_order(&mut troop, order);
+    self.troops.insert(order.troop_id.clone(), troop);
+  }
+
+  fn give_order(&mut self, troop: &mut Troop, order: &DeployOrder) {
+    let destination = match order.destination {
+      // Holding and switching stance don't go anywhere, so there's no destination to draw
+      _ if matches!(order.mode, OrderMode::Hold | OrderMode::Stance(_)) => Some(troop.position),
+      Some(destination) if self.territories.contains_key(&order.territory_id) => Some(destination),
+      Some(_) => None,
+      None => self.get_random_position_in(&order.territory_id),
+    };
+
+    let Some(destination) = destination else {
+      return;
+    };
+
+    let queued_order = QueuedOrder {
+      territory_id: order.territory_id.clone(),
+      destination,
+      route: order.route,
+    };
+    troop.receive_order(self, queued_order, order.pace, order.mode);
+  }
+
+  /// Carries out the orders of the replay being played, or the ones the bots come up with
+  fn give_orders(&mut self) {
+    let tick = self.clock.tick;
+
+    let orders: Vec<ReplayOrder> = match self.playback.as_mut() {
+      Some(playback) => playback.take_orders(tick),
+      None => self.bots
+        .iter()
+        .flat_map(|bot| bot.think(self).into_iter().map(|order| ReplayOrder {
+          tick,
+          player_id: bot.player_id,
+          source: OrderSource::Bot,
+          order,
+        }))
+        .collect(),
+    };
+
+    for replay_order in orders {
+      if let Some(recorder) = self.recorder.as_mut() {
+        recorder.record_order(replay_order.tick, replay_order.player_id, replay_order.source, &replay_order.order);
+      }
+
+      self.deploy_troop(&replay_order.order);
+    }
+  }
+
+  /// Every troop steers around where the others were before any of them moved this tick
+  fn tick_troops(&mut self, delta: f64) {
+    let troop_ids: Vec<TroopId> = self.troops.keys().cloned().collect();
+    let steering = Steering::new(self.troops.values().map(Troop::get_steering_agent).collect());
+
+    for (steering_index, troop_id) in troop_ids.into_iter().enumerate() {
+      let Some(mut troop) = self.troops.remove(&troop_id) else {
+        continue;
+      };
+
+      troop.tick(delta, self, &steering, steering_index);
+      self.troops.insert(troop_id, troop);
+    }
+  }
+
+  /// Every projectile moves first and then the ones that got there hit,
+  /// so the ones still flying towards a troop dying on the way can go after another one
+  fn tick_projectiles(&mut self, delta: f64) {
+    let mut hitting_projectiles = Vec::new();
+
+    for mut projectile in std::mem::take(&mut self.projectiles) {
+      // The target is already gone and no one was around to take the hit, the projectile just fizzles
+      if !projectile.target.as_ref().is_some_and(|target_id| self.troops.contains_key(target_id)) {
+        continue;
+      }
+
+      if projectile.move_towards_target(delta) {
+        hitting_projectiles.push(projectile);
+      } else {
+        self.projectiles.push(projectile);
+      }
+    }
+
+    for projectile in hitting_projectiles {
+      if let Some(target_id) = &projectile.target {
+        self.hit_troop(target_id, &projectile);
+      }
+    }
+  }
+
+  fn hit_troop(&mut self, troop_id: &TroopId, projectile: &Projectile) {
+    // Killed by another projectile of the same tick
+    let Some(troop) = self.troops.get_mut(troop_id) else {
+      return;
+    };
+
+    if troop.take_a_hit(projectile.damage, &projectile.fired_by) {
+      let troop = self.troops.remove(troop_id).expect("Expected troop to exist");
+      self.kill_troop(&troop, &projectile.fired_by, projectile.fired_by_player);
+    }
+  }
+
+  /// Ported from `RootScene::check_victory`
+  fn check_victory(&mut self, delta: f64) {
+    for player in self.players.values_mut() {
+      if self.victory.eliminate_if_defeated(self.clock.tick, player) {
+        self.events.emit(GameEvent::PlayerEliminated { player_id: player.player_id });
+      }
+    }
+
+    let players: Vec<PlayerState> = self.players.values().cloned().collect();
+    let outcome = self.victory.check_victory(
+      self.clock.tick,
+      delta,
+      &self.rules.victory_conditions,
+      &players,
+      &self.territories,
+    );
+
+    if let Some(outcome) = outcome {
+      self.events.emit(GameEvent::MatchEnded { outcome });
+    }
+  }
+
+  /// The summary is kept from the events too, check `MatchSummary::record_event`
+  fn emit_event(&mut self, event: GameEvent) {
+    self.summary.record_event(self.clock.get_elapsed_secs(), &event);
+    self.events.emit(event);
+  }
+
+  fn record_territories_sample(&mut self) {
+    let territories_by_player = self.players
+      .values()
+      .map(|player| (player.player_id, player.territory_counter))
+      .collect();
+
+    self.summary.territories_over_time.push(TerritoriesSample {
+      elapsed_secs: self.clock.get_elapsed_secs(),
+      territories_by_player,
+    });
+  }
+}
+
+/// Troops move straight along their velocity, there are no bodies to bump into
+impl Battlefield for World {
+  fn get_rules(&self) -> &Rules {
+    &self.rules
+  }
+
+  fn get_coordinate_map(&self) -> &CoordinateMap {
+    &self.coordinate_map
+  }
+
+  fn get_territories(&self) -> &Territories {
+    &self.territories
+  }
+
+  fn get_territories_mut(&mut self) -> &mut Territories {
+    &mut self.territories
+  }
+
+  fn get_adjacency(&self) -> &AdjacencyGraph {
+    &self.adjacency
+  }
+
+  fn get_random_position_in(&mut self, territory_id: &TerritoryId) -> Option<Vec3> {
+    self.territories
+      .get(territory_id)
+      .and_then(|territory| territory.get_random_coordinate(&mut self.rng))
+      .map(|coordinates| self.get_cartesian_from_coordinates(&coordinates))
+  }
+
+  fn get_troop(&self, troop_id: &TroopId) -> Option<TroopSighting> {
+    self.troops.get(troop_id).map(Troop::get_sighting)
+  }
+
+  fn get_other_troops(&self) -> Vec<TroopSighting> {
+    self.troops.values().map(Troop::get_sighting).collect()
+  }
+
+  fn move_troop(&mut self, troop: &Troop, velocity: Vec3, delta: f64) -> Vec3 {
+    troop.position + velocity * delta as f32
+  }
+
+  fn fire(&mut self, _troop: &Troop, projectile: Projectile) {
+    self.projectiles.push(projectile);
+  }
+
+  fn emit(&mut self, event: GameEvent) {
+    self.emit_event(event);
+  }
+}
+
+impl Realm for World {
+  fn get_territories(&self) -> &Territories {
+    &self.territories
+  }
+
+  fn get_territories_mut(&mut self) -> &mut Territories {
+    &mut self.territories
+  }
+
+  fn with_player<R>(&mut self, player_id: PlayerId, lend: impl FnOnce(&mut PlayerState, &mut Territories, &Rules) -> R) -> R {
+    let player = self.players.get_mut(&player_id).expect("Expected player to exist");
+    lend(player, &mut self.territories, &self.rules)
+  }
+
+  fn get_troop_meshes(&self, player_id: PlayerId) -> TroopMeshes {
+    World::get_troop_meshes(self, player_id).clone()
+  }
+
+  fn get_troops(&self) -> Vec<TroopSighting> {
+    self.troops.values().map(Troop::get_sighting).collect()
+  }
+
+  /// Spawns are checked against the replay being played, or recorded
+  fn add_troop(&mut self, troop: Troop) {
+    let spawn = TroopSpawn {
+      tick: self.clock.tick,
+      troop_id: troop.troop_id.clone(),
+      player_id: troop.owner,
+      territory_id: troop.deployed_to_territory.clone(),
+    };
+    if let Some(recorder) = self.recorder.as_mut() {
+      recorder.record_spawn(spawn.clone());
+    }
+    if let Some(playback) = self.playback.as_mut() {
+      playback.check_spawn(&spawn);
+    }
+
+    self.troops.insert(troop.troop_id.clone(), troop);
+  }
+
+  fn forget_troop(&mut self, troop_id: &TroopId) {
+    for troop in self.troops.values_mut() {
+      troop.combat_stats.forget_troop(troop_id);
+    }
+  }
+
+  fn retarget_projectiles(&mut self, troop_id: &TroopId, new_target: Option<&TroopSighting>) {
+    self.projectiles
+      .iter_mut()
+      .filter(|projectile| projectile.target.as_ref() == Some(troop_id))
+      .for_each(|projectile| match new_target {
+        Some(new_target) => projectile.retarget(new_target.troop_id.clone(), new_target.position),
+        None => projectile.target = None,
+      });
+  }
+
+  fn emit(&mut self, event: GameEvent) {
+    self.emit_event(event);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use serde_json::Value;
+
+  use crate::clock::SimulationClock;
+  use crate::events::game_event::GameEvent;
+  use crate::math::Vec3;
+  use crate::setup::{MatchSetup, PlayerSetup};
+  use crate::territories::realm::Realm;
+  use crate::troops::combat::projectile::Projectile;
+
+  use super::World;
+
+  const SAMPLE_EVERY_TICKS: u64 = 10 * SimulationClock::TICKS_PER_SECOND as u64;
+
+  fn get_summary(setup: &MatchSetup, max_ticks: u64) -> Value {
+    let summary = World::new(setup).run(max_ticks, SAMPLE_EVERY_TICKS);
+    serde_json::to_value(summary).expect("Expected summary to be serializable")
+  }
+
+  #[test]
+  fn same_seed_and_setup_give_the_same_summary() {
+    let setup = MatchSetup::default();
+    let summary = get_summary(&setup, 3000);
+
+    assert_eq!(get_summary(&setup, 3000), summary);
+
+    let mut other_seed_setup = setup.clone();
+    other_seed_setup.seed += 1;
+    assert_ne!(get_summary(&other_seed_setup, 3000), summary);
+  }
+
+  #[test]
+  fn matches_hitting_the_tick_limit_are_reported_unfinished() {
+    let mut world = World::new(&MatchSetup::default());
+    let summary = world.run(300, SAMPLE_EVERY_TICKS);
+
+    assert_eq!(summary.ticks, 300);
+    assert_eq!(summary.winner, None);
+    assert_eq!(summary.reason, None);
+    assert_eq!(summary.elapsed_secs, 300. * SimulationClock::FIXED_DELTA);
+    assert!(!world.victory.is_game_over());
+    assert_eq!(summary.standings.len(), 4);
+  }
+
+  #[test]
+  fn every_player_starts_with_its_initial_territory_and_troops() {
+    let setup = MatchSetup::default();
+    let world = World::new(&setup);
+
+    for player_setup in &setup.players {
+      let player = &world.players[&player_setup.player_id];
+      let territory = &world.territories[&player_setup.initial_territory];
+
+      assert_eq!(territory.current_ruler, Some(player_setup.player_id));
+      assert_eq!(player.territory_counter, 1);
+      assert_eq!(player.troops_counter, PlayerSetup::DEFAULT_INITIAL_TROOPS);
+      assert_eq!(territory.get_num_of_arrived_troops_by_player(player_setup.player_id), PlayerSetup::DEFAULT_INITIAL_TROOPS as usize);
+    }
+    assert_eq!(world.get_summary().players[0].troops_spawned, PlayerSetup::DEFAULT_INITIAL_TROOPS);
+  }
+
+  #[test]
+  fn conquered_territories_are_taken_from_their_ruler() {
+    let mut world = World::new(&MatchSetup::default());
+    world.events.dispatch();
+
+    world.conquer_territory(&"baffin_bay".to_owned(), 2);
+
+    assert_eq!(world.territories["baffin_bay"].current_ruler, Some(2));
+    assert_eq!(world.players[&1].territory_counter, 0);
+    assert_eq!(world.players[&2].territory_counter, 2);
+    assert_eq!(world.events.dispatch(), vec![
+      GameEvent::TerritoryConquered { territory_id: "baffin_bay".to_owned(), player_id: 2, taken_from: Some(1) },
+      GameEvent::TerritoryLost { territory_id: "baffin_bay".to_owned(), player_id: 1, lost_to: 2 },
+    ]);
+
+    let conquest = &world.get_summary().conquest_events[0];
+    assert_eq!((conquest.conquered_by, conquest.taken_from), (2, Some(1)));
+  }
+
+  #[test]
+  fn projectiles_after_a_killed_troop_go_after_another_troop_of_its_owner() {
+    let mut world = World::new(&MatchSetup::default());
+    world.spawn_troop(1, &"baffin_bay".to_owned());
+    world.troops.get_mut("troop_2_1").expect("Expected troop to exist").combat_stats.opening_fire_on_troop = Some("troop_1_1".to_owned());
+
+    let target_position = world.troops["troop_1_1"].position;
+    world.projectiles.push(Projectile::new("troop_2_1".to_owned(), 2, "troop_1_1".to_owned(), 10, Vec3::ZERO, target_position));
+    world.events.dispatch();
+
+    let troop = world.troops.remove("troop_1_1").expect("Expected troop to exist");
+    world.kill_troop(&troop, &"troop_2_1".to_owned(), 2);
+
+    assert_eq!(world.troops["troop_2_1"].combat_stats.opening_fire_on_troop, None);
+    assert_eq!(world.projectiles[0].target, Some("troop_1_2".to_owned()));
+    assert_eq!(world.players[&1].troops_counter, 1);
+    assert_eq!(world.players[&2].troops_killed, 1);
+    assert_eq!(world.territories["baffin_bay"].get_num_of_arrived_troops_by_player(1), 1);
+    assert!(matches!(world.events.dispatch().as_slice(), [GameEvent::TroopDied { killer_player_id: 2, .. }]));
+
+    let summary = world.get_summary();
+    assert_eq!(summary.players[0].troops_lost, 1);
+    assert_eq!(summary.players[1].troops_killed_by_player[&1], 1);
+  }
+}
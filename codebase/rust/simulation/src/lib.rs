@@ -0,0 +1,22 @@
+//! Expansionists game rules without any Godot dependency.
+//!
+//! The GDExtension classes (`VirtualPlanet`, `Troop`, `Player`, ...) hold
+//! the scene tree and render it, while the bookkeeping of territories,
+//! players and combat lives here, so it can run headless and under `cargo test`.
+//!
+//! `headless` plays whole bot-vs-bot matches with these rules, see the `match_runner` binary.
+
+pub mod math;
+pub mod clock;
+pub mod random;
+pub mod rules;
+pub mod setup;
+pub mod save;
+pub mod replay;
+pub mod events;
+pub mod victory;
+pub mod globe;
+pub mod territories;
+pub mod players;
+pub mod troops;
+pub mod headless;
@@ -0,0 +1,144 @@
+use std::ops::{Add, AddAssign, Div, Mul, Neg, Sub, SubAssign};
+
+use serde::{Deserialize, Serialize};
+
+/// Engine-independent counterpart of Godot's `Vector3`
+/// The GDExtension converts it from/to `Vector3` at the boundaries
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub struct Vec3 {
+  pub x: f32,
+  pub y: f32,
+  pub z: f32,
+}
+
+impl Vec3 {
+  pub const ZERO: Vec3 = Vec3 { x: 0., y: 0., z: 0. };
+
+  /// Below that, a vector is considered to have no direction
+  const EPSILON: f32 = 1e-6;
+
+  pub const fn new(x: f32, y: f32, z: f32) -> Vec3 {
+    Vec3 { x, y, z }
+  }
+
+  pub fn dot(self, other: Vec3) -> f32 {
+    self.x * other.x + self.y * other.y + self.z * other.z
+  }
+
+  pub fn cross(self, other: Vec3) -> Vec3 {
+    Vec3 {
+      x: self.y * other.z - self.z * other.y,
+      y: self.z * other.x - self.x * other.z,
+      z: self.x * other.y - self.y * other.x,
+    }
+  }
+
+  pub fn length_squared(self) -> f32 {
+    self.dot(self)
+  }
+
+  pub fn length(self) -> f32 {
+    self.length_squared().sqrt()
+  }
+
+  pub fn distance_to(self, other: Vec3) -> f32 {
+    (other - self).length()
+  }
+
+  /// Returns None if the vector is too short to have a direction
+  pub fn try_normalized(self) -> Option<Vec3> {
+    let length = self.length();
+    if length < Self::EPSILON {
+      return None;
+    }
+    Some(self / length)
+  }
+
+  /// Same as `try_normalized`, but returns `Vec3::ZERO` instead of None
+  pub fn normalized(self) -> Vec3 {
+    self.try_normalized().unwrap_or(Vec3::ZERO)
+  }
+
+  /// Angle in radians between both vectors
+  pub fn angle_to(self, other: Vec3) -> f32 {
+    self.cross(other).length().atan2(self.dot(other))
+  }
+
+  pub fn lerp(self, to: Vec3, weight: f32) -> Vec3 {
+    self + (to - self) * weight
+  }
+
+  /// Rotates the vector around a normalized axis (Rodrigues' rotation)
+  pub fn rotated(self, axis: Vec3, angle: f32) -> Vec3 {
+    let (sin, cos) = angle.sin_cos();
+    self * cos + axis.cross(self) * sin + axis * (axis.dot(self) * (1. - cos))
+  }
+
+  /// Spherical interpolation, mirrors `Vector3::slerp` from Godot:
+  /// rotates towards `to` and interpolates the length linearly
+  pub fn slerp(self, to: Vec3, weight: f32) -> Vec3 {
+    let start_length_squared = self.length_squared();
+    let end_length_squared = to.length_squared();
+    if start_length_squared == 0. || end_length_squared == 0. {
+      return self.lerp(to, weight);
+    }
+
+    let Some(axis) = self.cross(to).try_normalized() else {
+      // Parallel vectors, there's no plane to rotate on
+      return self.lerp(to, weight);
+    };
+
+    let start_length = start_length_squared.sqrt();
+    let result_length = start_length + (end_length_squared.sqrt() - start_length) * weight;
+    let angle = self.angle_to(to);
+
+    self.rotated(axis, angle * weight) * (result_length / start_length)
+  }
+}
+
+impl Add for Vec3 {
+  type Output = Vec3;
+  fn add(self, other: Vec3) -> Vec3 {
+    Vec3::new(self.x + other.x, self.y + other.y, self.z + other.z)
+  }
+}
+
+impl AddAssign for Vec3 {
+  fn add_assign(&mut self, other: Vec3) {
+    *self = *self + other;
+  }
+}
+
+impl Sub for Vec3 {
+  type Output = Vec3;
+  fn sub(self, other: Vec3) -> Vec3 {
+    Vec3::new(self.x - other.x, self.y - other.y, self.z - other.z)
+  }
+}
+
+impl SubAssign for Vec3 {
+  fn sub_assign(&mut self, other: Vec3) {
+    *self = *self - other;
+  }
+}
+
+impl Mul<f32> for Vec3 {
+  type Output = Vec3;
+  fn mul(self, scalar: f32) -> Vec3 {
+    Vec3::new(self.x * scalar, self.y * scalar, self.z * scalar)
+  }
+}
+
+impl Div<f32> for Vec3 {
+  type Output = Vec3;
+  fn div(self, scalar: f32) -> Vec3 {
+    Vec3::new(self.x / scalar, self.y / scalar, self.z / scalar)
+  }
+}
+
+impl Neg for Vec3 {
+  type Output = Vec3;
+  fn neg(self) -> Vec3 {
+    Vec3::new(-self.x, -self.y, -self.z)
+  }
+}
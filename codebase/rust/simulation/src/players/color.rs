@@ -0,0 +1,16 @@
+use serde::{Deserialize, Serialize};
+
+/// Each player has its own color, the GDExtension maps it to the
+/// actual `Color`s used on troops, banners and lands
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum PlayerColor {
+  Red,
+  Blue,
+  Green,
+  Yellow,
+  Purple,
+  Orange,
+  Black,
+  White,
+  LightBlue,
+}
@@ -0,0 +1,75 @@
+use serde::{Deserialize, Serialize};
+
+use crate::territories::bonuses::HeldRegionBonus;
+use crate::territories::territory::TerritoryId;
+use crate::troops::troop::TroopId;
+
+pub type PlayerId = u32;
+
+#[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
+pub enum PlayerType {
+  MainPlayer,
+  OtherPlayers,
+  Bot,
+}
+
+/// Counters the rules need to know about a player,
+/// the Godot `Player` node holds one of these
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PlayerState {
+  pub player_id: PlayerId,
+  pub troops_counter: u32,
+  pub territory_counter: u32,
+
+  /// it's the sum of every territory's organic_max_troops being ruled by the player,
+  /// plus the extra troops of `region_bonus`
+  pub max_troop_allowed: u32,
+  /// Check `PlayerState::refresh_region_bonus`
+  pub region_bonus: HeldRegionBonus,
+  /// Every troop the player ever had, it names the next troop spawned
+  pub troops_spawned: u32,
+  /// Enemy troops killed by the troops of the player
+  pub troops_killed: u32,
+
+  pub alive: bool,
+}
+
+impl PlayerState {
+  pub fn new(player_id: PlayerId) -> PlayerState {
+    PlayerState {
+      player_id,
+      troops_counter: 0,
+      territory_counter: 0,
+      max_troop_allowed: 0,
+      region_bonus: HeldRegionBonus::default(),
+      troops_spawned: 0,
+      troops_killed: 0,
+      alive: true,
+    }
+  }
+
+  /// Returns the id the new troop should be named after, it's the same on every run
+  /// of a match, unlike the names Godot would pick on its own
+  pub fn register_troop_spawning(&mut self) -> TroopId {
+    self.troops_counter += 1;
+    self.troops_spawned += 1;
+
+    format!("troop_{}_{}", self.player_id, self.troops_spawned)
+  }
+
+  pub fn register_troop_loss(&mut self) {
+    self.troops_counter = self.troops_counter.saturating_sub(1);
+  }
+
+  pub fn register_kill(&mut self) {
+    self.troops_killed += 1;
+  }
+
+  pub fn register_territory_occupation(&mut self, _territory_id: TerritoryId) {
+    self.territory_counter += 1;
+  }
+
+  pub fn register_territory_loss(&mut self) {
+    self.territory_counter = self.territory_counter.saturating_sub(1);
+  }
+}
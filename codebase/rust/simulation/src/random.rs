@@ -0,0 +1,119 @@
+use std::ops::Range;
+
+/// Every random draw of a match comes from the match seed,
+/// two matches with the same seed and the same orders are identical
+pub type MatchSeed = u64;
+
+#[derive(Debug, Clone)]
+pub struct MatchRng {
+  seed: MatchSeed,
+  rng: fastrand::Rng,
+}
+
+impl MatchRng {
+  pub const DEFAULT_SEED: MatchSeed = 0x6578_7061_6e73;
+
+  pub fn new(seed: MatchSeed) -> MatchRng {
+    MatchRng {
+      seed,
+      rng: fastrand::Rng::with_seed(seed),
+    }
+  }
+
+  pub fn get_seed(&self) -> MatchSeed {
+    self.seed
+  }
+
+  /// Current internal state of the generator, it's what a saved match needs
+  /// to keep drawing the very same numbers after being loaded
+  pub fn get_state(&self) -> u64 {
+    self.rng.get_seed()
+  }
+
+  pub fn restore_state(&mut self, state: u64) {
+    self.rng.seed(state);
+  }
+
+  /// Returns a random index in the range, panics if the range is empty
+  pub fn usize(&mut self, range: Range<usize>) -> usize {
+    self.rng.usize(range)
+  }
+
+  /// Returns a random number between 0 and 1
+  pub fn f32(&mut self) -> f32 {
+    self.rng.f32()
+  }
+
+  /// Returns a random number between 0 and 1
+  pub fn f64(&mut self) -> f64 {
+    self.rng.f64()
+  }
+
+  /// Picks a random element of the slice, None if it's empty
+  pub fn choice<'a, T>(&mut self, items: &'a [T]) -> Option<&'a T> {
+    if items.is_empty() {
+      return None;
+    }
+
+    items.get(self.usize(0..items.len()))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::MatchRng;
+
+  fn get_draws(rng: &mut MatchRng) -> Vec<usize> {
+    (0..32).map(|_| rng.usize(0..1000)).collect()
+  }
+
+  #[test]
+  fn same_seed_draws_the_same_numbers() {
+    let mut rng = MatchRng::new(42);
+    let mut other_rng = MatchRng::new(42);
+
+    assert_eq!(get_draws(&mut rng), get_draws(&mut other_rng));
+    assert_eq!(rng.f64(), other_rng.f64());
+    assert_eq!(rng.get_seed(), 42);
+  }
+
+  #[test]
+  fn different_seeds_draw_different_numbers() {
+    assert_ne!(get_draws(&mut MatchRng::new(1)), get_draws(&mut MatchRng::new(2)));
+  }
+
+  #[test]
+  fn restored_state_keeps_drawing_the_same_numbers() {
+    let mut rng = MatchRng::new(7);
+    get_draws(&mut rng);
+    let state = rng.get_state();
+    let expected_draws = get_draws(&mut rng);
+
+    let mut restored_rng = MatchRng::new(7);
+    restored_rng.restore_state(state);
+
+    assert_eq!(get_draws(&mut restored_rng), expected_draws);
+    assert_eq!(restored_rng.get_seed(), 7);
+  }
+
+  #[test]
+  fn choice_picks_an_element_of_the_slice() {
+    let mut rng = MatchRng::new(MatchRng::DEFAULT_SEED);
+    let items = [3, 5, 8];
+
+    for _ in 0..32 {
+      assert!(items.contains(rng.choice(&items).expect("Expected an element")));
+    }
+    assert_eq!(rng.choice::<u32>(&[]), None);
+  }
+
+  #[test]
+  fn draws_between_0_and_1() {
+    let mut rng = MatchRng::new(MatchRng::DEFAULT_SEED);
+
+    for _ in 0..100 {
+      assert!((0. ..1.).contains(&rng.f32()));
+      assert!((0. ..1.).contains(&rng.f64()));
+    }
+  }
+}
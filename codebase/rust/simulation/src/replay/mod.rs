@@ -0,0 +1,3 @@
+pub mod record;
+pub mod recorder;
+pub mod playback;
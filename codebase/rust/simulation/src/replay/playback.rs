@@ -0,0 +1,280 @@
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+use crate::clock::SimulationClock;
+
+use super::record::{Replay, ReplayOrder, TroopSpawn};
+
+/// First difference found between a replay and its playback
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReplayDesync {
+  pub tick: u64,
+  pub expected: Option<TroopSpawn>,
+  pub found: Option<TroopSpawn>,
+}
+
+impl Display for ReplayDesync {
+  fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+    write!(f, "the playback drifted away from the replay at tick {}, expected spawn {:?}, found {:?}",
+      self.tick, self.expected, self.found)
+  }
+}
+
+/// Where the match should go to reach the tick asked by `ReplayPlayback::get_seek`
+#[derive(Debug, Clone, PartialEq)]
+pub enum Seek {
+  /// Ticks ahead, it keeps going from the current tick
+  Forward(u64),
+  /// The tick is behind, the match has to be loaded again from `Replay::start`
+  /// and then stepped up to `target_tick`
+  Restart { target_tick: u64 },
+}
+
+/// Feeds the recorded orders back to a match, one tick at a time
+/// Seeking is done by simulating the match again, so it doesn't need anything but the replay
+#[derive(Debug, Clone)]
+pub struct ReplayPlayback {
+  pub replay: Replay,
+  pub paused: bool,
+  speed_index: usize,
+
+  next_order: usize,
+  next_spawn: usize,
+  desync: Option<ReplayDesync>,
+}
+
+impl ReplayPlayback {
+  /// Speed multipliers it goes through, `SimulationClock::MAX_TICKS_PER_FRAME` caps how fast it really goes
+  pub const SPEEDS: [f64; 6] = [0.25, 0.5, 1., 2., 4., 8.];
+  const NORMAL_SPEED_INDEX: usize = 2;
+
+  pub fn new(replay: Replay) -> ReplayPlayback {
+    ReplayPlayback {
+      replay,
+      paused: false,
+      speed_index: Self::NORMAL_SPEED_INDEX,
+
+      next_order: 0,
+      next_spawn: 0,
+      desync: None,
+    }
+  }
+
+  pub fn get_speed(&self) -> f64 {
+    Self::SPEEDS[self.speed_index]
+  }
+
+  pub fn speed_up(&mut self) {
+    self.speed_index = (self.speed_index + 1).min(Self::SPEEDS.len() - 1);
+  }
+
+  pub fn slow_down(&mut self) {
+    self.speed_index = self.speed_index.saturating_sub(1);
+  }
+
+  pub fn toggle_pause(&mut self) {
+    self.paused = !self.paused;
+  }
+
+  /// Frame delta the clock should accumulate, given the pause and the speed
+  pub fn scale_frame_delta(&self, frame_delta: f64) -> f64 {
+    if self.paused {
+      return 0.;
+    }
+
+    frame_delta * self.get_speed()
+  }
+
+  pub fn is_finished(&self, tick: u64) -> bool {
+    tick >= self.replay.last_tick
+  }
+
+  /// Orders given right after `tick` was stepped, to be carried out before stepping the next one
+  pub fn take_orders(&mut self, tick: u64) -> Vec<ReplayOrder> {
+    let mut orders = Vec::new();
+
+    while let Some(order) = self.replay.orders.get(self.next_order) {
+      if order.tick > tick {
+        break;
+      }
+
+      orders.push(order.clone());
+      self.next_order += 1;
+    }
+
+    orders
+  }
+
+  /// Compares a spawn of the playback with the recorded ones, only the first desync is kept
+  pub fn check_spawn(&mut self, found: &TroopSpawn) {
+    if self.desync.is_some() {
+      return;
+    }
+
+    let expected = self.replay.spawns.get(self.next_spawn);
+    if expected == Some(found) {
+      self.next_spawn += 1;
+      return;
+    }
+
+    self.desync = Some(ReplayDesync {
+      tick: found.tick,
+      expected: expected.cloned(),
+      found: Some(found.clone()),
+    });
+  }
+
+  /// Should be called once `tick` has been stepped, catches the spawns that never happened up to it
+  pub fn check_missing_spawns(&mut self, tick: u64) {
+    if self.desync.is_some() {
+      return;
+    }
+
+    let Some(expected) = self.replay.spawns.get(self.next_spawn) else {
+      return;
+    };
+
+    if expected.tick <= tick {
+      self.desync = Some(ReplayDesync {
+        tick,
+        expected: Some(expected.clone()),
+        found: None,
+      });
+    }
+  }
+
+  pub fn get_desync(&self) -> Option<&ReplayDesync> {
+    self.desync.as_ref()
+  }
+
+  /// Tick to go to when moving `offset_secs` away from `current_tick`, kept within the replay
+  pub fn get_seek(&self, current_tick: u64, offset_secs: f64) -> Seek {
+    let offset_ticks = (offset_secs.abs() * SimulationClock::TICKS_PER_SECOND as f64) as u64;
+
+    let target_tick = if offset_secs < 0. {
+      current_tick.saturating_sub(offset_ticks).max(self.replay.start.tick)
+    } else {
+      current_tick.saturating_add(offset_ticks).min(self.replay.last_tick)
+    };
+
+    if target_tick >= current_tick {
+      Seek::Forward(target_tick - current_tick)
+    } else {
+      Seek::Restart { target_tick }
+    }
+  }
+
+  /// Goes back to the beginning of the replay, keeping the pause and the speed
+  pub fn rewind(&mut self) {
+    self.next_order = 0;
+    self.next_spawn = 0;
+    self.desync = None;
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::headless::world::World;
+  use crate::setup::MatchSetup;
+
+  use super::super::record::Replay;
+  use super::{ReplayPlayback, Seek};
+
+  fn get_recorded_replay(ticks: u32) -> Replay {
+    let mut world = World::new(&MatchSetup::default());
+    for _ in 0..ticks {
+      world.tick();
+    }
+
+    world.get_replay().expect("Expected a new match to be recorded")
+  }
+
+  fn play(replay: &Replay) -> World {
+    let mut world = World::from_replay(replay);
+    while world.clock.tick < replay.last_tick {
+      world.tick();
+    }
+
+    world
+  }
+
+  #[test]
+  fn playback_goes_through_the_same_match() {
+    let replay = get_recorded_replay(1200);
+    assert!(!replay.orders.is_empty() && !replay.spawns.is_empty());
+
+    let replay = Replay::from_json(&replay.to_json()).expect("Expected replay to be read");
+    let world = play(&replay);
+
+    assert_eq!(world.get_replay_desync(), None);
+    assert_eq!(world.clock.tick, 1200);
+  }
+
+  #[test]
+  fn playback_of_a_tampered_replay_drifts_away() {
+    let replay = get_recorded_replay(1200);
+    let last_spawn = replay.spawns.last().expect("Expected troops to be spawned").clone();
+
+    let mut moved_spawn_replay = replay.clone();
+    moved_spawn_replay.spawns.last_mut().expect("Expected troops to be spawned").tick += 1;
+    let desync = play(&moved_spawn_replay).get_replay_desync().cloned().expect("Expected the playback to drift away");
+
+    assert_eq!(desync.tick, last_spawn.tick);
+    assert_eq!(desync.found, Some(last_spawn.clone()));
+
+    let mut missing_spawn_replay = replay.clone();
+    let mut missing_spawn = last_spawn.clone();
+    missing_spawn.troop_id = "troop_9_1".to_owned();
+    missing_spawn_replay.spawns.push(missing_spawn.clone());
+    let desync = play(&missing_spawn_replay).get_replay_desync().cloned().expect("Expected the playback to drift away");
+
+    assert_eq!(desync.expected, Some(missing_spawn));
+    assert_eq!(desync.found, None);
+  }
+
+  #[test]
+  fn orders_are_taken_once_their_tick_is_stepped() {
+    let replay = get_recorded_replay(1200);
+    let first_order_tick = replay.orders.first().expect("Expected bots to give orders").tick;
+    let mut playback = ReplayPlayback::new(replay);
+
+    if first_order_tick > 0 {
+      assert!(playback.take_orders(first_order_tick - 1).is_empty());
+    }
+
+    let orders = playback.take_orders(first_order_tick);
+    assert!(!orders.is_empty());
+    assert!(orders.iter().all(|order| order.tick == first_order_tick));
+    assert!(playback.take_orders(first_order_tick).is_empty());
+
+    playback.rewind();
+    assert_eq!(playback.take_orders(first_order_tick), orders);
+  }
+
+  #[test]
+  fn seeking_stays_within_the_replay() {
+    let playback = ReplayPlayback::new(get_recorded_replay(600));
+
+    assert_eq!(playback.get_seek(300, 2.), Seek::Forward(120));
+    assert_eq!(playback.get_seek(300, 60.), Seek::Forward(300));
+    assert_eq!(playback.get_seek(300, -2.), Seek::Restart { target_tick: 180 });
+    assert_eq!(playback.get_seek(300, -60.), Seek::Restart { target_tick: 0 });
+  }
+
+  #[test]
+  fn speed_is_kept_within_its_steps() {
+    let mut playback = ReplayPlayback::new(get_recorded_replay(0));
+
+    for _ in 0..10 {
+      playback.speed_up();
+    }
+    assert_eq!(playback.scale_frame_delta(1.), 8.);
+
+    for _ in 0..10 {
+      playback.slow_down();
+    }
+    assert_eq!(playback.scale_frame_delta(1.), 0.25);
+
+    playback.toggle_pause();
+    assert_eq!(playback.scale_frame_delta(1.), 0.);
+  }
+}
@@ -0,0 +1,110 @@
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::players::player::PlayerId;
+use crate::save::match_save::{MatchSave, SaveError};
+use crate::save::migration::migrate;
+use crate::territories::territory::{Territories, TerritoryId};
+use crate::troops::orders::DeployOrder;
+use crate::troops::troop::TroopId;
+
+/// Everything needed to play a match again: the state it started from and every order given
+/// Spawns aren't needed to reproduce the match, they're kept to tell when a playback drifts away from it
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Replay {
+  /// Format of the replay, check `Replay::CURRENT_VERSION`
+  pub version: u32,
+  /// Saved right when the recording started, it holds the match seed
+  pub start: MatchSave,
+  /// Ordered by tick
+  pub orders: Vec<ReplayOrder>,
+  /// Ordered by tick
+  pub spawns: Vec<TroopSpawn>,
+  pub last_tick: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum OrderSource {
+  Player,
+  Bot,
+}
+
+/// Orders are given in between ticks, `tick` is the last tick stepped before it
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ReplayOrder {
+  pub tick: u64,
+  pub player_id: PlayerId,
+  pub source: OrderSource,
+  pub order: DeployOrder,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TroopSpawn {
+  pub tick: u64,
+  pub troop_id: TroopId,
+  pub player_id: PlayerId,
+  pub territory_id: TerritoryId,
+}
+
+/// Why a replay can't be played, meant to be shown as is
+#[derive(Debug, Clone, PartialEq)]
+pub enum ReplayError {
+  Parse(String),
+  NewerVersion(u32),
+  Start(SaveError),
+}
+
+impl Display for ReplayError {
+  fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+    match self {
+      ReplayError::Parse(reason) =>
+        write!(f, "couldn't read the replay: {reason}"),
+      ReplayError::NewerVersion(version) =>
+        write!(f, "the replay is from version {version}, but this game only plays up to version {}", Replay::CURRENT_VERSION),
+      ReplayError::Start(error) =>
+        write!(f, "the match the replay starts from is invalid, {error}"),
+    }
+  }
+}
+
+impl std::error::Error for ReplayError {}
+
+impl Replay {
+  pub const CURRENT_VERSION: u32 = 1;
+
+  /// Compact on purpose, replays of long matches get big
+  pub fn to_json(&self) -> String {
+    serde_json::to_string(self).expect("Expected replay to be serializable")
+  }
+
+  /// The match the replay starts from is migrated like any other save
+  pub fn from_json(content: &str) -> Result<Replay, ReplayError> {
+    let mut raw_replay: Value = serde_json::from_str(content).map_err(|error| ReplayError::Parse(error.to_string()))?;
+
+    let version = raw_replay
+      .get("version")
+      .and_then(Value::as_u64)
+      .ok_or_else(|| ReplayError::Parse("the replay has no valid version".to_owned()))? as u32;
+
+    if version > Self::CURRENT_VERSION {
+      return Err(ReplayError::NewerVersion(version));
+    }
+
+    let raw_start = raw_replay
+      .get_mut("start")
+      .map(Value::take)
+      .ok_or_else(|| ReplayError::Parse("the replay has no match to start from".to_owned()))?;
+    raw_replay["start"] = migrate(raw_start).map_err(ReplayError::Start)?;
+
+    serde_json::from_value(raw_replay).map_err(|error| ReplayError::Parse(error.to_string()))
+  }
+
+  /// Same as `from_json`, but also checks the match it starts from against the territories of the map
+  pub fn load(content: &str, territories: &Territories) -> Result<Replay, ReplayError> {
+    let replay = Self::from_json(content)?;
+    replay.start.validate(territories).map_err(ReplayError::Start)?;
+    Ok(replay)
+  }
+}
@@ -0,0 +1,48 @@
+use crate::players::player::PlayerId;
+use crate::save::match_save::MatchSave;
+use crate::troops::orders::DeployOrder;
+
+use super::record::{OrderSource, Replay, ReplayOrder, TroopSpawn};
+
+/// Keeps every order and spawn of a match as it's played
+#[derive(Debug, Clone)]
+pub struct ReplayRecorder {
+  replay: Replay,
+}
+
+impl ReplayRecorder {
+  /// `start` is the match as it is right before the first order recorded
+  pub fn new(start: MatchSave) -> ReplayRecorder {
+    let last_tick = start.tick;
+
+    ReplayRecorder {
+      replay: Replay {
+        version: Replay::CURRENT_VERSION,
+        start,
+        orders: Vec::new(),
+        spawns: Vec::new(),
+        last_tick,
+      },
+    }
+  }
+
+  pub fn record_order(&mut self, tick: u64, player_id: PlayerId, source: OrderSource, order: &DeployOrder) {
+    self.replay.orders.push(ReplayOrder {
+      tick,
+      player_id,
+      source,
+      order: order.clone(),
+    });
+  }
+
+  pub fn record_spawn(&mut self, spawn: TroopSpawn) {
+    self.replay.spawns.push(spawn);
+  }
+
+  /// The replay recorded so far, up to `last_tick`
+  pub fn get_replay(&self, last_tick: u64) -> Replay {
+    let mut replay = self.replay.clone();
+    replay.last_tick = last_tick;
+    replay
+  }
+}
@@ -0,0 +1,75 @@
+use serde::{Deserialize, Serialize};
+
+use crate::territories::adjacency::AdjacencyGraph;
+use crate::territories::bonuses::RegionBonus;
+use crate::territories::territory::Territory;
+use crate::troops::archetype::Archetype;
+use crate::troops::combat::combat_stats::CombatStats;
+use crate::troops::combat::location::LocationRules;
+use crate::troops::naval::Naval;
+use crate::victory::conditions::VictoryCondition;
+
+/// Numbers used to balance the game, by default the ones the game ships with
+/// A match can override any of them, fields left out keep their default value
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Rules {
+  /// Check `Territory::BASE_TROOP_NUMBER_PER_TERRITORY`
+  pub base_troop_number_per_territory: f32,
+  /// Check `Territory::MAX_NUMBER_OF_TROOPS_GENERATED_PER_TERRITORY`
+  pub max_number_of_troops_generated_per_territory: u32,
+  /// Check `Territory::BASE_TROOP_GROWTH_VELOCITY`
+  pub base_troop_growth_velocity: f32,
+  /// Check `Territory::BASE_SECONDS_FOR_A_TROOP_TO_SPAWN`
+  pub base_seconds_for_a_troop_to_spawn: f64,
+  /// Check `Territory::BASE_TERRITORY_OCCUPATION_TIME`
+  pub base_territory_occupation_time: f64,
+  /// Check `AdjacencyGraph::MAX_SEA_CONNECTION_DISTANCE`
+  pub max_sea_connection_distance: f32,
+  /// What a player gets for ruling a whole continent or sub-continent, check `RegionBonus::get_default_bonuses`
+  pub region_bonuses: Vec<RegionBonus>,
+
+  /// Hp, damage, range, fire rate, speeds and armor of every kind of troop, check `Archetype`,
+  /// by default the ones of `codebase/godot/data/archetypes.ron`
+  pub archetypes: Vec<Archetype>,
+  /// Damage, defence, fire rate and morale multipliers depending on whose land a troop is on, check `LocationRules`
+  pub location_modifiers: LocationRules,
+
+  /// Check `CombatStats::AFTER_COMBAT_COOL_DOWN_IN_SECS`, 0 lets troops heal and territories spawn right away
+  pub after_combat_cool_down_in_secs: f64,
+  /// Check `Territory::BASE_HEALING_PER_COORDINATE`, 0 turns healing off
+  pub base_healing_per_coordinate: f64,
+
+  /// Check `Naval::EMBARK_TIME_IN_SECS`, 0 lets troops take to the sea right away
+  pub embark_time_in_secs: f32,
+  /// Check `Naval::DISEMBARK_TIME_IN_SECS`, 0 lets troops land right away
+  pub disembark_time_in_secs: f32,
+
+  /// Check `VictoryCondition`, the match also ends when a single player is left
+  pub victory_conditions: Vec<VictoryCondition>,
+}
+
+impl Default for Rules {
+  fn default() -> Self {
+    Rules {
+      base_troop_number_per_territory: Territory::BASE_TROOP_NUMBER_PER_TERRITORY,
+      max_number_of_troops_generated_per_territory: Territory::MAX_NUMBER_OF_TROOPS_GENERATED_PER_TERRITORY,
+      base_troop_growth_velocity: Territory::BASE_TROOP_GROWTH_VELOCITY,
+      base_seconds_for_a_troop_to_spawn: Territory::BASE_SECONDS_FOR_A_TROOP_TO_SPAWN,
+      base_territory_occupation_time: Territory::BASE_TERRITORY_OCCUPATION_TIME,
+      max_sea_connection_distance: AdjacencyGraph::MAX_SEA_CONNECTION_DISTANCE,
+      region_bonuses: RegionBonus::get_default_bonuses(),
+
+      archetypes: Archetype::get_bundled(),
+      location_modifiers: LocationRules::default(),
+
+      after_combat_cool_down_in_secs: CombatStats::AFTER_COMBAT_COOL_DOWN_IN_SECS,
+      base_healing_per_coordinate: Territory::BASE_HEALING_PER_COORDINATE,
+
+      embark_time_in_secs: Naval::EMBARK_TIME_IN_SECS,
+      disembark_time_in_secs: Naval::DISEMBARK_TIME_IN_SECS,
+
+      victory_conditions: VictoryCondition::get_default_conditions(),
+    }
+  }
+}
@@ -0,0 +1,300 @@
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+use serde::{Deserialize, Serialize};
+
+use crate::math::Vec3;
+use crate::players::player::{PlayerId, PlayerState};
+use crate::random::MatchSeed;
+use crate::rules::Rules;
+use crate::setup::{MatchSetup, PlayerSetup};
+use crate::territories::territory::{Territories, TerritoryId};
+use crate::troops::archetype::{Archetype, ArchetypeDataError};
+use crate::troops::combat::combat_stats::CombatStats;
+use crate::troops::combat::projectile::Projectile;
+use crate::troops::orders::OrderQueue;
+use crate::troops::speed::SpeedType;
+use crate::troops::surface::Surface;
+use crate::troops::troop::{TroopActivities, TroopId};
+use crate::victory::tracker::VictoryTracker;
+
+use super::migration::{migrate, CURRENT_VERSION};
+use super::territory_save::TerritorySave;
+
+/// A match frozen in a given tick, written as JSON
+/// Holds every troop's velocity along with its position, as the steering of the next tick starts from it,
+/// so a headless `World` loaded from it goes through the very same steps the saved one would have
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MatchSave {
+  /// Format of the save, check `migration::CURRENT_VERSION`
+  pub version: u32,
+  pub tick: u64,
+  pub seed: MatchSeed,
+  /// Check `MatchRng::get_state`
+  pub rng_state: u64,
+  pub rules: Rules,
+
+  pub players: Vec<PlayerSave>,
+  pub territories: Vec<TerritorySave>,
+  pub troops: Vec<TroopSave>,
+  pub projectiles: Vec<Projectile>,
+  pub victory: VictoryTracker,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PlayerSave {
+  /// How the player was set up when the match started
+  pub setup: PlayerSetup,
+  pub state: PlayerState,
+}
+
+/// Same fields a `Troop` needs to keep going, whether in the game or headless
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TroopSave {
+  pub troop_id: TroopId,
+  pub owner: PlayerId,
+  pub position: Vec3,
+  /// Steering of the troops around it starts from it on the next tick, check `Troop::velocity`
+  pub velocity: Vec3,
+
+  pub deployed_to_territory: TerritoryId,
+  pub arrived_to_territory: bool,
+
+  pub combat_stats: CombatStats,
+  pub troop_activities: TroopActivities,
+  pub adopted_speed: SpeedType,
+  pub idle_timer: f32,
+  pub surface: Surface,
+  pub boarding_timer: f32,
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub pace: Option<f32>,
+  #[serde(default, skip_serializing_if = "OrderQueue::is_empty")]
+  pub order_queue: OrderQueue,
+
+  /// Empty while the troop has nowhere to go
+  pub moving_trajectory_points: Vec<Vec3>,
+  pub current_trajectory_point: usize,
+  pub moving_and_combating: bool,
+  pub waiting_for_deployment_following_action: bool,
+}
+
+/// Why a save can't be loaded, meant to be shown as is
+#[derive(Debug, Clone, PartialEq)]
+pub enum SaveError {
+  Parse(String),
+  MissingVersion,
+  NewerVersion(u32),
+  Migration(u32, String),
+  UnknownTerritory(TerritoryId),
+  UnknownPlayer(PlayerId),
+  InvalidArchetypes(ArchetypeDataError),
+}
+
+impl Display for SaveError {
+  fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+    match self {
+      SaveError::Parse(reason) =>
+        write!(f, "couldn't read the save: {reason}"),
+      SaveError::MissingVersion =>
+        write!(f, "the save has no valid version"),
+      SaveError::NewerVersion(version) =>
+        write!(f, "the save is from version {version}, but this game only reads up to version {CURRENT_VERSION}"),
+      SaveError::Migration(from_version, reason) =>
+        write!(f, "couldn't migrate the save from version {from_version}: {reason}"),
+      SaveError::UnknownTerritory(territory_id) =>
+        write!(f, "the save mentions '{territory_id}', which isn't a territory"),
+      SaveError::UnknownPlayer(player_id) =>
+        write!(f, "the save mentions player {player_id}, who isn't in it"),
+      SaveError::InvalidArchetypes(error) =>
+        write!(f, "{error}"),
+    }
+  }
+}
+
+impl std::error::Error for SaveError {}
+
+impl MatchSave {
+  pub fn to_json(&self) -> String {
+    serde_json::to_string_pretty(self).expect("Expected match save to be serializable")
+  }
+
+  /// Reads a save of any known version, migrating it to `CURRENT_VERSION` if needed
+  pub fn from_json(content: &str) -> Result<MatchSave, SaveError> {
+    let raw_save = serde_json::from_str(content).map_err(|error| SaveError::Parse(error.to_string()))?;
+    let raw_save = migrate(raw_save)?;
+
+    serde_json::from_value(raw_save).map_err(|error| SaveError::Parse(error.to_string()))
+  }
+
+  /// Same as `from_json`, but also checks every territory and player mentioned exist,
+  /// and every troop has an archetype
+  pub fn load(content: &str, territories: &Territories) -> Result<MatchSave, SaveError> {
+    let save = Self::from_json(content)?;
+    save.validate(territories)?;
+    Ok(save)
+  }
+
+  pub fn validate(&self, territories: &Territories) -> Result<(), SaveError> {
+    Archetype::validate(&self.rules.archetypes).map_err(SaveError::InvalidArchetypes)?;
+
+    let check_territory = |territory_id: &TerritoryId| {
+      if territories.contains_key(territory_id) {
+        Ok(())
+      } else {
+        Err(SaveError::UnknownTerritory(territory_id.clone()))
+      }
+    };
+
+    let check_player = |player_id: PlayerId| {
+      if self.players.iter().any(|player| player.setup.player_id == player_id) {
+        Ok(())
+      } else {
+        Err(SaveError::UnknownPlayer(player_id))
+      }
+    };
+
+    for territory in &self.territories {
+      check_territory(&territory.territory_id)?;
+
+      let players_in_territory = territory.current_ruler.iter()
+        .chain(territory.player_trying_to_conquer.iter())
+        .chain(territory.all_troops_deployed_by_player.keys())
+        .chain(territory.all_troops_deployed_and_arrived_by_player.keys());
+
+      for player_id in players_in_territory {
+        check_player(*player_id)?;
+      }
+    }
+
+    for troop in &self.troops {
+      check_territory(&troop.deployed_to_territory)?;
+      check_player(troop.owner)?;
+    }
+
+    for (player_id, _) in &self.victory.eliminations {
+      check_player(*player_id)?;
+    }
+
+    Ok(())
+  }
+
+  /// The setup the saved match was started with
+  pub fn get_setup(&self) -> MatchSetup {
+    MatchSetup {
+      seed: self.seed,
+      rules: self.rules.clone(),
+      players: self.players.iter().map(|player| player.setup.clone()).collect(),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use serde_json::Value;
+
+  use crate::headless::world::World;
+  use crate::players::player::PlayerId;
+  use crate::setup::MatchSetup;
+  use crate::territories::territory::{Territory, TerritoryId};
+
+  use super::{MatchSave, SaveError};
+
+  type TerritoryProgress = (TerritoryId, Option<PlayerId>, Option<PlayerId>);
+
+  /// Ticks in between saves, and ticks the loaded match is played against the saved one
+  const SAVE_EVERY_TICKS: u32 = 600;
+  const TICKS_AFTER_LOADING: u32 = 300;
+
+  /// Four players sending plenty of troops after each other
+  fn get_crowded_setup() -> MatchSetup {
+    let mut setup = MatchSetup::default();
+    for player_setup in &mut setup.players {
+      player_setup.initial_troops = 12;
+    }
+    setup
+  }
+
+  /// Every field of every troop, activities are a `HashSet`, so they're sorted to be compared
+  fn get_troops_state(save: &MatchSave) -> Vec<Value> {
+    save.troops
+      .iter()
+      .map(|troop| {
+        let mut troop = serde_json::to_value(troop).expect("Expected troop to be serializable");
+        troop["troop_activities"]
+          .as_array_mut()
+          .expect("Expected troop activities to be a list")
+          .sort_by_key(|activity| activity.to_string());
+        troop
+      })
+      .collect()
+  }
+
+  /// Territory states are a `HashSet`, so two saves of the same match aren't always written the same way
+  fn get_progress(save: &MatchSave) -> (u64, u64, Vec<Value>, Vec<TerritoryProgress>, Value) {
+    let territories = save.territories
+      .iter()
+      .map(|territory| (territory.territory_id.clone(), territory.current_ruler, territory.player_trying_to_conquer))
+      .collect();
+    let projectiles = serde_json::to_value(&save.projectiles).expect("Expected projectiles to be serializable");
+
+    (save.tick, save.rng_state, get_troops_state(save), territories, projectiles)
+  }
+
+  fn tick(world: &mut World, ticks: u32) {
+    for _ in 0..ticks {
+      world.tick();
+    }
+  }
+
+  #[test]
+  fn loaded_match_goes_on_as_the_saved_one() {
+    let territories = Territory::get_map();
+    let mut world = World::new(&get_crowded_setup());
+
+    for _ in 0..12 {
+      tick(&mut world, SAVE_EVERY_TICKS - TICKS_AFTER_LOADING);
+
+      let save = MatchSave::load(&world.get_save().to_json(), &territories).expect("Expected save to be loaded");
+      let mut loaded_world = World::from_save(&save);
+      assert_eq!(get_progress(&loaded_world.get_save()), get_progress(&world.get_save()));
+
+      tick(&mut world, TICKS_AFTER_LOADING);
+      tick(&mut loaded_world, TICKS_AFTER_LOADING);
+
+      assert_eq!(get_progress(&loaded_world.get_save()), get_progress(&world.get_save()), "Diverged after tick {}", save.tick);
+    }
+
+    assert!(world.troops.len() > 20, "Expected the match to stay crowded");
+  }
+
+  #[test]
+  fn saves_mentioning_unknown_territories_or_players_are_turned_down() {
+    let territories = Territory::get_map();
+    let save = World::new(&MatchSetup::default()).get_save();
+
+    let mut unknown_territory_save = save.clone();
+    unknown_territory_save.troops[0].deployed_to_territory = "atlantis".to_owned();
+    assert_eq!(unknown_territory_save.validate(&territories), Err(SaveError::UnknownTerritory("atlantis".to_owned())));
+
+    let mut unknown_player_save = save.clone();
+    unknown_player_save.territories[0].current_ruler = Some(99);
+    assert_eq!(unknown_player_save.validate(&territories), Err(SaveError::UnknownPlayer(99)));
+
+    assert_eq!(save.validate(&territories), Ok(()));
+  }
+
+  #[test]
+  fn saves_are_migrated_before_being_read() {
+    let mut save = serde_json::to_value(World::new(&MatchSetup::default()).get_save()).expect("Expected save to be serializable");
+
+    save["version"] = serde_json::Value::from(0);
+    assert_eq!(MatchSave::from_json(&save.to_string()).err(), Some(SaveError::MissingVersion));
+
+    save.as_object_mut().expect("Expected save to be an object").remove("version");
+    assert_eq!(MatchSave::from_json(&save.to_string()).err(), Some(SaveError::MissingVersion));
+
+    save["version"] = serde_json::Value::from(99);
+    assert_eq!(MatchSave::from_json(&save.to_string()).err(), Some(SaveError::NewerVersion(99)));
+
+    assert!(matches!(MatchSave::from_json("{"), Err(SaveError::Parse(_))));
+  }
+}
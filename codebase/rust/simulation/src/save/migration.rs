@@ -0,0 +1,68 @@
+use serde_json::Value;
+
+use super::match_save::SaveError;
+
+/// Format of the saves written by this build, bump it whenever `MatchSave` changes
+/// in a way old saves can't be read anymore, and add the migration from the previous version
+pub const CURRENT_VERSION: u32 = 1;
+
+/// Takes a save from a version to the next one, editing its raw JSON in place
+type Migration = fn(&mut Value) -> Result<(), String>;
+
+/// `MIGRATIONS[i]` takes a save from version `i + 1` to version `i + 2`,
+/// so it must always have `CURRENT_VERSION - 1` entries
+const MIGRATIONS: [Migration; CURRENT_VERSION as usize - 1] = [];
+
+/// Reads the version of a raw save and runs every migration needed to bring it to `CURRENT_VERSION`
+pub fn migrate(mut save: Value) -> Result<Value, SaveError> {
+  let version = save
+    .get("version")
+    .and_then(Value::as_u64)
+    .ok_or(SaveError::MissingVersion)? as u32;
+
+  if version == 0 {
+    return Err(SaveError::MissingVersion);
+  }
+
+  if version > CURRENT_VERSION {
+    return Err(SaveError::NewerVersion(version));
+  }
+
+  for from_version in version..CURRENT_VERSION {
+    let migration = MIGRATIONS[from_version as usize - 1];
+    migration(&mut save).map_err(|reason| SaveError::Migration(from_version, reason))?;
+    save["version"] = Value::from(from_version + 1);
+  }
+
+  Ok(save)
+}
+
+#[cfg(test)]
+mod tests {
+  use serde_json::json;
+
+  use crate::save::match_save::SaveError;
+
+  use super::{migrate, CURRENT_VERSION};
+
+  #[test]
+  fn current_saves_are_left_as_they_are() {
+    let save = json!({ "version": CURRENT_VERSION, "tick": 3 });
+
+    assert_eq!(migrate(save.clone()), Ok(save));
+  }
+
+  #[test]
+  fn saves_without_a_valid_version_are_turned_down() {
+    for save in [json!({ "tick": 3 }), json!({ "version": 0 }), json!({ "version": "1" }), json!({ "version": -1 })] {
+      assert_eq!(migrate(save), Err(SaveError::MissingVersion));
+    }
+  }
+
+  #[test]
+  fn saves_from_newer_versions_are_turned_down() {
+    let save = json!({ "version": CURRENT_VERSION + 1 });
+
+    assert_eq!(migrate(save), Err(SaveError::NewerVersion(CURRENT_VERSION + 1)));
+  }
+}
@@ -0,0 +1,3 @@
+pub mod match_save;
+pub mod migration;
+pub mod territory_save;
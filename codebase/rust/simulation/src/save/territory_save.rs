@@ -0,0 +1,90 @@
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+
+use serde::{Deserialize, Serialize};
+
+use crate::players::player::PlayerId;
+use crate::territories::territory::{Territory, TerritoryId, TerritoryState};
+use crate::troops::troop::TroopId;
+
+/// What changes in a territory while the match is played,
+/// everything else is recalculated from its coordinates and the rules
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TerritorySave {
+  pub territory_id: TerritoryId,
+  pub territory_states: HashSet<TerritoryState>,
+
+  pub current_ruler: Option<PlayerId>,
+  pub player_trying_to_conquer: Option<PlayerId>,
+  pub conquering_progress_per_second: f64,
+  pub progress_to_reset_idle_conquering: f64,
+
+  pub next_troop_progress: f64,
+  pub valid_seconds_elasped_since_last_troop: f64,
+  pub after_combat_timer: f64,
+
+  pub all_troops_deployed_by_player: BTreeMap<PlayerId, BTreeSet<TroopId>>,
+  pub all_troops_deployed_and_arrived_by_player: BTreeMap<PlayerId, BTreeSet<TroopId>>,
+}
+
+impl Territory {
+  pub fn get_save(&self) -> TerritorySave {
+    TerritorySave {
+      territory_id: self.territory_id.clone(),
+      territory_states: self.territory_states.clone(),
+
+      current_ruler: self.current_ruler,
+      player_trying_to_conquer: self.player_trying_to_conquer,
+      conquering_progress_per_second: self.conquering_progress_per_second,
+      progress_to_reset_idle_conquering: self.progress_to_reset_idle_conquering,
+
+      next_troop_progress: self.next_troop_progress,
+      valid_seconds_elasped_since_last_troop: self.valid_seconds_elasped_since_last_troop,
+      after_combat_timer: self.after_combat_timer,
+
+      all_troops_deployed_by_player: self.all_troops_deployed_by_player.clone(),
+      all_troops_deployed_and_arrived_by_player: self.all_troops_deployed_and_arrived_by_player.clone(),
+    }
+  }
+
+  /// Overwrites the match progress of the territory with the saved one,
+  /// the troop sets not split by player are rebuilt from the ones that are
+  pub fn restore_save(&mut self, save: &TerritorySave) {
+    self.territory_states = save.territory_states.clone();
+
+    self.current_ruler = save.current_ruler;
+    self.player_trying_to_conquer = save.player_trying_to_conquer;
+    self.conquering_progress_per_second = save.conquering_progress_per_second;
+    self.progress_to_reset_idle_conquering = save.progress_to_reset_idle_conquering;
+
+    self.next_troop_progress = save.next_troop_progress;
+    self.valid_seconds_elasped_since_last_troop = save.valid_seconds_elasped_since_last_troop;
+    self.after_combat_timer = save.after_combat_timer;
+
+    self.all_troops_deployed_by_player = save.all_troops_deployed_by_player.clone();
+    self.all_troops_deployed = self.all_troops_deployed_by_player
+      .values()
+      .flatten()
+      .cloned()
+      .collect();
+
+    self.all_troops_deployed_and_arrived_by_player = save.all_troops_deployed_and_arrived_by_player.clone();
+    self.all_troops_deployed_and_arrived = self.all_troops_deployed_and_arrived_by_player
+      .values()
+      .flatten()
+      .cloned()
+      .collect();
+
+    self.set_troops_from_different_players_flag();
+  }
+
+  /// Same state the territory had before the match started
+  pub fn restore_unoccupied(&mut self) {
+    let base_territory = Territory::get_base_territory(
+      &self.territory_id,
+      self.location.continent.clone(),
+      self.location.sub_continent.clone(),
+    );
+
+    self.restore_save(&base_territory.get_save());
+  }
+}
@@ -0,0 +1,432 @@
+use std::collections::BTreeSet;
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+use serde::{Deserialize, Serialize};
+
+use crate::players::color::PlayerColor;
+use crate::players::player::{PlayerId, PlayerType};
+use crate::random::{MatchRng, MatchSeed};
+use crate::rules::Rules;
+use crate::territories::bonuses::Region;
+use crate::territories::continents::get_rulable_continents;
+use crate::territories::territory::{Territories, TerritoryId};
+use crate::troops::archetype::{Archetype, ArchetypeDataError};
+use crate::troops::combat::location::LocationRules;
+use crate::troops::mesh_map::{MeshId, TroopMeshes};
+use crate::troops::surface::Surface;
+use crate::victory::conditions::VictoryCondition;
+
+/// Who plays a match, how they look like and where each player starts
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PlayerSetup {
+  pub player_id: PlayerId,
+  pub user_name: String,
+  #[serde(default = "PlayerSetup::default_player_type")]
+  pub player_type: PlayerType,
+  pub color: PlayerColor,
+  pub initial_territory: TerritoryId,
+  #[serde(default = "PlayerSetup::default_troop_meshes")]
+  pub troop_meshes: TroopMeshes,
+  #[serde(default = "PlayerSetup::default_initial_troops")]
+  pub initial_troops: u32,
+  /// Code of the language the player reads the game in, such as `en_international`, `pt_br` or `es_us`
+  #[serde(default = "PlayerSetup::default_language")]
+  pub language: String,
+}
+
+impl PlayerSetup {
+  pub const DEFAULT_INITIAL_TROOPS: u32 = 1;
+  pub const DEFAULT_LANGUAGE: &'static str = "en_international";
+
+  fn default_player_type() -> PlayerType {
+    PlayerType::Bot
+  }
+
+  fn default_troop_meshes() -> TroopMeshes {
+    TroopMeshes {
+      land: MeshId::Tank1,
+      sea: MeshId::Boat1,
+    }
+  }
+
+  fn default_initial_troops() -> u32 {
+    Self::DEFAULT_INITIAL_TROOPS
+  }
+
+  fn default_language() -> String {
+    Self::DEFAULT_LANGUAGE.to_owned()
+  }
+}
+
+/// Everything needed to start a match, fields left out keep their default value
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MatchSetup {
+  pub seed: MatchSeed,
+  /// Rule overrides, check `Rules`
+  pub rules: Rules,
+  pub players: Vec<PlayerSetup>,
+}
+
+/// Why a match setup can't be used, meant to be shown as is
+#[derive(Debug, Clone, PartialEq)]
+pub enum MatchSetupError {
+  UnsupportedFormat(String),
+  Parse(String),
+  NoPlayers,
+  DuplicatePlayerId(PlayerId),
+  MoreThanOneMainPlayer(Vec<PlayerId>),
+  DuplicateColor(PlayerColor, Vec<PlayerId>),
+  UnknownTerritory(PlayerId, TerritoryId),
+  DuplicateInitialTerritory(TerritoryId, Vec<PlayerId>),
+  WrongMeshSurface(PlayerId, MeshId, Surface),
+  InvalidArchetypes(ArchetypeDataError),
+  InvalidLocationModifiers,
+  /// Check `LocationRules::ally_land`
+  AllyLandModifiers,
+  NegativeRecovery,
+  ImpossibleVictoryCondition(VictoryCondition),
+  InvalidRegionBonus(Region),
+}
+
+impl Display for MatchSetupError {
+  fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+    match self {
+      MatchSetupError::UnsupportedFormat(path) =>
+        write!(f, "'{path}' should be a .ron or a .json file"),
+      MatchSetupError::Parse(reason) =>
+        write!(f, "couldn't read the match setup: {reason}"),
+      MatchSetupError::NoPlayers =>
+        write!(f, "a match needs at least one player"),
+      MatchSetupError::DuplicatePlayerId(player_id) =>
+        write!(f, "player id {player_id} is used by more than one player"),
+      MatchSetupError::MoreThanOneMainPlayer(player_ids) =>
+        write!(f, "players {player_ids:?} are all MainPlayer, only one is allowed"),
+      MatchSetupError::DuplicateColor(color, player_ids) =>
+        write!(f, "players {player_ids:?} have the same color {color:?}"),
+      MatchSetupError::UnknownTerritory(player_id, territory_id) =>
+        write!(f, "player {player_id} starts at '{territory_id}', which isn't a territory"),
+      MatchSetupError::DuplicateInitialTerritory(territory_id, player_ids) =>
+        write!(f, "players {player_ids:?} all start at '{territory_id}'"),
+      MatchSetupError::WrongMeshSurface(player_id, mesh_id, surface) =>
+        write!(f, "player {player_id} uses {mesh_id:?} as its {surface} mesh, but it's not a {surface} mesh"),
+      MatchSetupError::InvalidArchetypes(error) =>
+        write!(f, "{error}"),
+      MatchSetupError::InvalidLocationModifiers =>
+        write!(f, "the location modifiers need positive multipliers"),
+      MatchSetupError::AllyLandModifiers =>
+        write!(f, "there are no alliances yet, the ally_land location modifiers can't be set"),
+      MatchSetupError::NegativeRecovery =>
+        write!(f, "the after combat cool down and the healing can't be negative"),
+      MatchSetupError::ImpossibleVictoryCondition(condition) =>
+        write!(f, "victory condition {condition:?} can't be met on this map"),
+      MatchSetupError::InvalidRegionBonus(region) =>
+        write!(f, "the bonus of {region:?} needs positive multipliers and a region with territories"),
+    }
+  }
+}
+
+impl std::error::Error for MatchSetupError {}
+
+impl MatchSetup {
+  /// Parses a match setup file, RON or JSON depending on the extension of `path`
+  pub fn parse(path: &str, content: &str) -> Result<MatchSetup, MatchSetupError> {
+    if path.ends_with(".ron") {
+      ron::from_str(content).map_err(|error| MatchSetupError::Parse(error.to_string()))
+    } else if path.ends_with(".json") {
+      serde_json::from_str(content).map_err(|error| MatchSetupError::Parse(error.to_string()))
+    } else {
+      Err(MatchSetupError::UnsupportedFormat(path.to_owned()))
+    }
+  }
+
+  /// Same as `parse`, but also validates the setup against the territories of the map
+  pub fn load(path: &str, content: &str, territories: &Territories) -> Result<MatchSetup, MatchSetupError> {
+    let setup = Self::parse(path, content)?;
+    setup.validate(territories)?;
+    Ok(setup)
+  }
+
+  /// Checks the setup can be played, so starting the match doesn't panic half way
+  pub fn validate(&self, territories: &Territories) -> Result<(), MatchSetupError> {
+    if self.players.is_empty() {
+      return Err(MatchSetupError::NoPlayers);
+    }
+
+    Archetype::validate(&self.rules.archetypes).map_err(MatchSetupError::InvalidArchetypes)?;
+
+    let mut player_ids = BTreeSet::new();
+    for player in &self.players {
+      if !player_ids.insert(player.player_id) {
+        return Err(MatchSetupError::DuplicatePlayerId(player.player_id));
+      }
+
+      if !territories.contains_key(&player.initial_territory) {
+        return Err(MatchSetupError::UnknownTerritory(player.player_id, player.initial_territory.clone()));
+      }
+
+      for (mesh_id, surface) in [(&player.troop_meshes.land, Surface::Land), (&player.troop_meshes.sea, Surface::Sea)] {
+        if !self.rules.get_archetype(mesh_id).surfaces.contains(&surface) {
+          return Err(MatchSetupError::WrongMeshSurface(player.player_id, mesh_id.clone(), surface));
+        }
+      }
+    }
+
+    let main_players: Vec<PlayerId> = self.players
+      .iter()
+      .filter(|player| player.player_type == PlayerType::MainPlayer)
+      .map(|player| player.player_id)
+      .collect();
+    if main_players.len() > 1 {
+      return Err(MatchSetupError::MoreThanOneMainPlayer(main_players));
+    }
+
+    let colors: Vec<_> = self.players.iter().map(|player| (&player.color, player.player_id)).collect();
+    if let Some((color, player_ids)) = Self::find_shared(&colors) {
+      return Err(MatchSetupError::DuplicateColor(color.clone(), player_ids));
+    }
+
+    let initial_territories: Vec<_> = self.players.iter().map(|player| (&player.initial_territory, player.player_id)).collect();
+    if let Some((territory_id, player_ids)) = Self::find_shared(&initial_territories) {
+      return Err(MatchSetupError::DuplicateInitialTerritory(territory_id.clone(), player_ids));
+    }
+
+    let num_of_continents = get_rulable_continents(territories).len() as u32;
+    for condition in &self.rules.victory_conditions {
+      let can_be_met = match condition {
+        VictoryCondition::WorldDomination => true,
+        VictoryCondition::Continents(continents) => (1..=num_of_continents).contains(continents),
+        VictoryCondition::HoldSpecialTerritories { secs } |
+        VictoryCondition::TimeLimit { secs } => *secs > 0.,
+      };
+
+      if !can_be_met {
+        return Err(MatchSetupError::ImpossibleVictoryCondition(condition.clone()));
+      }
+    }
+
+    if let Some(bonus) = self.rules.region_bonuses.iter().find(|bonus| !bonus.is_valid(territories)) {
+      return Err(MatchSetupError::InvalidRegionBonus(bonus.region.clone()));
+    }
+
+    if !self.rules.location_modifiers.is_valid() {
+      return Err(MatchSetupError::InvalidLocationModifiers);
+    }
+
+    if self.rules.location_modifiers.ally_land != LocationRules::default().ally_land {
+      return Err(MatchSetupError::AllyLandModifiers);
+    }
+
+    if self.rules.after_combat_cool_down_in_secs < 0. || self.rules.base_healing_per_coordinate < 0. {
+      return Err(MatchSetupError::NegativeRecovery);
+    }
+
+    Ok(())
+  }
+
+  /// Returns the first value shared by more than one player, along with those players
+  fn find_shared<'a, T: PartialEq>(values: &[(&'a T, PlayerId)]) -> Option<(&'a T, Vec<PlayerId>)> {
+    values.iter().find_map(|(value, _)| {
+      let player_ids: Vec<PlayerId> = values
+        .iter()
+        .filter(|(other_value, _)| other_value == value)
+        .map(|(_, player_id)| *player_id)
+        .collect();
+
+      (player_ids.len() > 1).then_some((*value, player_ids))
+    })
+  }
+}
+
+impl Default for MatchSetup {
+  /// Same players the game used to start with, before matches were described by files
+  fn default() -> Self {
+    let player = |
+      player_id: PlayerId,
+      user_name: &str,
+      player_type: PlayerType,
+      color: PlayerColor,
+      initial_territory: &str,
+      troop_meshes: TroopMeshes,
+    | PlayerSetup {
+      player_id,
+      user_name: user_name.to_owned(),
+      player_type,
+      color,
+      initial_territory: initial_territory.to_owned(),
+      troop_meshes,
+      initial_troops: PlayerSetup::DEFAULT_INITIAL_TROOPS,
+      language: PlayerSetup::DEFAULT_LANGUAGE.to_owned(),
+    };
+
+    MatchSetup {
+      seed: MatchRng::DEFAULT_SEED,
+      rules: Rules::default(),
+      players: vec![
+        player(1, "Torque", PlayerType::MainPlayer, PlayerColor::Blue, "baffin_bay",
+          TroopMeshes { land: MeshId::Tank1, sea: MeshId::Boat6 }),
+        player(2, "Eastest", PlayerType::Bot, PlayerColor::Red, "west_siberia",
+          TroopMeshes { land: MeshId::Tonk, sea: MeshId::Galleon }),
+        player(3, "Eagle", PlayerType::Bot, PlayerColor::Yellow, "east_savanna",
+          TroopMeshes { land: MeshId::Truck1, sea: MeshId::Boat6 }),
+        player(4, "Tiger", PlayerType::Bot, PlayerColor::Green, "korean_peninsula",
+          TroopMeshes { land: MeshId::Cannon, sea: MeshId::Boat5 }),
+      ],
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::players::color::PlayerColor;
+  use crate::players::player::PlayerType;
+  use crate::territories::bonuses::{Region, RegionBonus};
+  use crate::territories::territory::{Continent, Territories, Territory};
+  use crate::troops::archetype::ArchetypeDataError;
+  use crate::troops::mesh_map::MeshId;
+  use crate::troops::surface::Surface;
+  use crate::victory::conditions::VictoryCondition;
+
+  use super::{MatchSetup, MatchSetupError};
+
+  fn get_territories() -> Territories {
+    Territory::get_map()
+  }
+
+  fn get_error(setup: &MatchSetup) -> MatchSetupError {
+    setup.validate(&get_territories()).expect_err("Expected the setup to be invalid")
+  }
+
+  #[test]
+  fn default_setup_is_valid() {
+    assert_eq!(MatchSetup::default().validate(&get_territories()), Ok(()));
+  }
+
+  #[test]
+  fn match_needs_players() {
+    let setup = MatchSetup { players: Vec::new(), ..MatchSetup::default() };
+
+    assert_eq!(get_error(&setup), MatchSetupError::NoPlayers);
+  }
+
+  #[test]
+  fn player_ids_are_unique() {
+    let mut setup = MatchSetup::default();
+    setup.players[1].player_id = 1;
+
+    assert_eq!(get_error(&setup), MatchSetupError::DuplicatePlayerId(1));
+  }
+
+  #[test]
+  fn only_one_main_player_is_allowed() {
+    let mut setup = MatchSetup::default();
+    setup.players[2].player_type = PlayerType::MainPlayer;
+
+    assert_eq!(get_error(&setup), MatchSetupError::MoreThanOneMainPlayer(vec![1, 3]));
+  }
+
+  #[test]
+  fn colors_are_unique() {
+    let mut setup = MatchSetup::default();
+    setup.players[3].color = PlayerColor::Blue;
+
+    assert_eq!(get_error(&setup), MatchSetupError::DuplicateColor(PlayerColor::Blue, vec![1, 4]));
+  }
+
+  #[test]
+  fn initial_territories_exist_and_are_unique() {
+    let mut setup = MatchSetup::default();
+    setup.players[0].initial_territory = "atlantis".to_owned();
+
+    assert_eq!(get_error(&setup), MatchSetupError::UnknownTerritory(1, "atlantis".to_owned()));
+
+    setup.players[0].initial_territory = "west_siberia".to_owned();
+
+    assert_eq!(get_error(&setup), MatchSetupError::DuplicateInitialTerritory("west_siberia".to_owned(), vec![1, 2]));
+  }
+
+  #[test]
+  fn meshes_match_their_surface() {
+    let mut setup = MatchSetup::default();
+    setup.players[0].troop_meshes.sea = MeshId::Tank1;
+
+    assert_eq!(get_error(&setup), MatchSetupError::WrongMeshSurface(1, MeshId::Tank1, Surface::Sea));
+  }
+
+  #[test]
+  fn archetypes_are_validated() {
+    let mut setup = MatchSetup::default();
+    setup.rules.archetypes.retain(|archetype| archetype.mesh != MeshId::Galleon);
+
+    assert_eq!(get_error(&setup), MatchSetupError::InvalidArchetypes(ArchetypeDataError::MissingMesh(MeshId::Galleon)));
+  }
+
+  #[test]
+  fn victory_conditions_can_be_met() {
+    let mut setup = MatchSetup::default();
+
+    for condition in [
+      VictoryCondition::Continents(0),
+      VictoryCondition::Continents(99),
+      VictoryCondition::TimeLimit { secs: 0. },
+      VictoryCondition::HoldSpecialTerritories { secs: -1. },
+    ] {
+      setup.rules.victory_conditions = vec![condition.clone()];
+      assert_eq!(get_error(&setup), MatchSetupError::ImpossibleVictoryCondition(condition));
+    }
+
+    setup.rules.victory_conditions = vec![VictoryCondition::Continents(3), VictoryCondition::TimeLimit { secs: 600. }];
+    assert_eq!(setup.validate(&get_territories()), Ok(()));
+  }
+
+  #[test]
+  fn region_bonuses_need_positive_multipliers() {
+    let mut setup = MatchSetup::default();
+    setup.rules.region_bonuses = vec![RegionBonus {
+      region: Region::Continent(Continent::Europe),
+      extra_max_troops: 0,
+      spawn_time_multiplier: 0.,
+      occupation_time_multiplier: 1.,
+    }];
+
+    assert_eq!(get_error(&setup), MatchSetupError::InvalidRegionBonus(Region::Continent(Continent::Europe)));
+  }
+
+  #[test]
+  fn location_modifiers_are_positive_and_leave_ally_land_alone() {
+    let mut setup = MatchSetup::default();
+    setup.rules.location_modifiers.enemy_land.damage = 0.;
+
+    assert_eq!(get_error(&setup), MatchSetupError::InvalidLocationModifiers);
+
+    let mut setup = MatchSetup::default();
+    setup.rules.location_modifiers.ally_land.damage *= 2.;
+
+    assert_eq!(get_error(&setup), MatchSetupError::AllyLandModifiers);
+  }
+
+  #[test]
+  fn recovery_is_not_negative() {
+    let mut setup = MatchSetup::default();
+    setup.rules.base_healing_per_coordinate = -1.;
+
+    assert_eq!(get_error(&setup), MatchSetupError::NegativeRecovery);
+  }
+
+  #[test]
+  fn setup_files_are_ron_or_json() {
+    assert_eq!(
+      MatchSetup::parse("match.toml", "").expect_err("Expected the format to be unsupported"),
+      MatchSetupError::UnsupportedFormat("match.toml".to_owned())
+    );
+
+    let setup = MatchSetup::parse("match.ron", "(seed: 3)").expect("Expected the setup to be parsed");
+    assert_eq!(setup.seed, 3);
+    assert_eq!(setup.players.len(), 4);
+
+    let setup = MatchSetup::parse("match.json", r#"{"seed": 5}"#).expect("Expected the setup to be parsed");
+    assert_eq!(setup.seed, 5);
+
+    assert!(matches!(MatchSetup::parse("match.ron", "(seed: \"three\")"), Err(MatchSetupError::Parse(_))));
+  }
+}
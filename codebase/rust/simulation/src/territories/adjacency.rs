@@ -0,0 +1,226 @@
+use std::collections::BTreeMap;
+
+use crate::globe::coordinates::{CoordinateMap, Planet};
+use crate::math::Vec3;
+
+use super::territory::TerritoryId;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum BorderKind {
+  /// The territories touch each other on the grid
+  Land,
+  /// Only an ocean stretch shorter than `Rules::max_sea_connection_distance` separates their coasts
+  Sea,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Border {
+  pub kind: BorderKind,
+  /// Neighbouring coordinate pairs across the border, 0 for sea borders
+  pub shared_border_length: u32,
+  /// Distance between the closest coast coordinates of both territories, 0 for land borders
+  pub sea_distance: f32,
+}
+
+/// Which territories border each other, built once the coordinates are matched with the territories,
+/// see `VirtualPlanet::match_surface_points_and_territories`
+#[derive(Debug, Clone, Default)]
+pub struct AdjacencyGraph {
+  /// Every border is there twice, once from each side
+  borders: BTreeMap<TerritoryId, BTreeMap<TerritoryId, Border>>,
+}
+
+impl AdjacencyGraph {
+  /// Around 1000km of ocean on the real Earth
+  pub const MAX_SEA_CONNECTION_DISTANCE: f32 = 0.5;
+
+  pub fn from_coordinate_map(coordinate_map: &CoordinateMap, max_sea_connection_distance: f32) -> AdjacencyGraph {
+    let mut graph = AdjacencyGraph::default();
+    let mut coasts: BTreeMap<&TerritoryId, Vec<Vec3>> = BTreeMap::new();
+
+    // Walking the grid in order, so the graph is the same on every run
+    for lat_long in Planet::all_coordinates() {
+      let Some(coordinate_metadata) = coordinate_map.get(&lat_long) else {
+        continue;
+      };
+      let Some(territory_id) = &coordinate_metadata.territory_id else {
+        continue;
+      };
+
+      let mut is_coast = false;
+      for neighbor in Planet::get_neighbors(lat_long) {
+        match coordinate_map.get(&neighbor).and_then(|neighbor_metadata| neighbor_metadata.territory_id.as_ref()) {
+          Some(neighbor_territory_id) if neighbor_territory_id != territory_id => {
+            graph.add_land_border_step(territory_id, neighbor_territory_id);
+          },
+          Some(_) => (),
+          None => is_coast = true,
+        }
+      }
+
+      if is_coast {
+        coasts.entry(territory_id).or_default().push(coordinate_metadata.cartesian);
+      }
+    }
+
+    graph.add_sea_borders(&coasts, max_sea_connection_distance);
+    graph
+  }
+
+  pub fn are_neighbors(&self, territory_id: &TerritoryId, other_territory_id: &TerritoryId) -> bool {
+    self.get_border(territory_id, other_territory_id).is_some()
+  }
+
+  pub fn get_border(&self, territory_id: &TerritoryId, other_territory_id: &TerritoryId) -> Option<&Border> {
+    self.borders
+      .get(territory_id)
+      .and_then(|borders| borders.get(other_territory_id))
+  }
+
+  /// Bordering territories by id, both by land and by sea
+  pub fn get_neighbors(&self, territory_id: &TerritoryId) -> impl Iterator<Item = (&TerritoryId, &Border)> {
+    self.borders
+      .get(territory_id)
+      .into_iter()
+      .flat_map(|borders| borders.iter())
+  }
+
+  pub fn get_land_neighbors(&self, territory_id: &TerritoryId) -> impl Iterator<Item = &TerritoryId> {
+    self.get_neighbors(territory_id)
+      .filter(|(_, border)| border.kind == BorderKind::Land)
+      .map(|(neighbor_id, _)| neighbor_id)
+  }
+
+  /// Counted from each side, so both directions end up with the same length
+  fn add_land_border_step(&mut self, territory_id: &TerritoryId, neighbor_territory_id: &TerritoryId) {
+    let border = self.borders
+      .entry(territory_id.clone())
+      .or_default()
+      .entry(neighbor_territory_id.clone())
+      .or_insert(Border {
+        kind: BorderKind::Land,
+        shared_border_length: 0,
+        sea_distance: 0.,
+      });
+
+    border.shared_border_length += 1;
+  }
+
+  /// Territories already bordering by land don't get a sea border
+  fn add_sea_borders(&mut self, coasts: &BTreeMap<&TerritoryId, Vec<Vec3>>, max_sea_connection_distance: f32) {
+    // Every coast fits in a sphere, so most pairs are discarded without comparing their coordinates
+    let bounds: Vec<(&TerritoryId, &Vec<Vec3>, Vec3, f32)> = coasts
+      .iter()
+      .map(|(territory_id, coast)| {
+        let center = coast.iter().fold(Vec3::ZERO, |sum, point| sum + *point) / coast.len() as f32;
+        let radius = coast.iter().map(|point| point.distance_to(center)).fold(0., f32::max);
+        (*territory_id, coast, center, radius)
+      })
+      .collect();
+
+    for (index, (territory_id, coast, center, radius)) in bounds.iter().enumerate() {
+      for (other_territory_id, other_coast, other_center, other_radius) in &bounds[index + 1..] {
+        if center.distance_to(*other_center) - radius - other_radius > max_sea_connection_distance ||
+          self.are_neighbors(territory_id, other_territory_id) {
+          continue;
+        }
+
+        let sea_distance = coast
+          .iter()
+          .flat_map(|point| other_coast.iter().map(move |other_point| point.distance_to(*other_point)))
+          .fold(f32::MAX, f32::min);
+
+        if sea_distance > max_sea_connection_distance {
+          continue;
+        }
+
+        for (from, to) in [(territory_id, other_territory_id), (other_territory_id, territory_id)] {
+          self.borders
+            .entry((*from).clone())
+            .or_default()
+            .insert((*to).clone(), Border {
+              kind: BorderKind::Sea,
+              shared_border_length: 0,
+              sea_distance,
+            });
+        }
+      }
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::globe::coordinates::{CoordinateMap, Planet};
+  use crate::territories::territory::TerritoryId;
+
+  use super::{AdjacencyGraph, BorderKind};
+
+  /// `west` and `east` touch each other, `island` is a few coordinates of ocean away from `east`,
+  /// `far_away` is on the other side of the planet, `seam_west` and `seam_east` touch across the last longitude
+  fn get_coordinate_map() -> CoordinateMap {
+    let mut coordinate_map = Planet::get_blank_coordinate_map();
+    Planet::add_territory(&mut coordinate_map, "west", 100..110, 0..10);
+    Planet::add_territory(&mut coordinate_map, "east", 100..110, 10..20);
+    Planet::add_territory(&mut coordinate_map, "island", 100..110, 22..30);
+    Planet::add_territory(&mut coordinate_map, "far_away", 100..110, 200..210);
+    Planet::add_territory(&mut coordinate_map, "seam_west", 50..55, 445..450);
+    Planet::add_territory(&mut coordinate_map, "seam_east", 50..55, 0..3);
+    coordinate_map
+  }
+
+  fn get_id(territory_id: &str) -> TerritoryId {
+    territory_id.to_owned()
+  }
+
+  #[test]
+  fn touching_territories_border_by_land_from_both_sides() {
+    let graph = AdjacencyGraph::from_coordinate_map(&get_coordinate_map(), AdjacencyGraph::MAX_SEA_CONNECTION_DISTANCE);
+
+    let border = graph.get_border(&get_id("west"), &get_id("east")).expect("Expected a border");
+    assert_eq!(border.kind, BorderKind::Land);
+    // 10 coordinates on each side of the border, the ones at both ends only touch 2 coordinates across it
+    assert_eq!(border.shared_border_length, 28);
+    assert_eq!(border.sea_distance, 0.);
+    assert_eq!(graph.get_border(&get_id("east"), &get_id("west")), Some(border));
+  }
+
+  #[test]
+  fn land_borders_wrap_around_the_longitudes() {
+    let graph = AdjacencyGraph::from_coordinate_map(&get_coordinate_map(), AdjacencyGraph::MAX_SEA_CONNECTION_DISTANCE);
+
+    let land_neighbors: Vec<&TerritoryId> = graph.get_land_neighbors(&get_id("seam_west")).collect();
+    assert_eq!(land_neighbors, vec![&get_id("seam_east")]);
+  }
+
+  #[test]
+  fn close_coasts_border_by_sea() {
+    let graph = AdjacencyGraph::from_coordinate_map(&get_coordinate_map(), AdjacencyGraph::MAX_SEA_CONNECTION_DISTANCE);
+
+    let border = graph.get_border(&get_id("island"), &get_id("east")).expect("Expected a border");
+    assert_eq!(border.kind, BorderKind::Sea);
+    assert_eq!(border.shared_border_length, 0);
+    assert!(border.sea_distance > 0. && border.sea_distance <= AdjacencyGraph::MAX_SEA_CONNECTION_DISTANCE);
+    assert_eq!(graph.get_border(&get_id("east"), &get_id("island")), Some(border));
+
+    assert_eq!(graph.get_land_neighbors(&get_id("island")).count(), 0);
+    assert!(!graph.are_neighbors(&get_id("far_away"), &get_id("west")));
+    assert_eq!(graph.get_neighbors(&get_id("far_away")).count(), 0);
+  }
+
+  #[test]
+  fn territories_bordering_by_land_dont_border_by_sea() {
+    let graph = AdjacencyGraph::from_coordinate_map(&get_coordinate_map(), AdjacencyGraph::MAX_SEA_CONNECTION_DISTANCE);
+
+    // Both coasts are next to each other, a sea border would take the place of the land one
+    assert_eq!(graph.get_border(&get_id("west"), &get_id("east")).map(|border| &border.kind), Some(&BorderKind::Land));
+  }
+
+  #[test]
+  fn sea_borders_stop_at_the_max_distance() {
+    let graph = AdjacencyGraph::from_coordinate_map(&get_coordinate_map(), 0.01);
+
+    assert!(!graph.are_neighbors(&get_id("island"), &get_id("east")));
+    assert!(graph.are_neighbors(&get_id("west"), &get_id("east")));
+  }
+}
@@ -0,0 +1,263 @@
+use serde::{Deserialize, Serialize};
+
+use crate::players::player::{PlayerId, PlayerState};
+
+use super::territory::{Continent, SubContinent, Territories, Territory};
+
+/// A group of territories a player can get a bonus for, by ruling every one of them
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum Region {
+  Continent(Continent),
+  SubContinent(SubContinent),
+}
+
+impl Region {
+  pub fn contains(&self, territory: &Territory) -> bool {
+    match self {
+      Region::Continent(continent) => territory.location.continent == *continent,
+      Region::SubContinent(sub_continent) => territory.location.sub_continent.as_ref() == Some(sub_continent),
+    }
+  }
+
+  /// False for regions without territories, so nobody gets their bonus for free
+  pub fn is_ruled_by(&self, territories: &Territories, player_id: PlayerId) -> bool {
+    let mut region_territories = territories
+      .values()
+      .filter(|territory| self.contains(territory))
+      .peekable();
+
+    region_territories.peek().is_some() &&
+      region_territories.all(|territory| territory.current_ruler == Some(player_id))
+  }
+}
+
+/// What ruling a whole region is worth, fields left out of a match setup don't change anything
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RegionBonus {
+  pub region: Region,
+  /// Added to the player's `max_troop_allowed`
+  #[serde(default)]
+  pub extra_max_troops: u32,
+  /// Multiplies `Territory::seconds_to_spawn_troop` of every territory the player rules,
+  /// the lower the value, the faster the troops spawn
+  #[serde(default = "RegionBonus::get_neutral_multiplier")]
+  pub spawn_time_multiplier: f64,
+  /// Multiplies `Territory::time_to_be_conquered` of every territory the player occupies,
+  /// the lower the value, the faster the territories are conquered
+  #[serde(default = "RegionBonus::get_neutral_multiplier")]
+  pub occupation_time_multiplier: f64,
+}
+
+impl RegionBonus {
+  fn get_neutral_multiplier() -> f64 {
+    1.
+  }
+
+  /// Bonus table the game ships with, the greater and the harder to hold the region, the greater the bonus
+  pub fn get_default_bonuses() -> Vec<RegionBonus> {
+    let troops = |region: Region, extra_max_troops: u32| RegionBonus {
+      region,
+      extra_max_troops,
+      spawn_time_multiplier: 1.,
+      occupation_time_multiplier: 1.,
+    };
+    let spawn_time = |sub_continent: SubContinent| RegionBonus {
+      region: Region::SubContinent(sub_continent),
+      extra_max_troops: 0,
+      spawn_time_multiplier: 0.85,
+      occupation_time_multiplier: 1.,
+    };
+
+    vec![
+      troops(Region::Continent(Continent::Asia), 40),
+      troops(Region::Continent(Continent::NorthAmerica), 25),
+      troops(Region::Continent(Continent::Africa), 15),
+      troops(Region::Continent(Continent::Europe), 15),
+      troops(Region::Continent(Continent::SouthAmerica), 10),
+      troops(Region::Continent(Continent::Oceania), 10),
+      RegionBonus {
+        region: Region::Continent(Continent::Antarctica),
+        extra_max_troops: 5,
+        spawn_time_multiplier: 1.,
+        occupation_time_multiplier: 0.75,
+      },
+
+      spawn_time(SubContinent::MiddleEast),
+      spawn_time(SubContinent::InteriorAsia),
+      spawn_time(SubContinent::IndianSubcontinent),
+      spawn_time(SubContinent::SoutheastAsia),
+      spawn_time(SubContinent::EastAsia),
+      spawn_time(SubContinent::EuropeRelatedAsia),
+    ]
+  }
+
+  /// Multipliers have to be positive, and the region needs territories to ever be ruled
+  pub fn is_valid(&self, territories: &Territories) -> bool {
+    self.spawn_time_multiplier > 0. &&
+      self.occupation_time_multiplier > 0. &&
+      territories.values().any(|territory| self.region.contains(territory))
+  }
+}
+
+/// Every bonus the player currently gets, added up
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HeldRegionBonus {
+  pub regions: Vec<Region>,
+  pub extra_max_troops: u32,
+  pub spawn_time_multiplier: f64,
+  pub occupation_time_multiplier: f64,
+}
+
+impl Default for HeldRegionBonus {
+  fn default() -> Self {
+    HeldRegionBonus {
+      regions: Vec::new(),
+      extra_max_troops: 0,
+      spawn_time_multiplier: 1.,
+      occupation_time_multiplier: 1.,
+    }
+  }
+}
+
+impl PlayerState {
+  /// Should be called every time the player gets or loses a territory,
+  /// the bonus of a region is gone as soon as one of its territories falls
+  pub fn refresh_region_bonus(&mut self, territories: &Territories, bonuses: &[RegionBonus]) {
+    let mut held_bonus = HeldRegionBonus::default();
+
+    for bonus in bonuses.iter().filter(|bonus| bonus.region.is_ruled_by(territories, self.player_id)) {
+      held_bonus.regions.push(bonus.region.clone());
+      held_bonus.extra_max_troops += bonus.extra_max_troops;
+      held_bonus.spawn_time_multiplier *= bonus.spawn_time_multiplier;
+      held_bonus.occupation_time_multiplier *= bonus.occupation_time_multiplier;
+    }
+
+    self.max_troop_allowed = self.max_troop_allowed.saturating_sub(self.region_bonus.extra_max_troops) +
+      held_bonus.extra_max_troops;
+    self.region_bonus = held_bonus;
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::players::player::PlayerState;
+  use crate::territories::territory::{Continent, SubContinent, Territories, Territory};
+
+  use super::{Region, RegionBonus};
+
+  fn get_territories() -> Territories {
+    [
+      ("iran", Continent::Asia, Some(SubContinent::MiddleEast)),
+      ("iraq", Continent::Asia, Some(SubContinent::MiddleEast)),
+      ("japan", Continent::Asia, Some(SubContinent::EastAsia)),
+      ("peru", Continent::SouthAmerica, None),
+    ]
+      .into_iter()
+      .map(|(territory_id, continent, sub_continent)| {
+        let mut territory = Territory::get_base_territory(territory_id, continent, sub_continent);
+        territory.organic_max_troops = 3;
+        (territory_id.to_owned(), territory)
+      })
+      .collect()
+  }
+
+  fn get_bonuses() -> Vec<RegionBonus> {
+    vec![
+      RegionBonus {
+        region: Region::Continent(Continent::Asia),
+        extra_max_troops: 10,
+        spawn_time_multiplier: 1.,
+        occupation_time_multiplier: 0.5,
+      },
+      RegionBonus {
+        region: Region::SubContinent(SubContinent::MiddleEast),
+        extra_max_troops: 2,
+        spawn_time_multiplier: 0.8,
+        occupation_time_multiplier: 1.,
+      },
+      RegionBonus {
+        region: Region::Continent(Continent::Europe),
+        extra_max_troops: 50,
+        spawn_time_multiplier: 1.,
+        occupation_time_multiplier: 1.,
+      },
+    ]
+  }
+
+  fn rule(territories: &mut Territories, territory_ids: &[&str], player: &mut PlayerState) {
+    for territory_id in territory_ids {
+      territories.get_mut(*territory_id).expect("Expected territory to exist").set_new_ruler(player);
+    }
+  }
+
+  #[test]
+  fn ruling_a_whole_region_gives_its_bonus() {
+    let mut territories = get_territories();
+    let mut player = PlayerState::new(1);
+    rule(&mut territories, &["iran", "iraq"], &mut player);
+    let max_troop_allowed = player.max_troop_allowed;
+
+    player.refresh_region_bonus(&territories, &get_bonuses());
+
+    assert_eq!(player.region_bonus.regions, vec![Region::SubContinent(SubContinent::MiddleEast)]);
+    assert_eq!(player.region_bonus.spawn_time_multiplier, 0.8);
+    assert_eq!(player.max_troop_allowed, max_troop_allowed + 2);
+  }
+
+  #[test]
+  fn bonuses_of_every_region_held_add_up() {
+    let mut territories = get_territories();
+    let mut player = PlayerState::new(1);
+    rule(&mut territories, &["iran", "iraq", "japan"], &mut player);
+    let max_troop_allowed = player.max_troop_allowed;
+
+    player.refresh_region_bonus(&territories, &get_bonuses());
+    player.refresh_region_bonus(&territories, &get_bonuses());
+
+    assert_eq!(player.region_bonus.regions.len(), 2);
+    assert_eq!(player.region_bonus.extra_max_troops, 12);
+    assert_eq!(player.region_bonus.spawn_time_multiplier, 0.8);
+    assert_eq!(player.region_bonus.occupation_time_multiplier, 0.5);
+    assert_eq!(player.max_troop_allowed, max_troop_allowed + 12);
+  }
+
+  #[test]
+  fn bonus_is_gone_as_soon_as_a_territory_falls() {
+    let mut territories = get_territories();
+    let mut player = PlayerState::new(1);
+    rule(&mut territories, &["iran", "iraq", "japan"], &mut player);
+    player.refresh_region_bonus(&territories, &get_bonuses());
+
+    territories.get_mut("japan").expect("Expected territory to exist").release_ruler(&mut player);
+    let max_troop_allowed_without_bonus = player.max_troop_allowed - 12;
+    player.refresh_region_bonus(&territories, &get_bonuses());
+
+    assert_eq!(player.region_bonus.regions, vec![Region::SubContinent(SubContinent::MiddleEast)]);
+    assert_eq!(player.region_bonus.occupation_time_multiplier, 1.);
+    assert_eq!(player.max_troop_allowed, max_troop_allowed_without_bonus + 2);
+
+    territories.get_mut("iran").expect("Expected territory to exist").release_ruler(&mut player);
+    player.refresh_region_bonus(&territories, &get_bonuses());
+
+    assert!(player.region_bonus.regions.is_empty());
+    assert_eq!(player.region_bonus.extra_max_troops, 0);
+    assert_eq!(player.max_troop_allowed, 3);
+  }
+
+  #[test]
+  fn regions_without_territories_are_never_ruled() {
+    let territories = get_territories();
+
+    assert!(!Region::Continent(Continent::Europe).is_ruled_by(&territories, 1));
+    assert!(!get_bonuses()[2].is_valid(&territories));
+    assert!(get_bonuses()[0].is_valid(&territories));
+  }
+
+  #[test]
+  fn bonuses_need_positive_multipliers() {
+    let mut bonus = get_bonuses().remove(0);
+    bonus.occupation_time_multiplier = 0.;
+
+    assert!(!bonus.is_valid(&get_territories()));
+  }
+}
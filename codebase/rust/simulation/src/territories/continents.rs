@@ -0,0 +1,41 @@
+use crate::players::player::PlayerId;
+
+use super::territory::{Continent, Territories};
+
+/// Continents a player can rule, `Continent::Special` is left out
+/// as it only gathers the territories connecting the others
+pub fn get_rulable_continents(territories: &Territories) -> Vec<Continent> {
+  let mut continents: Vec<Continent> = Vec::new();
+
+  for territory in territories.values() {
+    let continent = &territory.location.continent;
+    if *continent != Continent::Special && !continents.contains(continent) {
+      continents.push(continent.clone());
+    }
+  }
+
+  continents
+}
+
+/// Continents whose every territory is ruled by the player
+pub fn get_continents_ruled_by(territories: &Territories, player_id: PlayerId) -> Vec<Continent> {
+  get_rulable_continents(territories)
+    .into_iter()
+    .filter(|continent| territories
+      .values()
+      .filter(|territory| territory.location.continent == *continent)
+      .all(|territory| territory.current_ruler == Some(player_id)))
+    .collect()
+}
+
+/// The player ruling every `Continent::Special` territory, None if they're split or there are none
+pub fn get_special_territories_ruler(territories: &Territories) -> Option<PlayerId> {
+  let mut special_territories = territories
+    .values()
+    .filter(|territory| territory.location.continent == Continent::Special);
+
+  let ruler = special_territories.next()?.current_ruler?;
+  special_territories
+    .all(|territory| territory.current_ruler == Some(ruler))
+    .then_some(ruler)
+}
@@ -0,0 +1,228 @@
+use std::collections::BTreeSet;
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+use serde::{Deserialize, Serialize};
+
+use super::territory::{Continent, SubContinent, Territories, Territory, TerritoryId};
+
+/// The data file the game ships with, the Godot project keeps it at `res://data/territories.ron`
+const BUNDLED_TERRITORIES: &str = include_str!("../../../../godot/data/territories.ron");
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum TerritoryTag {
+  Airport,
+  Spaceport,
+}
+
+/// A territory as it's written on the data file, the rest of `Territory` is filled on the fly
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TerritoryDefinition {
+  pub id: TerritoryId,
+  pub continent: Continent,
+  #[serde(default)]
+  pub sub_continent: Option<SubContinent>,
+  #[serde(default)]
+  pub tags: BTreeSet<TerritoryTag>,
+}
+
+/// Why the territories data file can't be used, meant to be shown as is
+#[derive(Debug, Clone, PartialEq)]
+pub enum TerritoryDataError {
+  Parse(String),
+  NoTerritories,
+  EmptyId,
+  DuplicateId(TerritoryId),
+  /// Only Asia is split in sub-continents
+  SubContinentOutsideAsia(TerritoryId),
+}
+
+impl Display for TerritoryDataError {
+  fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+    match self {
+      TerritoryDataError::Parse(reason) =>
+        write!(f, "couldn't read the territories: {reason}"),
+      TerritoryDataError::NoTerritories =>
+        write!(f, "there are no territories"),
+      TerritoryDataError::EmptyId =>
+        write!(f, "a territory has an empty id"),
+      TerritoryDataError::DuplicateId(territory_id) =>
+        write!(f, "'{territory_id}' is defined more than once"),
+      TerritoryDataError::SubContinentOutsideAsia(territory_id) =>
+        write!(f, "'{territory_id}' has a sub-continent, but it's not in Asia"),
+    }
+  }
+}
+
+impl std::error::Error for TerritoryDataError {}
+
+impl TerritoryDefinition {
+  /// Parses and checks a RON territories file, check `codebase/godot/data/territories.ron`
+  pub fn parse(content: &str) -> Result<Vec<TerritoryDefinition>, TerritoryDataError> {
+    let definitions: Vec<TerritoryDefinition> = ron::from_str(content)
+      .map_err(|error| TerritoryDataError::Parse(error.to_string()))?;
+
+    if definitions.is_empty() {
+      return Err(TerritoryDataError::NoTerritories);
+    }
+
+    let mut territory_ids = BTreeSet::new();
+    for definition in &definitions {
+      if definition.id.is_empty() {
+        return Err(TerritoryDataError::EmptyId);
+      }
+
+      if !territory_ids.insert(&definition.id) {
+        return Err(TerritoryDataError::DuplicateId(definition.id.clone()));
+      }
+
+      if definition.sub_continent.is_some() && definition.continent != Continent::Asia {
+        return Err(TerritoryDataError::SubContinentOutsideAsia(definition.id.clone()));
+      }
+    }
+
+    Ok(definitions)
+  }
+
+  /// The territories the game ships with
+  pub fn get_bundled() -> Vec<TerritoryDefinition> {
+    Self::parse(BUNDLED_TERRITORIES)
+      .unwrap_or_else(|error| panic!("Expected the bundled territories to be valid: {error}"))
+  }
+}
+
+/// Territory ids some other source has, compared with the ones of the data file
+#[derive(Debug, Clone, PartialEq)]
+pub struct TerritoryIdsMismatch {
+  /// On the data file, but not on the other source
+  pub missing: Vec<TerritoryId>,
+  /// On the other source, but not on the data file
+  pub unknown: Vec<TerritoryId>,
+}
+
+impl TerritoryIdsMismatch {
+  /// None if both have exactly the same ids
+  pub fn compare<'a>(territories: &Territories, other_ids: impl IntoIterator<Item = &'a str>) -> Option<TerritoryIdsMismatch> {
+    let other_ids: BTreeSet<&str> = other_ids.into_iter().collect();
+
+    let missing: Vec<TerritoryId> = territories
+      .keys()
+      .filter(|territory_id| !other_ids.contains(territory_id.as_str()))
+      .cloned()
+      .collect();
+    let unknown: Vec<TerritoryId> = other_ids
+      .into_iter()
+      .filter(|territory_id| !territories.contains_key(*territory_id))
+      .map(str::to_owned)
+      .collect();
+
+    if missing.is_empty() && unknown.is_empty() {
+      return None;
+    }
+
+    Some(TerritoryIdsMismatch { missing, unknown })
+  }
+}
+
+impl Display for TerritoryIdsMismatch {
+  fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+    write!(f, "missing {:?}, unknown {:?}", self.missing, self.unknown)
+  }
+}
+
+impl Territory {
+  /// returns a map of territories
+  /// called from GlobeScene::init and VirtualPlanet::init
+  /// at VirtualPlanet we have coordinates filled in too
+  pub fn get_map() -> Territories {
+    Self::get_map_from_definitions(&TerritoryDefinition::get_bundled())
+  }
+
+  pub fn get_map_from_definitions(definitions: &[TerritoryDefinition]) -> Territories {
+    definitions
+      .iter()
+      .map(|definition| {
+        let mut territory = Self::get_base_territory(
+          &definition.id,
+          definition.continent.clone(),
+          definition.sub_continent.clone()
+        );
+        territory.tags = definition.tags.clone();
+        (territory.territory_id.clone(), territory)
+      })
+      .collect()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::territories::territory::{Continent, Territory};
+
+  use super::{TerritoryDataError, TerritoryDefinition, TerritoryIdsMismatch, TerritoryTag};
+
+  #[test]
+  fn bundled_territories_are_valid() {
+    let definitions = TerritoryDefinition::get_bundled();
+    let territories = Territory::get_map();
+
+    assert_eq!(territories.len(), definitions.len());
+    assert!(territories.values().any(|territory| territory.location.continent == Continent::Special));
+  }
+
+  #[test]
+  fn definitions_are_turned_into_territories() {
+    let definitions = TerritoryDefinition::parse(r#"[
+      (id: "iran", continent: Asia, sub_continent: Some(MiddleEast)),
+      (id: "panama", continent: Special, tags: [Airport]),
+    ]"#).expect("Expected definitions to be valid");
+
+    let territories = Territory::get_map_from_definitions(&definitions);
+
+    assert_eq!(territories.keys().collect::<Vec<_>>(), vec!["iran", "panama"]);
+    assert!(territories["panama"].tags.contains(&TerritoryTag::Airport));
+    assert!(territories["iran"].tags.is_empty());
+  }
+
+  #[test]
+  fn invalid_definitions_are_turned_down() {
+    let cases = [
+      ("[", None),
+      ("[]", Some(TerritoryDataError::NoTerritories)),
+      (r#"[(id: "", continent: Europe)]"#, Some(TerritoryDataError::EmptyId)),
+      (
+        r#"[(id: "peru", continent: SouthAmerica), (id: "peru", continent: SouthAmerica)]"#,
+        Some(TerritoryDataError::DuplicateId("peru".to_owned())),
+      ),
+      (
+        r#"[(id: "peru", continent: SouthAmerica, sub_continent: Some(EastAsia))]"#,
+        Some(TerritoryDataError::SubContinentOutsideAsia("peru".to_owned())),
+      ),
+      (r#"[(id: "peru", continent: Atlantis)]"#, None),
+    ];
+
+    for (content, expected_error) in cases {
+      let error = TerritoryDefinition::parse(content).expect_err("Expected definitions to be invalid");
+
+      match expected_error {
+        Some(expected_error) => assert_eq!(error, expected_error),
+        None => assert!(matches!(error, TerritoryDataError::Parse(_))),
+      }
+    }
+  }
+
+  #[test]
+  fn mismatching_ids_are_reported_from_both_sides() {
+    let territories = Territory::get_map_from_definitions(&TerritoryDefinition::parse(r#"[
+      (id: "chile", continent: SouthAmerica),
+      (id: "peru", continent: SouthAmerica),
+    ]"#).expect("Expected definitions to be valid"));
+
+    assert_eq!(TerritoryIdsMismatch::compare(&territories, ["peru", "chile"]), None);
+    assert_eq!(
+      TerritoryIdsMismatch::compare(&territories, ["peru", "bolivia"]),
+      Some(TerritoryIdsMismatch {
+        missing: vec!["chile".to_owned()],
+        unknown: vec!["bolivia".to_owned()],
+      })
+    );
+  }
+}
@@ -0,0 +1,8 @@
+pub mod territory;
+pub mod data;
+pub mod occupation;
+pub mod spawning;
+pub mod continents;
+pub mod adjacency;
+pub mod bonuses;
+pub mod realm;
\ No newline at end of file
@@ -0,0 +1,315 @@
+use crate::players::player::{PlayerId, PlayerState};
+use crate::troops::troop::TroopId;
+
+use super::territory::{Territory, TerritoryState};
+
+/// What happens to a territory when a deployed troop arrives to it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ArrivalOutcome {
+  /// Unoccupied and peaceful territory, the troop's player starts occupying it
+  OccupationStarted,
+  /// Entering own territory, could start patrolling or start defending it from invaders
+  EnteredOwnTerritory,
+  /// Entering enemy territory, could start combat or keep combatting until the territory is conquered
+  EnteredEnemyTerritory,
+  /// Entering a territory that started being occupied by someone else
+  EnteredTerritoryUnderOccupation,
+  /// None of the above, should not happen
+  Unexpected,
+}
+
+/// Change on the occupation of a territory after a conflict is settled
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OccupationChange {
+  /// The only troops left in the territory belong to a player other than the ruler,
+  /// they start occupying it
+  Started(PlayerId),
+  /// The player trying to conquer the territory has no troops left on it
+  GivenUp,
+}
+
+impl Territory {
+  /// Registers the troop arrival and updates the territory states
+  /// Ported from `Troop::get_deployment_next_action`
+  pub fn inform_deployment_arrival(&mut self, troop_id: &TroopId, player_id: PlayerId) -> ArrivalOutcome {
+    self.inform_troop_arrived(troop_id, player_id);
+
+    let is_ruled_by_the_player = self.current_ruler.is_some_and(|ruler_id| ruler_id == player_id);
+    let is_ruled_by_other_player = self.current_ruler.is_some_and(|ruler_id| ruler_id != player_id);
+
+    if self.territory_states.contains(&TerritoryState::Unoccupied) && !self.has_troops_from_different_players {
+      self.territory_states.insert(TerritoryState::OccupationInProgress);
+      self.territory_states.remove(&TerritoryState::UnoccupiedUnderConflict);
+      self.player_trying_to_conquer = Some(player_id);
+      ArrivalOutcome::OccupationStarted
+
+    } else if is_ruled_by_the_player {
+      ArrivalOutcome::EnteredOwnTerritory
+
+    } else if is_ruled_by_other_player {
+      self.territory_states.insert(TerritoryState::OccupiedUnderConflict);
+      self.player_trying_to_conquer = Some(player_id);
+      ArrivalOutcome::EnteredEnemyTerritory
+
+    } else if self.territory_states.contains(&TerritoryState::Unoccupied) && self.has_troops_from_different_players {
+      // Should start combat and hold down the territory occupation until the conflict is finished
+      // TODO: implement this, for now battle is happening only if territory has a ruler
+      self.territory_states.insert(TerritoryState::UnoccupiedUnderConflict);
+      self.player_trying_to_conquer = Some(player_id);
+      ArrivalOutcome::EnteredTerritoryUnderOccupation
+
+    } else {
+      ArrivalOutcome::Unexpected
+    }
+  }
+
+  /// Advances the occupation progress of a territory with `OccupationInProgress` state,
+  /// the more troops the occupier has in the territory, the faster it goes.
+  /// `occupation_time_multiplier` comes from the occupier's `PlayerState::region_bonus`
+  /// Returns the id of the player that conquered the territory in this step, if any
+  pub fn advance_occupation(&mut self, delta: f64, occupation_time_multiplier: f64) -> Option<PlayerId> {
+    if !self.territory_states.contains(&TerritoryState::OccupationInProgress) {
+      return None;
+    }
+
+    // Occupation holds down while the territory is under conflict
+    if self.has_troops_from_different_players {
+      return None;
+    }
+
+    let player_id = self.player_trying_to_conquer?;
+    let num_of_troops_in_the_territory = self.get_num_of_arrived_troops_by_player(player_id);
+
+    // Speeding the progress up instead of shortening time_to_be_conquered, so the HUD percentage stays right
+    self.conquering_progress_per_second += delta * (num_of_troops_in_the_territory as f64) / occupation_time_multiplier;
+
+    if self.conquering_progress_per_second >= self.time_to_be_conquered {
+      self.conquering_progress_per_second = 0.;
+      return Some(player_id);
+    }
+
+    None
+  }
+
+  /// Makes the player the ruler of the territory, it doesn't paint the territory,
+  /// that's up to `Realm::set_ruler`
+  pub fn set_new_ruler(&mut self, player: &mut PlayerState) {
+    player.max_troop_allowed += self.organic_max_troops;
+    player.register_territory_occupation(self.territory_id.clone());
+
+    self.player_trying_to_conquer = None;
+    self.current_ruler = Some(player.player_id);
+    self.territory_states.remove(&TerritoryState::Unoccupied);
+    self.territory_states.remove(&TerritoryState::OccupationInProgress);
+    self.territory_states.remove(&TerritoryState::OccupiedUnderConflict);
+    self.territory_states.remove(&TerritoryState::UnoccupiedUnderConflict);
+    self.territory_states.insert(TerritoryState::Occupied);
+  }
+
+  /// Gives the territory up, should be called before `set_new_ruler` when the territory changes hands
+  pub fn release_ruler(&mut self, previous_ruler: &mut PlayerState) {
+    previous_ruler.max_troop_allowed = previous_ruler.max_troop_allowed.saturating_sub(self.organic_max_troops);
+    previous_ruler.register_territory_loss();
+    self.current_ruler = None;
+  }
+
+  pub fn is_under_conflict(&self) -> bool {
+    self.territory_states.contains(&TerritoryState::OccupiedUnderConflict) ||
+    self.territory_states.contains(&TerritoryState::UnoccupiedUnderConflict)
+  }
+
+  /// Clears the conflict states once there are no troops from different players anymore
+  pub fn check_territory_under_conflict(&mut self) {
+    if self.is_under_conflict() && !self.has_troops_from_different_players {
+      self.territory_states.remove(&TerritoryState::OccupiedUnderConflict);
+      self.territory_states.remove(&TerritoryState::UnoccupiedUnderConflict);
+    }
+  }
+
+  /// Once the territory is peaceful, starts or cancels the occupation depending on who's left on it:
+  /// a single player other than the ruler starts occupying it, and the occupation is given up
+  /// if the player trying to conquer the territory left or lost its troops
+  pub fn settle_occupation(&mut self) -> Option<OccupationChange> {
+    if self.has_troops_from_different_players {
+      return None;
+    }
+
+    let player_left = self.all_troops_deployed_and_arrived_by_player
+      .iter()
+      .find(|(_, troops)| !troops.is_empty())
+      .map(|(player_id, _)| *player_id);
+
+    let occupation_in_progress = self.territory_states.contains(&TerritoryState::OccupationInProgress);
+
+    match player_left {
+      Some(player_id) if self.current_ruler != Some(player_id) => {
+        if occupation_in_progress && self.player_trying_to_conquer == Some(player_id) {
+          return None;
+        }
+
+        self.territory_states.insert(TerritoryState::OccupationInProgress);
+        self.player_trying_to_conquer = Some(player_id);
+        self.conquering_progress_per_second = 0.;
+        Some(OccupationChange::Started(player_id))
+      },
+
+      _ if occupation_in_progress || self.player_trying_to_conquer.is_some() => {
+        self.territory_states.remove(&TerritoryState::OccupationInProgress);
+        self.player_trying_to_conquer = None;
+        self.conquering_progress_per_second = 0.;
+        Some(OccupationChange::GivenUp)
+      },
+
+      _ => None,
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::players::player::PlayerState;
+  use crate::territories::territory::{Continent, Territory, TerritoryState};
+
+  use super::{ArrivalOutcome, OccupationChange};
+
+  fn get_territory() -> Territory {
+    Territory::get_base_territory("brazil", Continent::SouthAmerica, None)
+  }
+
+  #[test]
+  fn first_arrival_to_an_unoccupied_territory_starts_the_occupation() {
+    let mut territory = get_territory();
+
+    assert_eq!(territory.inform_deployment_arrival(&"troop_1_1".to_string(), 1), ArrivalOutcome::OccupationStarted);
+    assert!(territory.territory_states.contains(&TerritoryState::OccupationInProgress));
+    assert_eq!(territory.player_trying_to_conquer, Some(1));
+  }
+
+  #[test]
+  fn arrival_to_an_own_territory_changes_nothing() {
+    let mut territory = get_territory();
+    territory.set_new_ruler(&mut PlayerState::new(1));
+
+    assert_eq!(territory.inform_deployment_arrival(&"troop_1_1".to_string(), 1), ArrivalOutcome::EnteredOwnTerritory);
+    assert!(!territory.is_under_conflict());
+    assert_eq!(territory.player_trying_to_conquer, None);
+  }
+
+  #[test]
+  fn arrival_to_an_enemy_territory_puts_it_under_conflict() {
+    let mut territory = get_territory();
+    territory.set_new_ruler(&mut PlayerState::new(1));
+
+    assert_eq!(territory.inform_deployment_arrival(&"troop_2_1".to_string(), 2), ArrivalOutcome::EnteredEnemyTerritory);
+    assert!(territory.territory_states.contains(&TerritoryState::OccupiedUnderConflict));
+    assert_eq!(territory.player_trying_to_conquer, Some(2));
+  }
+
+  #[test]
+  fn arrival_of_a_second_player_holds_the_occupation_down() {
+    let mut territory = get_territory();
+    territory.inform_deployment_arrival(&"troop_1_1".to_string(), 1);
+
+    let outcome = territory.inform_deployment_arrival(&"troop_2_1".to_string(), 2);
+
+    assert_eq!(outcome, ArrivalOutcome::EnteredTerritoryUnderOccupation);
+    assert!(territory.territory_states.contains(&TerritoryState::UnoccupiedUnderConflict));
+    assert_eq!(territory.advance_occupation(100., 1.), None);
+  }
+
+  #[test]
+  fn occupation_goes_faster_with_more_troops() {
+    let mut territory = get_territory();
+    territory.time_to_be_conquered = 10.;
+    territory.conquering_progress_per_second = 0.;
+    territory.inform_deployment_arrival(&"troop_1_1".to_string(), 1);
+    territory.inform_deployment_arrival(&"troop_1_2".to_string(), 1);
+
+    assert_eq!(territory.advance_occupation(4., 1.), None);
+    assert_eq!(territory.conquering_progress_per_second, 8.);
+    assert_eq!(territory.advance_occupation(1., 1.), Some(1));
+    assert_eq!(territory.conquering_progress_per_second, 0.);
+  }
+
+  #[test]
+  fn occupation_time_multiplier_slows_the_occupation_down() {
+    let mut territory = get_territory();
+    territory.conquering_progress_per_second = 0.;
+    territory.inform_deployment_arrival(&"troop_1_1".to_string(), 1);
+
+    territory.advance_occupation(1., 2.);
+
+    assert_eq!(territory.conquering_progress_per_second, 0.5);
+  }
+
+  #[test]
+  fn new_ruler_takes_the_territory_and_its_troops_allowance() {
+    let mut territory = get_territory();
+    territory.organic_max_troops = 3;
+    territory.inform_deployment_arrival(&"troop_1_1".to_string(), 1);
+    let mut player = PlayerState::new(1);
+
+    territory.set_new_ruler(&mut player);
+
+    assert_eq!(territory.current_ruler, Some(1));
+    assert_eq!(territory.player_trying_to_conquer, None);
+    assert_eq!(territory.territory_states.iter().collect::<Vec<_>>(), vec![&TerritoryState::Occupied]);
+    assert_eq!(player.max_troop_allowed, 3);
+    assert_eq!(player.territory_counter, 1);
+
+    territory.release_ruler(&mut player);
+
+    assert_eq!(territory.current_ruler, None);
+    assert_eq!(player.max_troop_allowed, 0);
+    assert_eq!(player.territory_counter, 0);
+  }
+
+  #[test]
+  fn conflict_is_cleared_once_a_single_player_is_left() {
+    let mut territory = get_territory();
+    territory.set_new_ruler(&mut PlayerState::new(1));
+    territory.inform_deployment_arrival(&"troop_1_1".to_string(), 1);
+    territory.inform_deployment_arrival(&"troop_2_1".to_string(), 2);
+
+    territory.check_territory_under_conflict();
+    assert!(territory.is_under_conflict());
+
+    territory.inform_territory_departure(&"troop_1_1".to_string(), 1);
+    territory.check_territory_under_conflict();
+
+    assert!(!territory.is_under_conflict());
+    assert_eq!(territory.settle_occupation(), Some(OccupationChange::Started(2)));
+    assert!(territory.territory_states.contains(&TerritoryState::OccupationInProgress));
+  }
+
+  #[test]
+  fn settling_twice_for_the_same_occupier_changes_nothing() {
+    let mut territory = get_territory();
+    territory.inform_deployment_arrival(&"troop_1_1".to_string(), 1);
+
+    assert_eq!(territory.settle_occupation(), None);
+  }
+
+  #[test]
+  fn occupation_is_given_up_when_the_occupier_leaves() {
+    let mut territory = get_territory();
+    territory.inform_deployment_arrival(&"troop_1_1".to_string(), 1);
+    territory.conquering_progress_per_second = 3.;
+
+    territory.inform_territory_departure(&"troop_1_1".to_string(), 1);
+
+    assert_eq!(territory.settle_occupation(), Some(OccupationChange::GivenUp));
+    assert!(!territory.territory_states.contains(&TerritoryState::OccupationInProgress));
+    assert_eq!(territory.player_trying_to_conquer, None);
+    assert_eq!(territory.conquering_progress_per_second, 0.);
+  }
+
+  #[test]
+  fn settling_a_territory_under_conflict_waits() {
+    let mut territory = get_territory();
+    territory.inform_deployment_arrival(&"troop_1_1".to_string(), 1);
+    territory.inform_deployment_arrival(&"troop_2_1".to_string(), 2);
+
+    assert_eq!(territory.settle_occupation(), None);
+  }
+}
@@ -0,0 +1,213 @@
+use crate::events::game_event::GameEvent;
+use crate::players::player::{PlayerId, PlayerState};
+use crate::rules::Rules;
+use crate::setup::PlayerSetup;
+use crate::troops::battlefield::TroopSighting;
+use crate::troops::combat::projectile::Projectile;
+use crate::troops::mesh_map::TroopMeshes;
+use crate::troops::troop::{Troop, TroopId};
+
+use super::occupation::OccupationChange;
+use super::territory::{Territories, TerritoryId, TerritoryState};
+
+/// The match around the territories while they're stepped by `Realm::tick_territories`,
+/// where troops are spawned and die. The headless `World` is one and the game has its own on top of the scene tree
+pub trait Realm {
+  fn get_territories(&self) -> &Territories;
+  fn get_territories_mut(&mut self) -> &mut Territories;
+
+  /// Lends the state of the player along with the territories and rules,
+  /// as they can't be borrowed from the realm at the same time
+  fn with_player<R>(&mut self, player_id: PlayerId, lend: impl FnOnce(&mut PlayerState, &mut Territories, &Rules) -> R) -> R;
+
+  fn get_troop_meshes(&self, player_id: PlayerId) -> TroopMeshes;
+
+  /// Every troop still alive, a troop being killed is already left out
+  fn get_troops(&self) -> Vec<TroopSighting>;
+
+  /// Puts a troop just spawned by `Realm::spawn_troop` in the match
+  fn add_troop(&mut self, troop: Troop);
+
+  /// No one keeps targeting the troop nor firing back at it
+  fn forget_troop(&mut self, troop_id: &TroopId);
+
+  /// Projectiles still on their way to the troop go after `new_target`, or fizzle if it's None
+  fn retarget_projectiles(&mut self, troop_id: &TroopId, new_target: Option<&TroopSighting>);
+
+  /// Shows the territory as it is now, there's nothing to show without rendering
+  fn paint_territory(&mut self, _territory_id: &TerritoryId) {}
+
+  fn emit(&mut self, event: GameEvent);
+
+  /// Territories step of the simulation clock: ruled territories spawn troops,
+  /// occupations go on and the conflicts that are over get settled
+  fn tick_territories(&mut self, delta: f64) {
+    let territory_ids: Vec<TerritoryId> = self.get_territories().keys().cloned().collect();
+
+    for territory_id in &territory_ids {
+      let Some(ruler_id) = self.get_territories()[territory_id].current_ruler else {
+        continue;
+      };
+
+      let should_spawn_troop = self.with_player(ruler_id, |ruler, territories, rules| {
+        territories
+          .get_mut(territory_id)
+          .expect("Expected territory to exist")
+          .advance_troop_spawn(ruler, rules.after_combat_cool_down_in_secs, delta)
+      });
+      if should_spawn_troop {
+        self.spawn_troop(ruler_id, territory_id);
+      }
+    }
+
+    for territory_id in &territory_ids {
+      let territory = &self.get_territories()[territory_id];
+      if !territory.territory_states.contains(&TerritoryState::OccupationInProgress) {
+        continue;
+      }
+      let Some(player_id) = territory.player_trying_to_conquer else {
+        continue;
+      };
+
+      let conquered_by = self.with_player(player_id, |player, territories, _| {
+        territories
+          .get_mut(territory_id)
+          .expect("Expected territory to exist")
+          .advance_occupation(delta, player.region_bonus.occupation_time_multiplier)
+      });
+      match conquered_by {
+        Some(player_id) => self.conquer_territory(territory_id, player_id),
+        None => self.paint_territory(territory_id),
+      }
+    }
+
+    for territory_id in &territory_ids {
+      let territory = self.get_territories_mut().get_mut(territory_id).expect("Expected territory to exist");
+      territory.check_territory_under_conflict();
+
+      match territory.settle_occupation() {
+        Some(OccupationChange::Started(player_id)) => self.emit(GameEvent::OccupationStarted {
+          territory_id: territory_id.clone(),
+          player_id,
+        }),
+        Some(OccupationChange::GivenUp) => self.paint_territory(territory_id),
+        None => (),
+      }
+    }
+  }
+
+  /// Every player gets its initial territory and troops, expects a setup already checked by `MatchSetup::validate`
+  fn start_match(&mut self, players: &[PlayerSetup]) {
+    for player_setup in players {
+      self.set_ruler(&player_setup.initial_territory, player_setup.player_id);
+
+      for _ in 0..player_setup.initial_troops {
+        self.spawn_troop(player_setup.player_id, &player_setup.initial_territory);
+      }
+    }
+
+    self.refresh_region_bonuses(players.iter().map(|player_setup| player_setup.player_id));
+  }
+
+  /// Whenever a troop is spawned in a territory, it also means it has arrived to it
+  fn spawn_troop(&mut self, player_id: PlayerId, territory_id: &TerritoryId) {
+    let troop_meshes = self.get_troop_meshes(player_id);
+
+    let troop = self.with_player(player_id, |player, territories, rules| {
+      let troop_id = player.register_troop_spawning();
+      let territory = territories.get_mut(territory_id).expect("Expected territory to exist");
+
+      territory.add_territory_deployment(&troop_id, player_id);
+      territory.inform_troop_arrived(&troop_id, player_id);
+      Troop::new(troop_id, player_id, &troop_meshes, territory, rules)
+    });
+
+    self.emit(GameEvent::TroopSpawned {
+      troop_id: troop.troop_id.clone(),
+      player_id,
+      territory_id: territory_id.clone(),
+    });
+    self.add_troop(troop);
+  }
+
+  /// Makes the player the ruler of the territory, it's taken from its previous ruler if it had one
+  /// Returns the previous ruler, check `Realm::conquer_territory` for the events and bonuses
+  fn set_ruler(&mut self, territory_id: &TerritoryId, player_id: PlayerId) -> Option<PlayerId> {
+    let taken_from = self.get_territories()
+      .get(territory_id)
+      .unwrap_or_else(|| panic!("Expected territory {territory_id} to exist"))
+      .current_ruler;
+
+    if let Some(previous_ruler_id) = taken_from {
+      self.with_player(previous_ruler_id, |previous_ruler, territories, _| {
+        territories.get_mut(territory_id).expect("Expected territory to exist").release_ruler(previous_ruler);
+      });
+    }
+    self.with_player(player_id, |player, territories, _| {
+      territories.get_mut(territory_id).expect("Expected territory to exist").set_new_ruler(player);
+    });
+
+    self.paint_territory(territory_id);
+    taken_from
+  }
+
+  fn conquer_territory(&mut self, territory_id: &TerritoryId, player_id: PlayerId) {
+    let taken_from = self.set_ruler(territory_id, player_id);
+    self.refresh_region_bonuses([Some(player_id), taken_from].into_iter().flatten());
+
+    self.emit(GameEvent::TerritoryConquered {
+      territory_id: territory_id.clone(),
+      player_id,
+      taken_from,
+    });
+    if let Some(previous_ruler_id) = taken_from {
+      self.emit(GameEvent::TerritoryLost {
+        territory_id: territory_id.clone(),
+        player_id: previous_ruler_id,
+        lost_to: player_id,
+      });
+    }
+  }
+
+  /// Check `PlayerState::refresh_region_bonus`, should be called once the territories changed hands
+  fn refresh_region_bonuses(&mut self, player_ids: impl IntoIterator<Item = PlayerId>) {
+    for player_id in player_ids {
+      self.with_player(player_id, |player, territories, rules| {
+        player.refresh_region_bonus(territories, &rules.region_bonuses);
+      });
+    }
+  }
+
+  /// Every bit of bookkeeping the troop leaves behind goes along with it,
+  /// expects the troop to be already out of `Realm::get_troops`
+  fn kill_troop(&mut self, troop: &Troop, killed_by: &TroopId, killer_player_id: PlayerId) {
+    self.with_player(troop.owner, |owner, territories, _| {
+      if let Some(territory) = territories.get_mut(&troop.deployed_to_territory) {
+        territory.inform_territory_departure(&troop.troop_id, troop.owner);
+      }
+      owner.register_troop_loss();
+    });
+    self.with_player(killer_player_id, |killer_player, _, _| killer_player.register_kill());
+
+    self.forget_troop(&troop.troop_id);
+
+    let troops_of_the_owner: Vec<TroopSighting> = self.get_troops()
+      .into_iter()
+      .filter(|candidate| candidate.owner == troop.owner)
+      .collect();
+    // All of them went after the troop, so all of them go after the same one
+    let new_target = Projectile::find_new_target(
+      troop.position,
+      troops_of_the_owner.iter().map(|candidate| (&candidate.troop_id, candidate.position)),
+    ).and_then(|new_target_id| troops_of_the_owner.iter().find(|candidate| candidate.troop_id == new_target_id));
+    self.retarget_projectiles(&troop.troop_id, new_target);
+
+    self.emit(GameEvent::TroopDied {
+      troop_id: troop.troop_id.clone(),
+      player_id: troop.owner,
+      territory_id: troop.deployed_to_territory.clone(),
+      killed_by: killed_by.clone(),
+      killer_player_id,
+    });
+  }
+}
@@ -0,0 +1,109 @@
+use crate::players::player::PlayerState;
+
+use super::territory::Territory;
+
+impl Territory {
+  /// Advances the troop spawning progress of a ruled territory
+  ///
+  /// Returns true when a troop should be spawned at the territory, the caller
+  /// is responsible for spawning it, check `Realm::spawn_troop`
+  pub fn advance_troop_spawn(&mut self, ruler: &PlayerState, after_combat_cool_down_in_secs: f64, delta: f64) -> bool {
+    // Spawning holds down while the territory is under conflict and for a while after it
+    if self.is_under_conflict() {
+      self.after_combat_timer = after_combat_cool_down_in_secs;
+      return false;
+    }
+
+    if self.after_combat_timer > 0. {
+      self.after_combat_timer -= delta;
+      return false;
+    }
+
+    if ruler.troops_counter >= ruler.max_troop_allowed ||
+      (self.all_troops_deployed_and_arrived.len() as u32) >= self.organic_max_troops {
+      return false;
+    }
+
+    self.valid_seconds_elasped_since_last_troop += delta;
+
+    if self.next_troop_progress >= 100. {
+      self.next_troop_progress = 0.;
+      self.valid_seconds_elasped_since_last_troop = 0.;
+      return true;
+    }
+
+    // Should represent how many seconds should take for a troop to be spawned at the territory
+    let seconds_to_spawn_troop = self.seconds_to_spawn_troop * ruler.region_bonus.spawn_time_multiplier;
+    self.next_troop_progress = 100. * self.valid_seconds_elasped_since_last_troop / seconds_to_spawn_troop;
+    false
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::players::player::PlayerState;
+  use crate::territories::territory::{Continent, Territory, TerritoryState};
+
+  fn get_ruled_territory() -> (Territory, PlayerState) {
+    let mut territory = Territory::get_base_territory("brazil", Continent::SouthAmerica, None);
+    territory.organic_max_troops = 2;
+    territory.seconds_to_spawn_troop = 10.;
+
+    let mut ruler = PlayerState::new(1);
+    territory.set_new_ruler(&mut ruler);
+    (territory, ruler)
+  }
+
+  /// Steps the spawning a second at a time, returning how many steps it took to spawn a troop
+  fn get_steps_to_spawn(territory: &mut Territory, ruler: &PlayerState) -> Option<u32> {
+    (1..=100).find(|_| territory.advance_troop_spawn(ruler, 5., 1.))
+  }
+
+  #[test]
+  fn troop_spawns_once_the_progress_is_full() {
+    let (mut territory, ruler) = get_ruled_territory();
+
+    assert_eq!(get_steps_to_spawn(&mut territory, &ruler), Some(11));
+    assert_eq!(territory.next_troop_progress, 0.);
+    assert_eq!(territory.valid_seconds_elasped_since_last_troop, 0.);
+  }
+
+  #[test]
+  fn spawn_time_multiplier_changes_the_spawning_pace() {
+    let (mut territory, mut ruler) = get_ruled_territory();
+    ruler.region_bonus.spawn_time_multiplier = 0.5;
+
+    assert_eq!(get_steps_to_spawn(&mut territory, &ruler), Some(6));
+  }
+
+  #[test]
+  fn spawning_stops_at_the_player_troops_limit() {
+    let (mut territory, mut ruler) = get_ruled_territory();
+    ruler.troops_counter = ruler.max_troop_allowed;
+
+    assert_eq!(get_steps_to_spawn(&mut territory, &ruler), None);
+  }
+
+  #[test]
+  fn spawning_stops_at_the_territory_troops_limit() {
+    let (mut territory, ruler) = get_ruled_territory();
+    territory.inform_troop_arrived(&"troop_1_1".to_string(), 1);
+    territory.inform_troop_arrived(&"troop_1_2".to_string(), 1);
+
+    assert_eq!(get_steps_to_spawn(&mut territory, &ruler), None);
+  }
+
+  #[test]
+  fn spawning_holds_down_during_and_after_a_conflict() {
+    let (mut territory, ruler) = get_ruled_territory();
+    territory.territory_states.insert(TerritoryState::OccupiedUnderConflict);
+
+    assert!(!territory.advance_troop_spawn(&ruler, 5., 1.));
+    assert_eq!(territory.after_combat_timer, 5.);
+
+    territory.check_territory_under_conflict();
+
+    // 5 seconds of cool down, then the 11 steps of a regular spawn
+    assert_eq!(get_steps_to_spawn(&mut territory, &ruler), Some(16));
+  }
+}
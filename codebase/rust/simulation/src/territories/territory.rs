@@ -0,0 +1,352 @@
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+use std::fmt::{Display, Formatter, Result};
+
+use serde::{Deserialize, Serialize};
+
+use crate::globe::coordinates::Coordinates;
+use crate::math::Vec3;
+use crate::players::player::PlayerId;
+use crate::random::MatchRng;
+use crate::rules::Rules;
+use crate::troops::troop::TroopId;
+
+use super::data::TerritoryTag;
+
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub enum Continent {
+  Africa,
+  Asia,
+  Europe,
+  NorthAmerica,
+  Oceania,
+  SouthAmerica,
+  Antarctica,
+  Special,
+}
+
+impl Display for Continent {
+  /// allows to use `&Continent::Africa.to_string()`
+  fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+    match self {
+      Continent::Africa => write!(f, "africa"),
+      Continent::Asia => write!(f, "asia"),
+      Continent::Europe => write!(f, "europe"),
+      Continent::NorthAmerica => write!(f, "north_america"),
+      Continent::Oceania => write!(f, "oceania"),
+      Continent::SouthAmerica => write!(f, "south_america"),
+      Continent::Antarctica => write!(f, "antarctica"),
+      Continent::Special => write!(f, "special"),
+    }
+  }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub enum SubContinent {
+  MiddleEast,
+  InteriorAsia,
+  IndianSubcontinent,
+  SoutheastAsia,
+  EastAsia,
+  EuropeRelatedAsia,
+}
+
+impl Display for SubContinent {
+  /// allows to use `&SubContinent::MiddleEast.to_string()`
+  fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+    match self {
+      SubContinent::MiddleEast => write!(f, "middle_east"),
+      SubContinent::InteriorAsia => write!(f, "interior_asia"),
+      SubContinent::IndianSubcontinent => write!(f, "indian_subcontinent"),
+      SubContinent::SoutheastAsia => write!(f, "southeast_asia"),
+      SubContinent::EastAsia => write!(f, "east_asia"),
+      SubContinent::EuropeRelatedAsia => write!(f, "europe_related_asia"),
+    }
+  }
+}
+
+#[derive(Debug, Clone)]
+pub struct Location {
+  pub continent: Continent,
+  pub sub_continent: Option<SubContinent>,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub enum Size {
+  Tiny,
+  Small,
+  Medium,
+  Large,
+  Huge,
+  Humongous,
+  None,
+}
+
+impl Display for Size {
+  /// allows to use `&Size::Tiny.to_string()`
+  fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+    match self {
+      Size::Tiny => write!(f, "tiny"),
+      Size::Small => write!(f, "small"),
+      Size::Medium => write!(f, "medium"),
+      Size::Large => write!(f, "large"),
+      Size::Huge => write!(f, "huge"),
+      Size::Humongous => write!(f, "humongous"),
+      Size::None => write!(f, "none"),
+    }
+  }
+}
+
+pub type TerritoryId = String;
+/// Ordered by id, so every tick walks over the territories in the same order
+pub type Territories = BTreeMap<TerritoryId, Territory>;
+
+
+#[derive(Debug, Clone, Hash, Eq, PartialEq, Serialize, Deserialize)]
+pub enum TerritoryState {
+  Unoccupied,
+  UnoccupiedUnderConflict,
+  OccupationInProgress,
+  Occupied,
+  OccupiedUnderConflict,
+}
+
+/// Not a Godot class, the Godot side of territories lives at `land.rs`,
+/// `surface_point.rs` and `virtual_planet.rs` in the GDExtension crate
+#[derive(Debug, Clone)]
+pub struct Territory {
+  pub territory_id: TerritoryId,
+  pub location: Location,
+  pub tags: BTreeSet<TerritoryTag>,
+
+  pub coordinates: Vec<Coordinates>,
+  pub size: Size,
+
+  pub organic_max_troops: u32,
+  troops_growth_velocity: f32,
+  pub seconds_to_spawn_troop: f64,
+  pub spawner_location: Vec3,
+  pub territory_states: HashSet<TerritoryState>,
+
+  /// It counts which troops are deployed in the territory, not necessarily arrived
+  pub(crate) all_troops_deployed: BTreeSet<TroopId>,
+  /// It counts which troops are deployed in the territory, not necessarily arrived, filtering by player
+  pub(crate) all_troops_deployed_by_player: BTreeMap<PlayerId, BTreeSet<TroopId>>,
+  /// It counts which troops are have arrived to the territory
+  pub all_troops_deployed_and_arrived: BTreeSet<TroopId>,
+  /// It counts which troops are have arrived to the territory, filtering by player
+  pub all_troops_deployed_and_arrived_by_player: BTreeMap<PlayerId, BTreeSet<TroopId>>,
+  pub has_troops_from_different_players: bool,
+
+  pub time_to_be_conquered: f64,
+  pub conquering_progress_per_second: f64,
+  /// TICKET: #93 This could possibly be a list of players trying to conquer the territory
+  pub player_trying_to_conquer: Option<PlayerId>,
+  pub progress_to_reset_idle_conquering: f64,
+
+  pub current_ruler: Option<PlayerId>,
+  pub next_troop_progress: f64,
+  pub valid_seconds_elasped_since_last_troop: f64,
+  /// Seconds left before the territory spawns troops again after a combat on it, check `Territory::advance_troop_spawn`
+  pub after_combat_timer: f64,
+
+  /// How fast the troops of the ruler heal while they rest on the territory, check `CombatStats::recover`
+  pub hp_healed_per_second: f64,
+}
+
+impl Territory {
+  /// It's a factor that helps setting how many troops a territory can generate
+  /// the lower the value, the less troops a territory can generate
+  pub const BASE_TROOP_NUMBER_PER_TERRITORY: f32 = 0.02;
+
+  /// organic_max_troops is clamped between 1 and MAX_NUMBER_OF_TROOPS_GENERATED_PER_TERRITORY
+  pub const MAX_NUMBER_OF_TROOPS_GENERATED_PER_TERRITORY: u32 = 20;
+
+  /// It's a factor that helps setting how fast the troops grow in a territory
+  /// the lower the value, the slower the troops grow
+  pub const BASE_TROOP_GROWTH_VELOCITY: f32 = 0.001;
+
+  /// It's a factor helping controlling the speed of the troops spawning
+  /// the lower the value, the faster the troops spawn
+  pub const BASE_SECONDS_FOR_A_TROOP_TO_SPAWN: f64 = 3.;
+
+  /// It's a factor that helps setting how much time a player has to occupying a territory
+  /// to take control of it and become a ruler
+  /// the lower the value, the faster the territories are conquered
+  pub const BASE_TERRITORY_OCCUPATION_TIME: f64 = 0.2;
+
+  /// It's a factor that helps setting how fast troops heal on a territory of their own player,
+  /// the greater the territory, the faster they heal
+  pub const BASE_HEALING_PER_COORDINATE: f64 = 0.01;
+
+  /// hp_healed_per_second is clamped between 0 and MAX_HP_HEALED_PER_SECOND
+  const MAX_HP_HEALED_PER_SECOND: f64 = 8.;
+
+  /// If user stops trying to conquer a territory, the progress to conquer it is reset
+  const _SECONDS_TO_RESET_IDLE_CONQUERING: f64 = 10.;
+
+
+  pub fn get_base_territory(territory_id: &str, continent: Continent, sub_continent: Option<SubContinent>) -> Territory {
+    Territory {
+      territory_id: territory_id.to_string(),
+      location: Location { continent, sub_continent },
+      tags: BTreeSet::new(),
+
+      // Fields below are filled on the fly
+      coordinates: Vec::new(),
+      size: Size::None,
+      organic_max_troops: 0,
+      troops_growth_velocity: 0.1,
+      seconds_to_spawn_troop: 10.,
+      spawner_location: Vec3::ZERO,
+
+      territory_states: HashSet::from([
+        TerritoryState::Unoccupied,
+      ]),
+
+      all_troops_deployed: BTreeSet::new(),
+      all_troops_deployed_by_player: BTreeMap::new(),
+
+      all_troops_deployed_and_arrived: BTreeSet::new(),
+      all_troops_deployed_and_arrived_by_player: BTreeMap::new(),
+
+      has_troops_from_different_players: false,
+
+      time_to_be_conquered: 10.,
+      conquering_progress_per_second: 0.1,
+      player_trying_to_conquer: None,
+      progress_to_reset_idle_conquering: 0.,
+
+      current_ruler: None,
+      next_troop_progress: 0.,
+      valid_seconds_elasped_since_last_troop: 0.,
+      after_combat_timer: 0.,
+
+      hp_healed_per_second: 0.,
+    }
+  }
+
+  /// Should be called when the coordinates of the territory are set
+  pub fn set_territory_size(&mut self) {
+    // TODO: Instead of using size, use coordinates.len to calculate organic_max_troops and troops_growth_velocity
+    let num_of_coordinates = self.coordinates.len();
+
+    self.size = match num_of_coordinates {
+      1..=40 => Size::Tiny,
+      41..=150 => Size::Small,
+      151..=450 => Size::Medium,
+      451..=750 => Size::Large,
+      751..=1000 => Size::Huge,
+      1001..=9999 => Size::Humongous,
+      _ => Size::None,
+    };
+  }
+
+  /// Should be called when the coordinates of the territory are set
+  pub fn set_troops_growth_velocity_and_secs_to_spawn(&mut self, rules: &Rules) {
+    let num_of_coordinates = self.coordinates.len();
+
+    self.troops_growth_velocity = (rules.base_troop_growth_velocity * num_of_coordinates as f32)
+      .clamp(0.01, 3.);
+
+    self.seconds_to_spawn_troop = rules.base_seconds_for_a_troop_to_spawn / self.troops_growth_velocity as f64;
+  }
+
+  /// Should be called when the coordinates of the territory are set
+  pub fn set_organic_max_troops(&mut self, rules: &Rules) {
+    let num_of_coordinates = self.coordinates.len();
+
+    self.organic_max_troops = ((
+      rules.base_troop_number_per_territory * num_of_coordinates as f32) as u32)
+      .clamp(1, rules.max_number_of_troops_generated_per_territory);
+  }
+
+  /// The greater the territory, the longer it takes to be conquered
+  pub fn set_time_to_be_conquered(&mut self, rules: &Rules) {
+    let num_of_coordinates = self.coordinates.len() as f64;
+    self.time_to_be_conquered = num_of_coordinates * rules.base_territory_occupation_time;
+  }
+
+  /// Should be called when the coordinates of the territory are set
+  pub fn set_hp_healed_per_second(&mut self, rules: &Rules) {
+    let num_of_coordinates = self.coordinates.len() as f64;
+
+    self.hp_healed_per_second = (rules.base_healing_per_coordinate * num_of_coordinates)
+      .clamp(0., Self::MAX_HP_HEALED_PER_SECOND);
+  }
+
+  /// Runs every setter that depends on the territory coordinates
+  pub fn set_coordinates_dependent_stats(&mut self, rules: &Rules) {
+    self.set_territory_size();
+    self.set_troops_growth_velocity_and_secs_to_spawn(rules);
+    self.set_organic_max_troops(rules);
+    self.set_time_to_be_conquered(rules);
+    self.set_hp_healed_per_second(rules);
+  }
+
+  /// Returns the coordinate troops are spawned at, None if the coordinates weren't matched yet
+  pub fn get_spawner_coordinate(&self) -> Option<Coordinates> {
+    // TICKET: #50 this "divided by 4" is a hack to get a coordinate in the territory not close to the border
+    // Sometimes it does not work, but it's good enough for now
+    self.coordinates.get(self.coordinates.len() / 4).copied()
+  }
+
+  pub fn add_territory_deployment(&mut self, troop_id: &TroopId, player_id: PlayerId) {
+    self.all_troops_deployed.insert(troop_id.clone());
+
+    self.all_troops_deployed_by_player
+      .entry(player_id)
+      .or_default()
+      .insert(troop_id.to_string());
+  }
+
+  pub fn inform_troop_arrived(&mut self, troop_id: &TroopId, player_id: PlayerId) {
+    self.all_troops_deployed_and_arrived.insert(troop_id.clone());
+
+    self.all_troops_deployed_and_arrived_by_player
+      .entry(player_id)
+      .or_default()
+      .insert(troop_id.to_string());
+
+    self.set_troops_from_different_players_flag();
+  }
+
+  pub fn inform_territory_departure(&mut self, troop_id: &TroopId, player_id: PlayerId) {
+    self.all_troops_deployed.remove(troop_id);
+    self.all_troops_deployed_and_arrived.remove(troop_id);
+
+    if let Some(player_troops) = self.all_troops_deployed_by_player.get_mut(&player_id) {
+      player_troops.remove(troop_id);
+    }
+
+    if let Some(player_troops) = self.all_troops_deployed_and_arrived_by_player.get_mut(&player_id) {
+      player_troops.remove(troop_id);
+    }
+
+    self.set_troops_from_different_players_flag();
+  }
+
+  /// It counts all the troops deployed and arrived to a territory, if there are troops from different players
+  /// it sets true to has_troops_from_different_players
+  /// That helps to know if a territory will be under conflict or when the conflict is finished
+  pub fn set_troops_from_different_players_flag(&mut self) {
+    let troops_by_player_counter = self.all_troops_deployed_and_arrived_by_player
+      .values()
+      .filter(|troops| !troops.is_empty())
+      .count();
+
+    self.has_troops_from_different_players = troops_by_player_counter > 1;
+  }
+
+  /// Picks a coordinate of the territory using the match rng, None if the
+  /// coordinates weren't matched yet
+  pub fn get_random_coordinate(&self, rng: &mut MatchRng) -> Option<Coordinates> {
+    rng.choice(&self.coordinates).copied()
+  }
+
+  /// Number of troops from a player that have arrived to the territory
+  pub fn get_num_of_arrived_troops_by_player(&self, player_id: PlayerId) -> usize {
+    self.all_troops_deployed_and_arrived_by_player
+      .get(&player_id)
+      .map_or(0, |troops| troops.len())
+  }
+}
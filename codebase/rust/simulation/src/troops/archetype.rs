@@ -0,0 +1,246 @@
+use std::collections::BTreeSet;
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+use serde::{Deserialize, Serialize};
+
+use crate::rules::Rules;
+
+use super::combat::combat_stats::CombatStats;
+use super::combat::location::LocationModifiers;
+use super::combat::projectile::Projectile;
+use super::mesh_map::{MeshId, TroopMeshes};
+use super::speed::SpeedType;
+use super::surface::Surface;
+
+/// The data file the game ships with, the Godot project keeps it at `res://data/archetypes.ron`
+const BUNDLED_ARCHETYPES: &str = include_str!("../../../../godot/data/archetypes.ron");
+
+/// How a kind of troop plays, every `MeshId` has one, check `codebase/godot/data/archetypes.ron`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Archetype {
+  pub mesh: MeshId,
+  pub hp: i32,
+  /// Dealt by every projectile it fires, before the armor of the target
+  pub damage: i32,
+  /// Check `Cannon::range`
+  pub range: f32,
+  /// Check `Cannon::cool_down_timer_in_secs`
+  pub cool_down_in_secs: f64,
+  /// Check `SpeedType::Patrolling`
+  pub patrolling_speed: f32,
+  /// Check `SpeedType::FightOrFlight`
+  pub fight_or_flight_speed: f32,
+  /// Share of every hit that's taken off, from 0 up to, but not including, 1
+  #[serde(default)]
+  pub armor: f32,
+  /// Surfaces the mesh can be picked for, check `TroopMeshes`
+  pub surfaces: Vec<Surface>,
+}
+
+/// Why the archetypes can't be used, meant to be shown as is
+#[derive(Debug, Clone, PartialEq)]
+pub enum ArchetypeDataError {
+  Parse(String),
+  MissingMesh(MeshId),
+  DuplicateMesh(MeshId),
+  /// The name of the stat that's zero or negative
+  NotPositive(MeshId, &'static str),
+  InvalidArmor(MeshId),
+  NoSurfaces(MeshId),
+}
+
+impl Display for ArchetypeDataError {
+  fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+    match self {
+      ArchetypeDataError::Parse(reason) =>
+        write!(f, "couldn't read the archetypes: {reason}"),
+      ArchetypeDataError::MissingMesh(mesh_id) =>
+        write!(f, "{mesh_id:?} doesn't have an archetype"),
+      ArchetypeDataError::DuplicateMesh(mesh_id) =>
+        write!(f, "{mesh_id:?} has more than one archetype"),
+      ArchetypeDataError::NotPositive(mesh_id, stat) =>
+        write!(f, "the {stat} of {mesh_id:?} should be positive"),
+      ArchetypeDataError::InvalidArmor(mesh_id) =>
+        write!(f, "the armor of {mesh_id:?} should be from 0 up to, but not including, 1"),
+      ArchetypeDataError::NoSurfaces(mesh_id) =>
+        write!(f, "{mesh_id:?} can't be picked for any surface"),
+    }
+  }
+}
+
+impl std::error::Error for ArchetypeDataError {}
+
+impl Archetype {
+  /// Parses and checks a RON archetypes file, check `codebase/godot/data/archetypes.ron`
+  pub fn parse(content: &str) -> Result<Vec<Archetype>, ArchetypeDataError> {
+    let archetypes: Vec<Archetype> = ron::from_str(content)
+      .map_err(|error| ArchetypeDataError::Parse(error.to_string()))?;
+
+    Self::validate(&archetypes)?;
+    Ok(archetypes)
+  }
+
+  /// Checks there's exactly one archetype per mesh and all of them can be played
+  pub fn validate(archetypes: &[Archetype]) -> Result<(), ArchetypeDataError> {
+    let mut mesh_ids = BTreeSet::new();
+    for archetype in archetypes {
+      let mesh_id = &archetype.mesh;
+      if !mesh_ids.insert(mesh_id) {
+        return Err(ArchetypeDataError::DuplicateMesh(mesh_id.clone()));
+      }
+
+      let stats = [
+        ("hp", archetype.hp as f64),
+        ("damage", archetype.damage as f64),
+        ("range", archetype.range as f64),
+        ("cool down", archetype.cool_down_in_secs),
+        ("patrolling speed", archetype.patrolling_speed as f64),
+        ("fight or flight speed", archetype.fight_or_flight_speed as f64),
+      ];
+      if let Some((stat, _)) = stats.iter().find(|(_, value)| *value <= 0.) {
+        return Err(ArchetypeDataError::NotPositive(mesh_id.clone(), stat));
+      }
+
+      if !(0. ..1.).contains(&archetype.armor) {
+        return Err(ArchetypeDataError::InvalidArmor(mesh_id.clone()));
+      }
+
+      if archetype.surfaces.is_empty() {
+        return Err(ArchetypeDataError::NoSurfaces(mesh_id.clone()));
+      }
+    }
+
+    if let Some(mesh_id) = MeshId::iter().find(|mesh_id| !mesh_ids.contains(mesh_id)) {
+      return Err(ArchetypeDataError::MissingMesh(mesh_id));
+    }
+
+    Ok(())
+  }
+
+  /// The archetypes the game ships with
+  pub fn get_bundled() -> Vec<Archetype> {
+    Self::parse(BUNDLED_ARCHETYPES)
+      .unwrap_or_else(|error| panic!("Expected the bundled archetypes to be valid: {error}"))
+  }
+
+  /// Placeholder for troops that weren't spawned yet, with the stats every archetype is balanced against
+  pub fn get_blank() -> Archetype {
+    Archetype {
+      mesh: MeshId::Tank1,
+      hp: CombatStats::BASE_HP,
+      damage: Projectile::DEFAULT_DAMAGE,
+      range: CombatStats::CANNON_RANGE,
+      cool_down_in_secs: CombatStats::COOL_DOWN_TIMER_IN_SECS,
+      patrolling_speed: SpeedType::BASE_PATROLLING_SPEED,
+      fight_or_flight_speed: SpeedType::BASE_FIGHT_OR_FLIGHT_SPEED,
+      armor: 0.,
+      surfaces: vec![Surface::Land],
+    }
+  }
+
+  /// What's left of a hit once the armor and the location take their share, a hit always takes at least 1 hp
+  pub fn get_damage_taken(&self, damage: i32, location_modifiers: &LocationModifiers) -> i32 {
+    let damage_taken = (location_modifiers.get_damage_taken(damage as f32) * (1. - self.armor)).ceil() as i32;
+    damage_taken.max(1)
+  }
+}
+
+impl Rules {
+  /// Expects rules checked by `Archetype::validate`
+  pub fn get_archetype(&self, mesh_id: &MeshId) -> &Archetype {
+    self.archetypes
+      .iter()
+      .find(|archetype| archetype.mesh == *mesh_id)
+      .expect("Expected every mesh to have an archetype")
+  }
+
+  /// Archetype of the troops of a player with these meshes while they are on that surface
+  pub fn get_troop_archetype(&self, troop_meshes: &TroopMeshes, surface: Surface) -> &Archetype {
+    self.get_archetype(troop_meshes.get(surface))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::troops::combat::location::LocationModifiers;
+  use crate::troops::mesh_map::MeshId;
+
+  use super::{Archetype, ArchetypeDataError};
+
+  type BreakStat = fn(&mut Archetype);
+
+  fn get_mut<'a>(archetypes: &'a mut [Archetype], mesh_id: &MeshId) -> &'a mut Archetype {
+    archetypes
+      .iter_mut()
+      .find(|archetype| archetype.mesh == *mesh_id)
+      .expect("Expected every mesh to have an archetype")
+  }
+
+  #[test]
+  fn bundled_archetypes_are_valid() {
+    assert_eq!(Archetype::validate(&Archetype::get_bundled()), Ok(()));
+  }
+
+  #[test]
+  fn every_mesh_has_exactly_one_archetype() {
+    let mut archetypes = Archetype::get_bundled();
+    archetypes.retain(|archetype| archetype.mesh != MeshId::Cannon);
+
+    assert_eq!(Archetype::validate(&archetypes), Err(ArchetypeDataError::MissingMesh(MeshId::Cannon)));
+
+    let mut archetypes = Archetype::get_bundled();
+    archetypes.push(archetypes[0].clone());
+
+    assert_eq!(Archetype::validate(&archetypes), Err(ArchetypeDataError::DuplicateMesh(archetypes[0].mesh.clone())));
+  }
+
+  #[test]
+  fn stats_are_positive() {
+    let cases: [(BreakStat, &str); 6] = [
+      (|archetype| archetype.hp = 0, "hp"),
+      (|archetype| archetype.damage = -1, "damage"),
+      (|archetype| archetype.range = 0., "range"),
+      (|archetype| archetype.cool_down_in_secs = 0., "cool down"),
+      (|archetype| archetype.patrolling_speed = -0.1, "patrolling speed"),
+      (|archetype| archetype.fight_or_flight_speed = 0., "fight or flight speed"),
+    ];
+
+    for (break_stat, stat) in cases {
+      let mut archetypes = Archetype::get_bundled();
+      break_stat(get_mut(&mut archetypes, &MeshId::Tonk));
+
+      assert_eq!(Archetype::validate(&archetypes), Err(ArchetypeDataError::NotPositive(MeshId::Tonk, stat)));
+    }
+  }
+
+  #[test]
+  fn armor_takes_a_share_of_the_hits() {
+    for armor in [-0.1, 1.] {
+      let mut archetypes = Archetype::get_bundled();
+      get_mut(&mut archetypes, &MeshId::Galleon).armor = armor;
+
+      assert_eq!(Archetype::validate(&archetypes), Err(ArchetypeDataError::InvalidArmor(MeshId::Galleon)));
+    }
+
+    let mut archetype = Archetype::get_blank();
+    archetype.armor = 0.5;
+    let modifiers = LocationModifiers::default();
+
+    assert_eq!(archetype.get_damage_taken(10, &modifiers), 5);
+    assert_eq!(archetype.get_damage_taken(1, &modifiers), 1);
+  }
+
+  #[test]
+  fn archetypes_can_be_picked_for_a_surface() {
+    let mut archetypes = Archetype::get_bundled();
+    get_mut(&mut archetypes, &MeshId::Boat3).surfaces.clear();
+
+    assert_eq!(Archetype::validate(&archetypes), Err(ArchetypeDataError::NoSurfaces(MeshId::Boat3)));
+  }
+
+  #[test]
+  fn archetypes_files_are_parsed_and_checked() {
+    assert!(matches!(Archetype::parse("["), Err(ArchetypeDataError::Parse(_))));
+    assert_eq!(Archetype::parse("[]"), Err(ArchetypeDataError::MissingMesh(MeshId::Cannon)));
+  }
+}
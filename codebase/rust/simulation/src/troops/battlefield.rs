@@ -0,0 +1,67 @@
+use crate::events::game_event::GameEvent;
+use crate::globe::coordinates::CoordinateMap;
+use crate::math::Vec3;
+use crate::players::player::PlayerId;
+use crate::rules::Rules;
+use crate::territories::adjacency::AdjacencyGraph;
+use crate::territories::territory::{Territories, TerritoryId};
+
+use super::combat::projectile::Projectile;
+use super::route::RoutePlanner;
+use super::troop::{Troop, TroopId};
+
+/// What a troop gets to know about another one, check `Battlefield::get_other_troops`
+#[derive(Debug, Clone, PartialEq)]
+pub struct TroopSighting {
+  pub troop_id: TroopId,
+  pub owner: PlayerId,
+  pub position: Vec3,
+  pub deployed_to_territory: TerritoryId,
+}
+
+/// The match around a troop while it's stepped by `Troop::tick` or takes an order,
+/// the headless `World` is one and the game has its own on top of the scene tree
+pub trait Battlefield {
+  fn get_rules(&self) -> &Rules;
+  fn get_coordinate_map(&self) -> &CoordinateMap;
+  fn get_territories(&self) -> &Territories;
+  fn get_territories_mut(&mut self) -> &mut Territories;
+  fn get_adjacency(&self) -> &AdjacencyGraph;
+
+  /// Position of a random coordinate of the territory, drawn from the match rng
+  fn get_random_position_in(&mut self, territory_id: &TerritoryId) -> Option<Vec3>;
+
+  /// None if the troop is gone, or if it's the one being stepped
+  fn get_troop(&self, troop_id: &TroopId) -> Option<TroopSighting>;
+
+  /// Every troop still alive but the one being stepped
+  fn get_other_troops(&self) -> Vec<TroopSighting>;
+
+  /// Moves the troop by `velocity` for `delta` seconds and returns where it ends up
+  fn move_troop(&mut self, troop: &Troop, velocity: Vec3, delta: f64) -> Vec3;
+
+  /// Puts a projectile fired by the troop in flight
+  fn fire(&mut self, troop: &Troop, projectile: Projectile);
+
+  fn emit(&mut self, event: GameEvent);
+
+  fn get_route_planner(&self) -> RoutePlanner<'_> {
+    RoutePlanner {
+      coordinate_map: self.get_coordinate_map(),
+      territories: self.get_territories(),
+      adjacency: self.get_adjacency(),
+      rules: self.get_rules(),
+    }
+  }
+}
+
+impl Troop {
+  pub fn get_sighting(&self) -> TroopSighting {
+    TroopSighting {
+      troop_id: self.troop_id.clone(),
+      owner: self.owner,
+      position: self.position,
+      deployed_to_territory: self.deployed_to_territory.clone(),
+    }
+  }
+}
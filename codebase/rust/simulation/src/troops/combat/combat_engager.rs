@@ -0,0 +1,91 @@
+use crate::events::game_event::GameEvent;
+use crate::players::player::PlayerId;
+use crate::territories::territory::Territory;
+use crate::troops::battlefield::Battlefield;
+use crate::troops::speed::SpeedType;
+use crate::troops::troop::{remove_combatting_states, Troop, TroopState};
+
+use super::combat_stats::{CombatStats, CombatTypes, FighthingBehavior};
+
+impl CombatTypes {
+  /// Returns the combat a troop from `player_id` should engage in the territory,
+  /// None if there's no one to fight against
+  pub fn get_engagement_at(territory: &Territory, player_id: PlayerId) -> Option<CombatTypes> {
+    if !territory.has_troops_from_different_players {
+      return None;
+    }
+
+    match territory.current_ruler {
+      // TODO: Still should be able to understand who attacked and who was already there
+      None => Some(CombatTypes::FightingOverUnoccupiedTerritory),
+      Some(ruler_id) if ruler_id != player_id => Some(CombatTypes::Attacking),
+      Some(_) => Some(CombatTypes::Defending),
+    }
+  }
+}
+
+impl CombatStats {
+  /// Whether the stance lets a troop from `player_id` engage the combat `CombatTypes::get_engagement_at` found,
+  /// a `Pacifist` troop only defends its own land or fights back the troop that hit it, if it's in the territory
+  pub fn allows_engagement(&self, combat_type: &CombatTypes, territory: &Territory, player_id: PlayerId) -> bool {
+    match self.fighting_behavior {
+      FighthingBehavior::Beligerent => true,
+      FighthingBehavior::Pacifist if *combat_type == CombatTypes::Defending => true,
+      FighthingBehavior::Pacifist => self.last_attacker.as_ref().is_some_and(|attacker| {
+        territory.all_troops_deployed_and_arrived_by_player
+          .iter()
+          .any(|(enemy_player_id, enemy_troops)| *enemy_player_id != player_id && enemy_troops.contains(attacker))
+      }),
+      FighthingBehavior::HoldFire => false,
+    }
+  }
+}
+
+impl Troop {
+  pub(crate) fn trigger_combat_engage_if_needed(&mut self, battlefield: &mut impl Battlefield) {
+    if !self.arrived_to_territory {
+      return;
+    }
+
+    let territory = battlefield.get_territories()
+      .get(&self.deployed_to_territory)
+      .expect("Expected deployed territory to exist");
+
+    let combat_type = CombatTypes::get_engagement_at(territory, self.owner)
+      .filter(|combat_type| self.combat_stats.allows_engagement(combat_type, territory, self.owner));
+
+    if let Some(combat_type) = combat_type {
+      if !self.is_combatting() {
+        battlefield.emit(GameEvent::CombatStarted {
+          troop_id: self.troop_id.clone(),
+          player_id: self.owner,
+          territory_id: self.deployed_to_territory.clone(),
+        });
+      }
+
+      self.troop_activities.remove(&TroopState::Patrolling);
+      self.troop_activities.remove(&TroopState::Idle);
+      self.adopted_speed = SpeedType::FightOrFlight;
+
+      if combat_type == CombatTypes::Defending && !self.moving_and_combating {
+        // Defenders should go after the attackers
+        self.reset_trajectory();
+      }
+
+      // Setting the combat type and combating states on the troops
+      self.troop_activities.insert(TroopState::Combating(combat_type));
+
+    } else if self.is_combatting() {
+      self.no_combat_reset_trajectory(true);
+      remove_combatting_states(&mut self.troop_activities);
+      self.combat_stats.leave_combat();
+      self.moving_and_combating = false;
+
+      battlefield.emit(GameEvent::CombatEnded {
+        troop_id: self.troop_id.clone(),
+        player_id: self.owner,
+        territory_id: self.deployed_to_territory.clone(),
+      });
+    }
+  }
+}
@@ -0,0 +1,294 @@
+use serde::{Deserialize, Serialize};
+
+use crate::troops::archetype::Archetype;
+use crate::troops::troop::TroopId;
+
+/// Whose land a troop is on, or whether it is at sea, check `LocationSituation::get` and `LocationModifiers`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum LocationSituation {
+  SelfLand,
+  AllyLand,
+  /// Territories no one rules yet
+  #[default]
+  NeutralLand,
+  EnemyLand,
+  /// Off every territory
+  Sea,
+}
+
+/// Stance of a troop, set by its player through `OrderMode::Stance`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum FighthingBehavior {
+  /// will fight any non-ally troop who crosses by it doesn't matter the territory
+  #[default]
+  Beligerent,
+
+  /// will only fight if attacked or if it's territory is attacked
+  Pacifist,
+
+  /// won't fire at all, not even back
+  HoldFire,
+}
+
+impl FighthingBehavior {
+  pub fn get_next(&self) -> FighthingBehavior {
+    match self {
+      FighthingBehavior::Beligerent => FighthingBehavior::Pacifist,
+      FighthingBehavior::Pacifist => FighthingBehavior::HoldFire,
+      FighthingBehavior::HoldFire => FighthingBehavior::Beligerent,
+    }
+  }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Cannon {
+  pub range: f32,
+  // pub firing: bool,
+  pub cooling_down_counter: f64,
+  pub cool_down_timer_in_secs: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CombatStats {
+  /// Turns true when the troop leaves a combat or is hit, and false once `after_combat_counter`
+  /// reaches the after combat cool down, check `CombatStats::recover`
+  pub in_after_combat: bool,
+  /// Seconds since the troop left its last combat or was last hit
+  pub after_combat_counter: f64,
+  /// Share of a hp healed, but not given back yet
+  pub healing_progress: f64,
+  pub cannon: Cannon,
+  pub moving_while_fighting: bool,
+
+  pub hp: i32,
+  pub alive: bool,
+
+  pub fighting_behavior: FighthingBehavior,
+  pub opening_fire_on_troop: Option<TroopId>,
+  /// The last troop that hit it, the only one a `Pacifist` troop fires back at out of its own land
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub last_attacker: Option<TroopId>,
+}
+
+impl Default for CombatStats {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+impl CombatStats {
+  pub const BASE_HP: i32 = 100;
+  pub const COOL_DOWN_TIMER_IN_SECS: f64 = 2.;
+  pub const CANNON_RANGE: f32 = 0.4;
+
+  pub fn new() -> CombatStats {
+    Self::from_archetype(&Archetype::get_blank())
+  }
+
+  pub fn from_archetype(archetype: &Archetype) -> CombatStats {
+    CombatStats {
+      in_after_combat: false,
+      after_combat_counter: 0.,
+      healing_progress: 0.,
+      hp: archetype.hp,
+      alive: true,
+      fighting_behavior: FighthingBehavior::Beligerent,
+      cannon: Cannon {
+        // firing: false,
+        range: archetype.range,
+        cooling_down_counter: archetype.cool_down_in_secs,
+        cool_down_timer_in_secs: archetype.cool_down_in_secs,
+      },
+      opening_fire_on_troop: None,
+      last_attacker: None,
+      moving_while_fighting: false,
+    }
+  }
+
+  /// The troop picks its target again under the new stance
+  pub fn adopt_stance(&mut self, fighting_behavior: FighthingBehavior) {
+    self.fighting_behavior = fighting_behavior;
+    self.opening_fire_on_troop = None;
+  }
+
+  /// Whether the stance lets the troop fire at `enemy_troop_id`, `defending` if it's fighting over its own land
+  pub fn may_target(&self, enemy_troop_id: &TroopId, defending: bool) -> bool {
+    match self.fighting_behavior {
+      FighthingBehavior::Beligerent => true,
+      FighthingBehavior::Pacifist => defending || self.last_attacker.as_ref() == Some(enemy_troop_id),
+      FighthingBehavior::HoldFire => false,
+    }
+  }
+
+  /// Takes the stats of the archetype a troop switches to when it changes surface,
+  /// it keeps the share of hp it had, so boarding doesn't heal or hurt it
+  pub fn switch_archetype(&mut self, previous: &Archetype, archetype: &Archetype) {
+    if self.alive {
+      let hp_share = self.hp as f32 / previous.hp as f32;
+      self.hp = ((archetype.hp as f32 * hp_share).round() as i32).max(1);
+    }
+
+    self.cannon.range = archetype.range;
+    self.cannon.cool_down_timer_in_secs = archetype.cool_down_in_secs;
+    self.cannon.cooling_down_counter = self.cannon.cooling_down_counter.min(archetype.cool_down_in_secs);
+  }
+
+  pub fn reset_cannon_cool_down(&mut self) {
+    self.cannon.cooling_down_counter = self.cannon.cool_down_timer_in_secs;
+  }
+
+  /// Counts the cool down up and returns true when the cannon is ready to fire again
+  pub fn has_cool_down_finished(&mut self, delta: f64) -> bool {
+    self.cannon.cooling_down_counter += delta;
+    if self.cannon.cooling_down_counter >= self.cannon.cool_down_timer_in_secs {
+      self.cannon.cooling_down_counter = 0.;
+      return true;
+    }
+
+    false
+  }
+
+  /// That's to ensure an attacking troop would be able to fight back even if
+  /// it's just a lil bit further than the cannon range
+  /// We had situations when neighbor troops were combating while some others
+  /// were just watching, which wasn't natural
+  pub fn get_cannon_range_plus_buffer(&self) -> f32 {
+    self.cannon.range + (self.cannon.range / 8.)
+  }
+
+  /// Takes the damage and returns true if the hit was lethal
+  pub fn take_a_hit(&mut self, damage: i32, attacker: &TroopId) -> bool {
+    if !self.alive {
+      return false;
+    }
+
+    self.last_attacker = Some(attacker.clone());
+    self.start_after_combat();
+
+    self.hp -= damage;
+    if self.hp <= 0 {
+      self.die();
+      return true;
+    }
+
+    false
+  }
+
+  pub fn die(&mut self) {
+    self.alive = false;
+    self.hp = 0;
+    self.in_after_combat = true;
+  }
+
+  /// Stops targeting the troop and firing back at it, should be called when it dies
+  pub fn forget_troop(&mut self, troop_id: &TroopId) {
+    if self.opening_fire_on_troop.as_ref() == Some(troop_id) {
+      self.opening_fire_on_troop = None;
+    }
+
+    if self.last_attacker.as_ref() == Some(troop_id) {
+      self.last_attacker = None;
+    }
+  }
+
+  /// Should be called when there are no enemies left in the territory
+  pub fn leave_combat(&mut self) {
+    self.start_after_combat();
+    self.reset_cannon_cool_down();
+    self.opening_fire_on_troop = None;
+    self.last_attacker = None;
+  }
+}
+
+/// Combat types the troop can engage
+/// Needs to populate CombatTypes::iter() method
+#[derive(Debug, Hash, Eq, PartialEq, Clone, Serialize, Deserialize)]
+pub enum CombatTypes {
+  Attacking,
+  Defending,
+  FightingOverUnoccupiedTerritory,
+}
+
+impl CombatTypes {
+  pub fn iter() -> impl Iterator<Item = CombatTypes> {
+    [
+      Self::Attacking,
+      Self::Defending,
+      Self::FightingOverUnoccupiedTerritory,
+    ]
+    .iter()
+    .cloned()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::troops::troop::TroopId;
+
+  use super::{CombatStats, FighthingBehavior};
+
+  fn get_id(troop_id: &str) -> TroopId {
+    troop_id.to_owned()
+  }
+
+  fn get_stats(fighting_behavior: FighthingBehavior) -> CombatStats {
+    let mut combat_stats = CombatStats::new();
+    combat_stats.adopt_stance(fighting_behavior);
+    combat_stats
+  }
+
+  #[test]
+  fn stances_cycle_back_to_beligerent() {
+    let stance = FighthingBehavior::default();
+
+    assert_eq!(stance, FighthingBehavior::Beligerent);
+    assert_eq!(stance.get_next(), FighthingBehavior::Pacifist);
+    assert_eq!(stance.get_next().get_next(), FighthingBehavior::HoldFire);
+    assert_eq!(stance.get_next().get_next().get_next(), FighthingBehavior::Beligerent);
+  }
+
+  #[test]
+  fn adopting_a_stance_drops_the_target() {
+    let mut combat_stats = CombatStats::new();
+    combat_stats.opening_fire_on_troop = Some(get_id("enemy"));
+
+    combat_stats.adopt_stance(FighthingBehavior::Pacifist);
+
+    assert_eq!(combat_stats.fighting_behavior, FighthingBehavior::Pacifist);
+    assert_eq!(combat_stats.opening_fire_on_troop, None);
+  }
+
+  #[test]
+  fn beligerent_troops_fire_at_anyone() {
+    let combat_stats = get_stats(FighthingBehavior::Beligerent);
+
+    assert!(combat_stats.may_target(&get_id("enemy"), false));
+    assert!(combat_stats.may_target(&get_id("enemy"), true));
+  }
+
+  #[test]
+  fn pacifist_troops_only_defend_or_fire_back() {
+    let mut combat_stats = get_stats(FighthingBehavior::Pacifist);
+
+    assert!(!combat_stats.may_target(&get_id("enemy"), false));
+    assert!(combat_stats.may_target(&get_id("enemy"), true));
+
+    combat_stats.take_a_hit(1, &get_id("attacker"));
+
+    assert!(combat_stats.may_target(&get_id("attacker"), false));
+    assert!(!combat_stats.may_target(&get_id("enemy"), false));
+
+    combat_stats.forget_troop(&get_id("attacker"));
+
+    assert!(!combat_stats.may_target(&get_id("attacker"), false));
+  }
+
+  #[test]
+  fn hold_fire_troops_never_fire() {
+    let mut combat_stats = get_stats(FighthingBehavior::HoldFire);
+    combat_stats.take_a_hit(1, &get_id("attacker"));
+
+    assert!(!combat_stats.may_target(&get_id("attacker"), false));
+    assert!(!combat_stats.may_target(&get_id("attacker"), true));
+  }
+}
@@ -0,0 +1,221 @@
+use std::collections::BTreeSet;
+
+use crate::globe::coordinates::Planet;
+use crate::globe::trajectory::Trajectory;
+use crate::math::Vec3;
+use crate::players::player::PlayerId;
+use crate::territories::territory::Territory;
+use crate::troops::battlefield::{Battlefield, TroopSighting};
+use crate::troops::troop::{Troop, TroopId, TroopState};
+
+use super::combat_stats::{CombatTypes, FighthingBehavior};
+use super::projectile::Projectile;
+
+/// Returns the troops, arrived to the territory, of some player other than `player_id`
+/// TODO: check and solve: what's up when it has 3+ players
+pub fn find_enemy_troops_in_territory(territory: &Territory, player_id: PlayerId) -> Option<&BTreeSet<TroopId>> {
+  territory.all_troops_deployed_and_arrived_by_player
+    .iter()
+    // Players who already left the territory keep an empty set
+    .find(|(enemy_player_id, enemy_troops)| **enemy_player_id != player_id && !enemy_troops.is_empty())
+    .map(|(_, enemy_troops)| enemy_troops)
+}
+
+/// Picks the closest enemy troop from `self_position`
+///
+/// # Arguments
+/// * `enemy_positions` - The enemy troops and their positions, troops not found can just be left out
+/// * `max_distance` - If set, troops further than it are ignored
+pub fn find_closest_enemy_troop<'a>(
+  self_position: Vec3,
+  enemy_positions: impl Iterator<Item = (&'a TroopId, Vec3)>,
+  max_distance: Option<f32>,
+) -> Option<TroopId> {
+  enemy_positions
+    .fold(None, |current_closest: Option<(&TroopId, f32)>, (enemy_troop_id, enemy_position)| {
+      let new_comparable_distance = self_position.distance_to(enemy_position);
+
+      if max_distance.is_some_and(|max_distance| new_comparable_distance > max_distance) {
+        return current_closest;
+      }
+
+      match current_closest {
+        // Keep the previous closest if it's better
+        Some((_, current_min_distance))
+          if new_comparable_distance >= current_min_distance => current_closest,
+
+        // Otherwise, update the closest:
+        _ => Some((enemy_troop_id, new_comparable_distance)),
+      }
+    })
+    .map(|(enemy_troop_id, _closest_distance)| enemy_troop_id.clone())
+}
+
+impl Troop {
+  /// Attackers only go after the enemies around the cannon range, defenders after any of them in the territory.
+  /// Fighting over an unoccupied territory goes the way defending does, otherwise no one would ever take it
+  pub(crate) fn keep_fighting_if_combatting(&mut self, delta: f64, battlefield: &mut impl Battlefield) {
+    if !self.is_combatting() {
+      return;
+    }
+
+    // TODO: Combat:
+    // How it should be?
+    // 1. Attacker invades it
+    // 2. Defender approaches the Attacker within the combat radius
+    // 3. Defender open fire
+    // 4. Attacker returns fire
+
+    // Caveat:
+    // Should be aware a attacker can be ordered directly to attack a defender, (Right click on the defender)
+    // so the defender should be able to open fire
+    let attacking = self.troop_activities.contains(&TroopState::Combating(CombatTypes::Attacking));
+
+    let enemy_troop = match &self.combat_stats.opening_fire_on_troop {
+      Some(enemy_troop_id) => battlefield.get_troop(enemy_troop_id),
+      None => self.find_closest_enemy_troop_to_be_attacked(battlefield, attacking),
+    };
+
+    self.handle_combat(delta, battlefield, enemy_troop);
+  }
+
+  fn handle_combat(&mut self, delta: f64, battlefield: &mut impl Battlefield, enemy_troop: Option<TroopSighting>) {
+    let Some(enemy_troop) = enemy_troop else {
+      self.combat_stats.opening_fire_on_troop = None;
+      return;
+    };
+
+    // Being sure isn't targetting some troop no longer combatting on that territory
+    if enemy_troop.deployed_to_territory != self.deployed_to_territory {
+      self.combat_stats.opening_fire_on_troop = None;
+      return;
+    }
+
+    self.combat_stats.opening_fire_on_troop = Some(enemy_troop.troop_id.clone());
+
+    // Troop might be deployed while combatting, so it should not keep firing
+    // whenever it's being deployed to another territory
+    if self.troop_activities.contains(&TroopState::Deploying) {
+      return;
+    }
+
+    if self.position.distance_to(enemy_troop.position) > self.combat_stats.cannon.range {
+      self.set_trajectory_to_get_closer_to_enemy(enemy_troop.position, battlefield);
+
+    } else {
+      self.reset_trajectory();
+      self.moving_and_combating = false;
+
+      if self.has_cool_down_finished(delta) {
+        self.open_fire_on_the_enemy(&enemy_troop, battlefield);
+      }
+    }
+  }
+
+  /// Out of a territory fight, the troop fires at the closest enemy troop in range without stopping,
+  /// even while crossing other territories, as far as its stance lets it, check `CombatStats::may_target`
+  pub(crate) fn fire_at_enemies_in_range_if_needed(&mut self, delta: f64, battlefield: &mut impl Battlefield) {
+    if self.is_combatting() || self.combat_stats.fighting_behavior == FighthingBehavior::HoldFire {
+      return;
+    }
+
+    // It only looks around once per cool down, whether it fires or not
+    if !self.has_cool_down_finished(delta) {
+      return;
+    }
+
+    let enemy_troops: Vec<TroopSighting> = battlefield.get_other_troops()
+      .into_iter()
+      .filter(|troop| troop.owner != self.owner && self.combat_stats.may_target(&troop.troop_id, false))
+      .collect();
+
+    let Some(closest_enemy_troop_id) = find_closest_enemy_troop(
+      self.position,
+      enemy_troops.iter().map(|enemy_troop| (&enemy_troop.troop_id, enemy_troop.position)),
+      Some(self.combat_stats.cannon.range),
+    ) else {
+      return;
+    };
+
+    let Some(enemy_troop) = enemy_troops
+      .into_iter()
+      .find(|enemy_troop| enemy_troop.troop_id == closest_enemy_troop_id) else {
+      return;
+    };
+
+    self.open_fire_on_the_enemy(&enemy_troop, battlefield);
+  }
+
+  fn find_closest_enemy_troop_to_be_attacked(
+    &self,
+    battlefield: &impl Battlefield,
+    close_to_the_cannon_range: bool,
+  ) -> Option<TroopSighting> {
+    if !self.arrived_to_territory {
+      // won't look for enemy troops if it hasn't arrived to the territory
+      return None;
+    }
+
+    let territory = battlefield.get_territories().get(&self.deployed_to_territory)?;
+    let enemy_troops = find_enemy_troops_in_territory(territory, self.owner)?;
+    let defending = self.troop_activities.contains(&TroopState::Combating(CombatTypes::Defending));
+
+    // If the enemy troop isn't found, it's just left out
+    let enemy_troops: Vec<TroopSighting> = enemy_troops
+      .iter()
+      .filter(|enemy_troop_id| self.combat_stats.may_target(enemy_troop_id, defending))
+      .filter_map(|enemy_troop_id| battlefield.get_troop(enemy_troop_id))
+      .collect();
+
+    let max_distance = close_to_the_cannon_range
+      .then(|| self.combat_stats.get_cannon_range_plus_buffer());
+
+    let closest_enemy_troop_id = find_closest_enemy_troop(
+      self.position,
+      enemy_troops.iter().map(|enemy_troop| (&enemy_troop.troop_id, enemy_troop.position)),
+      max_distance,
+    )?;
+
+    enemy_troops
+      .into_iter()
+      .find(|enemy_troop| enemy_troop.troop_id == closest_enemy_troop_id)
+  }
+
+  fn set_trajectory_to_get_closer_to_enemy(&mut self, target_position: Vec3, battlefield: &impl Battlefield) {
+    if !self.moving_trajectory_points.is_empty() {
+      return;
+    }
+
+    self.moving_trajectory_points = Trajectory::get_in_the_frontiers_trajectory(
+      battlefield.get_coordinate_map(),
+      self.position,
+      target_position,
+      Planet::get_planet_radius() as f32,
+      &self.deployed_to_territory,
+    );
+    self.current_trajectory_point = 0;
+    self.moving_and_combating = true;
+  }
+
+  fn has_cool_down_finished(&mut self, delta: f64) -> bool {
+    self.combat_stats.has_cool_down_finished(self.location.modifiers.get_cool_down_delta(delta))
+  }
+
+  fn open_fire_on_the_enemy(&self, enemy_troop: &TroopSighting, battlefield: &mut impl Battlefield) {
+    let projectile = Projectile::new(
+      self.troop_id.clone(),
+      self.owner,
+      enemy_troop.troop_id.clone(),
+      self.location.modifiers.get_damage_dealt(self.archetype.damage),
+      self.position,
+      enemy_troop.position,
+    );
+    battlefield.fire(self, projectile);
+  }
+
+  /// Returns true if the hit was lethal, the bookkeeping of the dead troop is up to the caller
+  pub fn take_a_hit(&mut self, damage: i32, attacker: &TroopId) -> bool {
+    let damage_taken = self.archetype.get_damage_taken(damage, &self.location.modifiers);
+    self.combat_stats.take_a_hit(damage_taken, attacker)
+  }
+}
@@ -0,0 +1,254 @@
+use serde::{Deserialize, Serialize};
+
+use crate::players::player::PlayerId;
+use crate::territories::adjacency::AdjacencyGraph;
+use crate::territories::territory::{Territories, TerritoryId};
+
+use super::combat_stats::LocationSituation;
+
+/// Multipliers a troop gets from where it is, on top of its archetype stats
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LocationModifiers {
+  /// Multiplies the damage of every projectile it fires
+  pub damage: f32,
+  /// Divides the damage of every hit it takes
+  pub defence: f32,
+  /// Divides the cool down in between its shots
+  pub fire_rate: f32,
+  /// Multiplies both its damage and its defence
+  pub morale: f32,
+}
+
+/// Location modifiers of every situation, fields left out of a match setup don't change anything
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LocationRules {
+  pub self_land: LocationModifiers,
+  /// There are no alliances yet, so it's never picked and `MatchSetup::validate` rejects overriding it
+  pub ally_land: LocationModifiers,
+  pub neutral_land: LocationModifiers,
+  pub enemy_land: LocationModifiers,
+  /// As neutral as the land no one rules by default, no one rules the sea either
+  pub sea: LocationModifiers,
+  /// Multiplies the morale on enemy land not bordering any territory of the troop's player,
+  /// so pushing deep into the enemy gets harder
+  pub deep_invasion_morale: f32,
+}
+
+/// Where a troop is and what it gets from it, refreshed every tick along with its surface
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Location {
+  pub situation: LocationSituation,
+  /// On enemy land with no territory of its own player around, check `LocationRules::deep_invasion_morale`
+  pub deep_invasion: bool,
+  pub modifiers: LocationModifiers,
+}
+
+impl Default for LocationModifiers {
+  fn default() -> Self {
+    LocationModifiers { damage: 1., defence: 1., fire_rate: 1., morale: 1. }
+  }
+}
+
+impl LocationModifiers {
+  fn is_valid(&self) -> bool {
+    self.damage > 0. && self.defence > 0. && self.fire_rate > 0. && self.morale > 0.
+  }
+
+  /// Damage of a projectile fired with `base_damage`
+  pub fn get_damage_dealt(&self, base_damage: i32) -> i32 {
+    (base_damage as f32 * self.damage * self.morale).round() as i32
+  }
+
+  /// What's left of a hit once the defence and the morale take their share
+  pub fn get_damage_taken(&self, damage: f32) -> f32 {
+    damage / (self.defence * self.morale)
+  }
+
+  /// Seconds of cool down counted in `delta`
+  pub fn get_cool_down_delta(&self, delta: f64) -> f64 {
+    delta * self.fire_rate as f64
+  }
+}
+
+impl Default for LocationRules {
+  fn default() -> Self {
+    LocationRules {
+      self_land: LocationModifiers { damage: 1., defence: 1.25, fire_rate: 1.1, morale: 1.1 },
+      ally_land: LocationModifiers { damage: 1., defence: 1.1, fire_rate: 1., morale: 1.05 },
+      neutral_land: LocationModifiers::default(),
+      enemy_land: LocationModifiers { damage: 1., defence: 0.9, fire_rate: 0.95, morale: 0.95 },
+      sea: LocationModifiers::default(),
+      deep_invasion_morale: 0.85,
+    }
+  }
+}
+
+impl LocationRules {
+  pub fn is_valid(&self) -> bool {
+    [&self.self_land, &self.ally_land, &self.neutral_land, &self.enemy_land, &self.sea]
+      .iter()
+      .all(|modifiers| modifiers.is_valid()) &&
+      self.deep_invasion_morale > 0.
+  }
+
+  /// Where a troop of `player_id` touching `territory_id` is, None being the sea
+  pub fn get_location(
+    &self,
+    territory_id: Option<&TerritoryId>,
+    player_id: PlayerId,
+    territories: &Territories,
+    adjacency: &AdjacencyGraph,
+  ) -> Location {
+    let situation = LocationSituation::get(territory_id, player_id, territories);
+
+    let deep_invasion = situation == LocationSituation::EnemyLand && territory_id.is_some_and(|territory_id| {
+      !adjacency
+        .get_neighbors(territory_id)
+        .filter_map(|(neighbor_id, _)| territories.get(neighbor_id))
+        .any(|neighbor| neighbor.current_ruler == Some(player_id))
+    });
+
+    let mut modifiers = match situation {
+      LocationSituation::SelfLand => self.self_land,
+      LocationSituation::AllyLand => self.ally_land,
+      LocationSituation::NeutralLand => self.neutral_land,
+      LocationSituation::EnemyLand => self.enemy_land,
+      LocationSituation::Sea => self.sea,
+    };
+    if deep_invasion {
+      modifiers.morale *= self.deep_invasion_morale;
+    }
+
+    Location { situation, deep_invasion, modifiers }
+  }
+}
+
+impl LocationSituation {
+  /// None being the sea, the territories no one rules yet are neutral
+  pub fn get(territory_id: Option<&TerritoryId>, player_id: PlayerId, territories: &Territories) -> LocationSituation {
+    let Some(territory_id) = territory_id else {
+      return LocationSituation::Sea;
+    };
+    let ruler = territories
+      .get(territory_id)
+      .and_then(|territory| territory.current_ruler);
+
+    match ruler {
+      None => LocationSituation::NeutralLand,
+      Some(ruler_id) if ruler_id == player_id => LocationSituation::SelfLand,
+      // TODO: there are no alliances yet, so every other player is an enemy and `AllyLand` is never picked
+      Some(_) => LocationSituation::EnemyLand,
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::globe::coordinates::Planet;
+  use crate::players::player::PlayerState;
+  use crate::territories::adjacency::AdjacencyGraph;
+  use crate::territories::territory::{Continent, Territories, Territory, TerritoryId};
+  use crate::troops::combat::combat_stats::LocationSituation;
+
+  use super::{LocationModifiers, LocationRules};
+
+  /// `home` and `border` touch each other, `far_away` is on the other side of the planet
+  fn get_adjacency() -> AdjacencyGraph {
+    let mut coordinate_map = Planet::get_blank_coordinate_map();
+    Planet::add_territory(&mut coordinate_map, "home", 100..110, 0..10);
+    Planet::add_territory(&mut coordinate_map, "border", 100..110, 10..20);
+    Planet::add_territory(&mut coordinate_map, "far_away", 100..110, 200..210);
+    Planet::add_territory(&mut coordinate_map, "unclaimed", 150..160, 100..110);
+    AdjacencyGraph::from_coordinate_map(&coordinate_map, AdjacencyGraph::MAX_SEA_CONNECTION_DISTANCE)
+  }
+
+  /// Player 1 rules `home`, player 2 rules `border` and `far_away`, no one rules `unclaimed`
+  fn get_territories() -> Territories {
+    let mut territories: Territories = ["home", "border", "far_away", "unclaimed"]
+      .into_iter()
+      .map(|territory_id| (territory_id.to_owned(), Territory::get_base_territory(territory_id, Continent::Europe, None)))
+      .collect();
+
+    let mut player = PlayerState::new(1);
+    let mut enemy = PlayerState::new(2);
+    territories.get_mut("home").expect("Expected territory to exist").set_new_ruler(&mut player);
+    for territory_id in ["border", "far_away"] {
+      territories.get_mut(territory_id).expect("Expected territory to exist").set_new_ruler(&mut enemy);
+    }
+
+    territories
+  }
+
+  fn get_id(territory_id: &str) -> TerritoryId {
+    territory_id.to_owned()
+  }
+
+  #[test]
+  fn situation_depends_on_who_rules_the_land() {
+    let territories = get_territories();
+
+    assert_eq!(LocationSituation::get(Some(&get_id("home")), 1, &territories), LocationSituation::SelfLand);
+    assert_eq!(LocationSituation::get(Some(&get_id("border")), 1, &territories), LocationSituation::EnemyLand);
+    assert_eq!(LocationSituation::get(Some(&get_id("border")), 2, &territories), LocationSituation::SelfLand);
+    assert_eq!(LocationSituation::get(Some(&get_id("unclaimed")), 1, &territories), LocationSituation::NeutralLand);
+    assert_eq!(LocationSituation::get(None, 1, &territories), LocationSituation::Sea);
+  }
+
+  #[test]
+  fn troops_get_the_modifiers_of_their_situation() {
+    let rules = LocationRules::default();
+    let territories = get_territories();
+    let adjacency = get_adjacency();
+
+    let home = rules.get_location(Some(&get_id("home")), 1, &territories, &adjacency);
+    assert_eq!(home.modifiers, rules.self_land);
+    assert!(!home.deep_invasion);
+
+    let sea = rules.get_location(None, 1, &territories, &adjacency);
+    assert_eq!(sea.situation, LocationSituation::Sea);
+    assert_eq!(sea.modifiers, rules.sea);
+  }
+
+  #[test]
+  fn enemy_land_away_from_the_troop_player_is_a_deep_invasion() {
+    let rules = LocationRules::default();
+    let territories = get_territories();
+    let adjacency = get_adjacency();
+
+    let border = rules.get_location(Some(&get_id("border")), 1, &territories, &adjacency);
+    assert_eq!(border.situation, LocationSituation::EnemyLand);
+    assert!(!border.deep_invasion);
+    assert_eq!(border.modifiers, rules.enemy_land);
+
+    let far_away = rules.get_location(Some(&get_id("far_away")), 1, &territories, &adjacency);
+    assert!(far_away.deep_invasion);
+    assert_eq!(far_away.modifiers.morale, rules.enemy_land.morale * rules.deep_invasion_morale);
+    assert_eq!(far_away.modifiers.defence, rules.enemy_land.defence);
+
+    let unclaimed = rules.get_location(Some(&get_id("unclaimed")), 1, &territories, &adjacency);
+    assert!(!unclaimed.deep_invasion);
+  }
+
+  #[test]
+  fn modifiers_scale_damage_and_fire_rate() {
+    let modifiers = LocationModifiers { damage: 1.5, defence: 2., fire_rate: 0.5, morale: 1. };
+
+    assert_eq!(modifiers.get_damage_dealt(10), 15);
+    assert_eq!(modifiers.get_damage_taken(10.), 5.);
+    assert_eq!(modifiers.get_cool_down_delta(1.), 0.5);
+  }
+
+  #[test]
+  fn rules_need_positive_modifiers() {
+    assert!(LocationRules::default().is_valid());
+
+    let mut rules = LocationRules::default();
+    rules.enemy_land.fire_rate = 0.;
+    assert!(!rules.is_valid());
+
+    let rules = LocationRules { deep_invasion_morale: -1., ..LocationRules::default() };
+    assert!(!rules.is_valid());
+  }
+}
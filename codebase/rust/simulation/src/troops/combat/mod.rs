@@ -0,0 +1,6 @@
+pub mod combat_stats;
+pub mod combat_engager;
+pub mod combatting;
+pub mod projectile;
+pub mod location;
+pub mod recovery;
\ No newline at end of file
@@ -0,0 +1,135 @@
+use serde::{Deserialize, Serialize};
+
+use crate::math::Vec3;
+use crate::players::player::PlayerId;
+use crate::troops::troop::TroopId;
+
+use super::combatting::find_closest_enemy_troop;
+
+pub enum TypesOfTarget {
+  Troop,
+}
+
+/// A cannon ball on its way to the targeted troop
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Projectile {
+  pub fired_by: TroopId,
+  /// Player of `fired_by`, who's credited with the kill even if the troop died meanwhile
+  pub fired_by_player: PlayerId,
+  pub target: Option<TroopId>,
+  pub damage: i32,
+
+  pub position: Vec3,
+  /// Where the target was when the projectile was fired, it doesn't home in, check `Projectile::retarget`
+  pub up_to_date_target_position: Vec3,
+}
+
+impl Projectile {
+  pub const DEFAULT_DAMAGE: i32 = 11;
+  pub const PROJECTILE_SPEED: f32 = 0.95;
+
+  /// Distance to the target where the projectile is considered to hit it
+  pub const HIT_DISTANCE: f32 = 0.1;
+
+  /// How far from where its target died a projectile looks for another one, check `Projectile::find_new_target`
+  pub const RETARGET_RANGE: f32 = 0.2;
+
+  pub fn new(
+    fired_by: TroopId,
+    fired_by_player: PlayerId,
+    target: TroopId,
+    damage: i32,
+    position: Vec3,
+    target_position: Vec3,
+  ) -> Projectile {
+    Projectile {
+      fired_by,
+      fired_by_player,
+      target: Some(target),
+      damage,
+      position,
+      up_to_date_target_position: target_position,
+    }
+  }
+
+  /// Once its target dies, a projectile goes after the closest of `candidates` around where the target died,
+  /// meant to be the other troops of the same player. None if there's no one around, the projectile just fizzles
+  pub fn find_new_target<'a>(dead_target_position: Vec3, candidates: impl Iterator<Item = (&'a TroopId, Vec3)>) -> Option<TroopId> {
+    find_closest_enemy_troop(dead_target_position, candidates, Some(Self::RETARGET_RANGE))
+  }
+
+  /// Goes after another troop once its target died, heading to where that one is now
+  pub fn retarget(&mut self, new_target: TroopId, new_target_position: Vec3) {
+    self.target = Some(new_target);
+    self.up_to_date_target_position = new_target_position;
+  }
+
+  /// Moves the projectile towards where its target was, returns true when it gets there and hits the target
+  pub fn move_towards_target(&mut self, delta: f64) -> bool {
+    let to_target = self.up_to_date_target_position - self.position;
+    let step = Self::PROJECTILE_SPEED * delta as f32;
+
+    if to_target.length() <= step.max(Self::HIT_DISTANCE) {
+      self.position = self.up_to_date_target_position;
+      return true;
+    }
+
+    self.position += to_target.normalized() * step;
+    false
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::math::Vec3;
+  use crate::troops::troop::TroopId;
+
+  use super::Projectile;
+
+  fn get_candidates() -> Vec<(TroopId, Vec3)> {
+    vec![
+      (TroopId::from("far"), Vec3::new(Projectile::RETARGET_RANGE * 0.9, 0., 0.)),
+      (TroopId::from("close"), Vec3::new(0., Projectile::RETARGET_RANGE * 0.5, 0.)),
+      (TroopId::from("out_of_range"), Vec3::new(0., 0., Projectile::RETARGET_RANGE * 1.5)),
+    ]
+  }
+
+  fn find_new_target(candidates: &[(TroopId, Vec3)]) -> Option<TroopId> {
+    Projectile::find_new_target(Vec3::new(0., 0., 0.), candidates.iter().map(|(troop_id, position)| (troop_id, *position)))
+  }
+
+  #[test]
+  fn projectiles_go_after_the_closest_troop_around_their_dead_target() {
+    assert_eq!(find_new_target(&get_candidates()), Some(TroopId::from("close")));
+  }
+
+  #[test]
+  fn projectiles_fizzle_without_anyone_in_range() {
+    let mut candidates = get_candidates();
+    candidates.retain(|(troop_id, _)| troop_id == "out_of_range");
+
+    assert_eq!(find_new_target(&candidates), None);
+    assert_eq!(find_new_target(&[]), None);
+  }
+
+  #[test]
+  fn retargeted_projectiles_head_to_the_new_target() {
+    let mut projectile = Projectile::new(
+      TroopId::from("shooter"),
+      1,
+      TroopId::from("dead"),
+      Projectile::DEFAULT_DAMAGE,
+      Vec3::new(0., 0., 0.),
+      Vec3::new(1., 0., 0.),
+    );
+
+    projectile.retarget(TroopId::from("close"), Vec3::new(0., 1., 0.));
+
+    assert_eq!(projectile.target, Some(TroopId::from("close")));
+    assert_eq!(projectile.up_to_date_target_position, Vec3::new(0., 1., 0.));
+    assert!(!projectile.move_towards_target(0.5));
+    assert!(projectile.position.y > 0.);
+    assert!(projectile.move_towards_target(1.));
+    assert_eq!(projectile.position, Vec3::new(0., 1., 0.));
+  }
+}
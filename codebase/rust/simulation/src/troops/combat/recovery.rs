@@ -0,0 +1,174 @@
+use crate::territories::territory::Territory;
+use crate::troops::battlefield::Battlefield;
+use crate::troops::troop::Troop;
+
+use super::combat_stats::CombatStats;
+
+impl CombatStats {
+  /// Seconds a troop has to stay out of combat, without being hit, before it starts healing,
+  /// a territory doesn't spawn troops for as long after a combat on it
+  pub const AFTER_COMBAT_COOL_DOWN_IN_SECS: f64 = 5.;
+
+  /// Starts the after combat cool down over, check `AFTER_COMBAT_COOL_DOWN_IN_SECS`
+  pub fn start_after_combat(&mut self) {
+    self.in_after_combat = true;
+    self.after_combat_counter = 0.;
+    self.healing_progress = 0.;
+  }
+
+  /// Counts the after combat cool down up and, once it's over, heals the troop up to `max_hp`
+  /// at the pace of `resting_at`, the territory of its own player it sits at,
+  /// None while it's fighting, on its way somewhere else or out of its player's land
+  pub fn recover(&mut self, delta: f64, after_combat_cool_down_in_secs: f64, resting_at: Option<&Territory>, max_hp: i32) {
+    if !self.alive {
+      return;
+    }
+
+    if self.in_after_combat {
+      self.after_combat_counter += delta;
+      if self.after_combat_counter < after_combat_cool_down_in_secs {
+        return;
+      }
+
+      self.in_after_combat = false;
+      self.after_combat_counter = 0.;
+    }
+
+    let Some(territory) = resting_at else {
+      self.healing_progress = 0.;
+      return;
+    };
+
+    if self.hp >= max_hp {
+      self.healing_progress = 0.;
+      return;
+    }
+
+    // Hp only comes back whole, what's left over is kept for the next tick
+    self.healing_progress += territory.hp_healed_per_second * delta;
+    let healed = self.healing_progress.floor();
+    self.healing_progress -= healed;
+    self.hp = (self.hp + healed as i32).min(max_hp);
+  }
+}
+
+impl Troop {
+  /// Heals the troop while it sits, out of combat, on a territory of its own player
+  pub(crate) fn recover_if_resting(&mut self, delta: f64, battlefield: &impl Battlefield) {
+    let resting_at = battlefield.get_territories()
+      .get(&self.deployed_to_territory)
+      .filter(|territory| territory.current_ruler == Some(self.owner))
+      .filter(|_| self.arrived_to_territory && !self.is_combatting());
+
+    let after_combat_cool_down_in_secs = battlefield.get_rules().after_combat_cool_down_in_secs;
+    self.combat_stats.recover(delta, after_combat_cool_down_in_secs, resting_at, self.archetype.hp);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::territories::territory::{Continent, Territory};
+  use crate::troops::combat::combat_stats::CombatStats;
+  use crate::troops::troop::TroopId;
+
+  const COOL_DOWN_IN_SECS: f64 = 5.;
+  const MAX_HP: i32 = 100;
+
+  fn get_territory(hp_healed_per_second: f64) -> Territory {
+    let mut territory = Territory::get_base_territory("home", Continent::Europe, None);
+    territory.hp_healed_per_second = hp_healed_per_second;
+    territory
+  }
+
+  /// A troop down to `hp` that was just hit
+  fn get_hit_stats(hp: i32) -> CombatStats {
+    let mut combat_stats = CombatStats::new();
+    combat_stats.take_a_hit(MAX_HP - hp, &TroopId::from("enemy"));
+    combat_stats
+  }
+
+  /// Finishes the cool down away from any territory, so the troop doesn't heal meanwhile
+  fn finish_cool_down(combat_stats: &mut CombatStats) {
+    combat_stats.recover(COOL_DOWN_IN_SECS, COOL_DOWN_IN_SECS, None, MAX_HP);
+  }
+
+  #[test]
+  fn troops_only_heal_once_the_after_combat_cool_down_is_over() {
+    let territory = get_territory(10.);
+    let mut combat_stats = get_hit_stats(50);
+
+    combat_stats.recover(COOL_DOWN_IN_SECS - 1., COOL_DOWN_IN_SECS, Some(&territory), MAX_HP);
+
+    assert!(combat_stats.in_after_combat);
+    assert_eq!(combat_stats.hp, 50);
+
+    combat_stats.recover(1., COOL_DOWN_IN_SECS, Some(&territory), MAX_HP);
+    combat_stats.recover(1., COOL_DOWN_IN_SECS, Some(&territory), MAX_HP);
+
+    assert!(!combat_stats.in_after_combat);
+    assert_eq!(combat_stats.hp, 70);
+  }
+
+  #[test]
+  fn being_hit_starts_the_cool_down_over() {
+    let territory = get_territory(10.);
+    let mut combat_stats = get_hit_stats(50);
+
+    combat_stats.recover(COOL_DOWN_IN_SECS - 1., COOL_DOWN_IN_SECS, Some(&territory), MAX_HP);
+    combat_stats.take_a_hit(10, &TroopId::from("enemy"));
+    combat_stats.recover(1., COOL_DOWN_IN_SECS, Some(&territory), MAX_HP);
+
+    assert!(combat_stats.in_after_combat);
+    assert_eq!(combat_stats.hp, 40);
+  }
+
+  #[test]
+  fn partial_hp_is_kept_for_the_next_tick() {
+    let territory = get_territory(1.5);
+    let mut combat_stats = get_hit_stats(50);
+    finish_cool_down(&mut combat_stats);
+
+    combat_stats.recover(0.5, COOL_DOWN_IN_SECS, Some(&territory), MAX_HP);
+    assert_eq!(combat_stats.hp, 50);
+
+    combat_stats.recover(0.5, COOL_DOWN_IN_SECS, Some(&territory), MAX_HP);
+    assert_eq!(combat_stats.hp, 51);
+    assert_eq!(combat_stats.healing_progress, 0.5);
+  }
+
+  #[test]
+  fn healing_stops_at_max_hp() {
+    let territory = get_territory(100.);
+    let mut combat_stats = get_hit_stats(90);
+    finish_cool_down(&mut combat_stats);
+
+    combat_stats.recover(1., COOL_DOWN_IN_SECS, Some(&territory), MAX_HP);
+
+    assert_eq!(combat_stats.hp, MAX_HP);
+  }
+
+  #[test]
+  fn troops_away_from_their_land_do_not_heal() {
+    let territory = get_territory(1.5);
+    let mut combat_stats = get_hit_stats(50);
+    finish_cool_down(&mut combat_stats);
+    combat_stats.recover(0.5, COOL_DOWN_IN_SECS, Some(&territory), MAX_HP);
+
+    combat_stats.recover(10., COOL_DOWN_IN_SECS, None, MAX_HP);
+
+    assert_eq!(combat_stats.hp, 50);
+    assert_eq!(combat_stats.healing_progress, 0.);
+  }
+
+  #[test]
+  fn dead_troops_do_not_heal() {
+    let territory = get_territory(100.);
+    let mut combat_stats = get_hit_stats(50);
+    combat_stats.die();
+
+    combat_stats.recover(COOL_DOWN_IN_SECS * 2., COOL_DOWN_IN_SECS, Some(&territory), MAX_HP);
+
+    assert!(!combat_stats.alive);
+    assert_eq!(combat_stats.hp, 0);
+  }
+}
@@ -0,0 +1,43 @@
+use crate::events::game_event::GameEvent;
+use crate::territories::occupation::ArrivalOutcome;
+
+use super::battlefield::Battlefield;
+use super::troop::Troop;
+
+impl Troop {
+  /// Can trigger Combat or Colonization/Occupation/War,
+  /// the troop arrives as soon as it touches the territory it was deployed to, not its destination
+  pub(crate) fn get_deployment_next_action(&mut self, battlefield: &mut impl Battlefield) {
+    if !self.waiting_for_deployment_following_action {
+      return;
+    }
+
+    // Some if troop hit a land
+    let Some(touching_territory_id) = &self.touching_territory_id else {
+      return;
+    };
+
+    if *touching_territory_id != self.deployed_to_territory {
+      return;
+    }
+
+    self.waiting_for_deployment_following_action = false;
+    self.arrived_to_territory = true;
+
+    let arrival_outcome = battlefield.get_territories_mut()
+      .get_mut(&self.deployed_to_territory)
+      .expect("Expected deployed territory to exist")
+      .inform_deployment_arrival(&self.troop_id, self.owner);
+
+    match arrival_outcome {
+      ArrivalOutcome::OccupationStarted => battlefield.emit(GameEvent::OccupationStarted {
+        territory_id: self.deployed_to_territory.clone(),
+        player_id: self.owner,
+      }),
+      ArrivalOutcome::Unexpected => {
+        eprintln!("Troop {} has no idea what to do after the deployment! ::: {}", self.troop_id, self.deployed_to_territory);
+      },
+      _ => (),
+    }
+  }
+}
@@ -0,0 +1,401 @@
+use std::collections::BTreeSet;
+use std::f32::consts::PI;
+
+use serde::{Deserialize, Serialize};
+
+use crate::globe::coordinates::{CoordinateMap, Coordinates, Planet};
+use crate::math::Vec3;
+use crate::players::player::PlayerId;
+use crate::territories::territory::{Territory, TerritoryId};
+
+use super::route::{RouteOption, RoutePlanner};
+
+/// How a group of troops sent together spreads around where they were sent
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Formation {
+  /// Rings around the destination
+  #[default]
+  Blob,
+  /// Side by side, across the way they're heading
+  Line,
+  /// An arrow pointing the way they're heading
+  Wedge,
+}
+
+/// A troop of the group sent together, check `Formation::get_group_orders`
+#[derive(Debug, Clone, PartialEq)]
+pub struct FormationTroop {
+  /// Where it sets off from
+  pub origin: Vec3,
+  pub origin_territory_id: TerritoryId,
+  /// How fast it goes when deployed, check `SpeedType::FightOrFlight`
+  pub speed: f32,
+}
+
+/// Where a troop of the group goes and how fast, check `Formation::get_group_orders`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FormationSlot {
+  pub destination: Vec3,
+  /// Check `DeployOrder::pace`
+  pub pace: Option<f32>,
+}
+
+impl Formation {
+  /// Distance between two troops next to each other
+  pub const SPACING: f32 = 0.07;
+
+  /// The slowest a troop of the group goes, so the ones close to the destination don't crawl there
+  pub const MIN_PACE: f32 = 0.25;
+
+  pub fn all() -> [Formation; 3] {
+    [Formation::Blob, Formation::Line, Formation::Wedge]
+  }
+
+  /// The formation after this one, wrapping around, so the player can cycle through them
+  pub fn get_next(&self) -> Formation {
+    let formations = Self::all();
+    let index = formations.iter().position(|formation| formation == self).unwrap_or(0);
+    formations[(index + 1) % formations.len()]
+  }
+
+  /// Spreads the troops around `center` and returns where every one of them goes, in the same order.
+  /// The formation faces the way the group is heading, and every slot falling off the territory
+  /// is moved to its closest free coordinate. The troop taking the longest to get to its slot goes at full speed
+  /// and the others slow down, so the whole group arrives together
+  pub fn get_group_orders(
+    &self,
+    route_planner: &RoutePlanner,
+    route_option: RouteOption,
+    player_id: PlayerId,
+    territory: &Territory,
+    troops: &[FormationTroop],
+    center: Vec3,
+  ) -> Vec<FormationSlot> {
+    if troops.len() < 2 {
+      return troops
+        .iter()
+        .map(|_| FormationSlot { destination: center, pace: None })
+        .collect();
+    }
+
+    let origins: Vec<Vec3> = troops.iter().map(|troop| troop.origin).collect();
+    let slots = self.get_slots(route_planner.coordinate_map, territory, &origins, center);
+    let destinations = Self::assign_slots(&origins, &slots);
+
+    let travel_times: Vec<f32> = troops
+      .iter()
+      .zip(&destinations)
+      .map(|(troop, destination)| {
+        let distance = Self::get_travel_distance(route_planner, route_option, player_id, troop, territory, *destination);
+        distance / troop.speed
+      })
+      .collect();
+    let longest_travel_time = travel_times.iter().copied().fold(0., f32::max);
+
+    destinations
+      .into_iter()
+      .zip(travel_times)
+      .map(|(destination, travel_time)| {
+        let pace = if longest_travel_time > 0. { (travel_time / longest_travel_time).max(Self::MIN_PACE) } else { 1. };
+        FormationSlot { destination, pace: (pace < 1.).then_some(pace) }
+      })
+      .collect()
+  }
+
+  /// How far the troop goes to get to its slot, along the great circle or along the route it's going to take
+  fn get_travel_distance(
+    route_planner: &RoutePlanner,
+    route_option: RouteOption,
+    player_id: PlayerId,
+    troop: &FormationTroop,
+    territory: &Territory,
+    destination: Vec3,
+  ) -> f32 {
+    if route_option.is_shortest() {
+      return troop.origin.angle_to(destination) * troop.origin.length();
+    }
+
+    let route = route_planner.plan_or_shortest(
+      route_option,
+      player_id,
+      troop.origin,
+      &troop.origin_territory_id,
+      destination,
+      &territory.territory_id,
+    );
+    route.get_length()
+  }
+
+  fn get_slots(&self, coordinate_map: &CoordinateMap, territory: &Territory, origins: &[Vec3], center: Vec3) -> Vec<Vec3> {
+    let up = center.normalized();
+    let centroid = origins.iter().fold(Vec3::ZERO, |sum, origin| sum + *origin) / origins.len() as f32;
+    // Pointing from where the group comes from to the center, flat on the globe surface
+    let forward = (center - centroid)
+      .try_normalized()
+      .map(|heading| heading - up * heading.dot(up))
+      .and_then(Vec3::try_normalized)
+      .unwrap_or_else(|| up.cross(Vec3::new(0., 0., 1.)).try_normalized().unwrap_or(Vec3::new(1., 0., 0.)));
+    let right = forward.cross(up);
+
+    let slots: Vec<(Vec3, Option<Coordinates>)> = self.get_slot_offsets(origins.len())
+      .into_iter()
+      .map(|(sideways, ahead)| {
+        let slot = (center + right * sideways * Self::SPACING + forward * ahead * Self::SPACING).normalized() * center.length();
+        (slot, Self::get_coordinates_inside_the_territory(coordinate_map, territory, slot))
+      })
+      .collect();
+
+    // Slots already inside are reserved first, so the ones moved in don't land on top of them
+    let mut taken_coordinates: BTreeSet<Coordinates> = slots
+      .iter()
+      .filter_map(|(_, inside_coordinates)| *inside_coordinates)
+      .collect();
+
+    slots
+      .into_iter()
+      .map(|(slot, inside_coordinates)| match inside_coordinates {
+        Some(_) => slot,
+        None => Self::move_inside_the_territory(coordinate_map, territory, slot, &mut taken_coordinates),
+      })
+      .collect()
+  }
+
+  /// Offsets of every slot from the center, as (sideways, ahead) multiples of `SPACING`
+  fn get_slot_offsets(&self, num_of_troops: usize) -> Vec<(f32, f32)> {
+    match self {
+      Formation::Line => (0..num_of_troops)
+        .map(|index| (index as f32 - (num_of_troops - 1) as f32 / 2., 0.))
+        .collect(),
+      Formation::Wedge => (0..num_of_troops)
+        .map(|index| {
+          // Every row behind the tip has a troop on each side
+          let row = index.div_ceil(2) as f32;
+          let side = if index % 2 == 0 { 1. } else { -1. };
+          (row * side, -row)
+        })
+        .collect(),
+      Formation::Blob => {
+        let mut offsets = vec![(0., 0.)];
+        let mut ring = 1;
+        while offsets.len() < num_of_troops {
+          // Every ring fits 6 more troops than the one inside it
+          let slots_in_the_ring = 6 * ring;
+          for slot in 0..slots_in_the_ring {
+            let angle = 2. * PI * slot as f32 / slots_in_the_ring as f32;
+            offsets.push((ring as f32 * angle.cos(), ring as f32 * angle.sin()));
+          }
+          ring += 1;
+        }
+        offsets.truncate(num_of_troops);
+        offsets
+      },
+    }
+  }
+
+  /// The coordinate `slot` is on, if it's on the territory
+  fn get_coordinates_inside_the_territory(coordinate_map: &CoordinateMap, territory: &Territory, slot: Vec3) -> Option<Coordinates> {
+    Planet::get_coordinate_metadata_from_cartesian(coordinate_map, slot)
+      .filter(|(_, coordinate_metadata)| coordinate_metadata.territory_id.as_ref() == Some(&territory.territory_id))
+      .map(|(coordinates, _)| coordinates)
+  }
+
+  /// Moves a slot falling off the territory to the closest coordinate of the territory no other slot is on,
+  /// so they don't pile up on its border
+  fn move_inside_the_territory(
+    coordinate_map: &CoordinateMap,
+    territory: &Territory,
+    slot: Vec3,
+    taken_coordinates: &mut BTreeSet<Coordinates>,
+  ) -> Vec3 {
+    let closest_coordinate = territory.coordinates
+      .iter()
+      .filter(|coordinates| !taken_coordinates.contains(*coordinates))
+      .filter_map(|coordinates| coordinate_map.get(coordinates).map(|coordinate_metadata| (coordinates, coordinate_metadata.cartesian)))
+      .min_by(|(_, a), (_, b)| a.distance_to(slot).total_cmp(&b.distance_to(slot)));
+
+    match closest_coordinate {
+      Some((coordinates, cartesian)) => {
+        taken_coordinates.insert(*coordinates);
+        cartesian
+      },
+      None => slot,
+    }
+  }
+
+  /// Hands the slots out one by one, each to the closest troop without one yet,
+  /// returns the slot of every troop in the same order as `origins`
+  fn assign_slots(origins: &[Vec3], slots: &[Vec3]) -> Vec<Vec3> {
+    let mut destinations: Vec<Option<Vec3>> = vec![None; origins.len()];
+
+    for slot in slots {
+      let closest_troop = origins
+        .iter()
+        .enumerate()
+        .filter(|(index, _)| destinations[*index].is_none())
+        .min_by(|(_, a), (_, b)| a.distance_to(*slot).total_cmp(&b.distance_to(*slot)))
+        .map(|(index, _)| index);
+
+      if let Some(index) = closest_troop {
+        destinations[index] = Some(*slot);
+      }
+    }
+
+    destinations
+      .into_iter()
+      .map(|destination| destination.expect("Expected every troop to have a slot"))
+      .collect()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::collections::BTreeSet;
+  use std::ops::Range;
+
+  use crate::globe::coordinates::{CoordinateMap, Coordinates, Planet};
+  use crate::math::Vec3;
+  use crate::rules::Rules;
+  use crate::territories::adjacency::AdjacencyGraph;
+  use crate::territories::territory::{Continent, Territories, Territory};
+  use crate::troops::route::{RouteOption, RoutePlanner};
+
+  use super::{Formation, FormationSlot, FormationTroop};
+
+  struct Map {
+    coordinate_map: CoordinateMap,
+    territories: Territories,
+    adjacency: AdjacencyGraph,
+    rules: Rules,
+  }
+
+  impl Map {
+    fn new(latitudes: Range<i16>, longitudes: Range<i16>) -> Map {
+      let mut coordinate_map = Planet::get_blank_coordinate_map();
+      let mut territory = Territory::get_base_territory("target", Continent::Europe, None);
+
+      territory.coordinates = Planet::add_territory(&mut coordinate_map, "target", latitudes, longitudes);
+
+      Map {
+        coordinate_map,
+        territories: Territories::from([("target".to_owned(), territory)]),
+        adjacency: AdjacencyGraph::default(),
+        rules: Rules::default(),
+      }
+    }
+
+    fn get_group_orders(&self, formation: Formation, troops: &[FormationTroop], center: Coordinates) -> Vec<FormationSlot> {
+      let route_planner = RoutePlanner {
+        coordinate_map: &self.coordinate_map,
+        territories: &self.territories,
+        adjacency: &self.adjacency,
+        rules: &self.rules,
+      };
+
+      formation.get_group_orders(
+        &route_planner,
+        RouteOption::Shortest,
+        1,
+        &self.territories["target"],
+        troops,
+        Planet::get_cartesian_from_coordinates(center),
+      )
+    }
+
+    fn get_coordinates_inside(&self, slot: &FormationSlot) -> Option<Coordinates> {
+      Planet::get_coordinate_metadata_from_cartesian(&self.coordinate_map, slot.destination)
+        .filter(|(_, coordinate_metadata)| coordinate_metadata.territory_id.as_deref() == Some("target"))
+        .map(|(coordinates, _)| coordinates)
+    }
+  }
+
+  fn get_troops(origins: &[Coordinates]) -> Vec<FormationTroop> {
+    origins
+      .iter()
+      .map(|origin| FormationTroop {
+        origin: Planet::get_cartesian_from_coordinates(*origin),
+        origin_territory_id: "home".to_owned(),
+        speed: 1.,
+      })
+      .collect()
+  }
+
+  #[test]
+  fn single_troop_goes_straight_to_the_center() {
+    let map = Map::new(100..130, 100..140);
+    let center = (115, 120);
+
+    let slots = map.get_group_orders(Formation::Wedge, &get_troops(&[(115, 60)]), center);
+
+    assert_eq!(slots, vec![FormationSlot { destination: Planet::get_cartesian_from_coordinates(center), pace: None }]);
+  }
+
+  #[test]
+  fn line_stands_side_by_side_across_the_heading() {
+    let map = Map::new(100..130, 100..140);
+    let center = Planet::get_cartesian_from_coordinates((115, 120));
+    let troops = get_troops(&[(114, 60), (115, 60), (116, 60), (117, 60)]);
+
+    let slots = map.get_group_orders(Formation::Line, &troops, (115, 120));
+    let mut destinations: Vec<Vec3> = slots.iter().map(|slot| slot.destination).collect();
+    destinations.sort_by(|a, b| a.z.total_cmp(&b.z));
+
+    // Coming from the west, the line goes from north to south
+    for pair in destinations.windows(2) {
+      assert!((pair[0].distance_to(pair[1]) - Formation::SPACING).abs() < 0.005);
+    }
+    let line_center = destinations.iter().fold(Vec3::ZERO, |sum, destination| sum + *destination) / 4.;
+    assert!(line_center.distance_to(center) < 0.005);
+  }
+
+  #[test]
+  fn wedge_points_the_way_the_group_is_heading() {
+    let map = Map::new(100..130, 100..140);
+    let center = Planet::get_cartesian_from_coordinates((115, 120));
+    let origin = Planet::get_cartesian_from_coordinates((115, 60));
+    let troops = get_troops(&[(115, 60), (114, 60), (116, 60)]);
+
+    let slots = map.get_group_orders(Formation::Wedge, &troops, (115, 120));
+
+    // The tip is at the center, the rest of the wedge trails behind it, towards where the group comes from
+    let (tip, trail): (Vec<Vec3>, Vec<Vec3>) = slots
+      .iter()
+      .map(|slot| slot.destination)
+      .partition(|destination| destination.distance_to(center) < 1e-4);
+
+    assert_eq!(tip.len(), 1);
+    assert!(trail.iter().all(|destination| destination.distance_to(origin) < center.distance_to(origin)));
+  }
+
+  #[test]
+  fn slots_off_the_territory_are_moved_to_free_coordinates_inside() {
+    let map = Map::new(100..103, 100..103);
+    let origins: Vec<Coordinates> = (0..9).map(|index| (95 + index, 60)).collect();
+
+    let slots = map.get_group_orders(Formation::Blob, &get_troops(&origins), (101, 101));
+    let coordinates_inside: BTreeSet<Coordinates> = slots
+      .iter()
+      .map(|slot| map.get_coordinates_inside(slot).expect("Expected the slot to be inside the territory"))
+      .collect();
+
+    assert_eq!(coordinates_inside.len(), 9);
+  }
+
+  #[test]
+  fn group_is_paced_to_arrive_together() {
+    let map = Map::new(100..130, 100..140);
+    let troops = get_troops(&[(115, 60), (115, 90), (115, 118)]);
+
+    let slots = map.get_group_orders(Formation::Blob, &troops, (115, 120));
+
+    assert_eq!(slots[0].pace, None);
+    assert!(slots[1].pace.is_some_and(|pace| pace < 1. && pace > slots[2].pace.unwrap_or(1.)));
+    assert_eq!(slots[2].pace, Some(Formation::MIN_PACE));
+  }
+
+  #[test]
+  fn formations_cycle_around() {
+    assert_eq!(Formation::Blob.get_next(), Formation::Line);
+    assert_eq!(Formation::Line.get_next(), Formation::Wedge);
+    assert_eq!(Formation::Wedge.get_next(), Formation::Blob);
+  }
+}
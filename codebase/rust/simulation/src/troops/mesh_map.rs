@@ -0,0 +1,58 @@
+use serde::{Deserialize, Serialize};
+
+use super::surface::Surface;
+
+/// Identifies the mesh a troop is rendered with,
+/// the scene of each one is mapped at `TroopMesh` in the GDExtension
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+pub enum MeshId {
+  Cannon,
+  Tank1,
+  Tonk,
+  Truck1,
+
+  Boat1,
+  Boat2,
+  Boat3,
+  Boat4,
+  Boat5,
+  Boat6,
+  Galleon,
+}
+
+/// Meshes a player's troops use on each surface
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TroopMeshes {
+  pub land: MeshId,
+  pub sea: MeshId,
+}
+
+impl MeshId {
+  /// Every mesh, check `Archetype::validate`
+  pub fn iter() -> impl Iterator<Item = MeshId> {
+    [
+      MeshId::Cannon,
+      MeshId::Tank1,
+      MeshId::Tonk,
+      MeshId::Truck1,
+
+      MeshId::Boat1,
+      MeshId::Boat2,
+      MeshId::Boat3,
+      MeshId::Boat4,
+      MeshId::Boat5,
+      MeshId::Boat6,
+      MeshId::Galleon,
+    ]
+    .into_iter()
+  }
+}
+
+impl TroopMeshes {
+  pub fn get(&self, surface: Surface) -> &MeshId {
+    match surface {
+      Surface::Land => &self.land,
+      Surface::Sea => &self.sea,
+    }
+  }
+}
@@ -0,0 +1,14 @@
+pub mod troop;
+pub mod speed;
+pub mod surface;
+pub mod naval;
+pub mod mesh_map;
+pub mod archetype;
+pub mod combat;
+pub mod orders;
+pub mod route;
+pub mod formation;
+pub mod steering;
+pub mod battlefield;
+pub mod deployment;
+pub mod persistence;
\ No newline at end of file
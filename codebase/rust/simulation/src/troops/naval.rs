@@ -0,0 +1,41 @@
+use std::f64::consts::PI;
+
+use crate::globe::coordinates::Planet;
+use crate::rules::Rules;
+
+use super::speed::SpeedType;
+use super::surface::Surface;
+
+/// Rules for going from the land to the sea and back:
+/// troops embark at the coast, sail through open water and disembark at the coast again
+pub struct Naval {}
+
+impl Naval {
+  /// Time a troop holds still once it reaches the sea, while it boards the ships
+  pub const EMBARK_TIME_IN_SECS: f32 = 1.0;
+  /// Time a troop holds still once it reaches the land, while it leaves the ships
+  pub const DISEMBARK_TIME_IN_SECS: f32 = 1.0;
+
+  /// Routes pay this many grid steps every time they switch surface, besides the boarding time,
+  /// so they don't hop on and off the ships to cut a corner of land
+  pub const SURFACE_CHANGE_COST_IN_GRID_STEPS: f32 = 3.;
+
+  /// How long a troop holds still once it reaches `reached_surface` from the other one
+  pub fn get_boarding_time(rules: &Rules, reached_surface: Surface) -> f32 {
+    match reached_surface {
+      Surface::Sea => rules.embark_time_in_secs,
+      Surface::Land => rules.disembark_time_in_secs,
+    }
+  }
+
+  /// What a step switching surface costs on top of its length, as an angle seen from the planet center,
+  /// the same way `Pathfinder` measures its steps
+  pub fn get_surface_change_cost(rules: &Rules) -> f32 {
+    let grid_step = (PI / (Planet::get_num_of_latitudes() as f64)) as f32;
+    let boarding_time = (rules.embark_time_in_secs + rules.disembark_time_in_secs) / 2.;
+    // Routes are the same whoever takes them, so it's measured at the base speed of every archetype
+    let boarding_distance = boarding_time * SpeedType::BASE_FIGHT_OR_FLIGHT_SPEED;
+
+    grid_step * Self::SURFACE_CHANGE_COST_IN_GRID_STEPS + boarding_distance / Planet::get_planet_radius() as f32
+  }
+}
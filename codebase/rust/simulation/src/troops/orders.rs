@@ -0,0 +1,348 @@
+use std::collections::VecDeque;
+
+use serde::{Deserialize, Serialize};
+
+use crate::math::Vec3;
+use crate::players::player::PlayerId;
+use crate::territories::territory::{Territory, TerritoryId};
+use crate::troops::battlefield::Battlefield;
+use crate::troops::combat::combat_stats::FighthingBehavior;
+use crate::troops::route::RouteOption;
+use crate::troops::speed::SpeedType;
+use crate::troops::troop::{is_combatting, Troop, TroopActivities, TroopId, TroopState};
+
+/// Sends a troop to a territory, given either by a player clicking on it or by a bot
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DeployOrder {
+  pub troop_id: TroopId,
+  pub territory_id: TerritoryId,
+  /// Where the player clicked, None lets the coordinate be drawn from the match rng
+  /// when the order is carried out, see `World::deploy_troop`
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub destination: Option<Vec3>,
+  /// Falls back to `RouteOption::Shortest` if there's no way to the destination with it
+  #[serde(default, skip_serializing_if = "RouteOption::is_shortest")]
+  pub route: RouteOption,
+  /// Fraction of its speed the troop goes at, so the group it was sent with arrives together,
+  /// check `Formation::get_group_orders`. None is full speed
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub pace: Option<f32>,
+  /// How the order fits with the ones the troop already has
+  #[serde(default, skip_serializing_if = "OrderMode::is_now")]
+  pub mode: OrderMode,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum OrderMode {
+  /// Drops whatever the troop was up to and goes right away
+  #[default]
+  Now,
+  /// Goes once the troop is done with its previous orders, shift + right-click
+  Queued,
+  /// Goes back and forth between where the troop is and the territory, ctrl + right-click
+  Patrol,
+  /// Stays still at the territory it's deployed to instead of patrolling around it,
+  /// the order territory is the one the troop is at and it isn't going anywhere
+  Hold,
+  /// Switches the troop to the stance and keeps it up to whatever it was doing,
+  /// it isn't going anywhere either, the same way as `Hold`
+  Stance(FighthingBehavior),
+}
+
+/// An order waiting for the troop to be done with the previous ones, check `OrderQueue`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct QueuedOrder {
+  pub territory_id: TerritoryId,
+  pub destination: Vec3,
+  #[serde(default, skip_serializing_if = "RouteOption::is_shortest")]
+  pub route: RouteOption,
+}
+
+/// Orders a troop carries out in sequence, the next one starts once the troop
+/// has arrived to the territory of the previous one and it's not fighting nor occupying it
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct OrderQueue {
+  pub orders: VecDeque<QueuedOrder>,
+  /// Every order started goes back to the end of the queue, so the troop patrols between them
+  #[serde(default)]
+  pub looping: bool,
+  /// Once the orders are done the troop stays still instead of patrolling around the territory
+  #[serde(default)]
+  pub holding: bool,
+}
+
+impl OrderMode {
+  pub fn is_now(&self) -> bool {
+    *self == OrderMode::Now
+  }
+}
+
+impl OrderQueue {
+  pub fn is_empty(&self) -> bool {
+    self.orders.is_empty() && !self.looping && !self.holding
+  }
+
+  /// Takes an order given to the troop, returns it back if the troop should carry it out right away.
+  /// `current` is where the troop is, the other end of a patrol
+  pub fn receive(&mut self, mode: OrderMode, order: QueuedOrder, current: QueuedOrder) -> Option<QueuedOrder> {
+    match mode {
+      OrderMode::Now => {
+        *self = OrderQueue::default();
+        Some(order)
+      },
+      OrderMode::Queued => {
+        self.orders.push_back(order);
+        None
+      },
+      OrderMode::Patrol => {
+        // The order starts right away, so it's already at the end of the loop
+        *self = OrderQueue {
+          orders: VecDeque::from([current, order.clone()]),
+          looping: true,
+          holding: false,
+        };
+        Some(order)
+      },
+      OrderMode::Hold => {
+        *self = OrderQueue { holding: true, ..OrderQueue::default() };
+        None
+      },
+      OrderMode::Stance(_) => None,
+    }
+  }
+
+  /// The order to start now, None if the troop is still busy with the previous one or there's none left
+  pub fn take_next(
+    &mut self,
+    arrived_to_territory: bool,
+    troop_activities: &TroopActivities,
+    territory: Option<&Territory>,
+    player_id: PlayerId,
+  ) -> Option<QueuedOrder> {
+    if self.orders.is_empty() ||
+      !arrived_to_territory ||
+      troop_activities.contains(&TroopState::Deploying) ||
+      is_combatting(troop_activities) {
+      return None;
+    }
+
+    // Occupying the territory is part of arriving there
+    if territory.is_some_and(|territory| territory.player_trying_to_conquer == Some(player_id)) {
+      return None;
+    }
+
+    let order = self.orders.pop_front()?;
+    if self.looping {
+      self.orders.push_back(order.clone());
+    }
+    Some(order)
+  }
+
+  /// Territories of the orders left, in the order they're carried out
+  pub fn get_territories(&self) -> impl Iterator<Item = &TerritoryId> {
+    self.orders.iter().map(|order| &order.territory_id)
+  }
+}
+
+impl Troop {
+  /// Sends the troop right away, the previous order it had is dropped.
+  /// Falls back to the shortest route if there's no way to the destination with `order.route`
+  pub fn set_order_to_move_to(&mut self, battlefield: &mut impl Battlefield, order: &QueuedOrder, pace: Option<f32>) {
+    self.no_combat_reset_trajectory(false);
+    self.troop_activities.insert(TroopState::Moving);
+    self.troop_activities.insert(TroopState::Deploying);
+    self.troop_activities.remove(&TroopState::Patrolling);
+
+    let route = battlefield.get_route_planner().plan_or_shortest(
+      order.route,
+      self.owner,
+      self.position,
+      &self.deployed_to_territory,
+      order.destination,
+      &order.territory_id,
+    );
+
+    let territories = battlefield.get_territories_mut();
+    if let Some(origin_territory) = territories.get_mut(&self.deployed_to_territory) {
+      origin_territory.inform_territory_departure(&self.troop_id, self.owner);
+    }
+
+    self.arrived_to_territory = false;
+    self.moving_trajectory_points = route.trajectory_points;
+    self.adopted_speed = SpeedType::FightOrFlight;
+    self.pace = pace;
+    self.deployed_to_territory = order.territory_id.clone();
+
+    territories
+      .get_mut(&self.deployed_to_territory)
+      .expect("Expected territory to exist")
+      .add_territory_deployment(&self.troop_id, self.owner);
+
+    self.waiting_for_deployment_following_action = true;
+  }
+
+  /// Goes right away or once it's done with its previous orders, depending on `mode`.
+  /// `order.destination` is ignored while holding or switching stance, as they don't go anywhere
+  pub fn receive_order(&mut self, battlefield: &mut impl Battlefield, order: QueuedOrder, pace: Option<f32>, mode: OrderMode) {
+    let current = self.get_current_order(order.route);
+
+    if let Some(order) = self.order_queue.receive(mode, order, current) {
+      self.set_order_to_move_to(battlefield, &order, pace);
+    }
+
+    match mode {
+      OrderMode::Hold => self.hold_position(),
+      OrderMode::Stance(fighting_behavior) => self.combat_stats.adopt_stance(fighting_behavior),
+      _ => {},
+    }
+  }
+
+  /// Where the troop is, the other end of a patrol
+  fn get_current_order(&self, route_option: RouteOption) -> QueuedOrder {
+    QueuedOrder {
+      territory_id: self.deployed_to_territory.clone(),
+      destination: self.position,
+      route: route_option,
+    }
+  }
+
+  /// Where the troop ends up once it's done with its orders, so the next queued one starts from there
+  pub fn get_last_order_destination(&self) -> Vec3 {
+    if let Some(last_order) = self.order_queue.orders.back() {
+      return last_order.destination;
+    }
+
+    match self.moving_trajectory_points.last() {
+      Some(destination) if self.troop_activities.contains(&TroopState::Deploying) => *destination,
+      _ => self.position,
+    }
+  }
+
+  /// Territory of `get_last_order_destination`
+  pub fn get_last_order_territory_id(&self) -> &TerritoryId {
+    match self.order_queue.orders.back() {
+      Some(last_order) => &last_order.territory_id,
+      None => &self.deployed_to_territory,
+    }
+  }
+
+  /// Stops patrolling around the territory, a troop on its way somewhere holds once it gets there
+  pub fn hold_position(&mut self) {
+    let wandering = self.troop_activities.contains(&TroopState::Patrolling) &&
+      self.troop_activities.contains(&TroopState::Moving) &&
+      !self.is_combatting();
+
+    if wandering {
+      self.no_combat_reset_trajectory(true);
+    }
+  }
+
+  /// Starts the next queued order once the troop is done with the previous one
+  pub(crate) fn maybe_carry_out_next_order(&mut self, battlefield: &mut impl Battlefield) {
+    let next_order = self.order_queue.take_next(
+      self.arrived_to_territory,
+      &self.troop_activities,
+      battlefield.get_territories().get(&self.deployed_to_territory),
+      self.owner,
+    );
+
+    if let Some(next_order) = next_order {
+      self.set_order_to_move_to(battlefield, &next_order, None);
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::collections::VecDeque;
+
+  use crate::math::Vec3;
+  use crate::territories::territory::{Continent, Territory};
+  use crate::troops::combat::combat_stats::{CombatTypes, FighthingBehavior};
+  use crate::troops::route::RouteOption;
+  use crate::troops::troop::{TroopActivities, TroopState};
+
+  use super::{OrderMode, OrderQueue, QueuedOrder};
+
+  fn get_order(territory_id: &str) -> QueuedOrder {
+    QueuedOrder {
+      territory_id: territory_id.to_owned(),
+      destination: Vec3::new(1., 0., 0.),
+      route: RouteOption::Shortest,
+    }
+  }
+
+  fn get_queue(territory_ids: &[&str]) -> OrderQueue {
+    OrderQueue {
+      orders: territory_ids.iter().map(|territory_id| get_order(territory_id)).collect(),
+      ..OrderQueue::default()
+    }
+  }
+
+  fn get_patrolling() -> TroopActivities {
+    TroopActivities::from([TroopState::Patrolling])
+  }
+
+  #[test]
+  fn now_drops_the_previous_orders() {
+    let mut queue = get_queue(&["peru", "chile"]);
+    queue.holding = true;
+
+    assert_eq!(queue.receive(OrderMode::Now, get_order("bolivia"), get_order("here")), Some(get_order("bolivia")));
+    assert!(queue.is_empty());
+  }
+
+  #[test]
+  fn queued_orders_wait_their_turn() {
+    let mut queue = OrderQueue::default();
+
+    assert_eq!(queue.receive(OrderMode::Queued, get_order("peru"), get_order("here")), None);
+    assert_eq!(queue.receive(OrderMode::Queued, get_order("chile"), get_order("here")), None);
+
+    assert_eq!(queue.get_territories().collect::<Vec<_>>(), vec!["peru", "chile"]);
+  }
+
+  #[test]
+  fn patrol_goes_back_and_forth() {
+    let mut queue = get_queue(&["peru"]);
+
+    assert_eq!(queue.receive(OrderMode::Patrol, get_order("chile"), get_order("here")), Some(get_order("chile")));
+    assert!(queue.looping);
+    assert_eq!(queue.orders, VecDeque::from([get_order("here"), get_order("chile")]));
+
+    for territory_id in ["here", "chile", "here"] {
+      assert_eq!(queue.take_next(true, &get_patrolling(), None, 1), Some(get_order(territory_id)));
+    }
+    assert_eq!(queue.orders.len(), 2);
+  }
+
+  #[test]
+  fn hold_and_stance_dont_go_anywhere() {
+    let mut queue = get_queue(&["peru"]);
+
+    assert_eq!(queue.receive(OrderMode::Stance(FighthingBehavior::Pacifist), get_order("chile"), get_order("here")), None);
+    assert_eq!(queue.orders.len(), 1);
+
+    assert_eq!(queue.receive(OrderMode::Hold, get_order("chile"), get_order("here")), None);
+    assert!(queue.orders.is_empty());
+    assert!(queue.holding);
+    assert!(!queue.is_empty());
+  }
+
+  #[test]
+  fn next_order_waits_for_the_troop_to_be_done() {
+    let mut queue = get_queue(&["peru"]);
+    let mut territory = Territory::get_base_territory("chile", Continent::SouthAmerica, None);
+
+    assert_eq!(queue.take_next(false, &get_patrolling(), None, 1), None);
+    assert_eq!(queue.take_next(true, &TroopActivities::from([TroopState::Deploying]), None, 1), None);
+    assert_eq!(queue.take_next(true, &TroopActivities::from([TroopState::Combating(CombatTypes::Defending)]), None, 1), None);
+
+    territory.player_trying_to_conquer = Some(1);
+    assert_eq!(queue.take_next(true, &get_patrolling(), Some(&territory), 1), None);
+
+    territory.player_trying_to_conquer = None;
+    assert_eq!(queue.take_next(true, &get_patrolling(), Some(&territory), 1), Some(get_order("peru")));
+    assert_eq!(queue.take_next(true, &get_patrolling(), Some(&territory), 1), None);
+  }
+}
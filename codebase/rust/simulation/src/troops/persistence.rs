@@ -0,0 +1,72 @@
+use crate::rules::Rules;
+use crate::save::match_save::TroopSave;
+
+use super::combat::location::Location;
+use super::mesh_map::TroopMeshes;
+use super::troop::{Troop, TroopState};
+
+impl Troop {
+  pub fn get_save(&self) -> TroopSave {
+    TroopSave {
+      troop_id: self.troop_id.clone(),
+      owner: self.owner,
+      position: self.position,
+      velocity: self.velocity,
+
+      deployed_to_territory: self.deployed_to_territory.clone(),
+      arrived_to_territory: self.arrived_to_territory,
+
+      combat_stats: self.combat_stats.clone(),
+      troop_activities: self.troop_activities.clone(),
+      adopted_speed: self.adopted_speed.clone(),
+      idle_timer: self.idle_timer,
+      surface: self.surface,
+      boarding_timer: self.boarding_timer,
+      pace: self.pace,
+      order_queue: self.order_queue.clone(),
+
+      moving_trajectory_points: self.moving_trajectory_points.clone(),
+      current_trajectory_point: self.current_trajectory_point,
+      moving_and_combating: self.moving_and_combating,
+      waiting_for_deployment_following_action: self.waiting_for_deployment_following_action,
+    }
+  }
+
+  /// `troop_meshes` are the ones of the owner, the archetype isn't saved but picked from them
+  pub fn from_save(save: &TroopSave, troop_meshes: &TroopMeshes, rules: &Rules) -> Troop {
+    let mut troop_activities = save.troop_activities.clone();
+    // Selection belongs to whoever was playing, not to the match
+    troop_activities.remove(&TroopState::Selected);
+
+    Troop {
+      troop_id: save.troop_id.clone(),
+      owner: save.owner,
+      troop_meshes: troop_meshes.clone(),
+      position: save.position,
+      velocity: save.velocity,
+
+      deployed_to_territory: save.deployed_to_territory.clone(),
+      arrived_to_territory: save.arrived_to_territory,
+      // Refreshed on the next tick along with the location
+      touching_territory_id: None,
+
+      surface: save.surface,
+      combat_stats: save.combat_stats.clone(),
+      archetype: rules.get_troop_archetype(troop_meshes, save.surface).clone(),
+      location: Location::default(),
+
+      troop_activities,
+      adopted_speed: save.adopted_speed.clone(),
+      pace: save.pace,
+      order_queue: save.order_queue.clone(),
+
+      idle_timer: save.idle_timer,
+      boarding_timer: save.boarding_timer,
+
+      moving_trajectory_points: save.moving_trajectory_points.clone(),
+      current_trajectory_point: save.current_trajectory_point,
+      moving_and_combating: save.moving_and_combating,
+      waiting_for_deployment_following_action: save.waiting_for_deployment_following_action,
+    }
+  }
+}
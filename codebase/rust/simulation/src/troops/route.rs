@@ -0,0 +1,503 @@
+use std::collections::{BTreeSet, VecDeque};
+use std::f64::consts::PI;
+use std::iter;
+
+use serde::{Deserialize, Serialize};
+
+use crate::globe::coordinates::{CoordinateMap, Coordinates, Planet};
+use crate::globe::pathfinding::{PathConstraint, Pathfinder};
+use crate::globe::trajectory::Trajectory;
+use crate::math::Vec3;
+use crate::players::player::PlayerId;
+use crate::rules::Rules;
+use crate::territories::adjacency::AdjacencyGraph;
+use crate::territories::territory::{Territories, Territory, TerritoryId, TerritoryState};
+
+use super::naval::Naval;
+use super::surface::Surface;
+
+/// How a deployed troop gets to its destination
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RouteOption {
+  /// Straight along the great circle, through whatever is on the way
+  #[default]
+  Shortest,
+  /// Only through territories the player rules, besides the sea and both ends
+  FriendlyOnly,
+  /// Around territories under conflict or being occupied
+  AvoidContested,
+}
+
+impl RouteOption {
+  pub fn all() -> [RouteOption; 3] {
+    [RouteOption::Shortest, RouteOption::FriendlyOnly, RouteOption::AvoidContested]
+  }
+
+  pub fn is_shortest(&self) -> bool {
+    *self == RouteOption::Shortest
+  }
+
+  /// The option after this one, wrapping around, so the player can cycle through them
+  pub fn get_next(&self) -> RouteOption {
+    let options = Self::all();
+    let index = options.iter().position(|option| option == self).unwrap_or(0);
+    options[(index + 1) % options.len()]
+  }
+}
+
+/// A planned way to the destination, made of legs following the great circle
+#[derive(Debug, Clone, PartialEq)]
+pub struct Route {
+  pub option: RouteOption,
+  /// Every leg's trajectory, one after the other
+  pub trajectory_points: Vec<Vec3>,
+  /// Territories stepped on along the way, in order, the sea left out
+  pub territories: Vec<TerritoryId>,
+}
+
+impl Route {
+  /// Sum of the distances between every trajectory point and the next
+  pub fn get_length(&self) -> f32 {
+    self.trajectory_points
+      .windows(2)
+      .map(|step| step[0].distance_to(step[1]))
+      .sum()
+  }
+}
+
+/// Plans deploy routes over the territories adjacency and the coordinates grid.
+/// Every route follows the `Naval` rules: it only takes to the sea at the coast and sails through open water
+pub struct RoutePlanner<'a> {
+  pub coordinate_map: &'a CoordinateMap,
+  pub territories: &'a Territories,
+  pub adjacency: &'a AdjacencyGraph,
+  pub rules: &'a Rules,
+}
+
+impl RoutePlanner<'_> {
+  /// How many times a step between samples can be split in half by `is_step_clear`
+  const MAX_STEP_SPLITS: u32 = 8;
+
+  /// Returns None if the option leaves no way to the destination
+  pub fn plan(
+    &self,
+    option: RouteOption,
+    player_id: PlayerId,
+    origin: Vec3,
+    origin_territory_id: &TerritoryId,
+    destination: Vec3,
+    destination_territory_id: &TerritoryId,
+  ) -> Option<Route> {
+    let radius = Planet::get_planet_radius() as f32;
+
+    let constraint = match self.get_allowed_territory_ids(option, player_id) {
+      // Going straight only crosses the sea once, otherwise the ships would sail over the land in between
+      None if self.get_num_of_surface_changes(origin, destination) <= 2 => {
+        let trajectory_points: Vec<Vec3> = Trajectory::get_geodesic_trajectory(origin, destination, radius).collect();
+        let territories = self.get_territories_along(&trajectory_points);
+        return Some(Route { option, trajectory_points, territories });
+      },
+      None => PathConstraint::Anywhere,
+      Some(allowed_territory_ids) => {
+        let reachable_territory_ids = self.get_reachable_territory_ids(
+          origin_territory_id,
+          destination_territory_id,
+          &allowed_territory_ids
+        )?;
+        let avoided_territory_ids: BTreeSet<TerritoryId> = self.territories
+          .keys()
+          .filter(|territory_id| !reachable_territory_ids.contains(*territory_id))
+          .cloned()
+          .collect();
+        PathConstraint::Avoid(avoided_territory_ids)
+      },
+    };
+
+    let path = Pathfinder::find_naval_path(
+      self.coordinate_map,
+      Planet::get_coordinates_from_cartesian(origin)?,
+      Planet::get_coordinates_from_cartesian(destination)?,
+      &constraint,
+      Naval::get_surface_change_cost(self.rules),
+    )?;
+
+    let mut trajectory_points: Vec<Vec3> = Vec::new();
+    for (leg_origin, leg_destination) in self.get_legs(origin, destination, &path, &constraint) {
+      let leg = Trajectory::get_geodesic_trajectory(leg_origin, leg_destination, radius);
+      // Every leg starts where the previous one ended
+      let skip = if trajectory_points.is_empty() { 0 } else { 1 };
+      trajectory_points.extend(leg.skip(skip));
+    }
+
+    Some(Route {
+      option,
+      trajectory_points,
+      territories: self.get_territories_on_path(&path),
+    })
+  }
+
+  /// Same as `plan`, but falls back to `RouteOption::Shortest`, that one always exists
+  pub fn plan_or_shortest(
+    &self,
+    option: RouteOption,
+    player_id: PlayerId,
+    origin: Vec3,
+    origin_territory_id: &TerritoryId,
+    destination: Vec3,
+    destination_territory_id: &TerritoryId,
+  ) -> Route {
+    [option, RouteOption::Shortest]
+      .into_iter()
+      .find_map(|option| self.plan(
+        option,
+        player_id,
+        origin,
+        origin_territory_id,
+        destination,
+        destination_territory_id,
+      ))
+      .expect("Expected the shortest route to always exist")
+  }
+
+  /// None when the route may go anywhere
+  fn get_allowed_territory_ids(&self, option: RouteOption, player_id: PlayerId) -> Option<BTreeSet<&TerritoryId>> {
+    let is_allowed: fn(&Territory, PlayerId) -> bool = match option {
+      RouteOption::Shortest => return None,
+      RouteOption::FriendlyOnly => |territory, player_id| territory.current_ruler == Some(player_id),
+      RouteOption::AvoidContested => |territory, _| !Self::is_contested(territory),
+    };
+
+    Some(self.territories
+      .values()
+      .filter(|territory| is_allowed(territory, player_id))
+      .map(|territory| &territory.territory_id)
+      .collect())
+  }
+
+  fn is_contested(territory: &Territory) -> bool {
+    territory.is_under_conflict() ||
+      territory.has_troops_from_different_players ||
+      territory.territory_states.contains(&TerritoryState::OccupationInProgress)
+  }
+
+  /// Walks the adjacency graph from the origin through the allowed territories, both ends are always allowed
+  /// Returns None if the destination can't be reached that way
+  fn get_reachable_territory_ids(
+    &self,
+    origin_territory_id: &TerritoryId,
+    destination_territory_id: &TerritoryId,
+    allowed_territory_ids: &BTreeSet<&TerritoryId>,
+  ) -> Option<BTreeSet<TerritoryId>> {
+    let mut reachable_territory_ids = BTreeSet::from([origin_territory_id.clone()]);
+    let mut territories_to_visit = VecDeque::from([origin_territory_id]);
+
+    while let Some(territory_id) = territories_to_visit.pop_front() {
+      if territory_id == destination_territory_id {
+        continue;
+      }
+
+      for (neighbor_id, _) in self.adjacency.get_neighbors(territory_id) {
+        let can_step_on = neighbor_id == destination_territory_id || allowed_territory_ids.contains(neighbor_id);
+        if can_step_on && reachable_territory_ids.insert(neighbor_id.clone()) {
+          territories_to_visit.push_back(neighbor_id);
+        }
+      }
+    }
+
+    reachable_territory_ids
+      .contains(destination_territory_id)
+      .then_some(reachable_territory_ids)
+  }
+
+  /// Pulls the grid path tight: every leg goes straight as far as the great circle stays off
+  /// the avoided territories, so the troop doesn't zigzag from one coordinate to the next.
+  /// Legs stay on a single surface, the path is only left to embark or disembark where it does
+  fn get_legs(&self, origin: Vec3, destination: Vec3, path: &[Coordinates], constraint: &PathConstraint) -> Vec<(Vec3, Vec3)> {
+    if path.len() < 2 {
+      return vec![(origin, destination)];
+    }
+
+    let surfaces: Vec<Surface> = path
+      .iter()
+      .map(|coordinates| Surface::from_coordinate_metadata(&self.coordinate_map[coordinates]))
+      .collect();
+    let mut waypoints: Vec<Vec3> = path
+      .iter()
+      .map(|coordinates| self.coordinate_map[coordinates].cartesian)
+      .collect();
+    // The exact ends instead of the coordinates around them
+    waypoints[0] = origin;
+    *waypoints.last_mut().expect("Expected path to have both ends") = destination;
+
+    let mut legs = Vec::new();
+    let mut leg_origin = 0;
+    while leg_origin < waypoints.len() - 1 {
+      let surface = surfaces[leg_origin];
+      let is_clear = |leg_destination: usize| {
+        self.is_leg_clear(waypoints[leg_origin], waypoints[leg_destination], constraint, surface)
+      };
+
+      // The next waypoint is always reachable, it's a neighbor. Beyond it the leg gallops ahead while it's clear,
+      // and then the farthest waypoint is narrowed down between the last clear one and the first blocked one
+      let mut clear = leg_origin + 1;
+      // It can't go past the surface it starts on
+      let mut blocked = leg_origin + surfaces[leg_origin..].iter().take_while(|next_surface| **next_surface == surface).count();
+      let mut step = 1;
+      while clear + step < blocked {
+        if !is_clear(clear + step) {
+          blocked = clear + step;
+          break;
+        }
+        clear += step;
+        step *= 2;
+      }
+      while blocked - clear > 1 {
+        let middle = (clear + blocked) / 2;
+        if is_clear(middle) {
+          clear = middle;
+        } else {
+          blocked = middle;
+        }
+      }
+
+      legs.push((waypoints[leg_origin], waypoints[clear]));
+      leg_origin = clear;
+    }
+
+    legs
+  }
+
+  /// Checks the great circle between both positions step by step, it must stay on `surface`
+  fn is_leg_clear(&self, leg_origin: Vec3, leg_destination: Vec3, constraint: &PathConstraint, surface: Surface) -> bool {
+    let positions: Vec<Vec3> = iter::once(leg_origin)
+      .chain(Self::get_samples_between(leg_origin, leg_destination))
+      .chain(iter::once(leg_destination))
+      .collect();
+
+    positions
+      .windows(2)
+      .all(|step| self.is_step_clear(step[0], step[1], constraint, surface, Self::MAX_STEP_SPLITS))
+  }
+
+  /// Steps are short enough to end on the same coordinate or a neighbor one, but going to a diagonal neighbor
+  /// the great circle also crosses one of the two coordinates in between, so the step is split to find out which
+  fn is_step_clear(&self, from: Vec3, to: Vec3, constraint: &PathConstraint, surface: Surface, splits_left: u32) -> bool {
+    let Some((to_coordinates, to_metadata)) = Planet::get_coordinate_metadata_from_cartesian(self.coordinate_map, to) else {
+      return true;
+    };
+    if !constraint.allows(to_metadata) || Surface::from_coordinate_metadata(to_metadata) != surface {
+      return false;
+    }
+
+    let Some(from_coordinates) = Planet::get_coordinates_from_cartesian(from) else {
+      return true;
+    };
+    let is_diagonal = from_coordinates.0 != to_coordinates.0 && from_coordinates.1 != to_coordinates.1;
+    if !is_diagonal || splits_left == 0 {
+      return true;
+    }
+
+    let middle = from.slerp(to, 0.5);
+    self.is_step_clear(from, middle, constraint, surface, splits_left - 1) &&
+      self.is_step_clear(middle, to, constraint, surface, splits_left - 1)
+  }
+
+  /// How many times the great circle between both positions goes from the land to the sea or back
+  fn get_num_of_surface_changes(&self, origin: Vec3, destination: Vec3) -> usize {
+    let surfaces: Vec<Surface> = iter::once(origin)
+      .chain(Self::get_samples_between(origin, destination))
+      .chain(iter::once(destination))
+      .filter_map(|position| Surface::get_surface_at(self.coordinate_map, position))
+      .collect();
+
+    surfaces.windows(2).filter(|pair| pair[0] != pair[1]).count()
+  }
+
+  /// Positions along the great circle between both ends, the ends left out, about half a coordinate apart,
+  /// so a leg can't slip over a coordinate in between. Coordinates get narrower towards the poles, and so do the steps
+  fn get_samples_between(leg_origin: Vec3, leg_destination: Vec3) -> impl Iterator<Item = Vec3> {
+    let leg_angle = leg_origin.angle_to(leg_destination) as f64;
+    let latitude_step = PI / (Planet::get_num_of_latitudes() as f64);
+    let longitude_step = 2. * PI / (Planet::get_num_of_longitudes() as f64);
+    let narrowest_step = longitude_step * latitude_step.sin();
+
+    iter::successors(Some(0.), move |progress: &f64| {
+      let direction = leg_origin.slerp(leg_destination, *progress as f32).normalized();
+      let distance_to_the_axis = (1. - (direction.z as f64).powi(2)).max(0.).sqrt();
+      let step = latitude_step.min(longitude_step * distance_to_the_axis).max(narrowest_step) / 2.;
+
+      let next_progress = progress + step / leg_angle;
+      (next_progress < 1.).then_some(next_progress)
+    })
+      .skip(1)
+      .map(move |progress| leg_origin.slerp(leg_destination, progress as f32))
+  }
+
+  fn get_territories_on_path(&self, path: &[Coordinates]) -> Vec<TerritoryId> {
+    let territory_ids = path
+      .iter()
+      .filter_map(|coordinates| self.coordinate_map.get(coordinates))
+      .filter_map(|coordinate_metadata| coordinate_metadata.territory_id.as_ref());
+    Self::dedup_territory_ids(territory_ids)
+  }
+
+  fn get_territories_along(&self, trajectory_points: &[Vec3]) -> Vec<TerritoryId> {
+    let territory_ids = trajectory_points
+      .iter()
+      .filter_map(|position| Planet::get_coordinate_metadata_from_cartesian(self.coordinate_map, *position))
+      .filter_map(|(_, coordinate_metadata)| coordinate_metadata.territory_id.as_ref());
+    Self::dedup_territory_ids(territory_ids)
+  }
+
+  /// Keeps every territory once, in the order they're first stepped on
+  fn dedup_territory_ids<'a>(territory_ids: impl Iterator<Item = &'a TerritoryId>) -> Vec<TerritoryId> {
+    let mut seen_territory_ids = BTreeSet::new();
+    territory_ids
+      .filter(|territory_id| seen_territory_ids.insert(*territory_id))
+      .cloned()
+      .collect()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::ops::Range;
+
+  use crate::globe::coordinates::{CoordinateMap, Coordinates, Planet};
+  use crate::math::Vec3;
+  use crate::rules::Rules;
+  use crate::territories::adjacency::AdjacencyGraph;
+  use crate::territories::territory::{Continent, Territories, Territory, TerritoryId, TerritoryState};
+
+  use super::{Route, RouteOption, RoutePlanner};
+
+  /// `home` and `destination` are split by `enemy`, `detour` runs along the three of them
+  struct Map {
+    coordinate_map: CoordinateMap,
+    territories: Territories,
+    adjacency: AdjacencyGraph,
+    rules: Rules,
+  }
+
+  impl Map {
+    fn new(with_detour: bool) -> Map {
+      let mut coordinate_map = Planet::get_blank_coordinate_map();
+      let mut territories = Territories::new();
+
+      let mut add_territory = |territory_id: &str, latitudes: Range<i16>, longitudes: Range<i16>, ruler: Option<u32>| {
+        Planet::add_territory(&mut coordinate_map, territory_id, latitudes, longitudes);
+
+        let mut territory = Territory::get_base_territory(territory_id, Continent::Europe, None);
+        territory.current_ruler = ruler;
+        territories.insert(territory_id.to_owned(), territory);
+      };
+
+      add_territory("home", 100..110, 100..110, Some(1));
+      add_territory("enemy", 100..110, 110..120, Some(2));
+      add_territory("destination", 100..110, 120..130, None);
+      if with_detour {
+        add_territory("detour", 110..120, 100..130, Some(1));
+      }
+
+      // Sea borders would let the routes sail around the enemy
+      let adjacency = AdjacencyGraph::from_coordinate_map(&coordinate_map, 0.01);
+      Map { coordinate_map, territories, adjacency, rules: Rules::default() }
+    }
+
+    fn get_planner(&self) -> RoutePlanner<'_> {
+      RoutePlanner {
+        coordinate_map: &self.coordinate_map,
+        territories: &self.territories,
+        adjacency: &self.adjacency,
+        rules: &self.rules,
+      }
+    }
+  }
+
+  const ORIGIN: Coordinates = (105, 102);
+  const DESTINATION: Coordinates = (105, 127);
+
+  fn get_id(territory_id: &str) -> TerritoryId {
+    territory_id.to_owned()
+  }
+
+  fn get_position(coordinates: Coordinates) -> Vec3 {
+    Planet::get_cartesian_from_coordinates(coordinates)
+  }
+
+  fn plan(map: &Map, option: RouteOption) -> Option<Route> {
+    map.get_planner().plan(
+      option,
+      1,
+      get_position(ORIGIN),
+      &get_id("home"),
+      get_position(DESTINATION),
+      &get_id("destination"),
+    )
+  }
+
+  #[test]
+  fn shortest_route_goes_straight_through() {
+    let map = Map::new(true);
+
+    let route = plan(&map, RouteOption::Shortest).expect("Expected a route");
+
+    assert_eq!(route.territories, vec![get_id("home"), get_id("enemy"), get_id("destination")]);
+    assert!(route.trajectory_points.first().is_some_and(|start| start.distance_to(get_position(ORIGIN)) < 1e-4));
+    assert!(route.trajectory_points.last().is_some_and(|end| end.distance_to(get_position(DESTINATION)) < 1e-4));
+    assert!((route.get_length() - get_position(ORIGIN).distance_to(get_position(DESTINATION))).abs() < 0.01);
+  }
+
+  #[test]
+  fn friendly_only_route_goes_around_the_other_territories() {
+    let map = Map::new(true);
+
+    let shortest_route = plan(&map, RouteOption::Shortest).expect("Expected a route");
+    let route = plan(&map, RouteOption::FriendlyOnly).expect("Expected a route");
+
+    assert_eq!(route.option, RouteOption::FriendlyOnly);
+    assert!(!route.territories.contains(&get_id("enemy")));
+    assert_eq!(route.territories.first(), Some(&get_id("home")));
+    assert_eq!(route.territories.last(), Some(&get_id("destination")));
+    assert!(route.get_length() > shortest_route.get_length());
+    assert!(route.trajectory_points.last().is_some_and(|end| end.distance_to(get_position(DESTINATION)) < 1e-4));
+  }
+
+  #[test]
+  fn avoid_contested_route_only_goes_around_territories_under_conflict() {
+    let mut map = Map::new(true);
+
+    let route = plan(&map, RouteOption::AvoidContested).expect("Expected a route");
+    assert!(route.territories.contains(&get_id("enemy")));
+
+    map.territories.get_mut("enemy").expect("Expected territory to exist")
+      .territory_states.insert(TerritoryState::OccupiedUnderConflict);
+    let route = plan(&map, RouteOption::AvoidContested).expect("Expected a route");
+
+    assert!(!route.territories.contains(&get_id("enemy")));
+  }
+
+  #[test]
+  fn no_route_when_the_option_leaves_no_way() {
+    let map = Map::new(false);
+
+    assert_eq!(plan(&map, RouteOption::FriendlyOnly), None);
+
+    let route = map.get_planner().plan_or_shortest(
+      RouteOption::FriendlyOnly,
+      1,
+      get_position(ORIGIN),
+      &get_id("home"),
+      get_position(DESTINATION),
+      &get_id("destination"),
+    );
+    assert_eq!(route.option, RouteOption::Shortest);
+  }
+
+  #[test]
+  fn options_cycle_around() {
+    assert_eq!(RouteOption::Shortest.get_next(), RouteOption::FriendlyOnly);
+    assert_eq!(RouteOption::FriendlyOnly.get_next(), RouteOption::AvoidContested);
+    assert_eq!(RouteOption::AvoidContested.get_next(), RouteOption::Shortest);
+  }
+}
@@ -0,0 +1,28 @@
+use serde::{Deserialize, Serialize};
+
+use super::archetype::Archetype;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum SpeedType {
+  Patrolling,
+  FightOrFlight,
+}
+
+impl SpeedType {
+  /// Check `Archetype::patrolling_speed`
+  pub const BASE_PATROLLING_SPEED: f32 = 0.05;
+  /// Check `Archetype::fight_or_flight_speed`
+  pub const BASE_FIGHT_OR_FLIGHT_SPEED: f32 = 0.15;
+
+  pub fn get_speed(&self, archetype: &Archetype) -> f32 {
+    match self {
+      SpeedType::Patrolling => archetype.patrolling_speed,
+      SpeedType::FightOrFlight => archetype.fight_or_flight_speed,
+    }
+  }
+
+  /// Check `DeployOrder::pace`
+  pub fn get_speed_at_pace(&self, archetype: &Archetype, pace: Option<f32>) -> f32 {
+    self.get_speed(archetype) * pace.unwrap_or(1.)
+  }
+}
@@ -0,0 +1,275 @@
+use std::collections::HashMap;
+
+use crate::math::Vec3;
+
+use super::troop::{Troop, TroopState};
+
+/// Where a troop is and how fast it's going at the start of the tick, check `Steering`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SteeringAgent {
+  pub position: Vec3,
+  /// Zero for troops holding still
+  pub velocity: Vec3,
+}
+
+/// Grid cell of the neighborhood lookup, `Steering::NEIGHBOR_RADIUS` wide on every axis
+type Cell = (i32, i32, i32);
+
+/// Local avoidance between troops, it only bends the velocity the trajectory asks for,
+/// so troops sidestep each other instead of overlapping or getting stuck.
+/// Built once per tick from every troop, so the ones stepping later see the same snapshot
+#[derive(Debug, Clone, Default)]
+pub struct Steering {
+  agents: Vec<SteeringAgent>,
+  /// Only looked up, never iterated, so it doesn't break determinism
+  cells: HashMap<Cell, Vec<usize>>,
+}
+
+impl Steering {
+  /// Half of the room a troop needs, two troops closer than twice that are overlapping
+  pub const TROOP_RADIUS: f32 = 0.03;
+
+  /// Troops farther than that are ignored
+  pub const NEIGHBOR_RADIUS: f32 = 0.15;
+
+  /// How far ahead a troop looks for a collision with a neighbor
+  pub const TIME_HORIZON_IN_SECS: f32 = 1.;
+
+  /// How hard overlapping troops push each other away, relative to their speed
+  pub const SEPARATION_WEIGHT: f32 = 1.5;
+
+  pub fn new(agents: Vec<SteeringAgent>) -> Steering {
+    let mut cells: HashMap<Cell, Vec<usize>> = HashMap::new();
+    for (index, agent) in agents.iter().enumerate() {
+      cells.entry(Self::get_cell(agent.position)).or_default().push(index);
+    }
+
+    Steering { agents, cells }
+  }
+
+  fn get_cell(position: Vec3) -> Cell {
+    let cell_size = Self::NEIGHBOR_RADIUS;
+    (
+      (position.x / cell_size).floor() as i32,
+      (position.y / cell_size).floor() as i32,
+      (position.z / cell_size).floor() as i32,
+    )
+  }
+
+  /// Agents within `NEIGHBOR_RADIUS` of the agent at `index` along with their index, leaving it out
+  fn get_neighbors(&self, index: usize, position: Vec3) -> impl Iterator<Item = (usize, &SteeringAgent)> + '_ {
+    let (x, y, z) = Self::get_cell(position);
+
+    (-1..=1)
+      .flat_map(move |dx| (-1..=1).flat_map(move |dy| (-1..=1).map(move |dz| (x + dx, y + dy, z + dz))))
+      .filter_map(|cell| self.cells.get(&cell))
+      .flatten()
+      .copied()
+      .filter(move |neighbor_index| *neighbor_index != index)
+      .map(|neighbor_index| (neighbor_index, &self.agents[neighbor_index]))
+      .filter(move |(_, neighbor)| neighbor.position.distance_to(position) < Self::NEIGHBOR_RADIUS)
+  }
+
+  /// Bends `desired_velocity` of the agent at `index` away from its neighbors.
+  /// Overlapping troops push each other apart, and troops about to run into each other
+  /// step aside, both to their right, so they pass each other instead of bumping.
+  /// The result stays flat on the globe surface, never goes faster than desired and never backwards
+  pub fn get_steered_velocity(&self, index: usize, desired_velocity: Vec3) -> Vec3 {
+    let Some(agent) = self.agents.get(index) else {
+      return desired_velocity;
+    };
+    let (Some(up), Some(forward)) = (agent.position.try_normalized(), desired_velocity.try_normalized()) else {
+      return desired_velocity;
+    };
+
+    let max_speed = desired_velocity.length();
+    let personal_space = Self::TROOP_RADIUS * 2.;
+    let mut separation = Vec3::ZERO;
+    let mut avoidance = Vec3::ZERO;
+
+    for (neighbor_index, neighbor) in self.get_neighbors(index, agent.position) {
+      let offset = neighbor.position - agent.position;
+      let distance = offset.length();
+
+      if distance < personal_space {
+        // Troops on top of each other split by their order, so they don't pick the same side
+        let away = (-offset).try_normalized().unwrap_or_else(|| {
+          let side = if index < neighbor_index { 1. } else { -1. };
+          forward.cross(up) * side
+        });
+        separation += away * (1. - distance / personal_space);
+      }
+
+      avoidance += Self::get_avoidance(offset, desired_velocity, neighbor.velocity, up, personal_space);
+    }
+
+    let steered = desired_velocity + separation * Self::SEPARATION_WEIGHT * max_speed + avoidance;
+    let mut flat = steered - up * steered.dot(up);
+
+    let backwards = flat.dot(forward);
+    if backwards < 0. {
+      flat -= forward * backwards;
+    }
+
+    match flat.try_normalized() {
+      Some(direction) if flat.length() > max_speed => direction * max_speed,
+      Some(_) => flat,
+      None => desired_velocity,
+    }
+  }
+
+  /// Velocity change to get out of the neighbor's way, zero if they don't collide within `TIME_HORIZON_IN_SECS`.
+  /// When the neighbor is moving it's expected to take half of the way out, as it steers too
+  fn get_avoidance(offset: Vec3, desired_velocity: Vec3, neighbor_velocity: Vec3, up: Vec3, personal_space: f32) -> Vec3 {
+    let relative_velocity = desired_velocity - neighbor_velocity;
+    if relative_velocity.try_normalized().is_none() {
+      return Vec3::ZERO;
+    }
+
+    let time_to_closest = offset.dot(relative_velocity) / relative_velocity.length_squared();
+    if time_to_closest <= 0. || time_to_closest > Self::TIME_HORIZON_IN_SECS {
+      return Vec3::ZERO;
+    }
+
+    let closest_offset = offset - relative_velocity * time_to_closest;
+    let miss_distance = closest_offset.length();
+    if miss_distance >= personal_space {
+      return Vec3::ZERO;
+    }
+
+    // Head-on, both step to their own right
+    let away = (-closest_offset)
+      .try_normalized()
+      .unwrap_or_else(|| relative_velocity.cross(up).normalized());
+    let share = if neighbor_velocity.try_normalized().is_some() { 0.5 } else { 1. };
+
+    away * (personal_space - miss_distance) / time_to_closest * share
+  }
+}
+
+impl Troop {
+  /// Snapshot of the troop for `Steering`, taken before any troop moves on the tick
+  pub fn get_steering_agent(&self) -> SteeringAgent {
+    let holding_still = self.moving_trajectory_points.is_empty() ||
+      self.troop_activities.contains(&TroopState::Idle) ||
+      self.troop_activities.contains(&TroopState::Boarding);
+
+    SteeringAgent {
+      position: self.position,
+      velocity: if holding_still { Vec3::ZERO } else { self.velocity },
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::math::Vec3;
+  use crate::troops::troop::{Troop, TroopState};
+
+  use super::{Steering, SteeringAgent};
+
+  /// On top of the globe, so the surface is flat on x and y, and the right of +x is -y
+  const TOP: Vec3 = Vec3::new(0., 0., 3.);
+  const SPEED: f32 = 0.1;
+
+  fn get_agent(offset: Vec3, velocity: Vec3) -> SteeringAgent {
+    SteeringAgent { position: TOP + offset, velocity }
+  }
+
+  fn assert_is_fine(steered: Vec3, desired: Vec3, agent: &SteeringAgent) {
+    assert!(steered.length() <= desired.length() + 1e-5, "Expected {steered:?} not to go faster than {desired:?}");
+    assert!(steered.dot(desired) >= -1e-5, "Expected {steered:?} not to go backwards");
+    assert!(steered.dot(agent.position.normalized()).abs() < 1e-5, "Expected {steered:?} to stay flat on the surface");
+  }
+
+  #[test]
+  fn lone_troop_goes_as_desired() {
+    let desired = Vec3::new(SPEED, 0., 0.);
+    let steering = Steering::new(vec![
+      get_agent(Vec3::ZERO, desired),
+      get_agent(Vec3::new(0., Steering::NEIGHBOR_RADIUS * 1.5, 0.), Vec3::ZERO),
+    ]);
+
+    assert_eq!(steering.get_steered_velocity(0, desired), desired);
+    assert_eq!(steering.get_steered_velocity(5, desired), desired);
+    assert_eq!(steering.get_steered_velocity(0, Vec3::ZERO), Vec3::ZERO);
+  }
+
+  #[test]
+  fn overlapping_troops_push_each_other_apart() {
+    let desired = Vec3::new(SPEED, 0., 0.);
+    let agents = vec![
+      get_agent(Vec3::ZERO, desired),
+      get_agent(Vec3::new(0., Steering::TROOP_RADIUS, 0.), Vec3::ZERO),
+    ];
+    let steering = Steering::new(agents.clone());
+
+    let steered = steering.get_steered_velocity(0, desired);
+
+    assert!(steered.y < 0.);
+    assert_is_fine(steered, desired, &agents[0]);
+  }
+
+  #[test]
+  fn troops_on_top_of_each_other_split_to_opposite_sides() {
+    let desired = Vec3::new(SPEED, 0., 0.);
+    let agents = vec![get_agent(Vec3::ZERO, desired), get_agent(Vec3::ZERO, desired)];
+    let steering = Steering::new(agents.clone());
+
+    let steered = steering.get_steered_velocity(0, desired);
+    let other_steered = steering.get_steered_velocity(1, desired);
+
+    assert!(steered.y * other_steered.y < 0.);
+    assert_is_fine(steered, desired, &agents[0]);
+    assert_is_fine(other_steered, desired, &agents[1]);
+  }
+
+  #[test]
+  fn head_on_troops_both_step_to_their_right() {
+    let desired = Vec3::new(SPEED, 0., 0.);
+    let other_desired = -desired;
+    let agents = vec![
+      get_agent(Vec3::ZERO, desired),
+      get_agent(Vec3::new(0.1, 0., 0.), other_desired),
+    ];
+    let steering = Steering::new(agents.clone());
+
+    let steered = steering.get_steered_velocity(0, desired);
+    let other_steered = steering.get_steered_velocity(1, other_desired);
+
+    assert!(steered.y < 0.);
+    assert!(other_steered.y > 0.);
+    assert_is_fine(steered, desired, &agents[0]);
+    assert_is_fine(other_steered, other_desired, &agents[1]);
+  }
+
+  #[test]
+  fn troops_going_away_are_left_alone() {
+    let desired = Vec3::new(SPEED, 0., 0.);
+    let steering = Steering::new(vec![
+      get_agent(Vec3::ZERO, desired),
+      get_agent(Vec3::new(-0.1, 0., 0.), Vec3::ZERO),
+      get_agent(Vec3::new(0.1, 0., 0.), desired * 2.),
+    ]);
+
+    assert_eq!(steering.get_steered_velocity(0, desired), desired);
+  }
+
+  #[test]
+  fn troops_holding_still_have_no_velocity() {
+    let mut troop = Troop::get_blank();
+    troop.position = TOP;
+    troop.velocity = Vec3::new(SPEED, 0., 0.);
+    troop.moving_trajectory_points = vec![TOP, TOP + troop.velocity];
+    troop.troop_activities.remove(&TroopState::Idle);
+
+    assert_eq!(troop.get_steering_agent(), SteeringAgent { position: TOP, velocity: troop.velocity });
+
+    troop.troop_activities.insert(TroopState::Idle);
+    assert_eq!(troop.get_steering_agent().velocity, Vec3::ZERO);
+
+    troop.troop_activities.remove(&TroopState::Idle);
+    troop.moving_trajectory_points.clear();
+    assert_eq!(troop.get_steering_agent().velocity, Vec3::ZERO);
+  }
+}
@@ -0,0 +1,105 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::globe::coordinates::{CoordinateMap, CoordinateMetadata, Planet};
+use crate::math::Vec3;
+use crate::territories::territory::TerritoryId;
+
+use super::battlefield::Battlefield;
+use super::naval::Naval;
+use super::troop::{Troop, TroopState};
+
+#[derive(PartialEq, Eq, Debug, Clone, Copy, Default, Serialize, Deserialize)]
+pub enum Surface {
+  #[default]
+  Land,
+  Sea,
+
+  // future_version:
+  // Air, // (Planes)
+  // Space, // (Satellites)
+}
+
+impl Surface {
+  /// Only the coordinates matched with a territory are land
+  pub fn from_territory_id(territory_id: Option<&TerritoryId>) -> Surface {
+    if territory_id.is_some() { Surface::Land } else { Surface::Sea }
+  }
+
+  pub fn from_coordinate_metadata(coordinate_metadata: &CoordinateMetadata) -> Surface {
+    Self::from_territory_id(coordinate_metadata.territory_id.as_ref())
+  }
+
+  /// None if the position isn't on the map
+  pub fn get_surface_at(coordinate_map: &CoordinateMap, position: Vec3) -> Option<Surface> {
+    Planet::get_coordinate_metadata_from_cartesian(coordinate_map, position)
+      .map(|(_, coordinate_metadata)| Self::from_coordinate_metadata(coordinate_metadata))
+  }
+}
+
+impl fmt::Display for Surface {
+  /// allows to use `&Surface::Land.to_string()`
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Surface::Land => write!(f, "land"),
+      Surface::Sea =>  write!(f, "sea"),
+    }
+  }
+}
+
+impl Troop {
+  /// Sets troop surface, location and archetype according to the coordinate the troop is touching,
+  /// a moving troop reaching the other surface starts boarding
+  pub(crate) fn set_surface_troop(&mut self, battlefield: &impl Battlefield) {
+    // Off the map it doesn't panic, just keeps the previous surface and location
+    let Some((_, coordinate_metadata)) = Planet::get_coordinate_metadata_from_cartesian(battlefield.get_coordinate_map(), self.position) else {
+      return;
+    };
+
+    let rules = battlefield.get_rules();
+    self.touching_territory_id = coordinate_metadata.territory_id.clone();
+    self.location = rules.location_modifiers.get_location(
+      self.touching_territory_id.as_ref(),
+      self.owner,
+      battlefield.get_territories(),
+      battlefield.get_adjacency(),
+    );
+
+    let surface = Surface::from_coordinate_metadata(coordinate_metadata);
+    if surface == self.surface {
+      return;
+    }
+
+    self.surface = surface;
+    let archetype = rules.get_troop_archetype(&self.troop_meshes, surface).clone();
+    self.combat_stats.switch_archetype(&self.archetype, &archetype);
+    self.archetype = archetype;
+
+    // A moving troop reaching the other surface has to embark or disembark first
+    if !self.moving_trajectory_points.is_empty() {
+      self.start_boarding(Naval::get_boarding_time(rules, surface));
+    }
+  }
+
+  fn start_boarding(&mut self, boarding_time: f32) {
+    if boarding_time <= 0. {
+      return;
+    }
+
+    self.boarding_timer = boarding_time;
+    self.troop_activities.insert(TroopState::Boarding);
+  }
+
+  pub(crate) fn decrease_boarding_timer_if_boarding(&mut self, delta: f64) {
+    if !self.troop_activities.contains(&TroopState::Boarding) {
+      return;
+    }
+
+    self.boarding_timer -= delta as f32;
+    if self.boarding_timer <= 0.0 {
+      self.boarding_timer = 0.;
+      self.troop_activities.remove(&TroopState::Boarding);
+    }
+  }
+}
@@ -0,0 +1,359 @@
+use std::collections::HashSet;
+
+use serde::{Deserialize, Serialize};
+
+use crate::globe::coordinates::{CoordinateMap, Planet};
+use crate::globe::trajectory::Trajectory;
+use crate::math::Vec3;
+use crate::players::player::PlayerId;
+use crate::rules::Rules;
+use crate::territories::territory::{Territory, TerritoryId};
+
+use super::archetype::Archetype;
+use super::battlefield::Battlefield;
+use super::combat::combat_stats::{CombatStats, CombatTypes};
+use super::combat::location::Location;
+use super::mesh_map::{MeshId, TroopMeshes};
+use super::orders::OrderQueue;
+use super::speed::SpeedType;
+use super::steering::Steering;
+use super::surface::Surface;
+
+#[derive(Debug, Clone, Hash, Eq, PartialEq, Serialize, Deserialize)]
+pub enum TroopState {
+  /// Whenever the troop is moving it doesn't matter the place nor reason
+  Moving,
+
+  /// Whenever the troop is patrolling in its territory
+  Patrolling,
+
+  /// Pauses in between movements while it patrols
+  Idle,
+
+  /// Holds still while it embarks or disembarks, check `Naval`
+  Boarding,
+
+  /// Like a patrolling but the troop is rotating in place
+  /// (TODO:) Maybe it could be used as Idle instead
+  // Rotating,
+
+  /// If the troop is selected by the player
+  Selected,
+
+  /// Whenever the troop is being deployed to another surface_point
+  /// other than the one it was before by the order of its player
+  Deploying,
+
+  /// Whenever the troop is in combat
+  Combating(CombatTypes),
+}
+
+pub type TroopActivities = HashSet<TroopState>;
+
+/// TroopId is a string name, in the game it's the base().get_name().to_string() of a troop
+pub type TroopId = String;
+
+/// Returns true if any of the combat states is in the activities
+pub fn is_combatting(troop_activities: &TroopActivities) -> bool {
+  CombatTypes::iter().any(|combat_type| troop_activities.contains(&TroopState::Combating(combat_type)))
+}
+
+/// Removes every combat state from the activities
+pub fn remove_combatting_states(troop_activities: &mut TroopActivities) {
+  for combat_type in CombatTypes::iter() {
+    troop_activities.remove(&TroopState::Combating(combat_type));
+  }
+}
+
+/// A troop as the match sees it, the Godot `Troop` and the headless `World` both step it with `Troop::tick`
+#[derive(Debug, Clone)]
+pub struct Troop {
+  pub troop_id: TroopId,
+  pub owner: PlayerId,
+  /// Meshes of its player, which pick its archetype on every surface
+  pub troop_meshes: TroopMeshes,
+  pub position: Vec3,
+  /// How it moved on the last tick, zero while holding still, check `SteeringAgent::velocity`
+  pub velocity: Vec3,
+
+  /// holds the territory id the troop is deployed to
+  /// it changes when the troop is deployed to another territory
+  pub deployed_to_territory: TerritoryId,
+  /// indicates troop has arrived to the territory it was deployed to
+  pub arrived_to_territory: bool,
+  /// Territory under the troop, None at sea, refreshed every tick along with the surface
+  pub touching_territory_id: Option<TerritoryId>,
+
+  pub surface: Surface,
+  pub combat_stats: CombatStats,
+  /// Follows the surface the troop is on, check `Rules::get_troop_archetype`
+  pub archetype: Archetype,
+  /// Refreshed every tick along with the surface, it isn't saved
+  pub location: Location,
+
+  pub troop_activities: TroopActivities,
+  pub adopted_speed: SpeedType,
+  /// check `DeployOrder::pace`, it's back to full speed once the troop arrives
+  pub pace: Option<f32>,
+  /// Orders it carries out after the current one, check `DeployOrder::mode`
+  pub order_queue: OrderQueue,
+
+  /// indicates the time the troop will wait before moving again while patrolling
+  pub idle_timer: f32,
+  /// indicates the time the troop will hold still while it embarks or disembarks
+  pub boarding_timer: f32,
+
+  /// Empty while the troop has nowhere to go
+  pub moving_trajectory_points: Vec<Vec3>,
+  pub current_trajectory_point: usize,
+  pub moving_and_combating: bool,
+
+  /// it turns true when the troop receives the deployment order
+  /// and false when troop arrives to the deployed territory
+  pub waiting_for_deployment_following_action: bool,
+}
+
+impl Troop {
+  /// Defines the time the troop will wait before moving again while patrolling
+  pub const DEFAULT_IDLE_TIMER: f32 = 0.7;
+
+  /// Distance to a waypoint where the troop is considered to have reached it
+  pub const WAYPOINT_DISTANCE: f32 = 0.1;
+
+  /// Share of a patrol trajectory checked ahead of the troop, check `have_future_invasion_in_the_trajectory`
+  const FUTURE_INVASION_BUFFER: f32 = 0.3;
+
+  /// A troop spawned at the territory spawner, troops always spawn on land
+  pub fn new(troop_id: TroopId, owner: PlayerId, troop_meshes: &TroopMeshes, territory: &Territory, rules: &Rules) -> Troop {
+    let archetype = rules.get_troop_archetype(troop_meshes, Surface::Land).clone();
+
+    Troop {
+      troop_id,
+      owner,
+      troop_meshes: troop_meshes.clone(),
+      position: territory.spawner_location,
+      velocity: Vec3::ZERO,
+
+      deployed_to_territory: territory.territory_id.clone(),
+      arrived_to_territory: true,
+      touching_territory_id: Some(territory.territory_id.clone()),
+
+      surface: Surface::Land,
+      combat_stats: CombatStats::from_archetype(&archetype),
+      archetype,
+      location: Location::default(),
+
+      troop_activities: HashSet::from([
+        TroopState::Idle,
+        TroopState::Patrolling,
+      ]),
+      adopted_speed: SpeedType::Patrolling,
+      pace: None,
+      order_queue: OrderQueue::default(),
+
+      idle_timer: Self::DEFAULT_IDLE_TIMER,
+      boarding_timer: 0.,
+
+      moving_trajectory_points: Vec::new(),
+      current_trajectory_point: 0,
+      moving_and_combating: false,
+
+      waiting_for_deployment_following_action: false,
+    }
+  }
+
+  /// Placeholder until the troop is spawned or restored, check `Troop::new` and `Troop::from_save`
+  pub fn get_blank() -> Troop {
+    let archetype = Archetype::get_blank();
+
+    Troop {
+      troop_id: "".to_string(),
+      owner: 0,
+      troop_meshes: TroopMeshes {
+        land: archetype.mesh.clone(),
+        sea: MeshId::Boat1,
+      },
+      position: Vec3::ZERO,
+      velocity: Vec3::ZERO,
+
+      deployed_to_territory: "".to_string(),
+      arrived_to_territory: true,
+      touching_territory_id: None,
+
+      surface: Surface::Land,
+      combat_stats: CombatStats::from_archetype(&archetype),
+      archetype,
+      location: Location::default(),
+
+      troop_activities: HashSet::from([
+        TroopState::Idle,
+        TroopState::Patrolling,
+      ]),
+      adopted_speed: SpeedType::Patrolling,
+      pace: None,
+      order_queue: OrderQueue::default(),
+
+      idle_timer: Self::DEFAULT_IDLE_TIMER,
+      boarding_timer: 0.,
+
+      moving_trajectory_points: Vec::new(),
+      current_trajectory_point: 0,
+      moving_and_combating: false,
+
+      waiting_for_deployment_following_action: false,
+    }
+  }
+
+  /// Troops step of the simulation clock, `steering_index` is where the troop is in `steering`
+  pub fn tick(&mut self, delta: f64, battlefield: &mut impl Battlefield, steering: &Steering, steering_index: usize) {
+    self.set_surface_troop(battlefield);
+    self.maybe_populate_trajectory_points(battlefield);
+    self.maybe_move_along_the_trajectory(delta, battlefield, steering, steering_index);
+    self.decrease_idle_timer_if_idling(delta);
+    self.decrease_boarding_timer_if_boarding(delta);
+    self.get_deployment_next_action(battlefield);
+
+    self.trigger_combat_engage_if_needed(battlefield);
+    self.keep_fighting_if_combatting(delta, battlefield);
+    self.fire_at_enemies_in_range_if_needed(delta, battlefield);
+    self.recover_if_resting(delta, battlefield);
+    self.maybe_carry_out_next_order(battlefield);
+  }
+
+  pub fn is_combatting(&self) -> bool {
+    is_combatting(&self.troop_activities)
+  }
+
+  /// Troops not moving to another territory nor fighting can take new orders
+  pub fn is_available_for_orders(&self) -> bool {
+    self.arrived_to_territory &&
+      !self.troop_activities.contains(&TroopState::Deploying) &&
+      !self.is_combatting()
+  }
+
+  /// Refills the trajectory points in place, so the troop keeps reusing the same buffer
+  pub(crate) fn set_trajectory_to(&mut self, destination: Vec3) {
+    let trajectory = Trajectory::get_geodesic_trajectory(self.position, destination, Planet::get_planet_radius() as f32);
+    self.moving_trajectory_points.clear();
+    self.moving_trajectory_points.extend(trajectory);
+    self.current_trajectory_point = 0;
+  }
+
+  fn maybe_populate_trajectory_points(&mut self, battlefield: &mut impl Battlefield) {
+    if self.is_combatting() ||
+      self.order_queue.holding ||
+      self.troop_activities.contains(&TroopState::Moving) ||
+      !self.troop_activities.contains(&TroopState::Patrolling) {
+      return;
+    }
+
+    let Some(destination) = battlefield.get_random_position_in(&self.deployed_to_territory) else {
+      return;
+    };
+
+    self.set_trajectory_to(destination);
+    self.troop_activities.insert(TroopState::Moving);
+  }
+
+  fn maybe_move_along_the_trajectory(
+    &mut self,
+    delta: f64,
+    battlefield: &mut impl Battlefield,
+    steering: &Steering,
+    steering_index: usize,
+  ) {
+    self.velocity = Vec3::ZERO;
+
+    if self.moving_trajectory_points.is_empty() ||
+      self.troop_activities.contains(&TroopState::Idle) ||
+      self.troop_activities.contains(&TroopState::Boarding) {
+      return;
+    }
+
+    if self.have_future_invasion_in_the_trajectory(battlefield.get_coordinate_map()) {
+      self.no_combat_reset_trajectory(true);
+      return;
+    }
+
+    let Some(current_target) = self.moving_trajectory_points.get(self.current_trajectory_point).copied() else {
+      self.reset_trajectory();
+      return;
+    };
+
+    // Already on the waypoint, so it goes on to the next one
+    let Some(direction) = (current_target - self.position).try_normalized() else {
+      self.reach_the_waypoint();
+      return;
+    };
+
+    let desired_velocity = direction * self.adopted_speed.get_speed_at_pace(&self.archetype, self.pace);
+    self.velocity = steering.get_steered_velocity(steering_index, desired_velocity);
+    self.position = battlefield.move_troop(self, self.velocity, delta);
+
+    if self.position.distance_to(current_target) < Self::WAYPOINT_DISTANCE {
+      self.reach_the_waypoint();
+    }
+  }
+
+  /// Goes on to the next waypoint, or finishes the movement on the last one
+  fn reach_the_waypoint(&mut self) {
+    if self.current_trajectory_point < self.moving_trajectory_points.len() - 1 {
+      self.current_trajectory_point += 1;
+      return;
+    }
+
+    self.no_combat_reset_trajectory(true);
+  }
+
+  /// Avoids future invasion by checking if the next N points (buffer_checker) on the geodesic trajectory
+  /// are on an different territory as the troop is patrolling at
+  /// that happens because the point the troop is moving to is get randomly and the geodesic trajectory
+  /// may pass through other territories
+  fn have_future_invasion_in_the_trajectory(&self, coordinate_map: &CoordinateMap) -> bool {
+    if !self.troop_activities.contains(&TroopState::Patrolling) {
+      return false;
+    }
+
+    // Where N is {troop position} + {buffer} on the geodesic trajectory
+    let buffer_checker = (self.moving_trajectory_points.len() as f32 * Self::FUTURE_INVASION_BUFFER) as usize;
+    let future_point = self.current_trajectory_point + buffer_checker;
+    if future_point >= self.moving_trajectory_points.len() - 1 {
+      return false;
+    }
+
+    Planet::get_coordinate_metadata_from_cartesian(coordinate_map, self.moving_trajectory_points[future_point])
+      .and_then(|(_, coordinate_metadata)| coordinate_metadata.territory_id.as_ref())
+      .is_some_and(|territory_id| *territory_id != self.deployed_to_territory)
+  }
+
+  fn decrease_idle_timer_if_idling(&mut self, delta: f64) {
+    if self.troop_activities.contains(&TroopState::Idle) {
+      self.idle_timer -= delta as f32;
+    }
+
+    if self.idle_timer <= 0.0 {
+      self.idle_timer = Self::DEFAULT_IDLE_TIMER;
+      self.troop_activities.remove(&TroopState::Idle);
+    }
+  }
+
+  /// Resets all the states needed to reset when the troop is not in combat
+  pub fn no_combat_reset_trajectory(&mut self, gets_back_to_patrolling: bool) {
+    self.troop_activities.remove(&TroopState::Moving);
+    self.troop_activities.remove(&TroopState::Deploying);
+    self.troop_activities.insert(TroopState::Idle);
+
+    if gets_back_to_patrolling {
+      self.troop_activities.insert(TroopState::Patrolling);
+    }
+
+    self.adopted_speed = SpeedType::Patrolling;
+    self.pace = None;
+    self.reset_trajectory();
+  }
+
+  pub fn reset_trajectory(&mut self) {
+    self.current_trajectory_point = 0;
+    self.moving_trajectory_points.clear();
+  }
+}
@@ -0,0 +1,31 @@
+use serde::{Deserialize, Serialize};
+
+/// How a match can be won, a match ends as soon as any of its conditions is met,
+/// or when a single player is left
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum VictoryCondition {
+  /// Every territory of the map is ruled by the same player
+  WorldDomination,
+  /// The player rules every territory of this many continents, `Continent::Special` doesn't count
+  Continents(u32),
+  /// The player rules every `Continent::Special` territory for `secs` in a row
+  HoldSpecialTerritories { secs: f64 },
+  /// Once `secs` have elapsed the match ends, the player with the highest score wins
+  TimeLimit { secs: f64 },
+}
+
+/// Why the match ended
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum VictoryReason {
+  /// Every other player was eliminated
+  LastPlayerStanding,
+  /// Every player was eliminated in the same tick, there's no winner
+  NoPlayersLeft,
+  Condition(VictoryCondition),
+}
+
+impl VictoryCondition {
+  pub fn get_default_conditions() -> Vec<VictoryCondition> {
+    vec![VictoryCondition::WorldDomination]
+  }
+}
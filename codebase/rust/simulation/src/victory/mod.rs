@@ -0,0 +1,3 @@
+pub mod conditions;
+pub mod standings;
+pub mod tracker;
\ No newline at end of file
@@ -0,0 +1,36 @@
+use serde::{Deserialize, Serialize};
+
+use crate::players::player::{PlayerId, PlayerState};
+
+use super::conditions::VictoryReason;
+
+/// Place of a player once the match is over, or at any point of it
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Standing {
+  /// Starts at 1
+  pub rank: u32,
+  pub player_id: PlayerId,
+  pub score: u32,
+  pub territories: u32,
+  pub troops: u32,
+  /// None while the player is still in the match
+  pub eliminated_at_tick: Option<u64>,
+}
+
+/// How the match ended, kept by `VictoryTracker` and emitted along `GameEvent::MatchEnded`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MatchOutcome {
+  pub tick: u64,
+  pub winner: Option<PlayerId>,
+  pub reason: VictoryReason,
+  pub standings: Vec<Standing>,
+}
+
+impl Standing {
+  /// Each territory is worth this many troops in the score
+  pub const TERRITORY_POINTS: u32 = 10;
+
+  pub fn get_score(player: &PlayerState) -> u32 {
+    player.territory_counter * Self::TERRITORY_POINTS + player.troops_counter
+  }
+}
@@ -0,0 +1,337 @@
+use std::cmp::Reverse;
+
+use serde::{Deserialize, Serialize};
+
+use crate::players::player::{PlayerId, PlayerState};
+use crate::territories::continents::{get_continents_ruled_by, get_special_territories_ruler};
+use crate::territories::territory::Territories;
+
+use super::conditions::{VictoryCondition, VictoryReason};
+use super::standings::{MatchOutcome, Standing};
+
+/// Eliminates the defeated players and checks the victory conditions once every tick,
+/// it's part of the save, as holding the special territories spans over many ticks
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct VictoryTracker {
+  /// Time played since the match started, the Godot clock is already ticking before it
+  pub match_elapsed_secs: f64,
+  /// Player ruling every `Continent::Special` territory and for how long it has been ruling them
+  pub special_territories_holder: Option<(PlayerId, f64)>,
+  /// Eliminated players and the tick they were eliminated at, in the order they fell
+  pub eliminations: Vec<(PlayerId, u64)>,
+  /// Set once the match is over, the simulation shouldn't be stepped anymore
+  pub outcome: Option<MatchOutcome>,
+}
+
+impl VictoryTracker {
+  pub fn new() -> VictoryTracker {
+    Self::default()
+  }
+
+  pub fn is_game_over(&self) -> bool {
+    self.outcome.is_some()
+  }
+
+  /// Players with no territories nor troops left are out of the match,
+  /// returns true if the player has just been eliminated
+  pub fn eliminate_if_defeated(&mut self, tick: u64, player: &mut PlayerState) -> bool {
+    if !player.alive || player.territory_counter > 0 || player.troops_counter > 0 {
+      return false;
+    }
+
+    player.alive = false;
+    self.eliminations.push((player.player_id, tick));
+    true
+  }
+
+  /// Should be called after `eliminate_if_defeated` went over every player,
+  /// returns the outcome on the tick the match ends
+  pub fn check_victory(
+    &mut self,
+    tick: u64,
+    delta: f64,
+    conditions: &[VictoryCondition],
+    players: &[PlayerState],
+    territories: &Territories,
+  ) -> Option<MatchOutcome> {
+    if self.is_game_over() {
+      return None;
+    }
+
+    self.match_elapsed_secs += delta;
+    self.keep_track_of_special_territories(delta, territories);
+
+    let players_left: Vec<&PlayerState> = players.iter().filter(|player| player.alive).collect();
+    let last_player_standing = match players_left.as_slice() {
+      // A single player match only ends by its conditions
+      [] if !players.is_empty() => Some((None, VictoryReason::NoPlayersLeft)),
+      [player] if players.len() > 1 => Some((Some(player.player_id), VictoryReason::LastPlayerStanding)),
+      _ => None,
+    };
+
+    let (winner, reason) = last_player_standing.or_else(|| conditions
+      .iter()
+      .find_map(|condition| self
+        .get_condition_winner(condition, &players_left, territories)
+        .map(|winner| (Some(winner), VictoryReason::Condition(condition.clone())))))?;
+
+    let outcome = MatchOutcome {
+      tick,
+      winner,
+      reason,
+      standings: self.get_standings(players, winner),
+    };
+    self.outcome = Some(outcome.clone());
+    Some(outcome)
+  }
+
+  /// Players still in the match come first, by score, then the eliminated ones, the last to fall first
+  ///
+  /// # Arguments
+  /// * `winner` - Ranked first whatever the score, if set
+  pub fn get_standings(&self, players: &[PlayerState], winner: Option<PlayerId>) -> Vec<Standing> {
+    let mut players_left: Vec<&PlayerState> = players.iter().filter(|player| player.alive).collect();
+    players_left.sort_by_key(|player| (
+      Reverse(winner == Some(player.player_id)),
+      Reverse(Standing::get_score(player)),
+      Reverse(player.territory_counter),
+      player.player_id,
+    ));
+
+    let eliminated_players = self.eliminations
+      .iter()
+      .rev()
+      .filter_map(|(player_id, tick)| players
+        .iter()
+        .find(|player| player.player_id == *player_id)
+        .map(|player| (player, Some(*tick))));
+
+    players_left
+      .into_iter()
+      .map(|player| (player, None))
+      .chain(eliminated_players)
+      .enumerate()
+      .map(|(index, (player, eliminated_at_tick))| Standing {
+        rank: index as u32 + 1,
+        player_id: player.player_id,
+        score: Standing::get_score(player),
+        territories: player.territory_counter,
+        troops: player.troops_counter,
+        eliminated_at_tick,
+      })
+      .collect()
+  }
+
+  fn get_condition_winner(
+    &self,
+    condition: &VictoryCondition,
+    players_left: &[&PlayerState],
+    territories: &Territories,
+  ) -> Option<PlayerId> {
+    match condition {
+      VictoryCondition::WorldDomination => {
+        let ruler = territories.values().next()?.current_ruler?;
+        territories
+          .values()
+          .all(|territory| territory.current_ruler == Some(ruler))
+          .then_some(ruler)
+      },
+
+      VictoryCondition::Continents(num_of_continents) => players_left
+        .iter()
+        .find(|player| get_continents_ruled_by(territories, player.player_id).len() as u32 >= *num_of_continents)
+        .map(|player| player.player_id),
+
+      VictoryCondition::HoldSpecialTerritories { secs } => self.special_territories_holder
+        .filter(|(_, held_for_secs)| held_for_secs >= secs)
+        .map(|(player_id, _)| player_id),
+
+      VictoryCondition::TimeLimit { secs } if self.match_elapsed_secs >= *secs => players_left
+        .iter()
+        .max_by_key(|player| (Standing::get_score(player), player.territory_counter, Reverse(player.player_id)))
+        .map(|player| player.player_id),
+
+      VictoryCondition::TimeLimit { .. } => None,
+    }
+  }
+
+  fn keep_track_of_special_territories(&mut self, delta: f64, territories: &Territories) {
+    self.special_territories_holder = match (get_special_territories_ruler(territories), self.special_territories_holder) {
+      (Some(ruler), Some((holder, held_for_secs))) if ruler == holder => Some((holder, held_for_secs + delta)),
+      (Some(ruler), _) => Some((ruler, delta)),
+      (None, _) => None,
+    };
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::players::player::{PlayerId, PlayerState};
+  use crate::territories::territory::{Continent, Territories, Territory};
+  use crate::victory::conditions::{VictoryCondition, VictoryReason};
+
+  use super::VictoryTracker;
+
+  /// Two territories in Europe, one in Africa and a special one
+  fn get_territories() -> Territories {
+    [
+      ("france", Continent::Europe),
+      ("spain", Continent::Europe),
+      ("egypt", Continent::Africa),
+      ("suez", Continent::Special),
+    ]
+      .into_iter()
+      .map(|(territory_id, continent)| (territory_id.to_owned(), Territory::get_base_territory(territory_id, continent, None)))
+      .collect()
+  }
+
+  fn get_players() -> Vec<PlayerState> {
+    (1..=3)
+      .map(|player_id| {
+        let mut player = PlayerState::new(player_id);
+        player.territory_counter = 1;
+        player
+      })
+      .collect()
+  }
+
+  fn rule(territories: &mut Territories, territory_ids: &[&str], player_id: PlayerId) {
+    for territory_id in territory_ids {
+      territories.get_mut(*territory_id).expect("Expected territory to exist").current_ruler = Some(player_id);
+    }
+  }
+
+  #[test]
+  fn players_without_territories_nor_troops_are_eliminated_once() {
+    let mut tracker = VictoryTracker::new();
+    let mut player = PlayerState::new(1);
+    player.troops_counter = 1;
+
+    assert!(!tracker.eliminate_if_defeated(10, &mut player));
+
+    player.troops_counter = 0;
+    assert!(tracker.eliminate_if_defeated(20, &mut player));
+    assert!(!tracker.eliminate_if_defeated(30, &mut player));
+
+    assert!(!player.alive);
+    assert_eq!(tracker.eliminations, vec![(1, 20)]);
+  }
+
+  #[test]
+  fn last_player_standing_wins() {
+    let mut tracker = VictoryTracker::new();
+    let mut players = get_players();
+    players[0].alive = false;
+    players[2].alive = false;
+    tracker.eliminations = vec![(3, 5), (1, 8)];
+
+    let outcome = tracker
+      .check_victory(10, 1., &[], &players, &get_territories())
+      .expect("Expected the match to end");
+
+    assert_eq!(outcome.winner, Some(2));
+    assert_eq!(outcome.reason, VictoryReason::LastPlayerStanding);
+    let ranking: Vec<(PlayerId, Option<u64>)> = outcome.standings
+      .iter()
+      .map(|standing| (standing.player_id, standing.eliminated_at_tick))
+      .collect();
+    assert_eq!(ranking, vec![(2, None), (1, Some(8)), (3, Some(5))]);
+
+    assert!(tracker.is_game_over());
+    assert_eq!(tracker.check_victory(11, 1., &[], &players, &get_territories()), None);
+  }
+
+  #[test]
+  fn match_without_players_left_has_no_winner() {
+    let mut tracker = VictoryTracker::new();
+    let mut players = get_players();
+    players.iter_mut().for_each(|player| player.alive = false);
+
+    let outcome = tracker
+      .check_victory(10, 1., &[], &players, &get_territories())
+      .expect("Expected the match to end");
+
+    assert_eq!(outcome.winner, None);
+    assert_eq!(outcome.reason, VictoryReason::NoPlayersLeft);
+  }
+
+  #[test]
+  fn single_player_match_only_ends_by_its_conditions() {
+    let mut tracker = VictoryTracker::new();
+    let players = vec![PlayerState::new(1)];
+
+    assert_eq!(tracker.check_victory(10, 1., &[], &players, &get_territories()), None);
+  }
+
+  #[test]
+  fn world_domination_needs_every_territory() {
+    let mut tracker = VictoryTracker::new();
+    let mut territories = get_territories();
+    let conditions = [VictoryCondition::WorldDomination];
+    rule(&mut territories, &["france", "spain", "egypt"], 1);
+
+    assert_eq!(tracker.check_victory(1, 1., &conditions, &get_players(), &territories), None);
+
+    rule(&mut territories, &["suez"], 1);
+    let outcome = tracker
+      .check_victory(2, 1., &conditions, &get_players(), &territories)
+      .expect("Expected the match to end");
+
+    assert_eq!(outcome.winner, Some(1));
+    assert_eq!(outcome.reason, VictoryReason::Condition(VictoryCondition::WorldDomination));
+    assert_eq!(outcome.standings[0].player_id, 1);
+  }
+
+  #[test]
+  fn continents_leave_the_special_territories_out() {
+    let mut tracker = VictoryTracker::new();
+    let mut territories = get_territories();
+    let conditions = [VictoryCondition::Continents(2)];
+    rule(&mut territories, &["france", "egypt"], 2);
+    rule(&mut territories, &["suez"], 3);
+
+    assert_eq!(tracker.check_victory(1, 1., &conditions, &get_players(), &territories), None);
+
+    rule(&mut territories, &["spain"], 2);
+    let outcome = tracker.check_victory(2, 1., &conditions, &get_players(), &territories);
+
+    assert_eq!(outcome.and_then(|outcome| outcome.winner), Some(2));
+  }
+
+  #[test]
+  fn special_territories_have_to_be_held_in_a_row() {
+    let mut tracker = VictoryTracker::new();
+    let mut territories = get_territories();
+    let conditions = [VictoryCondition::HoldSpecialTerritories { secs: 3. }];
+    rule(&mut territories, &["suez"], 1);
+
+    assert_eq!(tracker.check_victory(1, 2., &conditions, &get_players(), &territories), None);
+
+    rule(&mut territories, &["suez"], 2);
+    assert_eq!(tracker.check_victory(2, 2., &conditions, &get_players(), &territories), None);
+    assert_eq!(tracker.special_territories_holder, Some((2, 2.)));
+
+    let outcome = tracker.check_victory(3, 2., &conditions, &get_players(), &territories);
+
+    assert_eq!(outcome.and_then(|outcome| outcome.winner), Some(2));
+  }
+
+  #[test]
+  fn time_limit_goes_to_the_highest_score() {
+    let mut tracker = VictoryTracker::new();
+    let mut players = get_players();
+    let conditions = [VictoryCondition::TimeLimit { secs: 5. }];
+    players[2].troops_counter = 3;
+
+    assert_eq!(tracker.check_victory(1, 4., &conditions, &players, &get_territories()), None);
+
+    let outcome = tracker
+      .check_victory(2, 1., &conditions, &players, &get_territories())
+      .expect("Expected the match to end");
+
+    assert_eq!(outcome.winner, Some(3));
+    let ranking: Vec<PlayerId> = outcome.standings.iter().map(|standing| standing.player_id).collect();
+    assert_eq!(ranking, vec![3, 1, 2]);
+    assert_eq!(outcome.standings[0].score, 13);
+  }
+}
@@ -0,0 +1,24 @@
+use godot::prelude::*;
+use simulation::math::Vec3;
+
+/// Converts the simulation's `Vec3` into Godot's `Vector3`
+pub trait IntoVector3 {
+  fn to_vector3(&self) -> Vector3;
+}
+
+impl IntoVector3 for Vec3 {
+  fn to_vector3(&self) -> Vector3 {
+    Vector3::new(self.x, self.y, self.z)
+  }
+}
+
+/// Converts Godot's `Vector3` into the simulation's `Vec3`
+pub trait IntoVec3 {
+  fn to_vec3(&self) -> Vec3;
+}
+
+impl IntoVec3 for Vector3 {
+  fn to_vec3(&self) -> Vec3 {
+    Vec3::new(self.x, self.y, self.z)
+  }
+}
@@ -0,0 +1,149 @@
+use godot::classes::{INode, Node};
+use godot::prelude::*;
+use simulation::events::event_bus::{EventBus, SubscriptionId};
+use simulation::events::game_event::GameEvent;
+
+use crate::root::root::RootScene;
+
+/// Central stream of the match events, lives at /root_scene/game_events
+///
+/// Rust systems subscribe through `subscribe`, GDScript connects to the signals,
+/// named after `GameEvent::get_name`. Both get the events of a tick once it's over,
+/// while RootScene is still bound, so GDScript handlers that call back into RootScene
+/// should connect with `CONNECT_DEFERRED`
+#[derive(GodotClass)]
+#[class(base=Node)]
+pub struct GameEvents {
+  base: Base<Node>,
+  bus: EventBus,
+}
+
+#[godot_api]
+impl INode for GameEvents {
+  fn init(base: Base<Node>) -> GameEvents {
+
+    GameEvents {
+      base: base,
+      bus: EventBus::new(),
+    }
+  }
+}
+
+#[godot_api]
+impl GameEvents {
+  #[signal]
+  fn troop_spawned(troop_id: GString, player_id: u32, territory_id: GString);
+
+  #[signal]
+  fn troop_died(troop_id: GString, player_id: u32, territory_id: GString, killed_by: GString, killer_player_id: u32);
+
+  #[signal]
+  fn occupation_started(territory_id: GString, player_id: u32);
+
+  #[signal]
+  fn territory_conquered(territory_id: GString, player_id: u32);
+
+  #[signal]
+  fn territory_lost(territory_id: GString, player_id: u32, lost_to: u32);
+
+  #[signal]
+  fn combat_started(troop_id: GString, player_id: u32, territory_id: GString);
+
+  #[signal]
+  fn combat_ended(troop_id: GString, player_id: u32, territory_id: GString);
+
+  #[signal]
+  fn player_eliminated(player_id: u32);
+
+  /// `winner` is null if no one was left, `standings` holds a Dictionary per player, check `Standing`
+  #[signal]
+  fn match_ended(winner: Variant, reason: GString, standings: VariantArray);
+
+  /// Subscribers run while GameEvents is bound, they can't emit nor subscribe from there
+  pub fn subscribe(&mut self, subscriber: impl FnMut(&GameEvent) + 'static) -> SubscriptionId {
+    self.bus.subscribe(subscriber)
+  }
+
+  pub fn unsubscribe(&mut self, subscription_id: SubscriptionId) {
+    self.bus.unsubscribe(subscription_id);
+  }
+
+  pub fn emit(&mut self, event: GameEvent) {
+    self.bus.emit(event);
+  }
+
+  /// Shortcut for the systems that only have the root at hand
+  pub fn emit_from_root(root_scene: &Gd<RootScene>, event: GameEvent) {
+    Self::get_from_root(root_scene)
+      .bind_mut()
+      .emit(event);
+  }
+
+  pub fn get_from_root(root_scene: &Gd<RootScene>) -> Gd<GameEvents> {
+    root_scene.get_node_as::<GameEvents>("game_events")
+  }
+
+  /// Called by `RootScene::step_match` once the tick is over,
+  /// the Rust subscribers get the events first and then the signals are emitted
+  pub fn dispatch(game_events: &mut Gd<GameEvents>) {
+    let events = game_events.bind_mut().bus.dispatch();
+
+    for event in events {
+      let arguments = Self::get_signal_arguments(&event);
+      game_events.emit_signal(event.get_name(), &arguments);
+    }
+  }
+
+  fn get_signal_arguments(event: &GameEvent) -> Vec<Variant> {
+    match event {
+      GameEvent::TroopDied { troop_id, player_id, territory_id, killed_by, killer_player_id } => vec![
+        troop_id.to_variant(),
+        player_id.to_variant(),
+        territory_id.to_variant(),
+        killed_by.to_variant(),
+        killer_player_id.to_variant(),
+      ],
+      GameEvent::TroopSpawned { troop_id, player_id, territory_id } |
+      GameEvent::CombatStarted { troop_id, player_id, territory_id } |
+      GameEvent::CombatEnded { troop_id, player_id, territory_id } => vec![
+        troop_id.to_variant(),
+        player_id.to_variant(),
+        territory_id.to_variant(),
+      ],
+      GameEvent::OccupationStarted { territory_id, player_id } |
+      GameEvent::TerritoryConquered { territory_id, player_id, .. } => vec![
+        territory_id.to_variant(),
+        player_id.to_variant(),
+      ],
+      GameEvent::TerritoryLost { territory_id, player_id, lost_to } => vec![
+        territory_id.to_variant(),
+        player_id.to_variant(),
+        lost_to.to_variant(),
+      ],
+      GameEvent::PlayerEliminated { player_id } => vec![
+        player_id.to_variant(),
+      ],
+      GameEvent::MatchEnded { outcome } => {
+        let standings: VariantArray = outcome.standings
+          .iter()
+          .map(|standing| {
+            let mut standing_dictionary = Dictionary::new();
+            standing_dictionary.set("rank", standing.rank);
+            standing_dictionary.set("player_id", standing.player_id);
+            standing_dictionary.set("score", standing.score);
+            standing_dictionary.set("territories", standing.territories);
+            standing_dictionary.set("troops", standing.troops);
+            standing_dictionary.set("eliminated_at_tick", standing.eliminated_at_tick.map_or(Variant::nil(), |tick| (tick as i64).to_variant()));
+            standing_dictionary.to_variant()
+          })
+          .collect();
+
+        vec![
+          outcome.winner.map_or(Variant::nil(), |winner| winner.to_variant()),
+          format!("{:?}", outcome.reason).to_variant(),
+          standings.to_variant(),
+        ]
+      },
+    }
+  }
+}
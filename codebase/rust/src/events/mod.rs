@@ -0,0 +1 @@
+pub mod game_events;
\ No newline at end of file
@@ -1,2297 +0,0 @@
-use std::{collections::HashMap, sync::{Arc, Mutex, RwLock}};
-use std::collections::VecDeque;
-use std::fs::File;
-use std::io::Write;
-
-type Latitude = i16;
-type Longitude = i16;
-pub type Coordinates = (Latitude, Longitude);
-pub type TerritoryId = String;
-
-#[derive(Debug, Clone)]
-pub struct Vector3 {
-  pub x: f64,
-  pub y: f64,
-  pub z: f64,
-}
-
-#[derive(Debug, Clone)]
-pub struct CoordinateMetadata {
-  pub territory_id: Option<TerritoryId>,
-  pub cartesian: Vector3,
-}
-
-pub type CoordinateMap = HashMap<Coordinates, CoordinateMetadata>;
-struct VirtualPlanet {
-  coordinate_map: CoordinateMap,
-}
-
-impl VirtualPlanet {
-  #[inline] pub fn get_num_of_latitudes() -> i16 { (90. * 2.5) as i16 }
-  #[inline] pub fn get_num_of_longitudes() -> i16 { (180. * 2.5) as i16 }
-
-  pub fn get_cartesian_from_coordinates(&self, given_coordinates: &Coordinates) -> Vector3 {
-    let coordinate_metadata = self.coordinate_map.get(&given_coordinates).expect("Expected coordinates to exist");
-    coordinate_metadata.cartesian.clone()
-  }
-}
-
-pub struct CoordinatesSystem {}
-impl CoordinatesSystem {
-  fn populate_heat_map(
-    origin_lat_long: Coordinates,
-    dest_lat_long: Coordinates,
-    within_the_territory_id: &TerritoryId,
-    arc_coordinate_map: &Arc<RwLock<HashMap<Coordinates, CoordinateMetadata>>>,
-    arc_heat_map: &Arc<Mutex<HashMap<Coordinates, i32>>>,
-  ) -> Option<HashMap<Coordinates, i32>> {
-    // Check destination first with a single lock acquisition
-    {
-      let heat_map_lock = arc_heat_map.lock().unwrap();
-      if heat_map_lock.contains_key(&dest_lat_long) {
-        return Some(heat_map_lock.clone());
-      }
-    }
-
-    let neighbors = Self::get_neighbors(origin_lat_long);
-    
-    for neighbor in neighbors.iter() {
-      // Check heat map with minimal lock duration
-      {
-        let heat_map_lock = arc_heat_map.lock().unwrap();
-        if heat_map_lock.contains_key(neighbor) {
-          continue;
-        }
-      }
-
-      // Scope the coordinate map lock to this iteration
-      let neighbor_metadata = {
-        let coordinate_map_lock = arc_coordinate_map.read().unwrap();
-        match coordinate_map_lock.get(neighbor) {
-          Some(metadata) => metadata.clone(),
-          None => continue,
-        }
-      };
-
-      let in_other_territory = neighbor_metadata
-        .territory_id
-        .as_ref()
-        .is_some_and(|neighbor_territory_id| neighbor_territory_id != within_the_territory_id);
-
-      if in_other_territory {
-        let mut heat_map_lock = arc_heat_map.lock().unwrap();
-        heat_map_lock.insert(*neighbor, i32::MAX);
-        continue;
-      }
-
-      let distance_level_from_origin = Self::get_lowest_distance_from_neighbors(
-        &Self::get_neighbors(*neighbor).to_vec(),
-        arc_heat_map,
-      );
-
-      let neighbor_distance = distance_level_from_origin + 1;
-
-      // Minimize lock duration for insert
-      {
-        let mut heat_map_lock = arc_heat_map.lock().unwrap();
-        heat_map_lock.insert(*neighbor, neighbor_distance);
-      }
-
-      if let Some(result_map) = Self::populate_heat_map(
-        *neighbor,
-        dest_lat_long,
-        within_the_territory_id,
-        arc_coordinate_map,
-        arc_heat_map,
-      ) {
-        return Some(result_map);
-      }
-    }
-    
-    None
-  }
-
-  // Helper function with improved lock handling
-  fn get_lowest_distance_from_neighbors(
-    neighbors: &Vec<Coordinates>,
-    arc_heat_map: &Arc<Mutex<HashMap<Coordinates, i32>>>,
-  ) -> i32 {
-    let heat_map_lock = arc_heat_map.lock().unwrap();
-    let min_distance = neighbors.iter()
-      .filter_map(|neighbor| heat_map_lock.get(neighbor))
-      .fold(i32::MAX, |acc, &distance| acc.min(distance));
-    min_distance
-  }
-
-  fn get_neighbors(
-    current_coordinate: Coordinates,
-  ) -> [Coordinates; 8] {
-    const BUFFER: i16 = 1;
-
-    let (latitude, longitude) = current_coordinate;
-
-    let mut latitude_north = latitude + BUFFER;
-    let mut latitude_south = latitude - BUFFER;
-    let mut longitude_east = longitude + BUFFER;
-    let mut longitude_west = longitude - BUFFER;
-
-    if latitude == VirtualPlanet::get_num_of_latitudes() {
-      latitude_north = 0;
-    }
-
-    if latitude == 0 {
-      latitude_south = VirtualPlanet::get_num_of_latitudes();
-    }
-
-    if longitude == VirtualPlanet::get_num_of_longitudes() {
-      longitude_east = 0;
-    }
-
-    if longitude == 0 {
-      longitude_west = VirtualPlanet::get_num_of_longitudes();
-    }
-
-    [
-      // Trajectory passing by North
-      (latitude_north, longitude),
-      // Trajectory passing by South
-      (latitude_south, longitude),
-      // Trajectory passing by East
-      (latitude, longitude_east),
-      // Trajectory passing by West
-      (latitude, longitude_west),
-
-      // Trajectory passing by Northeast
-      (latitude_north, longitude_east),
-      // Trajectory passing by Northwest
-      (latitude_north, longitude_west),
-      // Trajectory passing by Southeast
-      (latitude_south, longitude_east),
-      // Trajectory passing by Southwest
-      (latitude_south, longitude_west),
-    ]
-  }
-
-  fn back_trace_dest_to_origin(
-    heat_map: &HashMap<Coordinates, i32>,
-    origin_lat_long: Coordinates,
-    dest_lat_long: Coordinates,
-    in_the_frontiers_coordinates: &mut VecDeque<Coordinates>,
-  ) {
-    if let Some(dest_distance) = heat_map.get(&dest_lat_long){
-      let dest_neighbors = Self::get_neighbors(dest_lat_long);
-
-      for neighbor in dest_neighbors.iter() {
-        if let Some(neighbor_distance) = heat_map.get(neighbor) {
-          if neighbor_distance < dest_distance {
-          // if (neighbor_distance -1) == *dest_distance {
-            // in_the_frontiers_coordinates.insert(0, *neighbor);
-            in_the_frontiers_coordinates.push_front(*neighbor);
-            
-            if neighbor == &origin_lat_long {
-              break;
-            }
-            Self::back_trace_dest_to_origin(heat_map, origin_lat_long, *neighbor, in_the_frontiers_coordinates);
-            break;
-          }
-        }
-      }
-      return;
-    };
-  }
-}
-
-fn get_in_the_frontiers_trajectory(virtual_planet: &VirtualPlanet) {
-  let origin_lat_long = (20, 12);
-  let dest_lat_long = (36, 15);
-  let within_the_territory_id = "latinos".to_string();
-  let distance_level_from_origin = 0;
-
-  // Concurrent read access to the coordinate_map made us create a Arc RwLock
-  let arc_coordinate_map = Arc::new(RwLock::new(virtual_planet.coordinate_map.clone()));
-
-  let arc_heat_map: Arc<Mutex<HashMap<Coordinates, i32>>> = Arc::new(Mutex::new(HashMap::new()));
-  {
-    let mut heat_map_lock = arc_heat_map.lock().unwrap();
-    heat_map_lock.insert(origin_lat_long, distance_level_from_origin);
-  }
-
-  let heat_map = CoordinatesSystem::populate_heat_map(
-    origin_lat_long,
-    dest_lat_long,
-    &within_the_territory_id,
-    &arc_coordinate_map,
-    &arc_heat_map,
-  );
-
-  let Some(heat_map) = heat_map else {
-    println!("Didn't find a continuous path from origin to destination");
-    return;
-  };
-
-  let mut in_the_frontiers_coordinates: VecDeque<Coordinates> = VecDeque::from(vec![]);
-  CoordinatesSystem::back_trace_dest_to_origin(
-    &heat_map,
-    origin_lat_long,
-    dest_lat_long,
-    &mut in_the_frontiers_coordinates,
-  );
-
-  let in_the_frontiers_trajectory = in_the_frontiers_coordinates.iter().map(|coordinate| {
-    let cartesian: Vector3 = virtual_planet.get_cartesian_from_coordinates(coordinate);
-    cartesian
-  }).collect::<Vec<Vector3>>();
-
-  let file_path = "output/in_the_frontiers_trajectory.txt";
-  let mut file = File::create(file_path).expect("Failed to create file");
-
-  for trajectory_point in in_the_frontiers_trajectory.iter() {
-    let str = format!("{:?}\n", trajectory_point);
-    file.write_all(str.as_bytes()).expect("Failed to write to file");
-  }
-
-  // let str = format!("{:?}", in_the_frontiers_trajectory);
-  // file.write_all(str.as_bytes()).expect("Failed to write to file");
-
-}
-
-/// Implements a moviment in between latino and amazon territories
-fn main() {
-  let coordinate_map_with_amazon_and_latinos_only: CoordinateMap = vec![
-    ((21, 17), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.90978414, y: 0.22009996, z: 3.10027900 } }),
-    ((31, 14), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.33252510, y: 0.26384708, z: 2.93983750 } }),
-    ((36, 13), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.53452810, y: 0.28163862, z: 2.83791920 } }),
-    ((21, 433), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.90978414, y: -0.22009996, z: 3.10027900 } }),
-    ((13, 419), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.53069690, y: -0.24521641, z: 3.18529630 } }),
-    ((49, 440), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 2.02690720, y: -0.28486323, z: 2.50965760 } }),
-    ((18, 447), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.80467576, y: -0.03372591, z: 3.13675670 } }),
-    ((16, 449), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.71741486, y: -0.01001765, z: 3.15802120 } }),
-    ((40, 443), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.70795310, y: -0.16746627, z: 2.74640370 } }),
-    ((23, 20), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.98262995, y: 0.28176460, z: 3.07293490 } }),
-    ((29, 20), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.22635920, y: 0.35165286, z: 2.97661970 } }),
-    ((30, 429), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.26099720, y: -0.38071750, z: 2.95851700 } }),
-    ((12, 424), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.50488120, y: -0.19178638, z: 3.19314810 } }),
-    ((22, 36), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.85810130, y: 0.47174520, z: 3.08690790 } }),
-    ((18, 33), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.72139030, y: 0.35810125, z: 3.13675670 } }),
-    ((38, 3), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.63735280, y: 0.06862542, z: 2.79325060 } }),
-    ((38, 445), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.63479830, y: -0.11431623, z: 2.79325060 } }),
-    ((31, 17), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.32030750, y: 0.31941602, z: 2.93983750 } }),
-    ((17, 15), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.74486697, y: 0.15832636, z: 3.14769580 } }),
-    ((25, 21), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.06035790, y: 0.32014093, z: 3.04319450 } }),
-    ((48, 6), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 2.00453200, y: 0.16832528, z: 2.53799130 } }),
-    ((19, 437), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.83515020, y: -0.15327874, z: 3.12520600 } }),
-    ((13, 415), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.51618110, y: -0.27445835, z: 3.18529630 } }),
-    ((19, 0), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.84909964, y: 0.00000000, z: 3.12520600 } }),
-    ((34, 437), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.45599230, y: -0.26722458, z: 2.88037500 } }),
-    ((18, 424), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.75289196, y: -0.28599682, z: 3.13675670 } }),
-    ((14, 444), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.62682190, y: -0.05263570, z: 3.17682340 } }),
-    ((31, 4), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.35627750, y: 0.07582769, z: 2.93983750 } }),
-    ((21, 12), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.92292136, y: 0.15610047, z: 3.10027900 } }),
-    ((14, 13), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.61869395, y: 0.11355159, z: 3.17682340 } }),
-    ((14, 428), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.59958357, y: -0.19019910, z: 3.17682340 } }),
-    ((14, 11), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.62162330, y: 0.09623237, z: 3.17682340 } }),
-    ((14, 424), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.58803150, y: -0.22337220, z: 3.17682340 } }),
-    ((28, 29), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.13430170, y: 0.48616230, z: 2.99414230 } }),
-    ((33, 18), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.39471230, y: 0.35810125, z: 2.90076260 } }),
-    ((28, 0), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.23409640, y: 0.00000000, z: 2.99414230 } }),
-    ((25, 25), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.04083380, y: 0.37883255, z: 3.04319450 } }),
-    ((23, 47), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.80990180, y: 0.62370830, z: 3.07293490 } }),
-    ((20, 444), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 0.88952094, y: -0.07469516, z: 3.11304600 } }),
-    ((19, 24), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.80186963, y: 0.27924055, z: 3.12520600 } }),
-    ((51, 446), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 2.11280320, y: -0.11812403, z: 2.45152850 } }),
-    ((49, 445), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 2.04184100, y: -0.14277942, z: 2.50965760 } }),
-    ((34, 3), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.47901320, y: 0.06198902, z: 2.88037500 } }),
-    ((37, 20), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.53766370, y: 0.44091800, z: 2.81585930 } }),
-    ((13, 6), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.58256096, y: 0.04891901, z: 3.18529630 } }),
-    ((45, 2), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.90280030, y: 0.05315003, z: 2.62000160 } }),
-    ((27, 24), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.12585840, y: 0.39206540, z: 3.01108120 } }),
-    ((16, 428), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.68389980, y: -0.21694580, z: 3.15802120 } }),
-    ((34, 19), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.42852510, y: 0.38812170, z: 2.88037500 } }),
-    ((41, 442), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.74338900, y: -0.19555241, z: 2.72217500 } }),
-    ((23, 1), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.02212980, y: 0.01427255, z: 3.07293490 } }),
-    ((13, 422), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.54050000, y: -0.22277804, z: 3.18529630 } }),
-    ((19, 446), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.84777570, y: -0.04739802, z: 3.12520600 } }),
-    ((17, 29), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.69992864, y: 0.29998982, z: 3.14769580 } }),
-    ((43, 436), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.79480030, y: -0.35538003, z: 2.67213000 } }),
-    ((39, 12), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.65413700, y: 0.27977633, z: 2.77009730 } }),
-    ((13, 420), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.53406894, y: -0.23778282, z: 3.18529630 } }),
-    ((22, 8), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.97312230, y: 0.10915315, z: 3.08690790 } }),
-    ((38, 0), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.63879040, y: 0.00000000, z: 2.79325060 } }),
-    ((18, 46), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.64489480, y: 0.48244295, z: 3.13675670 } }),
-    ((18, 441), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.79903150, y: -0.10094116, z: 3.13675670 } }),
-    ((28, 20), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.18628970, y: 0.34016308, z: 2.99414230 } }),
-    ((15, 424), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.62943870, y: -0.23910134, z: 3.16773100 } }),
-    ((27, 448), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.19170650, y: -0.03328738, z: 3.01108120 } }),
-    ((18, 446), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.80412644, y: -0.04495764, z: 3.13675670 } }),
-    ((16, 5), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.71573704, y: 0.05004921, z: 3.15802120 } }),
-    ((34, 9), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.46863890, y: 0.18553224, z: 2.88037500 } }),
-    ((27, 29), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.09576680, y: 0.46964628, z: 3.01108120 } }),
-    ((32, 429), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.33958650, y: -0.40444500, z: 2.92058470 } }),
-    ((44, 5), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.86222860, y: 0.13021971, z: 2.64632370 } }),
-    ((16, 433), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.69736725, y: -0.16871089, z: 3.15802120 } }),
-    ((37, 446), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.59713650, y: -0.08929379, z: 2.81585930 } }),
-    ((14, 426), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.59403914, y: -0.20686631, z: 3.17682340 } }),
-    ((47, 444), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.96902510, y: -0.16534366, z: 2.56583000 } }),
-    ((21, 37), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.81387290, y: 0.46234414, z: 3.10027900 } }),
-    ((14, 439), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.62162330, y: -0.09623237, z: 3.17682340 } }),
-    ((13, 425), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.54935485, y: -0.19994882, z: 3.18529630 } }),
-    ((27, 35), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.05262480, y: 0.55969054, z: 3.01108120 } }),
-    ((31, 10), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.34517570, y: 0.18905212, z: 2.93983750 } }),
-    ((20, 32), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.80502224, y: 0.38570200, z: 3.11304600 } }),
-    ((18, 37), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.70027570, y: 0.39781198, z: 3.13675670 } }),
-    ((27, 21), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.14128890, y: 0.34457543, z: 3.01108120 } }),
-    ((38, 8), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.62857720, y: 0.18267421, z: 2.79325060 } }),
-    ((17, 422), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.70404893, y: -0.29018804, z: 3.14769580 } }),
-    ((25, 18), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.07283390, y: 0.27545694, z: 3.04319450 } }),
-    ((33, 26), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.34610320, y: 0.51133660, z: 2.90076260 } }),
-    ((28, 16), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.20342830, y: 0.27341220, z: 2.99414230 } }),
-    ((26, 447), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.14900520, y: -0.04815758, z: 3.02743300 } }),
-    ((20, 5), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.89047710, y: 0.06226823, z: 3.11304600 } }),
-    ((14, 430), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.60466045, y: -0.17338360, z: 3.17682340 } }),
-    ((32, 439), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.38283750, y: -0.21407457, z: 2.92058470 } }),
-    ((24, 25), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.00080510, y: 0.36426330, z: 3.05836300 } }),
-    ((22, 449), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 0.97912943, y: -0.01367211, z: 3.08690790 } }),
-    ((34, 448), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.47973450, y: -0.04133273, z: 2.88037500 } }),
-    ((31, 9), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.34768430, y: 0.17025211, z: 2.93983750 } }),
-    ((46, 442), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.92784810, y: -0.21624280, z: 2.59316850 } }),
-    ((28, 34), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.09762560, y: 0.56410290, z: 2.99414230 } }),
-    ((36, 21), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.49357090, y: 0.45093563, z: 2.83791920 } }),
-    ((12, 416), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.48035650, y: -0.24686971, z: 3.19314810 } }),
-    ((32, 24), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.32147500, y: 0.46018630, z: 2.92058470 } }),
-    ((45, 9), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.88853250, y: 0.23857714, z: 2.62000160 } }),
-    ((36, 19), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.50557950, y: 0.40905687, z: 2.83791920 } }),
-    ((37, 0), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.59963070, y: 0.00000000, z: 2.81585930 } }),
-    ((32, 445), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.39590110, y: -0.09761091, z: 2.92058470 } }),
-    ((35, 29), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.39743840, y: 0.59894280, z: 2.85942580 } }),
-    ((39, 445), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.67354380, y: -0.11702558, z: 2.77009730 } }),
-    ((35, 2), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.51979090, y: 0.04245160, z: 2.85942580 } }),
-    ((31, 441), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.34768430, y: -0.17025211, z: 2.93983750 } }),
-    ((26, 44), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.93972796, y: 0.66290516, z: 3.02743300 } }),
-    ((25, 1), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.10752430, y: 0.01546496, z: 3.04319450 } }),
-    ((18, 425), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.75681174, y: -0.27545694, z: 3.13675670 } }),
-    ((30, 27), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.22471700, y: 0.48489980, z: 2.95851700 } }),
-    ((18, 9), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.79903150, y: 0.10094116, z: 3.13675670 } }),
-    ((17, 21), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.72900620, y: 0.22009996, z: 3.14769580 } }),
-    ((32, 32), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.26194300, y: 0.60462177, z: 2.92058470 } }),
-    ((35, 25), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.42869330, y: 0.52000180, z: 2.85942580 } }),
-    ((47, 6), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.96902510, y: 0.16534366, z: 2.56583000 } }),
-    ((26, 34), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.02284120, y: 0.52566900, z: 3.02743300 } }),
-    ((14, 5), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.62749565, y: 0.04387877, z: 3.17682340 } }),
-    ((15, 57), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.47109872, y: 0.48107020, z: 3.16773100 } }),
-    ((37, 13), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.57335110, y: 0.28876397, z: 2.81585930 } }),
-    ((14, 448), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.62878270, y: -0.01756349, z: 3.17682340 } }),
-    ((15, 435), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.65860830, y: -0.13999152, z: 3.16773100 } }),
-    ((14, 417), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.56342775, y: -0.27968794, z: 3.17682340 } }),
-    ((38, 2), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.63815140, y: 0.04575771, z: 2.79325060 } }),
-    ((15, 436), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.66049870, y: -0.13078226, z: 3.16773100 } }),
-    ((16, 438), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.70743716, y: -0.11965405, z: 3.15802120 } }),
-    ((44, 449), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.86659410, y: -0.02606426, z: 2.64632370 } }),
-    ((31, 435), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.32871140, y: -0.28242633, z: 2.93983750 } }),
-    ((33, 449), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.43981060, y: -0.02010486, z: 2.90076260 } }),
-    ((45, 439), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.88113470, y: -0.29121506, z: 2.62000160 } }),
-    ((18, 20), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.77418303, y: 0.22199342, z: 3.13675670 } }),
-    ((24, 35), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.94036980, y: 0.50000346, z: 3.05836300 } }),
-    ((18, 26), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.75289196, y: 0.28599682, z: 3.13675670 } }),
-    ((45, 11), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.88113470, y: 0.29121506, z: 2.62000160 } }),
-    ((19, 442), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.84380800, y: -0.09464823, z: 3.12520600 } }),
-    ((43, 0), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.82964560, y: 0.00000000, z: 2.67213000 } }),
-    ((20, 33), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.79955850, y: 0.39690430, z: 3.11304600 } }),
-    ((41, 6), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.74816940, y: 0.14679788, z: 2.72217500 } }),
-    ((24, 40), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.90320057, y: 0.56438240, z: 3.05836300 } }),
-    ((25, 432), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.07283390, y: -0.27545694, z: 3.04319450 } }),
-    ((29, 432), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.23569990, y: -0.31727380, z: 2.97661970 } }),
-    ((34, 12), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.45958140, y: 0.24686971, z: 2.88037500 } }),
-    ((15, 419), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.61122656, y: -0.28242633, z: 3.16773100 } }),
-    ((28, 448), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.23361530, y: -0.03445799, z: 2.99414230 } }),
-    ((20, 34), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.79393893, y: 0.40802920, z: 3.11304600 } }),
-    ((42, 7), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.78360520, y: 0.17488402, z: 2.69741540 } }),
-    ((34, 436), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.45211940, y: -0.28752738, z: 2.88037500 } }),
-    ((27, 438), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.17547620, y: -0.19881693, z: 3.01108120 } }),
-    ((17, 447), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.76083976, y: -0.03188863, z: 3.14769580 } }),
-    ((12, 431), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.52118665, y: -0.14160329, z: 3.19314810 } }),
-    ((40, 16), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.67349620, y: 0.38020903, z: 2.74640370 } }),
-    ((16, 12), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.70743716, y: 0.11965405, z: 3.15802120 } }),
-    ((33, 1), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.43981060, y: 0.02010486, z: 2.90076260 } }),
-    ((19, 41), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.71372480, y: 0.45996425, z: 3.12520600 } }),
-    ((47, 9), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.96037410, y: 0.24765284, z: 2.56583000 } }),
-    ((40, 438), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.69211080, y: -0.28619910, z: 2.74640370 } }),
-    ((13, 443), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.58182114, y: -0.05704806, z: 3.18529630 } }),
-    ((23, 19), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.98646826, y: 0.26801750, z: 3.07293490 } }),
-    ((30, 2), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.31670310, y: 0.03677884, z: 2.95851700 } }),
-    ((42, 447), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.79058650, y: -0.07504781, z: 2.69741540 } }),
-    ((49, 4), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 2.04363540, y: 0.11425696, z: 2.50965760 } }),
-    ((16, 1), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.71741486, y: 0.01001765, z: 3.15802120 } }),
-    ((15, 448), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.67305946, y: -0.01880025, z: 3.16773100 } }),
-    ((49, 448), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 2.04602890, y: -0.05715076, z: 2.50965760 } }),
-    ((23, 45), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.82700100, y: 0.60085136, z: 3.07293490 } }),
-    ((25, 12), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.09212100, y: 0.18471844, z: 3.04319450 } }),
-    ((33, 12), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.41978600, y: 0.24013883, z: 2.90076260 } }),
-    ((38, 10), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.62284170, y: 0.22807553, z: 2.79325060 } }),
-    ((20, 441), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.88561270, y: -0.11187891, z: 3.11304600 } }),
-    ((25, 24), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.04602170, y: 0.36426330, z: 3.04319450 } }),
-    ((45, 435), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.86194550, y: -0.39576876, z: 2.62000160 } }),
-    ((20, 24), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.84299904, y: 0.29356334, z: 3.11304600 } }),
-    ((23, 14), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.00276120, y: 0.19855207, z: 3.07293490 } }),
-    ((29, 24), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.20481730, y: 0.41956177, z: 2.97661970 } }),
-    ((24, 432), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.03157460, y: -0.26486334, z: 3.05836300 } }),
-    ((32, 2), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.39876410, y: 0.03907102, z: 2.92058470 } }),
-    ((50, 449), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 2.08146480, y: -0.02906462, z: 2.48083500 } }),
-    ((31, 2), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.35786590, y: 0.03792863, z: 2.93983750 } }),
-    ((34, 8), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.47108630, y: 0.16500875, z: 2.88037500 } }),
-    ((26, 25), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.08065960, y: 0.39332792, z: 3.02743300 } }),
-    ((19, 436), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.83292866, y: -0.16492432, z: 3.12520600 } }),
-    ((13, 423), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.54355780, y: -0.21520975, z: 3.18529630 } }),
-    ((25, 29), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.01806390, y: 0.43634275, z: 3.04319450 } }),
-    ((33, 25), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.35311140, y: 0.49249226, z: 2.90076260 } }),
-    ((20, 447), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.89186860, y: -0.03738037, z: 3.11304600 } }),
-    ((26, 21), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.10093070, y: 0.33239058, z: 3.02743300 } }),
-    ((49, 444), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 2.03964830, y: -0.17127407, z: 2.50965760 } }),
-    ((20, 429), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.85455275, y: -0.25800470, z: 3.11304600 } }),
-    ((27, 446), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.19031250, y: -0.06654880, z: 3.01108120 } }),
-    ((20, 16), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.87046860, y: 0.19776562, z: 3.11304600 } }),
-    ((14, 436), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.61704820, y: -0.12217884, z: 3.17682340 } }),
-    ((47, 1), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.97576250, y: 0.02758864, z: 2.56583000 } }),
-    ((36, 24), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.47337760, y: 0.51308435, z: 2.83791920 } }),
-    ((50, 0), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 2.08166770, y: 0.00000000, z: 2.48083500 } }),
-    ((22, 442), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 0.97312230, y: -0.10915315, z: 3.08690790 } }),
-    ((26, 435), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.12488330, y: -0.23910134, z: 3.02743300 } }),
-    ((31, 6), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.35363150, y: 0.11366761, z: 2.93983750 } }),
-    ((13, 1), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.58455426, y: 0.00816245, z: 3.18529630 } }),
-    ((28, 9), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.22436520, y: 0.15467330, z: 2.99414230 } }),
-    ((42, 444), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.78587320, y: -0.14996396, z: 2.69741540 } }),
-    ((45, 445), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.89890560, y: -0.13278441, z: 2.62000160 } }),
-    ((25, 37), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.96308060, y: 0.54710590, z: 3.04319450 } }),
-    ((29, 428), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.21606240, y: -0.38575770, z: 2.97661970 } }),
-    ((16, 448), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.71720505, y: -0.02003335, z: 3.15802120 } }),
-    ((38, 12), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.61584080, y: 0.27329900, z: 2.79325060 } }),
-    ((21, 19), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.90328390, y: 0.24541680, z: 3.10027900 } }),
-    ((39, 440), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.66130390, y: -0.23348103, z: 2.77009730 } }),
-    ((19, 435), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.83054477, y: -0.17653775, z: 3.12520600 } }),
-    ((17, 8), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.75676197, y: 0.08488446, z: 3.14769580 } }),
-    ((37, 430), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.53766370, y: -0.44091800, z: 2.81585930 } }),
-    ((27, 13), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.17258570, y: 0.21520975, z: 3.01108120 } }),
-    ((21, 18), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.90662240, y: 0.23278107, z: 3.10027900 } }),
-    ((39, 435), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.64097020, y: -0.34879900, z: 2.77009730 } }),
-    ((39, 3), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.67615890, y: 0.07025188, z: 2.77009730 } }),
-    ((22, 9), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.97150344, y: 0.12272942, z: 3.08690790 } }),
-    ((22, 440), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 0.96969515, y: -0.13628176, z: 3.08690790 } }),
-    ((34, 1), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.48016740, y: 0.02066838, z: 2.88037500 } }),
-    ((21, 22), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.89221454, y: 0.28302714, z: 3.10027900 } }),
-    ((15, 17), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.65444270, y: 0.15832636, z: 3.16773100 } }),
-    ((22, 446), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 0.97769800, y: -0.05466181, z: 3.08690790 } }),
-    ((35, 18), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.47261800, y: 0.37810403, z: 2.85942580 } }),
-    ((35, 13), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.49540600, y: 0.27445835, z: 2.85942580 } }),
-    ((35, 23), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.44265560, y: 0.47990763, z: 2.85942580 } }),
-    ((26, 24), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.08604600, y: 0.37820122, z: 3.02743300 } }),
-    ((22, 13), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.96313770, y: 0.17676884, z: 3.08690790 } }),
-    ((15, 426), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.63586940, y: -0.22143315, z: 3.16773100 } }),
-    ((37, 4), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.59713650, y: 0.08929379, z: 2.81585930 } }),
-    ((19, 6), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.84612170, y: 0.07105083, z: 3.12520600 } }),
-    ((19, 39), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.72628950, y: 0.43985656, z: 3.12520600 } }),
-    ((19, 26), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.79376020, y: 0.30152120, z: 3.12520600 } }),
-    ((18, 437), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.79215100, y: -0.14538690, z: 3.13675670 } }),
-    ((29, 442), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.26783010, y: -0.14220993, z: 2.97661970 } }),
-    ((33, 446), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.43770580, y: -0.08038023, z: 2.90076260 } }),
-    ((14, 8), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.62510777, y: 0.07011707, z: 3.17682340 } }),
-    ((25, 42), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.92257040, y: 0.61295430, z: 3.04319450 } }),
-    ((24, 444), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.06129940, y: -0.08911980, z: 3.05836300 } }),
-    ((33, 13), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.41629470, y: 0.25993872, z: 2.90076260 } }),
-    ((34, 10), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.46590530, y: 0.20601957, z: 2.88037500 } }),
-    ((35, 8), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.51090850, y: 0.16947554, z: 2.85942580 } }),
-    ((49, 435), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 2.00209880, y: -0.42555925, z: 2.50965760 } }),
-    ((49, 441), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 2.03068700, y: -0.25653544, z: 2.50965760 } }),
-    ((48, 437), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.97853970, y: -0.36312997, z: 2.53799130 } }),
-    ((28, 22), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.17632910, y: 0.37315360, z: 2.99414230 } }),
-    ((41, 8), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.74338900, y: 0.19555241, z: 2.72217500 } }),
-    ((32, 435), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.36873140, y: -0.29093283, z: 2.92058470 } }),
-    ((18, 41), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.67697740, y: 0.43628216, z: 3.13675670 } }),
-    ((24, 41), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.89523256, y: 0.57693803, z: 3.05836300 } }),
-    ((28, 12), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.21681420, y: 0.20580871, z: 2.99414230 } }),
-    ((44, 1), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.86659410, y: 0.02606426, z: 2.64632370 } }),
-    ((37, 442), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.58966170, y: -0.17830913, z: 2.81585930 } }),
-    ((31, 33), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.21673080, y: 0.60399044, z: 2.93983750 } }),
-    ((29, 445), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.27267310, y: -0.08899397, z: 2.97661970 } }),
-    ((29, 440), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.26336500, y: -0.17755438, z: 2.97661970 } }),
-    ((17, 6), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.75883704, y: 0.06372132, z: 3.14769580 } }),
-    ((14, 58), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.43378997, y: 0.45552433, z: 3.17682340 } }),
-    ((19, 33), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.76054853, y: 0.37753958, z: 3.12520600 } }),
-    ((18, 27), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.74882543, y: 0.29648095, z: 3.13675670 } }),
-    ((33, 30), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.31546070, y: 0.58568084, z: 2.90076260 } }),
-    ((15, 422), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.62251717, y: -0.25658306, z: 3.16773100 } }),
-    ((25, 34), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.98514620, y: 0.50629640, z: 3.04319450 } }),
-    ((39, 9), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.66440190, y: 0.21026285, z: 2.77009730 } }),
-    ((22, 21), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.93743110, y: 0.28302714, z: 3.08690790 } }),
-    ((38, 440), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.62284170, y: -0.22807553, z: 2.79325060 } }),
-    ((30, 436), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.29213050, y: -0.25584870, z: 2.95851700 } }),
-    ((32, 6), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.39440210, y: 0.11709122, z: 2.92058470 } }),
-    ((39, 18), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.62492470, y: 0.41720974, z: 2.77009730 } }),
-    ((29, 35), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.12644770, y: 0.59894280, z: 2.97661970 } }),
-    ((26, 438), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.13390910, y: -0.19178638, z: 3.02743300 } }),
-    ((38, 446), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.63623510, y: -0.09147974, z: 2.79325060 } }),
-    ((44, 7), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.85786660, y: 0.18216541, z: 2.64632370 } }),
-    ((20, 448), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.89230350, y: -0.02492429, z: 3.11304600 } }),
-    ((26, 22), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.09618250, y: 0.34772956, z: 3.02743300 } }),
-    ((47, 7), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.96652470, y: 0.19281943, z: 2.56583000 } }),
-    ((30, 428), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.25555860, y: -0.39828664, z: 2.95851700 } }),
-    ((27, 449), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.19205510, y: -0.01664531, z: 3.01108120 } }),
-    ((28, 433), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.19949350, y: -0.29018804, z: 2.99414230 } }),
-    ((36, 4), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.55772660, y: 0.08709044, z: 2.83791920 } }),
-    ((19, 431), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.81939520, y: -0.22262473, z: 3.12520600 } }),
-    ((41, 7), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.74594940, y: 0.17119183, z: 2.72217500 } }),
-    ((24, 449), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.06493080, y: -0.01487021, z: 3.05836300 } }),
-    ((28, 13), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.21382200, y: 0.22277804, z: 2.99414230 } }),
-    ((49, 8), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 2.03407100, y: 0.22815761, z: 2.50965760 } }),
-    ((28, 31), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.12028490, y: 0.51764430, z: 2.99414230 } }),
-    ((16, 425), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.67421520, y: -0.24539426, z: 3.15802120 } }),
-    ((16, 427), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.68080413, y: -0.22647339, z: 3.15802120 } }),
-    ((36, 449), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.56000720, y: -0.02178322, z: 2.83791920 } }),
-    ((28, 18), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.19532500, y: 0.30690730, z: 2.99414230 } }),
-    ((37, 435), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.56467500, y: -0.33258194, z: 2.81585930 } }),
-    ((26, 29), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.05701840, y: 0.45303866, z: 3.02743300 } }),
-    ((17, 25), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.71558320, y: 0.26045100, z: 3.14769580 } }),
-    ((37, 1), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.59947480, y: 0.02233433, z: 2.81585930 } }),
-    ((48, 4), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 2.00845050, y: 0.11228982, z: 2.53799130 } }),
-    ((32, 5), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.39590110, y: 0.09761091, z: 2.92058470 } }),
-    ((36, 1), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.56000720, y: 0.02178322, z: 2.83791920 } }),
-    ((38, 1), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.63863060, y: 0.02288109, z: 2.79325060 } }),
-    ((21, 14), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.91820294, y: 0.18180908, z: 3.10027900 } }),
-    ((26, 12), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.13390910, y: 0.19178638, z: 3.02743300 } }),
-    ((18, 22), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.76768280, y: 0.24352333, z: 3.13675670 } }),
-    ((36, 22), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.48712920, y: 0.47174520, z: 2.83791920 } }),
-    ((27, 430), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.14598870, y: -0.32860696, z: 3.01108120 } }),
-    ((21, 20), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.89976930, y: 0.25800470, z: 3.10027900 } }),
-    ((38, 436), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.60757980, y: -0.31830937, z: 2.79325060 } }),
-    ((19, 46), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.67990070, y: 0.50863075, z: 3.12520600 } }),
-    ((33, 23), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.36633500, y: 0.45451915, z: 2.90076260 } }),
-    ((31, 449), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.35826310, y: -0.01896616, z: 2.93983750 } }),
-    ((46, 0), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.93993800, y: 0.00000000, z: 2.59316850 } }),
-    ((27, 42), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.99298483, y: 0.65973760, z: 3.01108120 } }),
-    ((43, 438), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.80402340, y: -0.30512770, z: 2.67213000 } }),
-    ((22, 431), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 0.94496816, y: -0.25674218, z: 3.08690790 } }),
-    ((37, 3), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.59822760, y: 0.06698559, z: 2.81585930 } }),
-    ((40, 441), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.70261120, y: -0.21508981, z: 2.74640370 } }),
-    ((19, 439), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.83910435, y: -0.12990022, z: 3.12520600 } }),
-    ((26, 41), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.96666330, y: 0.62297195, z: 3.02743300 } }),
-    ((12, 430), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.51915880, y: -0.14886639, z: 3.19314810 } }),
-    ((46, 9), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.92464090, y: 0.24313869, z: 2.59316850 } }),
-    ((49, 9), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 2.03068700, y: 0.25653544, z: 2.50965760 } }),
-    ((37, 22), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.52475310, y: 0.48368016, z: 2.81585930 } }),
-    ((31, 24), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.28283670, y: 0.44673100, z: 2.93983750 } }),
-    ((13, 439), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.57772940, y: -0.08943724, z: 3.18529630 } }),
-    ((16, 434), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.69965490, y: -0.15895768, z: 3.15802120 } }),
-    ((45, 443), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.89445770, y: -0.18575320, z: 2.62000160 } }),
-    ((22, 28), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.90533847, y: 0.37315360, z: 3.08690790 } }),
-    ((17, 31), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.69127960, y: 0.31941602, z: 3.14769580 } }),
-    ((17, 427), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.72257644, y: -0.24036919, z: 3.14769580 } }),
-    ((29, 8), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.26783010, y: 0.14220993, z: 2.97661970 } }),
-    ((16, 445), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.71573704, y: -0.05004921, z: 3.15802120 } }),
-    ((19, 2), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.84876860, y: 0.02370825, z: 3.12520600 } }),
-    ((20, 14), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.87565120, y: 0.17338360, z: 3.11304600 } }),
-    ((41, 10), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.73724910, y: 0.24415444, z: 2.72217500 } }),
-    ((38, 442), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.62857720, y: -0.18267421, z: 2.79325060 } }),
-    ((18, 15), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.78778267, y: 0.16744837, z: 3.13675670 } }),
-    ((30, 22), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.25555860, y: 0.39828664, z: 2.95851700 } }),
-    ((23, 438), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.00791420, y: -0.17047592, z: 3.07293490 } }),
-    ((23, 0), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.02222940, y: 0.00000000, z: 3.07293490 } }),
-    ((51, 449), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 2.11589650, y: -0.02954541, z: 2.45152850 } }),
-    ((23, 17), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.99356710, y: 0.24036919, z: 3.07293490 } }),
-    ((26, 18), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.11388410, y: 0.28599682, z: 3.02743300 } }),
-    ((13, 440), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.57892185, y: -0.08136216, z: 3.18529630 } }),
-    ((29, 0), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.27578090, y: 0.00000000, z: 2.97661970 } }),
-    ((28, 443), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.22820650, y: -0.12042671, z: 2.99414230 } }),
-    ((48, 449), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 2.01139100, y: -0.02808614, z: 2.53799130 } }),
-    ((15, 5), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.67168180, y: 0.04696857, z: 3.16773100 } }),
-    ((28, 30), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.12740310, y: 0.50195223, z: 2.99414230 } }),
-    ((28, 1), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.23397610, y: 0.01723068, z: 2.99414230 } }),
-    ((16, 2), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.71720505, y: 0.02003335, z: 3.15802120 } }),
-    ((29, 6), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.27130650, y: 0.10675459, z: 2.97661970 } }),
-    ((27, 0), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.19217130, y: 0.00000000, z: 3.01108120 } }),
-    ((28, 4), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.23217210, y: 0.06888912, z: 2.99414230 } }),
-    ((14, 425), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.59109294, y: -0.21514022, z: 3.17682340 } }),
-    ((27, 19), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.15046500, y: 0.31257442, z: 3.01108120 } }),
-    ((33, 427), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.36633500, y: -0.45451915, z: 2.90076260 } }),
-    ((25, 38), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.95534796, y: 0.56049930, z: 3.04319450 } }),
-    ((31, 448), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.35786590, y: -0.03792863, z: 2.93983750 } }),
-    ((35, 14), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.49142810, y: 0.29531074, z: 2.85942580 } }),
-    ((35, 440), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.50558730, y: -0.21159650, z: 2.85942580 } }),
-    ((18, 428), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.76768280, y: -0.24352333, z: 3.13675670 } }),
-    ((15, 14), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.66049870, y: 0.13078226, z: 3.16773100 } }),
-    ((24, 33), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.95396405, y: 0.47355187, z: 3.05836300 } }),
-    ((29, 12), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.25791490, y: 0.21276037, z: 2.97661970 } }),
-    ((40, 11), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.69594180, y: 0.26254565, z: 2.74640370 } }),
-    ((36, 443), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.55271330, y: -0.15224487, z: 2.83791920 } }),
-    ((27, 6), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.18799030, y: 0.09975833, z: 3.01108120 } }),
-    ((29, 446), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.27379170, y: -0.07121601, z: 2.97661970 } }),
-    ((29, 449), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.27565650, y: -0.01781268, z: 2.97661970 } }),
-    ((34, 27), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.37635900, y: 0.54493904, z: 2.88037500 } }),
-    ((31, 0), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.35839560, y: 0.00000000, z: 2.93983750 } }),
-    ((24, 13), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.04753770, y: 0.19225915, z: 3.05836300 } }),
-    ((14, 434), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.61339617, y: -0.13936019, z: 3.17682340 } }),
-    ((28, 8), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.22640540, y: 0.13756340, z: 2.99414230 } }),
-    ((20, 42), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.74350846, y: 0.49398583, z: 3.11304600 } }),
-    ((15, 6), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.67096060, y: 0.05634213, z: 3.16773100 } }),
-    ((25, 13), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.08943550, y: 0.19994882, z: 3.04319450 } }),
-    ((28, 38), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.06442500, y: 0.62449443, z: 2.99414230 } }),
-    ((12, 449), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.54002790, y: -0.00754070, z: 3.19314810 } }),
-    ((27, 11), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.17813750, y: 0.18238534, z: 3.01108120 } }),
-    ((37, 27), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.48729910, y: 0.58886340, z: 2.81585930 } }),
-    ((33, 424), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.34610320, y: -0.51133660, z: 2.90076260 } }),
-    ((33, 21), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.37849320, y: 0.41619164, z: 2.90076260 } }),
-    ((25, 436), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.08653760, y: -0.21514022, z: 3.04319450 } }),
-    ((32, 14), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.37266000, y: 0.27179402, z: 2.92058470 } }),
-    ((24, 21), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.01957850, y: 0.30782887, z: 3.05836300 } }),
-    ((18, 44), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.65811396, y: 0.46424833, z: 3.13675670 } }),
-    ((18, 3), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.80467576, y: 0.03372591, z: 3.13675670 } }),
-    ((15, 11), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.66539590, y: 0.10300873, z: 3.16773100 } }),
-    ((45, 436), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.86728990, y: -0.36973336, z: 2.62000160 } }),
-    ((42, 438), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.76706120, y: -0.29887605, z: 2.69741540 } }),
-    ((17, 443), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.75787336, y: -0.07431013, z: 3.14769580 } }),
-    ((24, 441), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.05663660, y: -0.13348423, z: 3.05836300 } }),
-    ((39, 444), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.67174670, y: -0.14038050, z: 2.77009730 } }),
-    ((25, 2), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.10720040, y: 0.03092691, z: 3.04319450 } }),
-    ((28, 19), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.19092350, y: 0.32356673, z: 2.99414230 } }),
-    ((24, 46), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.85280660, y: 0.63798090, z: 3.05836300 } }),
-    ((51, 438), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 2.08646870, y: -0.35289980, z: 2.45152850 } }),
-    ((36, 20), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.49972140, y: 0.43003818, z: 2.83791920 } }),
-    ((16, 430), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.68969065, y: -0.19776562, z: 3.15802120 } }),
-    ((51, 441), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 2.09941650, y: -0.26521800, z: 2.45152850 } }),
-    ((17, 3), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.76083976, y: 0.03188863, z: 3.14769580 } }),
-    ((30, 430), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.26618990, y: -0.36307410, z: 2.95851700 } }),
-    ((48, 434), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.96159780, y: -0.44566410, z: 2.53799130 } }),
-    ((33, 7), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.43307880, y: 0.14051460, z: 2.90076260 } }),
-    ((31, 443), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.35191250, y: -0.13255619, z: 2.93983750 } }),
-    ((12, 429), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.51702970, y: -0.15610047, z: 3.19314810 } }),
-    ((13, 445), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.58318716, y: -0.04078042, z: 3.18529630 } }),
-    ((29, 437), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.25482170, y: -0.23030287, z: 2.97661970 } }),
-    ((32, 25), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.31492100, y: 0.47859210, z: 2.92058470 } }),
-    ((21, 39), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.80064620, y: 0.48488855, z: 3.10027900 } }),
-    ((19, 438), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.83720887, y: -0.14160329, z: 3.12520600 } }),
-    ((51, 445), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 2.11094780, y: -0.14761186, z: 2.45152850 } }),
-    ((17, 14), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.74700490, y: 0.14791097, z: 3.14769580 } }),
-    ((24, 435), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.04176100, y: -0.22143315, z: 3.05836300 } }),
-    ((47, 3), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.97422180, y: 0.08274441, z: 2.56583000 } }),
-    ((28, 10), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.22208630, y: 0.17175302, z: 2.99414230 } }),
-    ((31, 437), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.33607910, y: -0.24521641, z: 2.93983750 } }),
-    ((30, 12), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.29877030, y: 0.21967056, z: 2.95851700 } }),
-    ((45, 446), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.90057440, y: -0.10625861, z: 2.62000160 } }),
-    ((29, 9), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.26572100, y: 0.15989774, z: 2.97661970 } }),
-    ((15, 443), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.67010850, y: -0.06570471, z: 3.16773100 } }),
-    ((49, 436), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 2.00784540, y: -0.39756408, z: 2.50965760 } }),
-    ((51, 435), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 2.06986070, y: -0.43996248, z: 2.45152850 } }),
-    ((37, 19), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.54367000, y: 0.41940588, z: 2.81585930 } }),
-    ((20, 18), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.86460730, y: 0.22199342, z: 3.11304600 } }),
-    ((40, 442), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.70544840, y: -0.19129668, z: 2.74640370 } }),
-    ((25, 14), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.08653760, y: 0.21514022, z: 3.04319450 } }),
-    ((35, 447), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.51905000, y: -0.06366706, z: 2.85942580 } }),
-    ((47, 441), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.96037410, y: -0.24765284, z: 2.56583000 } }),
-    ((31, 439), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.34240510, y: -0.20781529, z: 2.93983750 } }),
-    ((45, 5), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.89890560, y: 0.13278441, z: 2.62000160 } }),
-    ((43, 439), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.80810770, y: -0.27990988, z: 2.67213000 } }),
-    ((39, 6), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.67174670, y: 0.14038050, z: 2.77009730 } }),
-    ((21, 29), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.86033780, y: 0.36874124, z: 3.10027900 } }),
-    ((32, 427), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.32777140, y: -0.44169077, z: 2.92058470 } }),
-    ((30, 24), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.24394820, y: 0.43318862, z: 2.95851700 } }),
-    ((35, 448), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.51979090, y: -0.04245160, z: 2.85942580 } }),
-    ((12, 417), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.48375654, y: -0.24013883, z: 3.19314810 } }),
-    ((38, 448), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.63815140, y: -0.04575771, z: 2.79325060 } }),
-    ((27, 427), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.13122280, y: -0.37630776, z: 3.01108120 } }),
-    ((28, 441), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.22436520, y: -0.15467330, z: 2.99414230 } }),
-    ((28, 427), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.17100450, y: -0.38954136, z: 2.99414230 } }),
-    ((27, 8), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.18474160, y: 0.13289008, z: 3.01108120 } }),
-    ((19, 19), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.81939520, y: 0.22262473, z: 3.12520600 } }),
-    ((30, 3), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.31606110, y: 0.05515931, z: 2.95851700 } }),
-    ((17, 424), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.71187700, y: -0.27041670, z: 3.14769580 } }),
-    ((35, 10), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.50558730, y: 0.21159650, z: 2.85942580 } }),
-    ((42, 15), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.75299560, y: 0.37261072, z: 2.69741540 } }),
-    ((41, 14), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.72091130, y: 0.34074960, z: 2.72217500 } }),
-    ((26, 449), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.14990170, y: -0.01605670, z: 3.02743300 } }),
-    ((26, 26), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.07506250, y: 0.40837795, z: 3.02743300 } }),
-    ((23, 431), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 0.98646826, y: -0.26801750, z: 3.07293490 } }),
-    ((36, 23), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.48039770, y: 0.49246278, z: 2.83791920 } }),
-    ((31, 27), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.26300430, y: 0.50005877, z: 2.93983750 } }),
-    ((17, 28), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.70404893, y: 0.29018804, z: 3.14769580 } }),
-    ((46, 440), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.92105850, y: -0.26998717, z: 2.59316850 } }),
-    ((40, 9), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.70261120, y: 0.21508981, z: 2.74640370 } }),
-    ((16, 51), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.54313240, y: 0.46881935, z: 3.15802120 } }),
-    ((24, 3), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.06410040, y: 0.04459902, z: 3.05836300 } }),
-    ((26, 17), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.11776860, y: 0.27041670, z: 3.02743300 } }),
-    ((22, 24), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.92475680, y: 0.32203442, z: 3.08690790 } }),
-    ((34, 435), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.44796320, y: -0.30777410, z: 2.88037500 } }),
-    ((24, 34), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.94725925, y: 0.48682514, z: 3.05836300 } }),
-    ((19, 35), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.74971050, y: 0.39862815, z: 3.12520600 } }),
-    ((34, 430), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.42296680, y: -0.40802920, z: 2.88037500 } }),
-    ((42, 4), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.78936410, y: 0.10004099, z: 2.69741540 } }),
-    ((42, 13), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.76271600, y: 0.32351893, z: 2.69741540 } }),
-    ((36, 12), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.53831090, y: 0.26018580, z: 2.83791920 } }),
-    ((22, 436), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 0.96057570, y: -0.19019910, z: 3.08690790 } }),
-    ((21, 428), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.89221454, y: -0.28302714, z: 3.10027900 } }),
-    ((27, 441), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.18277070, y: -0.14941870, z: 3.01108120 } }),
-    ((32, 23), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.32777140, y: 0.44169077, z: 2.92058470 } }),
-    ((35, 445), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.51668010, y: -0.10605660, z: 2.85942580 } }),
-    ((21, 23), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.88817590, y: 0.29545680, z: 3.10027900 } }),
-    ((31, 13), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.33607910, y: 0.24521641, z: 2.93983750 } }),
-    ((19, 13), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.83515020, y: 0.15327874, z: 3.12520600 } }),
-    ((32, 434), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.36453590, y: -0.31001496, z: 2.92058470 } }),
-    ((27, 440), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.18056930, y: -0.16591819, z: 3.01108120 } }),
-    ((12, 426), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.51003930, y: -0.17761450, z: 3.19314810 } }),
-    ((15, 0), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.67332200, y: 0.00000000, z: 3.16773100 } }),
-    ((26, 43), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.94889200, y: 0.64971990, z: 3.02743300 } }),
-    ((20, 40), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.75701150, y: 0.47303328, z: 3.11304600 } }),
-    ((26, 444), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.14598060, y: -0.09623069, z: 3.02743300 } }),
-    ((49, 2), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 2.04602890, y: 0.05715076, z: 2.50965760 } }),
-    ((19, 37), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.73828787, y: 0.41940588, z: 3.12520600 } }),
-    ((26, 446), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.14822080, y: -0.06419551, z: 3.02743300 } }),
-    ((25, 44), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.90509605, y: 0.63847500, z: 3.04319450 } }),
-    ((26, 436), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.12811210, y: -0.22337220, z: 3.02743300 } }),
-    ((35, 12), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.49909220, y: 0.25355247, z: 2.85942580 } }),
-    ((25, 0), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.10763230, y: 0.00000000, z: 3.04319450 } }),
-    ((24, 1), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.06493080, y: 0.01487021, z: 3.05836300 } }),
-    ((17, 27), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.70803200, y: 0.28032970, z: 3.14769580 } }),
-    ((46, 5), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.93521240, y: 0.13532323, z: 2.59316850 } }),
-    ((21, 2), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 0.93566453, y: 0.02613548, z: 3.10027900 } }),
-    ((28, 432), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.19532500, y: -0.30690730, z: 2.99414230 } }),
-    ((44, 447), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.86513850, y: -0.07817247, z: 2.64632370 } }),
-    ((32, 447), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.39808230, y: -0.05859701, z: 2.92058470 } }),
-    ((43, 10), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.81183970, y: 0.25463745, z: 2.67213000 } }),
-    ((25, 23), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.05100560, y: 0.34962305, z: 3.04319450 } }),
-    ((51, 436), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 2.07580180, y: -0.41101983, z: 2.45152850 } }),
-    ((17, 435), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.74486697, y: -0.15832636, z: 3.14769580 } }),
-    ((31, 12), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.33937260, y: 0.22653793, z: 2.93983750 } }),
-    ((35, 28), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.40566470, y: 0.57937320, z: 2.85942580 } }),
-    ((30, 427), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.24987530, y: -0.41577816, z: 2.95851700 } }),
-    ((13, 3), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.58409846, y: 0.02448098, z: 3.18529630 } }),
-    ((40, 5), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.71196300, y: 0.11971212, z: 2.74640370 } }),
-    ((15, 58), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.46433600, y: 0.48760086, z: 3.16773100 } }),
-    ((45, 447), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.90187280, y: -0.07971209, z: 2.62000160 } }),
-    ((32, 8), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.39058910, y: 0.15597956, z: 2.92058470 } }),
-    ((27, 16), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.16254510, y: 0.26412377, z: 3.01108120 } }),
-    ((26, 37), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.99993130, y: 0.56804000, z: 3.02743300 } }),
-    ((24, 437), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.04753770, y: -0.19225915, z: 3.05836300 } }),
-    ((46, 1), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.93974890, y: 0.02708576, z: 2.59316850 } }),
-    ((14, 420), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.57464564, y: -0.25584870, z: 3.17682340 } }),
-    ((50, 438), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 2.05251600, y: -0.34715712, z: 2.48083500 } }),
-    ((17, 4), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.76032037, y: 0.04250851, z: 3.14769580 } }),
-    ((26, 40), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.97526710, y: 0.60941450, z: 3.02743300 } }),
-    ((39, 438), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.65413700, y: -0.27977633, z: 2.77009730 } }),
-    ((39, 442), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.66717530, y: -0.18700367, z: 2.77009730 } }),
-    ((30, 21), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.26099720, y: 0.38071750, z: 2.95851700 } }),
-    ((16, 442), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.71301340, y: -0.07997727, z: 3.15802120 } }),
-    ((25, 45), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.89609330, y: 0.65104990, z: 3.04319450 } }),
-    ((22, 441), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 0.97150344, y: -0.12272942, z: 3.08690790 } }),
-    ((36, 433), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.51641390, y: -0.36685914, z: 2.83791920 } }),
-    ((22, 6), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 0.97579060, y: 0.08193943, z: 3.08690790 } }),
-    ((29, 33), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.14273190, y: 0.56725705, z: 2.97661970 } }),
-    ((23, 436), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.00276120, y: -0.19855207, z: 3.07293490 } }),
-    ((41, 1), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.75415100, y: 0.02449416, z: 2.72217500 } }),
-    ((43, 445), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.82518880, y: -0.12762962, z: 2.67213000 } }),
-    ((34, 20), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.42296680, y: 0.40802920, z: 2.88037500 } }),
-    ((19, 4), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.84777570, y: 0.04739802, z: 3.12520600 } }),
-    ((19, 15), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.83054477, y: 0.17653775, z: 3.12520600 } }),
-    ((16, 56), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.50910620, y: 0.50556433, z: 3.15802120 } }),
-    ((17, 49), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.59012620, y: 0.48129520, z: 3.14769580 } }),
-    ((48, 0), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 2.01158710, y: 0.00000000, z: 2.53799130 } }),
-    ((19, 425), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.79789270, y: -0.29040918, z: 3.12520600 } }),
-    ((42, 437), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.76271600, y: -0.32351893, z: 2.69741540 } }),
-    ((15, 449), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.67325640, y: -0.00940104, z: 3.16773100 } }),
-    ((24, 443), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.05995170, y: -0.10392917, z: 3.05836300 } }),
-    ((40, 449), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.71597620, y: -0.02396111, z: 2.74640370 } }),
-    ((31, 445), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.35508660, y: -0.09475689, z: 2.93983750 } }),
-    ((34, 426), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.39797130, y: -0.48682514, z: 2.88037500 } }),
-    ((17, 51), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.57645756, y: 0.49758485, z: 3.14769580 } }),
-    ((24, 6), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.06129940, y: 0.08911980, z: 3.05836300 } }),
-    ((39, 0), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.67763040, y: 0.00000000, z: 2.77009730 } }),
-    ((14, 440), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.62290627, y: -0.08754377, z: 3.17682340 } }),
-    ((38, 6), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.63304290, y: 0.13713044, z: 2.79325060 } }),
-    ((19, 432), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.82242364, y: -0.21116250, z: 3.12520600 } }),
-    ((49, 447), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 2.04503150, y: -0.08571222, z: 2.50965760 } }),
-    ((42, 441), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.77802680, y: -0.22461702, z: 2.69741540 } }),
-    ((23, 27), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.95044490, y: 0.37630776, z: 3.07293490 } }),
-    ((34, 29), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.36060680, y: 0.58315676, z: 2.88037500 } }),
-    ((39, 1), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.67746700, y: 0.02342338, z: 2.77009730 } }),
-    ((19, 441), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.84240425, y: -0.10642040, z: 3.12520600 } }),
-    ((31, 427), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.28894900, y: -0.42877626, z: 2.93983750 } }),
-    ((27, 437), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.17258570, y: -0.21520975, z: 3.01108120 } }),
-    ((22, 20), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.94129140, y: 0.26991096, z: 3.08690790 } }),
-    ((32, 426), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.32147500, y: -0.46018630, z: 2.92058470 } }),
-    ((44, 11), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.84480110, y: 0.28559030, z: 2.64632370 } }),
-    ((21, 35), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.82646495, y: 0.43943920, z: 3.10027900 } }),
-    ((37, 441), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.58701720, y: -0.20048690, z: 2.81585930 } }),
-    ((36, 0), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.56015930, y: 0.00000000, z: 2.83791920 } }),
-    ((28, 446), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.23217210, y: -0.06888912, z: 2.99414230 } }),
-    ((42, 449), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.79198380, y: -0.02502244, z: 2.69741540 } }),
-    ((40, 4), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.71346770, y: 0.09579772, z: 2.74640370 } }),
-    ((25, 4), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.10590520, y: 0.06182970, z: 3.04319450 } }),
-    ((14, 12), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.62021905, y: 0.10490220, z: 3.17682340 } }),
-    ((13, 0), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.58461124, y: 0.00000000, z: 3.18529630 } }),
-    ((37, 431), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.54367000, y: -0.41940588, z: 2.81585930 } }),
-    ((18, 48), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.63117280, y: 0.50026137, z: 3.13675670 } }),
-    ((19, 38), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.73236006, y: 0.42967310, z: 3.12520600 } }),
-    ((34, 440), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.46590530, y: -0.20601957, z: 2.88037500 } }),
-    ((31, 426), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.28283670, y: -0.44673100, z: 2.93983750 } }),
-    ((30, 11), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.30171080, y: 0.20151548, z: 2.95851700 } }),
-    ((26, 4), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.14822080, y: 0.06419551, z: 3.02743300 } }),
-    ((26, 428), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.09618250, y: -0.34772956, z: 3.02743300 } }),
-    ((39, 446), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.67501460, y: -0.09364786, z: 2.77009730 } }),
-    ((18, 31), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.73110783, y: 0.33781925, z: 3.13675670 } }),
-    ((27, 40), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.01101860, y: 0.63175460, z: 3.01108120 } }),
-    ((50, 447), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 2.07984160, y: -0.08717120, z: 2.48083500 } }),
-    ((32, 22), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.33380890, y: 0.42310910, z: 2.92058470 } }),
-    ((34, 443), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.47324670, y: -0.14445311, z: 2.88037500 } }),
-    ((41, 17), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.70513260, y: 0.41251498, z: 2.72217500 } }),
-    ((33, 0), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.43995110, y: 0.00000000, z: 2.90076260 } }),
-    ((36, 14), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.53044630, y: 0.30303650, z: 2.83791920 } }),
-    ((37, 21), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.53135760, y: 0.46234414, z: 2.81585930 } }),
-    ((14, 422), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.58156530, y: -0.23970392, z: 3.17682340 } }),
-    ((22, 32), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.88309690, y: 0.42310910, z: 3.08690790 } }),
-    ((37, 17), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.55477870, y: 0.37614056, z: 2.81585930 } }),
-    ((49, 438), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 2.01816320, y: -0.34134677, z: 2.50965760 } }),
-    ((37, 24), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.51065350, y: 0.52606520, z: 2.81585930 } }),
-    ((17, 428), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.72586210, y: -0.23025700, z: 3.14769580 } }),
-    ((36, 429), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.49357090, y: -0.45093563, z: 2.83791920 } }),
-    ((36, 9), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.54785690, y: 0.19553980, z: 2.83791920 } }),
-    ((21, 449), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 0.93593824, y: -0.01306901, z: 3.10027900 } }),
-    ((18, 434), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.78536790, y: -0.17843121, z: 3.13675670 } }),
-    ((30, 1), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.31708820, y: 0.01839122, z: 2.95851700 } }),
-    ((17, 438), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.75084360, y: -0.12699570, z: 3.14769580 } }),
-    ((23, 16), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.99682635, y: 0.22647339, z: 3.07293490 } }),
-    ((36, 17), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.51641390, y: 0.36685914, z: 2.83791920 } }),
-    ((38, 434), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.59806540, y: -0.36307153, z: 2.79325060 } }),
-    ((19, 428), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.80935390, y: -0.25674218, z: 3.12520600 } }),
-    ((19, 5), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.84703130, y: 0.05923020, z: 3.12520600 } }),
-    ((20, 17), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.86762250, y: 0.20989998, z: 3.11304600 } }),
-    ((26, 16), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.12143530, y: 0.25478384, z: 3.02743300 } }),
-    ((15, 53), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.49721822, y: 0.45402265, z: 3.16773100 } }),
-    ((28, 37), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.07304060, y: 0.60957190, z: 2.99414230 } }),
-    ((15, 55), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.48434730, y: 0.46772876, z: 3.16773100 } }),
-    ((34, 449), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.48016740, y: -0.02066838, z: 2.88037500 } }),
-    ((17, 5), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.75965273, y: 0.05312009, z: 3.14769580 } }),
-    ((18, 42), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.67081994, y: 0.44569170, z: 3.13675670 } }),
-    ((26, 432), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.11388410, y: -0.28599682, z: 3.02743300 } }),
-    ((17, 431), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.73486750, y: -0.19965910, z: 3.14769580 } }),
-    ((38, 439), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.61949910, y: -0.25071170, z: 2.79325060 } }),
-    ((20, 437), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.87798660, y: -0.16114070, z: 3.11304600 } }),
-    ((43, 7), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.82091340, y: 0.17854212, z: 2.67213000 } }),
-    ((16, 53), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.52983050, y: 0.48380172, z: 3.15802120 } }),
-    ((46, 7), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.93067930, y: 0.18930477, z: 2.59316850 } }),
-    ((28, 35), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.08964240, y: 0.57937320, z: 2.99414230 } }),
-    ((30, 17), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.28028320, y: 0.30973310, z: 2.95851700 } }),
-    ((30, 448), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.31670310, y: -0.03677884, z: 2.95851700 } }),
-    ((46, 446), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.93691310, y: -0.10829026, z: 2.59316850 } }),
-    ((16, 421), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.65946570, y: -0.28264734, z: 3.15802120 } }),
-    ((23, 448), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.02183080, y: -0.02854232, z: 3.07293490 } }),
-    ((33, 2), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.43938960, y: 0.04020579, z: 2.90076260 } }),
-    ((33, 10), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.42593750, y: 0.20040245, z: 2.90076260 } }),
-    ((15, 10), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.66676927, y: 0.09370831, z: 3.16773100 } }),
-    ((36, 7), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.55271330, y: 0.15224487, z: 2.83791920 } }),
-    ((33, 442), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.43097710, y: -0.16050981, z: 2.90076260 } }),
-    ((31, 26), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.26986300, y: 0.48237570, z: 2.93983750 } }),
-    ((31, 5), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.35508660, y: 0.09475689, z: 2.93983750 } }),
-    ((47, 442), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.96364080, y: -0.22025761, z: 2.56583000 } }),
-    ((28, 26), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.15366510, y: 0.43823624, z: 2.99414230 } }),
-    ((18, 30), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.73575324, y: 0.32757846, z: 3.13675670 } }),
-    ((22, 18), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.94846076, y: 0.24352333, z: 3.08690790 } }),
-    ((36, 444), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.55468760, y: -0.13055076, z: 2.83791920 } }),
-    ((50, 1), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 2.08146480, y: 0.02906462, z: 2.48083500 } }),
-    ((18, 18), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.78007966, y: 0.20029041, z: 3.13675670 } }),
-    ((28, 27), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.14743390, y: 0.45430118, z: 2.99414230 } }),
-    ((41, 5), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.75004860, y: 0.12237532, z: 2.72217500 } }),
-    ((18, 435), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.78778267, y: -0.16744837, z: 3.13675670 } }),
-    ((31, 431), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.31087410, y: -0.35615662, z: 2.93983750 } }),
-    ((31, 20), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.30577360, y: 0.37442458, z: 2.93983750 } }),
-    ((44, 440), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.84860870, y: -0.25980502, z: 2.64632370 } }),
-    ((12, 421), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.49640715, y: -0.21276037, z: 3.19314810 } }),
-    ((40, 18), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.66222770, y: 0.42678756, z: 2.74640370 } }),
-    ((19, 426), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.80186963, y: -0.27924055, z: 3.12520600 } }),
-    ((38, 443), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.63096900, y: -0.15991792, z: 2.79325060 } }),
-    ((46, 12), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.91277110, y: 0.32352102, z: 2.59316850 } }),
-    ((25, 442), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.10072930, y: -0.12346658, z: 3.04319450 } }),
-    ((22, 26), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.91540456, y: 0.34772956, z: 3.08690790 } }),
-    ((35, 9), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.50839500, y: 0.19055460, z: 2.85942580 } }),
-    ((32, 436), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.37266000, y: -0.27179402, z: 2.92058470 } }),
-    ((22, 447), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 0.97836596, y: -0.04100568, z: 3.08690790 } }),
-    ((16, 17), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.69736725, y: 0.16871089, z: 3.15802120 } }),
-    ((48, 2), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 2.01080270, y: 0.05616681, z: 2.53799130 } }),
-    ((22, 438), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 0.96551186, y: -0.16330410, z: 3.08690790 } }),
-    ((20, 19), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.86142350, y: 0.23404358, z: 3.11304600 } }),
-    ((15, 59), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.45748278, y: 0.49403647, z: 3.16773100 } }),
-    ((17, 448), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.76121086, y: -0.02126254, z: 3.14769580 } }),
-    ((40, 13), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.68794980, y: 0.30979675, z: 2.74640370 } }),
-    ((43, 443), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.82091340, y: -0.17854212, z: 2.67213000 } }),
-    ((12, 419), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.49027300, y: -0.22653793, z: 3.19314810 } }),
-    ((12, 1), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.54002790, y: 0.00754070, z: 3.19314810 } }),
-    ((16, 11), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.70903885, y: 0.10976502, z: 3.15802120 } }),
-    ((21, 9), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.92864860, y: 0.11731560, z: 3.10027900 } }),
-    ((27, 12), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.17547620, y: 0.19881693, z: 3.01108120 } }),
-    ((45, 8), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.89167950, y: 0.21218586, z: 2.62000160 } }),
-    ((45, 434), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.85623820, y: -0.42172700, z: 2.62000160 } }),
-    ((25, 33), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.99211920, y: 0.49249226, z: 3.04319450 } }),
-    ((21, 38), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.80733824, y: 0.47366253, z: 3.10027900 } }),
-    ((40, 14), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.68345990, y: 0.33333403, z: 2.74640370 } }),
-    ((42, 439), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.77106190, y: -0.27417487, z: 2.69741540 } }),
-    ((36, 437), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.53452810, y: -0.28163862, z: 2.83791920 } }),
-    ((34, 5), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.47670570, y: 0.10326132, z: 2.88037500 } }),
-    ((19, 448), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.84876860, y: -0.02370825, z: 3.12520600 } }),
-    ((38, 22), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.56207970, y: 0.49552086, z: 2.79325060 } }),
-    ((34, 17), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.43880520, y: 0.34808362, z: 2.88037500 } }),
-    ((15, 438), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.66389280, y: -0.11228907, z: 3.16773100 } }),
-    ((14, 416), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.55946773, y: -0.28752738, z: 3.17682340 } }),
-    ((18, 443), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.80153840, y: -0.07859153, z: 3.13675670 } }),
-    ((48, 439), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.98790740, y: -0.30774432, z: 2.53799130 } }),
-    ((25, 429), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.06035790, y: -0.32014093, z: 3.04319450 } }),
-    ((15, 13), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.66226030, y: 0.12154751, z: 3.16773100 } }),
-    ((12, 0), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.54008060, y: 0.00000000, z: 3.19314810 } }),
-    ((25, 440), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.09685280, y: -0.15415262, z: 3.04319450 } }),
-    ((37, 18), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.54937540, y: 0.39781198, z: 2.81585930 } }),
-    ((29, 4), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.27379170, y: 0.07121601, z: 2.97661970 } }),
-    ((33, 6), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.43490090, y: 0.12049200, z: 2.90076260 } }),
-    ((25, 433), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.07657530, y: -0.26045100, z: 3.04319450 } }),
-    ((41, 445), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.75004860, y: -0.12237532, z: 2.72217500 } }),
-    ((45, 0), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.90354250, y: 0.00000000, z: 2.62000160 } }),
-    ((34, 26), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.38383330, y: 0.52566900, z: 2.88037500 } }),
-    ((22, 434), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 0.95489055, y: -0.21694580, z: 3.08690790 } }),
-    ((38, 437), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.61186740, y: -0.29583302, z: 2.79325060 } }),
-    ((43, 4), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.82679280, y: 0.10213357, z: 2.67213000 } }),
-    ((29, 15), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.24790200, y: 0.26524976, z: 2.97661970 } }),
-    ((22, 7), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.97455144, y: 0.09555560, z: 3.08690790 } }),
-    ((23, 437), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.00543570, y: -0.18453197, z: 3.07293490 } }),
-    ((17, 52), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.56945400, y: 0.50558496, z: 3.14769580 } }),
-    ((31, 442), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.34992990, y: -0.15141891, z: 2.93983750 } }),
-    ((19, 47), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.67273290, y: 0.51807404, z: 3.12520600 } }),
-    ((15, 421), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.61887410, y: -0.26524976, z: 3.16773100 } }),
-    ((35, 441), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.50839500, y: -0.19055460, z: 2.85942580 } }),
-    ((24, 30), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.97295755, y: 0.43318862, z: 3.05836300 } }),
-    ((16, 9), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.71182720, y: 0.08992469, z: 3.15802120 } }),
-    ((19, 20), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.81620700, y: 0.23404358, z: 3.12520600 } }),
-    ((30, 35), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.16303320, y: 0.61839575, z: 2.95851700 } }),
-    ((44, 444), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.86022900, y: -0.15620780, z: 2.64632370 } }),
-    ((38, 9), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.62586800, y: 0.20539490, z: 2.79325060 } }),
-    ((21, 47), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.74160650, y: 0.57111390, z: 3.10027900 } }),
-    ((34, 428), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.41101920, y: -0.44760168, z: 2.88037500 } }),
-    ((44, 6), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.86022900, y: 0.15620780, z: 2.64632370 } }),
-    ((39, 2), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.67697640, y: 0.04684219, z: 2.77009730 } }),
-    ((25, 431), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.06888340, y: -0.29040918, z: 3.04319450 } }),
-    ((25, 40), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.93932540, y: 0.58695567, z: 3.04319450 } }),
-    ((20, 46), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.71477413, y: 0.53471935, z: 3.11304600 } }),
-    ((13, 8), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.58096790, y: 0.06516599, z: 3.18529630 } }),
-    ((46, 8), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.92784810, y: 0.21624280, z: 2.59316850 } }),
-    ((16, 55), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.51611537, y: 0.49840683, z: 3.15802120 } }),
-    ((14, 7), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.62602586, y: 0.06138237, z: 3.17682340 } }),
-    ((21, 10), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.92692010, y: 0.13027012, z: 3.10027900 } }),
-    ((27, 38), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.02826400, y: 0.60327893, z: 3.01108120 } }),
-    ((23, 18), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.99011420, y: 0.25421810, z: 3.07293490 } }),
-    ((27, 41), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.00209940, y: 0.64580900, z: 3.01108120 } }),
-    ((46, 438), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.91277110, y: -0.32352102, z: 2.59316850 } }),
-    ((32, 433), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.36007440, y: -0.32903665, z: 2.92058470 } }),
-    ((17, 13), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.74899730, y: 0.13746673, z: 3.14769580 } }),
-    ((44, 8), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.85514210, y: 0.20808753, z: 2.64632370 } }),
-    ((21, 429), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.89607924, y: -0.27054230, z: 3.10027900 } }),
-    ((16, 435), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.70180607, y: -0.14917348, z: 3.15802120 } }),
-    ((32, 11), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.38283750, y: 0.21407457, z: 2.92058470 } }),
-    ((32, 19), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.35035690, y: 0.36688387, z: 2.92058470 } }),
-    ((21, 28), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.86540234, y: 0.35669312, z: 3.10027900 } }),
-    ((30, 29), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.21070040, y: 0.51890683, z: 2.95851700 } }),
-    ((50, 441), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 2.06525300, y: -0.26090214, z: 2.48083500 } }),
-    ((16, 422), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.66334770, y: -0.27341220, z: 3.15802120 } }),
-    ((44, 435), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.82598260, y: -0.38812456, z: 2.64632370 } }),
-    ((50, 442), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 2.06869460, y: -0.23204127, z: 2.48083500 } }),
-    ((17, 26), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.71187700, y: 0.27041670, z: 3.14769580 } }),
-    ((18, 444), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.80255760, y: -0.06739265, z: 3.13675670 } }),
-    ((43, 435), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.78966340, y: -0.38040470, z: 2.67213000 } }),
-    ((30, 6), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.31259690, y: 0.11022185, z: 2.95851700 } }),
-    ((28, 442), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.22640540, y: -0.13756340, z: 2.99414230 } }),
-    ((13, 441), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.58000140, y: -0.07327122, z: 3.18529630 } }),
-    ((27, 429), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.14128890, y: -0.34457543, z: 3.01108120 } }),
-    ((29, 27), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.18619110, y: 0.46964628, z: 2.97661970 } }),
-    ((40, 436), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.68345990, y: -0.33333403, z: 2.74640370 } }),
-    ((27, 7), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.18648160, y: 0.11633554, z: 3.01108120 } }),
-    ((51, 442), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 2.10291480, y: -0.23587970, z: 2.45152850 } }),
-    ((23, 31), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.92795680, y: 0.42877626, z: 3.07293490 } }),
-    ((33, 19), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.38957640, y: 0.37753958, z: 2.90076260 } }),
-    ((30, 435), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.28843220, y: -0.27386475, z: 2.95851700 } }),
-    ((17, 16), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.74258375, y: 0.16871089, z: 3.14769580 } }),
-    ((39, 21), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.60602830, y: 0.48488855, z: 2.77009730 } }),
-    ((17, 24), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.71914990, y: 0.25043450, z: 3.14769580 } }),
-    ((39, 13), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.65006950, y: 0.30284440, z: 2.77009730 } }),
-    ((24, 44), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.87028760, y: 0.61392040, z: 3.05836300 } }),
-    ((29, 16), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.24407680, y: 0.28264734, z: 2.97661970 } }),
-    ((28, 430), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.18628970, y: -0.34016308, z: 2.99414230 } }),
-    ((29, 19), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.23114960, y: 0.33449593, z: 2.97661970 } }),
-    ((20, 449), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.89256454, y: -0.01246336, z: 3.11304600 } }),
-    ((41, 12), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.72975460, y: 0.29256610, z: 2.72217500 } }),
-    ((33, 426), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.35985580, y: -0.47355187, z: 2.90076260 } }),
-    ((22, 23), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.92916304, y: 0.30909140, z: 3.08690790 } }),
-    ((37, 5), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.59573410, y: 0.11158460, z: 2.81585930 } }),
-    ((24, 12), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.05011990, y: 0.17761450, z: 3.05836300 } }),
-    ((22, 27), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.91046030, y: 0.36047673, z: 3.08690790 } }),
-    ((39, 16), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.63594030, y: 0.37167650, z: 2.77009730 } }),
-    ((33, 20), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.38416980, y: 0.39690430, z: 2.90076260 } }),
-    ((23, 8), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.01585880, y: 0.11394682, z: 3.07293490 } }),
-    ((45, 441), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.88853250, y: -0.23857714, z: 2.62000160 } }),
-    ((46, 439), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.91710170, y: -0.29678303, z: 2.59316850 } }),
-    ((22, 14), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.96057570, y: 0.19019910, z: 3.08690790 } }),
-    ((32, 12), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.37971380, y: 0.23336112, z: 2.92058470 } }),
-    ((32, 31), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.27026190, y: 0.58694340, z: 2.92058470 } }),
-    ((18, 40), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.68300280, y: 0.42678756, z: 3.13675670 } }),
-    ((24, 45), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.86163110, y: 0.62601167, z: 3.05836300 } }),
-    ((18, 49), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.62412650, y: 0.50902516, z: 3.13675670 } }),
-    ((23, 23), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.96996900, y: 0.32266572, z: 3.07293490 } }),
-    ((26, 9), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.14094570, y: 0.14413495, z: 3.02743300 } }),
-    ((44, 10), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.84860870, y: 0.25980502, z: 2.64632370 } }),
-    ((34, 13), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.45599230, y: 0.26722458, z: 2.88037500 } }),
-    ((30, 447), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.31606110, y: -0.05515931, z: 2.95851700 } }),
-    ((42, 2), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.79145980, y: 0.05004000, z: 2.69741540 } }),
-    ((29, 2), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.27528350, y: 0.03562189, z: 2.97661970 } }),
-    ((48, 441), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.99572520, y: -0.25211870, z: 2.53799130 } }),
-    ((31, 432), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.31571910, y: -0.33781925, z: 2.93983750 } }),
-    ((44, 9), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.85205600, y: 0.23396908, z: 2.64632370 } }),
-    ((20, 442), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 0.88708850, y: -0.09950292, z: 3.11304600 } }),
-    ((46, 443), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.93067930, y: -0.18930477, z: 2.59316850 } }),
-    ((27, 23), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.13122280, y: 0.37630776, z: 3.01108120 } }),
-    ((26, 443), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.14452530, y: -0.11222169, z: 3.02743300 } }),
-    ((36, 2), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.55955100, y: 0.04356220, z: 2.83791920 } }),
-    ((20, 6), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.88952094, y: 0.07469516, z: 3.11304600 } }),
-    ((26, 3), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.14900520, y: 0.04815758, z: 3.02743300 } }),
-    ((34, 427), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.40463220, y: -0.46725893, z: 2.88037500 } }),
-    ((20, 23), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.84701570, y: 0.28176460, z: 3.11304600 } }),
-    ((40, 445), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.71196300, y: -0.11971212, z: 2.74640370 } }),
-    ((29, 26), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.19263270, y: 0.45303866, z: 2.97661970 } }),
-    ((42, 443), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.78360520, y: -0.17488402, z: 2.69741540 } }),
-    ((21, 7), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.93156220, y: 0.09134047, z: 3.10027900 } }),
-    ((43, 437), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.79958720, y: -0.33028606, z: 2.67213000 } }),
-    ((14, 0), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.62902796, y: 0.00000000, z: 3.17682340 } }),
-    ((35, 7), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.51312740, y: 0.14836344, z: 2.85942580 } }),
-    ((44, 438), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.84063370, y: -0.31131992, z: 2.64632370 } }),
-    ((44, 3), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.86513850, y: 0.07817247, z: 2.64632370 } }),
-    ((50, 436), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 2.04202270, y: -0.40433136, z: 2.48083500 } }),
-    ((18, 439), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.79590154, y: -0.12321207, z: 3.13675670 } }),
-    ((13, 421), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.53733690, y: -0.23030287, z: 3.18529630 } }),
-    ((26, 10), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.13882210, y: 0.16005100, z: 3.02743300 } }),
-    ((27, 5), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.18926730, y: 0.08316167, z: 3.01108120 } }),
-    ((42, 1), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.79198380, y: 0.02502244, z: 2.69741540 } }),
-    ((34, 0), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.48031160, y: 0.00000000, z: 2.88037500 } }),
-    ((46, 436), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.90299210, y: -0.37680260, z: 2.59316850 } }),
-    ((26, 13), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.13112090, y: 0.20759952, z: 3.02743300 } }),
-    ((25, 449), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.10752430, y: -0.01546496, z: 3.04319450 } }),
-    ((34, 11), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.46288600, y: 0.22646672, z: 2.88037500 } }),
-    ((36, 15), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.52606610, y: 0.32437536, z: 2.83791920 } }),
-    ((23, 2), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.02183080, y: 0.02854232, z: 3.07293490 } }),
-    ((20, 431), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.86142350, y: -0.23404358, z: 3.11304600 } }),
-    ((36, 18), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.51114400, y: 0.38799584, z: 2.83791920 } }),
-    ((26, 6), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.14598060, y: 0.09623069, z: 3.02743300 } }),
-    ((38, 17), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.59284030, y: 0.38534862, z: 2.79325060 } }),
-    ((41, 446), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.75158670, y: -0.09792890, z: 2.72217500 } }),
-    ((31, 16), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.32463860, y: 0.30095050, z: 2.93983750 } }),
-    ((22, 22), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.93338805, y: 0.29608813, z: 3.08690790 } }),
-    ((24, 433), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.03517210, y: -0.25043450, z: 3.05836300 } }),
-    ((37, 447), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.59822760, y: -0.06698559, z: 2.81585930 } }),
-    ((34, 433), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.43880520, y: -0.34808362, z: 2.88037500 } }),
-    ((40, 440), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.69944210, y: -0.23884101, z: 2.74640370 } }),
-    ((18, 39), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.68889517, y: 0.41720974, z: 3.13675670 } }),
-    ((23, 15), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.99989120, y: 0.21253344, z: 3.07293490 } }),
-    ((34, 429), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.41713120, y: -0.42785713, z: 2.88037500 } }),
-    ((15, 441), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.66801270, y: -0.08438963, z: 3.16773100 } }),
-    ((34, 25), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.39103800, y: 0.50629640, z: 2.88037500 } }),
-    ((18, 427), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.76420784, y: -0.25421810, z: 3.13675670 } }),
-    ((20, 44), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.72942567, y: 0.51455320, z: 3.11304600 } }),
-    ((25, 6), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.10374760, y: 0.09268428, z: 3.04319450 } }),
-    ((21, 432), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.90662240, y: -0.23278107, z: 3.10027900 } }),
-    ((13, 446), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.58369970, y: -0.03263388, z: 3.18529630 } }),
-    ((49, 1), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 2.04662730, y: 0.02857817, z: 2.50965760 } }),
-    ((23, 444), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.01864430, y: -0.08553795, z: 3.07293490 } }),
-    ((25, 36), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.97062550, y: 0.53360590, z: 3.04319450 } }),
-    ((13, 434), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.57008326, y: -0.12951975, z: 3.18529630 } }),
-    ((48, 445), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 2.00668700, y: -0.14032122, z: 2.53799130 } }),
-    ((15, 2), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.67305946, y: 0.01880025, z: 3.16773100 } }),
-    ((36, 11), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.54179370, y: 0.23868227, z: 2.83791920 } }),
-    ((25, 39), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.94742900, y: 0.57378340, z: 3.04319450 } }),
-    ((16, 16), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.69965490, y: 0.15895768, z: 3.15802120 } }),
-    ((12, 418), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.48706225, y: -0.23336112, z: 3.19314810 } }),
-    ((24, 18), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.03157460, y: 0.26486334, z: 3.05836300 } }),
-    ((23, 39), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.87437850, y: 0.52954245, z: 3.07293490 } }),
-    ((27, 435), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.16611960, y: -0.24786636, z: 3.01108120 } }),
-    ((28, 28), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.14097890, y: 0.47027758, z: 2.99414230 } }),
-    ((47, 449), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.97576250, y: -0.02758864, z: 2.56583000 } }),
-    ((33, 28), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.33130100, y: 0.54872270, z: 2.90076260 } }),
-    ((26, 429), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.10093070, y: -0.33239058, z: 3.02743300 } }),
-    ((14, 442), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.62510777, y: -0.07011707, z: 3.17682340 } }),
-    ((16, 447), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.71685547, y: -0.03004515, z: 3.15802120 } }),
-    ((21, 446), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 0.93456995, y: -0.05225058, z: 3.10027900 } }),
-    ((20, 12), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.88015090, y: 0.14886639, z: 3.11304600 } }),
-    ((32, 446), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.39712790, y: -0.07811157, z: 2.92058470 } }),
-    ((42, 8), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.78098960, y: 0.19977000, z: 2.69741540 } }),
-    ((19, 42), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.70723313, y: 0.46988457, z: 3.12520600 } }),
-    ((30, 10), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.30439760, y: 0.18332112, z: 2.95851700 } }),
-    ((22, 435), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 0.95782650, y: -0.20359230, z: 3.08690790 } }),
-    ((14, 433), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.61139065, y: -0.14791097, z: 3.17682340 } }),
-    ((29, 448), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.27528350, y: -0.03562189, z: 2.97661970 } }),
-    ((37, 436), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.56916610, y: -0.31070325, z: 2.81585930 } }),
-    ((42, 11), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.77106190, y: 0.27417487, z: 2.69741540 } }),
-    ((35, 5), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.51668010, y: 0.10605660, z: 2.85942580 } }),
-    ((34, 438), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.45958140, y: -0.24686971, z: 2.88037500 } }),
-    ((31, 21), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.30041860, y: 0.39261952, z: 2.93983750 } }),
-    ((19, 22), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.80935390, y: 0.25674218, z: 3.12520600 } }),
-    ((20, 427), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.84701570, y: -0.28176460, z: 3.11304600 } }),
-    ((32, 30), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.27833310, y: 0.56915050, z: 2.92058470 } }),
-    ((51, 440), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 2.09550880, y: -0.29450455, z: 2.45152850 } }),
-    ((20, 9), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.88561270, y: 0.11187891, z: 3.11304600 } }),
-    ((34, 28), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.36861620, y: 0.56410290, z: 2.88037500 } }),
-    ((39, 19), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.61894110, y: 0.43985656, z: 2.77009730 } }),
-    ((18, 12), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.79410370, y: 0.13431258, z: 3.13675670 } }),
-    ((20, 27), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.82996640, y: 0.32860696, z: 3.11304600 } }),
-    ((14, 4), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.62804717, y: 0.03511329, z: 3.17682340 } }),
-    ((35, 430), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.46148660, y: -0.41907454, z: 2.85942580 } }),
-    ((38, 438), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.61584080, y: -0.27329900, z: 2.79325060 } }),
-    ((35, 442), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.51090850, y: -0.16947554, z: 2.85942580 } }),
-    ((18, 23), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.76420784, y: 0.25421810, z: 3.13675670 } }),
-    ((46, 435), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.89754560, y: -0.40333578, z: 2.59316850 } }),
-    ((20, 426), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.84299904, y: -0.29356334, z: 3.11304600 } }),
-    ((33, 22), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.37254790, y: 0.43539783, z: 2.90076260 } }),
-    ((31, 32), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.22504530, y: 0.58694340, z: 2.93983750 } }),
-    ((33, 441), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.42859660, y: -0.18047372, z: 2.90076260 } }),
-    ((29, 29), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.17261530, y: 0.50258356, z: 2.97661970 } }),
-    ((38, 447), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.63735280, y: -0.06862542, z: 2.79325060 } }),
-    ((12, 420), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.49338818, y: -0.21967056, z: 3.19314810 } }),
-    ((17, 9), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.75550300, y: 0.09544223, z: 3.14769580 } }),
-    ((29, 11), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.26076280, y: 0.19517641, z: 2.97661970 } }),
-    ((23, 5), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.01973930, y: 0.07130712, z: 3.07293490 } }),
-    ((39, 17), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.63059140, y: 0.39448157, z: 2.77009730 } }),
-    ((47, 434), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.92685140, y: -0.43776990, z: 2.56583000 } }),
-    ((26, 35), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.01540200, y: 0.53989880, z: 3.02743300 } }),
-    ((16, 426), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.67757570, y: -0.23595682, z: 3.15802120 } }),
-    ((14, 57), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.44010780, y: 0.44942328, z: 3.17682340 } }),
-    ((25, 438), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.09212100, y: -0.18471844, z: 3.04319450 } }),
-    ((18, 36), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.70576180, y: 0.38799584, z: 3.13675670 } }),
-    ((13, 413), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.50831650, y: -0.28876397, z: 3.18529630 } }),
-    ((37, 9), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.58701720, y: 0.20048690, z: 2.81585930 } }),
-    ((47, 0), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.97595510, y: 0.00000000, z: 2.56583000 } }),
-    ((24, 9), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.05663660, y: 0.13348423, z: 3.05836300 } }),
-    ((21, 6), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.93274670, y: 0.07832493, z: 3.10027900 } }),
-    ((43, 3), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.82804070, y: 0.07661761, z: 2.67213000 } }),
-    ((26, 448), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.14956560, y: -0.03211027, z: 3.02743300 } }),
-    ((24, 438), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.05011990, y: -0.17761450, z: 3.05836300 } }),
-    ((15, 420), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.61511030, y: -0.27386475, z: 3.16773100 } }),
-    ((36, 447), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.55879080, y: -0.06533269, z: 2.83791920 } }),
-    ((15, 437), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.66226030, y: -0.12154751, z: 3.16773100 } }),
-    ((15, 4), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.67227215, y: 0.03758585, z: 3.16773100 } }),
-    ((33, 432), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.39471230, y: -0.35810125, z: 2.90076260 } }),
-    ((41, 13), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.72550110, y: 0.31668872, z: 2.72217500 } }),
-    ((13, 435), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.57183610, y: -0.12154751, z: 3.18529630 } }),
-    ((17, 22), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.72586210, y: 0.23025700, z: 3.14769580 } }),
-    ((23, 40), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.86689970, y: 0.54169905, z: 3.07293490 } }),
-    ((37, 11), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.58080050, y: 0.24472085, z: 2.81585930 } }),
-    ((18, 449), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.80530370, y: -0.01124489, z: 3.13675670 } }),
-    ((16, 20), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.68969065, y: 0.19776562, z: 3.15802120 } }),
-    ((47, 447), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.97422180, y: -0.08274441, z: 2.56583000 } }),
-    ((14, 432), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.60926586, y: -0.15643288, z: 3.17682340 } }),
-    ((15, 425), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.63271570, y: -0.23028970, z: 3.16773100 } }),
-    ((30, 433), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.28028320, y: -0.30973310, z: 2.95851700 } }),
-    ((22, 5), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 0.97683954, y: 0.06830727, z: 3.08690790 } }),
-    ((23, 435), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 0.99989120, y: -0.21253344, z: 3.07293490 } }),
-    ((23, 430), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 0.98262995, y: -0.28176460, z: 3.07293490 } }),
-    ((36, 431), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.50557950, y: -0.40905687, z: 2.83791920 } }),
-    ((18, 38), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.69465315, y: 0.40755057, z: 3.13675670 } }),
-    ((37, 14), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.56916610, y: 0.31070325, z: 2.81585930 } }),
-    ((19, 32), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.76574570, y: 0.36688387, z: 3.12520600 } }),
-    ((46, 447), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.93823620, y: -0.08123617, z: 2.59316850 } }),
-    ((43, 441), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.81521830, y: -0.22931540, z: 2.67213000 } }),
-    ((36, 440), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.54497590, y: -0.21713221, z: 2.83791920 } }),
-    ((20, 22), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.85086715, y: 0.26991096, z: 3.11304600 } }),
-    ((17, 44), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.62226220, y: 0.43895766, z: 3.14769580 } }),
-    ((39, 4), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.67501460, y: 0.09364786, z: 2.77009730 } }),
-    ((16, 446), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.71636605, y: -0.04005108, z: 3.15802120 } }),
-    ((19, 3), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.84835490, y: 0.03555660, z: 3.12520600 } }),
-    ((27, 31), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.08222630, y: 0.50005877, z: 3.01108120 } }),
-    ((27, 32), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.07513890, y: 0.51512027, z: 3.01108120 } }),
-    ((31, 25), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.27647420, y: 0.46459866, z: 2.93983750 } }),
-    ((48, 8), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.99905070, y: 0.22422947, z: 2.53799130 } }),
-    ((45, 449), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.90335700, y: -0.02657761, z: 2.62000160 } }),
-    ((21, 4), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.93456995, y: 0.05225058, z: 3.10027900 } }),
-    ((31, 23), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.28894900, y: 0.42877626, z: 2.93983750 } }),
-    ((34, 16), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.44352500, y: 0.32796082, z: 2.88037500 } }),
-    ((20, 37), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.77615600, y: 0.44091800, z: 3.11304600 } }),
-    ((48, 7), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 2.00198650, y: 0.19629650, z: 2.53799130 } }),
-    ((27, 39), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.01974080, y: 0.61757696, z: 3.01108120 } }),
-    ((18, 45), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.65156790, y: 0.47339177, z: 3.13675670 } }),
-    ((38, 11), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.61949910, y: 0.25071170, z: 2.79325060 } }),
-    ((17, 2), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.76121086, y: 0.02126254, z: 3.14769580 } }),
-    ((28, 434), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.20342830, y: -0.27341220, z: 2.99414230 } }),
-    ((18, 448), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.80506820, y: -0.02248759, z: 3.13675670 } }),
-    ((39, 5), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.67354380, y: 0.11702558, z: 2.77009730 } }),
-    ((30, 444), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.31259690, y: -0.11022185, z: 2.95851700 } }),
-    ((37, 440), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.58406330, y: -0.22262557, z: 2.81585930 } }),
-    ((38, 21), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.56884600, y: 0.47366253, z: 2.79325060 } }),
-    ((21, 11), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.92501090, y: 0.14319925, z: 3.10027900 } }),
-    ((50, 437), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 2.04746900, y: -0.37578088, z: 2.48083500 } }),
-    ((16, 6), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.71496850, y: 0.06003758, z: 3.15802120 } }),
-    ((36, 435), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.52606610, y: -0.32437536, z: 2.83791920 } }),
-    ((22, 45), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.79220957, y: 0.57557390, z: 3.08690790 } }),
-    ((40, 3), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.71463820, y: 0.07186463, z: 2.74640370 } }),
-    ((32, 425), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.31492100, y: -0.47859210, z: 2.92058470 } }),
-    ((37, 2), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.59900700, y: 0.04466431, z: 2.81585930 } }),
-    ((37, 8), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.58966170, y: 0.17830913, z: 2.81585930 } }),
-    ((30, 31), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.19573960, y: 0.55250925, z: 2.95851700 } }),
-    ((17, 440), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.75409680, y: -0.10598140, z: 3.14769580 } }),
-    ((13, 9), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.58000140, y: 0.07327122, z: 3.18529630 } }),
-    ((12, 432), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.52311295, y: -0.13431258, z: 3.19314810 } }),
-    ((20, 8), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.88708850, y: 0.09950292, z: 3.11304600 } }),
-    ((22, 19), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.94496816, y: 0.25674218, z: 3.08690790 } }),
-    ((33, 434), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.40416730, y: -0.31901900, z: 2.90076260 } }),
-    ((19, 447), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.84835490, y: -0.03555660, z: 3.12520600 } }),
-    ((48, 436), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.97327670, y: -0.39071932, z: 2.53799130 } }),
-    ((14, 2), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.62878270, y: 0.01756349, z: 3.17682340 } }),
-    ((18, 24), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.76058394, y: 0.26486334, z: 3.13675670 } }),
-    ((34, 431), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.42852510, y: -0.38812170, z: 2.88037500 } }),
-    ((23, 446), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.02063550, y: -0.05706238, z: 3.07293490 } }),
-    ((17, 423), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.70803200, y: -0.28032970, z: 3.14769580 } }),
-    ((30, 437), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.29557670, y: -0.23778282, z: 2.95851700 } }),
-    ((35, 432), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.47261800, y: -0.37810403, z: 2.85942580 } }),
-    ((15, 440), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.66676927, y: -0.09370831, z: 3.16773100 } }),
-    ((28, 435), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.20712840, y: -0.25658306, z: 2.99414230 } }),
-    ((24, 22), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.01518110, y: 0.32203442, z: 3.05836300 } }),
-    ((32, 424), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.30811060, y: -0.49690460, z: 2.92058470 } }),
-    ((27, 445), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.18926730, y: -0.08316167, z: 3.01108120 } }),
-    ((28, 428), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.17632910, y: -0.37315360, z: 2.99414230 } }),
-    ((27, 25), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.12027470, y: 0.40774660, z: 3.01108120 } }),
-    ((34, 14), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.45211940, y: 0.28752738, z: 2.88037500 } }),
-    ((23, 442), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.01585880, y: -0.11394682, z: 3.07293490 } }),
-    ((46, 444), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.93313430, y: -0.16232982, z: 2.59316850 } }),
-    ((33, 440), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.42593750, y: -0.20040245, z: 2.90076260 } }),
-    ((25, 441), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.09889820, y: -0.13882314, z: 3.04319450 } }),
-    ((20, 39), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.76354223, y: 0.46241763, z: 3.11304600 } }),
-    ((39, 432), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.62492470, y: -0.41720974, z: 2.77009730 } }),
-    ((28, 449), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.23397610, y: -0.01723068, z: 2.99414230 } }),
-    ((33, 31), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.30715510, y: 0.60399044, z: 2.90076260 } }),
-    ((21, 43), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.77233060, y: 0.52882576, z: 3.10027900 } }),
-    ((12, 444), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.53818643, y: -0.04519278, z: 3.19314810 } }),
-    ((22, 35), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.86460423, y: 0.45971823, z: 3.08690790 } }),
-    ((18, 432), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.78007966, y: -0.20029041, z: 3.13675670 } }),
-    ((22, 29), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.90004020, y: 0.38575770, z: 3.08690790 } }),
-    ((32, 448), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.39876410, y: -0.03907102, z: 2.92058470 } }),
-    ((28, 15), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.20712840, y: 0.25658306, z: 2.99414230 } }),
-    ((14, 427), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.59686950, y: -0.19855207, z: 3.17682340 } }),
-    ((24, 39), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.91099256, y: 0.55171670, z: 3.05836300 } }),
-    ((26, 8), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.14284700, y: 0.12819082, z: 3.02743300 } }),
-    ((24, 10), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.05466970, y: 0.14822417, z: 3.05836300 } }),
-    ((21, 430), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.89976930, y: -0.25800470, z: 3.10027900 } }),
-    ((27, 36), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.04470780, y: 0.57433295, z: 3.01108120 } }),
-    ((23, 21), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.97860014, y: 0.29545680, z: 3.07293490 } }),
-    ((30, 30), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.20333730, y: 0.53576030, z: 2.95851700 } }),
-    ((12, 2), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.53987000, y: 0.01507993, z: 3.19314810 } }),
-    ((47, 2), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.97518470, y: 0.05517190, z: 2.56583000 } }),
-    ((21, 440), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 0.92692010, y: -0.13027012, z: 3.10027900 } }),
-    ((35, 15), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.48715960, y: 0.31610554, z: 2.85942580 } }),
-    ((40, 6), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.71012470, y: 0.14360319, z: 2.74640370 } }),
-    ((38, 14), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.60757980, y: 0.31830937, z: 2.79325060 } }),
-    ((17, 32), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.68675244, y: 0.32903665, z: 3.14769580 } }),
-    ((17, 45), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.61607270, y: 0.44760302, z: 3.14769580 } }),
-    ((21, 25), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.87957996, y: 0.32014093, z: 3.10027900 } }),
-    ((13, 431), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.56415950, y: -0.15327874, z: 3.18529630 } }),
-    ((49, 449), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 2.04662730, y: -0.02857817, z: 2.50965760 } }),
-    ((17, 425), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.71558320, y: -0.26045100, z: 3.14769580 } }),
-    ((21, 31), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.84970650, y: 0.39261952, z: 3.10027900 } }),
-    ((17, 441), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.75550300, y: -0.09544223, z: 3.14769580 } }),
-    ((40, 433), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.66802450, y: -0.40353763, z: 2.74640370 } }),
-    ((20, 43), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.73653890, y: 0.50431870, z: 3.11304600 } }),
-    ((32, 442), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.39058910, y: -0.15597956, z: 2.92058470 } }),
-    ((19, 31), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.77079356, y: 0.35615662, z: 3.12520600 } }),
-    ((43, 440), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.81183970, y: -0.25463745, z: 2.67213000 } }),
-    ((31, 29), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.24854930, y: 0.53512895, z: 2.93983750 } }),
-    ((33, 431), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.38957640, y: -0.37753958, z: 2.90076260 } }),
-    ((19, 34), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.75520310, y: 0.38812170, z: 3.12520600 } }),
-    ((25, 19), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.06888340, y: 0.29040918, z: 3.04319450 } }),
-    ((12, 440), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.53482455, y: -0.07516469, z: 3.19314810 } }),
-    ((28, 429), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.18142460, y: -0.35669312, z: 2.99414230 } }),
-    ((17, 46), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.60976315, y: 0.45616110, z: 3.14769580 } }),
-    ((44, 442), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.85514210, y: -0.20808753, z: 2.64632370 } }),
-    ((29, 36), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.11797540, y: 0.61461210, z: 2.97661970 } }),
-    ((32, 20), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.34510280, y: 0.38570200, z: 2.92058470 } }),
-    ((29, 23), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.21055780, y: 0.40269900, z: 2.97661970 } }),
-    ((16, 443), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.71406054, y: -0.07001425, z: 3.15802120 } }),
-    ((36, 10), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.54497590, y: 0.21713221, z: 2.83791920 } }),
-    ((38, 449), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.63863060, y: -0.02288109, z: 2.79325060 } }),
-    ((41, 444), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.74816940, y: -0.14679788, z: 2.72217500 } }),
-    ((27, 27), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.10845290, y: 0.43886754, z: 3.01108120 } }),
-    ((46, 10), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.92105850, y: 0.26998717, z: 2.59316850 } }),
-    ((14, 438), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.62021905, y: -0.10490220, z: 3.17682340 } }),
-    ((30, 15), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.28843220, y: 0.27386475, z: 2.95851700 } }),
-    ((20, 30), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.81547780, y: 0.36307410, z: 3.11304600 } }),
-    ((48, 440), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.99201050, y: -0.27995880, z: 2.53799130 } }),
-    ((21, 445), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 0.93374940, y: -0.06529412, z: 3.10027900 } }),
-    ((16, 420), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.65545500, y: -0.29182735, z: 3.15802120 } }),
-    ((15, 56), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.47776958, y: 0.47444573, z: 3.16773100 } }),
-    ((46, 449), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.93974890, y: -0.02708576, z: 2.59316850 } }),
-    ((13, 432), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.56624460, y: -0.14538690, z: 3.18529630 } }),
-    ((26, 38), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.99190270, y: 0.58194584, z: 3.02743300 } }),
-    ((46, 437), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.90806760, y: -0.35019594, z: 2.59316850 } }),
-    ((17, 50), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.58334875, y: 0.48948774, z: 3.14769580 } }),
-    ((26, 445), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.14721250, y: -0.08022092, z: 3.02743300 } }),
-    ((24, 8), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.05839730, y: 0.11871827, z: 3.05836300 } }),
-    ((33, 444), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.43490090, y: -0.12049200, z: 2.90076260 } }),
-    ((16, 3), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.71685547, y: 0.03004515, z: 3.15802120 } }),
-    ((20, 0), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.89265156, y: 0.00000000, z: 3.11304600 } }),
-    ((47, 440), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.95672520, y: -0.27499980, z: 2.56583000 } }),
-    ((35, 428), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.44921550, y: -0.45971823, z: 2.85942580 } }),
-    ((30, 13), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.29557670, y: 0.23778282, z: 2.95851700 } }),
-    ((13, 448), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.58438330, y: -0.01632330, z: 3.18529630 } }),
-    ((45, 7), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.89445770, y: 0.18575320, z: 2.62000160 } }),
-    ((47, 445), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.97114180, y: -0.13783567, z: 2.56583000 } }),
-    ((17, 33), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.68209140, y: 0.33859313, z: 3.14769580 } }),
-    ((29, 431), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.23114960, y: -0.33449593, z: 2.97661970 } }),
-    ((30, 9), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.30682990, y: 0.16509102, z: 2.95851700 } }),
-    ((17, 445), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.75965273, y: -0.05312009, z: 3.14769580 } }),
-    ((18, 440), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.79754430, y: -0.11208754, z: 3.13675670 } }),
-    ((15, 434), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.65658950, y: -0.14917348, z: 3.16773100 } }),
-    ((35, 435), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.48715960, y: -0.31610554, z: 2.85942580 } }),
-    ((51, 434), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 2.06351610, y: -0.46881935, z: 2.45152850 } }),
-    ((38, 432), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.58730470, y: -0.40755057, z: 2.79325060 } }),
-    ((21, 33), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.83841260, y: 0.41619164, z: 3.10027900 } }),
-    ((42, 5), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.78779290, y: 0.12501466, z: 2.69741540 } }),
-    ((35, 434), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.48260120, y: -0.33683872, z: 2.85942580 } }),
-    ((12, 434), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.52665925, y: -0.11965405, z: 3.19314810 } }),
-    ((42, 448), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.79145980, y: -0.05004000, z: 2.69741540 } }),
-    ((29, 447), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.27466180, y: -0.05342416, z: 2.97661970 } }),
-    ((20, 36), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.78223650, y: 0.43003818, z: 3.11304600 } }),
-    ((15, 54), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.49083063, y: 0.46092063, z: 3.16773100 } }),
-    ((16, 0), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.71748483, y: 0.00000000, z: 3.15802120 } }),
-    ((21, 0), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 0.93602950, y: 0.00000000, z: 3.10027900 } }),
-    ((21, 427), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.88817590, y: -0.29545680, z: 3.10027900 } }),
-    ((21, 21), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.89607924, y: 0.27054230, z: 3.10027900 } }),
-    ((49, 434), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.99596190, y: -0.45347142, z: 2.50965760 } }),
-    ((19, 10), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.84083630, y: 0.11817183, z: 3.12520600 } }),
-    ((26, 27), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.06925580, y: 0.42334834, z: 3.02743300 } }),
-    ((20, 2), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.89230350, y: 0.02492429, z: 3.11304600 } }),
-    ((47, 11), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.95269490, y: 0.30229315, z: 2.56583000 } }),
-    ((39, 449), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.67746700, y: -0.02342338, z: 2.77009730 } }),
-    ((23, 37), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.88882330, y: 0.50492190, z: 3.07293490 } }),
-    ((31, 18), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.31571910, y: 0.33781925, z: 2.93983750 } }),
-    ((26, 442), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.14284700, y: -0.12819082, z: 3.02743300 } }),
-    ((15, 60), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.45054036, y: 0.50037575, z: 3.16773100 } }),
-    ((21, 5), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.93374940, y: 0.06529412, z: 3.10027900 } }),
-    ((23, 4), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.02063550, y: 0.05706238, z: 3.07293490 } }),
-    ((29, 430), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.22635920, y: -0.35165286, z: 2.97661970 } }),
-    ((13, 7), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.58182114, y: 0.05704806, z: 3.18529630 } }),
-    ((29, 434), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.24407680, y: -0.28264734, z: 2.97661970 } }),
-    ((16, 437), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.70569760, y: -0.12951975, z: 3.15802120 } }),
-    ((42, 440), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.77471730, y: -0.24942026, z: 2.69741540 } }),
-    ((15, 418), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.60722370, y: -0.29093283, z: 3.16773100 } }),
-    ((24, 37), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.92604220, y: 0.52606520, z: 3.05836300 } }),
-    ((43, 434), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.78417780, y: -0.40535524, z: 2.67213000 } }),
-    ((20, 15), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.87314500, y: 0.18559270, z: 3.11304600 } }),
-    ((29, 17), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.24000930, y: 0.29998982, z: 2.97661970 } }),
-    ((38, 433), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.59284030, y: -0.38534862, z: 2.79325060 } }),
-    ((48, 447), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 2.00982260, y: -0.08423653, z: 2.53799130 } }),
-    ((41, 448), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.75363800, y: -0.04898355, z: 2.72217500 } }),
-    ((23, 439), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.01019610, y: -0.15638661, z: 3.07293490 } }),
-    ((31, 434), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.32463860, y: -0.30095050, z: 2.93983750 } }),
-    ((31, 28), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.25589920, y: 0.51764430, z: 2.93983750 } }),
-    ((35, 20), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.46148660, y: 0.41907454, z: 2.85942580 } }),
-    ((35, 427), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.44265560, y: -0.47990763, z: 2.85942580 } }),
-    ((31, 425), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.27647420, y: -0.46459866, z: 2.93983750 } }),
-    ((27, 28), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.10221730, y: 0.45430118, z: 3.01108120 } }),
-    ((47, 443), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.96652470, y: -0.19281943, z: 2.56583000 } }),
-    ((51, 444), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 2.10868120, y: -0.17707090, z: 2.45152850 } }),
-    ((25, 26), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.03544310, y: 0.39332792, z: 3.04319450 } }),
-    ((34, 442), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.47108630, y: -0.16500875, z: 2.88037500 } }),
-    ((15, 15), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.65860830, y: 0.13999152, z: 3.16773100 } }),
-    ((35, 17), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.47775360, y: 0.35750622, z: 2.85942580 } }),
-    ((42, 10), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.77471730, y: 0.24942026, z: 2.69741540 } }),
-    ((22, 11), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.96769786, y: 0.14980754, z: 3.08690790 } }),
-    ((15, 431), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.64976686, y: -0.17653775, z: 3.16773100 } }),
-    ((31, 428), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.29480990, y: -0.41073793, z: 2.93983750 } }),
-    ((21, 436), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 0.91820294, y: -0.18180908, z: 3.10027900 } }),
-    ((42, 6), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.78587320, y: 0.14996396, z: 2.69741540 } }),
-    ((45, 444), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.89686660, y: -0.15928434, z: 2.62000160 } }),
-    ((49, 446), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 2.04363540, y: -0.11425696, z: 2.50965760 } }),
-    ((26, 39), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.98368080, y: 0.59573823, z: 3.02743300 } }),
-    ((13, 436), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.57347745, y: -0.11355159, z: 3.18529630 } }),
-    ((21, 32), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.84414184, y: 0.40444500, z: 3.10027900 } }),
-    ((24, 431), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.02777600, y: -0.27924055, z: 3.05836300 } }),
-    ((26, 11), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.13647640, y: 0.17593583, z: 3.02743300 } }),
-    ((38, 24), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.54763480, y: 0.53894347, z: 2.79325060 } }),
-    ((25, 31), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.00548360, y: 0.46459866, z: 3.04319450 } }),
-    ((48, 3), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 2.00982260, y: 0.08423653, z: 2.53799130 } }),
-    ((30, 34), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.17155400, y: 0.60209700, z: 2.95851700 } }),
-    ((47, 10), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.95672520, y: 0.27499980, z: 2.56583000 } }),
-    ((33, 3), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.43868790, y: 0.06029889, z: 2.90076260 } }),
-    ((17, 47), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.60333467, y: 0.46463025, z: 3.14769580 } }),
-    ((22, 37), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.85143110, y: 0.48368016, z: 3.08690790 } }),
-    ((33, 15), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.40848470, y: 0.29938266, z: 2.90076260 } }),
-    ((30, 5), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.31400800, y: 0.09188439, z: 2.95851700 } }),
-    ((27, 433), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.15874410, y: -0.28032970, z: 3.01108120 } }),
-    ((37, 437), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.57335110, y: -0.28876397, z: 2.81585930 } }),
-    ((41, 9), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.74048860, y: 0.21987486, z: 2.72217500 } }),
-    ((25, 16), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.08010690, y: 0.24539426, z: 3.04319450 } }),
-    ((32, 1), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.39917330, y: 0.01953741, z: 2.92058470 } }),
-    ((21, 26), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.87502440, y: 0.33239058, z: 3.10027900 } }),
-    ((44, 436), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.83122360, y: -0.36259204, z: 2.64632370 } }),
-    ((38, 444), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.63304290, y: -0.13713044, z: 2.79325060 } }),
-    ((30, 426), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.24394820, y: -0.43318862, z: 2.95851700 } }),
-    ((37, 23), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.51785120, y: 0.50492190, z: 2.81585930 } }),
-    ((15, 430), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.64723870, y: -0.18559270, z: 3.16773100 } }),
-    ((33, 9), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.42859660, y: 0.18047372, z: 2.90076260 } }),
-    ((35, 21), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.45549300, y: 0.43943920, z: 2.85942580 } }),
-    ((38, 435), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.60297880, y: -0.34072366, z: 2.79325060 } }),
-    ((29, 427), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.21055780, y: -0.40269900, z: 2.97661970 } }),
-    ((47, 438), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.94828390, y: -0.32952756, z: 2.56583000 } }),
-    ((14, 443), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.62602586, y: -0.06138237, z: 3.17682340 } }),
-    ((32, 17), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.36007440, y: 0.32903665, z: 2.92058470 } }),
-    ((29, 443), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.26969210, y: -0.12449440, z: 2.97661970 } }),
-    ((15, 444), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.67096060, y: -0.05634213, z: 3.16773100 } }),
-    ((20, 38), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.76992416, y: 0.45171183, z: 3.11304600 } }),
-    ((18, 14), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.79004383, y: 0.15643288, z: 3.13675670 } }),
-    ((34, 15), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.44796320, y: 0.30777410, z: 2.88037500 } }),
-    ((16, 439), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.70903885, y: -0.10976502, z: 3.15802120 } }),
-    ((17, 12), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.75084360, y: 0.12699570, z: 3.14769580 } }),
-    ((17, 442), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.75676197, y: -0.08488446, z: 3.14769580 } }),
-    ((42, 12), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.76706120, y: 0.29887605, z: 2.69741540 } }),
-    ((33, 433), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.39957620, y: -0.33859313, z: 2.90076260 } }),
-    ((35, 437), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.49540600, y: -0.27445835, z: 2.85942580 } }),
-    ((50, 446), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 2.07842180, y: -0.11620182, z: 2.48083500 } }),
-    ((12, 428), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.51479980, y: -0.16330410, z: 3.19314810 } }),
-    ((12, 441), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.53582190, y: -0.06769005, z: 3.19314810 } }),
-    ((30, 4), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.31516280, y: 0.07352901, z: 2.95851700 } }),
-    ((16, 58), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.49479154, y: 0.51958233, z: 3.15802120 } }),
-    ((23, 13), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.00543570, y: 0.18453197, z: 3.07293490 } }),
-    ((23, 441), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.01416890, y: -0.12811932, z: 3.07293490 } }),
-    ((18, 5), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.80342030, y: 0.05618062, z: 3.13675670 } }),
-    ((14, 421), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.57816184, y: -0.24780047, z: 3.17682340 } }),
-    ((38, 15), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.60297880, y: 0.34072366, z: 2.79325060 } }),
-    ((41, 440), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.73724910, y: -0.24415444, z: 2.72217500 } }),
-    ((22, 3), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 0.97836596, y: 0.04100568, z: 3.08690790 } }),
-    ((19, 44), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.69383746, y: 0.48944850, z: 3.12520600 } }),
-    ((24, 20), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.02377700, y: 0.29356334, z: 3.05836300 } }),
-    ((15, 52), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.50350890, y: 0.44703615, z: 3.16773100 } }),
-    ((23, 26), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.95560630, y: 0.36300078, z: 3.07293490 } }),
-    ((24, 5), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.06244030, y: 0.07429306, z: 3.05836300 } }),
-    ((35, 16), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.48260120, y: 0.33683872, z: 2.85942580 } }),
-    ((27, 432), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.15471710, y: -0.29648095, z: 3.01108120 } }),
-    ((27, 447), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.19112560, y: -0.04992295, z: 3.01108120 } }),
-    ((37, 433), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.55477870, y: -0.37614056, z: 2.81585930 } }),
-    ((28, 17), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.19949350, y: 0.29018804, z: 2.99414230 } }),
-    ((13, 5), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.58318716, y: 0.04078042, z: 3.18529630 } }),
-    ((41, 441), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.74048860, y: -0.21987486, z: 2.72217500 } }),
-    ((46, 448), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.93918160, y: -0.05416625, z: 2.59316850 } }),
-    ((42, 436), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.75802720, y: -0.34809875, z: 2.69741540 } }),
-    ((43, 1), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.82946730, y: 0.02554584, z: 2.67213000 } }),
-    ((49, 3), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 2.04503150, y: 0.08571222, z: 2.50965760 } }),
-    ((12, 446), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.53923845, y: -0.03014811, z: 3.19314810 } }),
-    ((20, 439), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.88214360, y: -0.13656305, z: 3.11304600 } }),
-    ((22, 437), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 0.96313770, y: -0.17676884, z: 3.08690790 } }),
-    ((29, 37), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.10928500, y: 0.63016160, z: 2.97661970 } }),
-    ((34, 24), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.39797130, y: 0.48682514, z: 2.88037500 } }),
-    ((51, 437), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 2.08133820, y: -0.38199705, z: 2.45152850 } }),
-    ((35, 24), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.43581440, y: 0.50000346, z: 2.85942580 } }),
-    ((36, 27), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.45059940, y: 0.57433295, z: 2.83791920 } }),
-    ((22, 439), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 0.96769786, y: -0.14980754, z: 3.08690790 } }),
-    ((24, 2), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.06461940, y: 0.02973751, z: 3.05836300 } }),
-    ((32, 13), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.37632110, y: 0.25260220, z: 2.92058470 } }),
-    ((40, 12), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.69211080, y: 0.28619910, z: 2.74640370 } }),
-    ((31, 447), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.35720410, y: -0.05688370, z: 2.93983750 } }),
-    ((30, 0), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.31721660, y: 0.00000000, z: 2.95851700 } }),
-    ((21, 431), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.90328390, y: -0.24541680, z: 3.10027900 } }),
-    ((18, 21), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.77100810, y: 0.23278107, z: 3.13675670 } }),
-    ((22, 42), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.81561720, y: 0.54189480, z: 3.08690790 } }),
-    ((25, 43), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.91392230, y: 0.62577564, z: 3.04319450 } }),
-    ((13, 428), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.55724597, y: -0.17676884, z: 3.18529630 } }),
-    ((13, 4), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.58369970, y: 0.03263388, z: 3.18529630 } }),
-    ((30, 431), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.27113580, y: -0.34535995, z: 2.95851700 } }),
-    ((40, 447), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.71463820, y: -0.07186463, z: 2.74640370 } }),
-    ((21, 8), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.93019605, y: 0.10433821, z: 3.10027900 } }),
-    ((21, 46), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.74950814, y: 0.56070375, z: 3.10027900 } }),
-    ((37, 438), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.57722960, y: -0.26676843, z: 2.81585930 } }),
-    ((22, 25), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.92017040, y: 0.33491462, z: 3.08690790 } }),
-    ((15, 429), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.64458430, y: -0.19461147, z: 3.16773100 } }),
-    ((19, 1), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.84901690, y: 0.01185528, z: 3.12520600 } }),
-    ((25, 30), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.01187240, y: 0.45051460, z: 3.04319450 } }),
-    ((23, 11), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.01019610, y: 0.15638661, z: 3.07293490 } }),
-    ((24, 445), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.06244030, y: -0.07429306, z: 3.05836300 } }),
-    ((18, 433), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.78280010, y: -0.18937927, z: 3.13675670 } }),
-    ((21, 36), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.82024890, y: 0.45093563, z: 3.10027900 } }),
-    ((30, 443), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.31093000, y: -0.12853782, z: 2.95851700 } }),
-    ((24, 24), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.00579360, y: 0.35025436, z: 3.05836300 } }),
-    ((34, 4), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.47800350, y: 0.08263323, z: 2.88037500 } }),
-    ((48, 1), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 2.01139100, y: 0.02808614, z: 2.53799130 } }),
-    ((23, 29), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.93956715, y: 0.40269900, z: 3.07293490 } }),
-    ((35, 439), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.50248620, y: -0.23259717, z: 2.85942580 } }),
-    ((16, 8), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.71301340, y: 0.07997727, z: 3.15802120 } }),
-    ((27, 20), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.14598870, y: 0.32860696, z: 3.01108120 } }),
-    ((30, 20), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.26618990, y: 0.36307410, z: 2.95851700 } }),
-    ((16, 424), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.67072320, y: -0.25478384, z: 3.15802120 } }),
-    ((34, 434), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.44352500, y: -0.32796082, z: 2.88037500 } }),
-    ((16, 13), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.70569760, y: 0.12951975, z: 3.15802120 } }),
-    ((35, 429), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.45549300, y: -0.43943920, z: 2.85942580 } }),
-    ((49, 6), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 2.03964830, y: 0.17127407, z: 2.50965760 } }),
-    ((13, 444), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.58256096, y: -0.04891901, z: 3.18529630 } }),
-    ((46, 441), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.92464090, y: -0.24313869, z: 2.59316850 } }),
-    ((29, 21), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.22132990, y: 0.36874124, z: 2.97661970 } }),
-    ((15, 8), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.66912580, y: 0.07505449, z: 3.16773100 } }),
-    ((36, 25), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.46607020, y: 0.53360590, z: 2.83791920 } }),
-    ((24, 29), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.97891100, y: 0.41956177, z: 3.05836300 } }),
-    ((49, 437), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 2.01320050, y: -0.36949143, z: 2.50965760 } }),
-    ((30, 449), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.31708820, y: -0.01839122, z: 2.95851700 } }),
-    ((17, 19), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.73486750, y: 0.19965910, z: 3.14769580 } }),
-    ((26, 15), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.12488330, y: 0.23910134, z: 3.02743300 } }),
-    ((19, 433), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.82529175, y: -0.19965910, z: 3.12520600 } }),
-    ((20, 10), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.88396436, y: 0.12423309, z: 3.11304600 } }),
-    ((23, 449), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.02212980, y: -0.01427255, z: 3.07293490 } }),
-    ((43, 448), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.82893230, y: -0.05108671, z: 2.67213000 } }),
-    ((47, 8), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.96364080, y: 0.22025761, z: 2.56583000 } }),
-    ((16, 431), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.69238466, y: -0.18811676, z: 3.15802120 } }),
-    ((33, 443), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.43307880, y: -0.14051460, z: 2.90076260 } }),
-    ((16, 49), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.55601090, y: 0.45347142, z: 3.15802120 } }),
-    ((26, 431), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.10978230, y: -0.30152120, z: 3.02743300 } }),
-    ((33, 430), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.38416980, y: -0.39690430, z: 2.90076260 } }),
-    ((44, 443), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.85786660, y: -0.18216541, z: 2.64632370 } }),
-    ((33, 445), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.43644330, y: -0.10044590, z: 2.90076260 } }),
-    ((35, 26), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.42129370, y: 0.53989880, z: 2.85942580 } }),
-    ((26, 14), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.12811210, y: 0.22337220, z: 3.02743300 } }),
-    ((42, 0), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.79215850, y: 0.00000000, z: 2.69741540 } }),
-    ((24, 15), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.04176100, y: 0.22143315, z: 3.05836300 } }),
-    ((12, 435), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.52827850, y: -0.11228907, z: 3.19314810 } }),
-    ((16, 52), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.53653380, y: 0.47635698, z: 3.15802120 } }),
-    ((19, 45), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.68693610, y: 0.49908826, z: 3.12520600 } }),
-    ((36, 445), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.55635880, y: -0.10883121, z: 2.83791920 } }),
-    ((30, 18), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.27583380, y: 0.32757846, z: 2.95851700 } }),
-    ((24, 440), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.05466970, y: -0.14822417, z: 3.05836300 } }),
-    ((45, 440), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.88501740, y: -0.26492190, z: 2.62000160 } }),
-    ((34, 30), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.35233200, y: 0.60209700, z: 2.88037500 } }),
-    ((23, 33), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.91562290, y: 0.45451915, z: 3.07293490 } }),
-    ((29, 441), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.26572100, y: -0.15989774, z: 2.97661970 } }),
-    ((20, 13), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.87798660, y: 0.16114070, z: 3.11304600 } }),
-    ((41, 0), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.75432200, y: 0.00000000, z: 2.72217500 } }),
-    ((12, 422), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.49932936, y: -0.20580871, z: 3.19314810 } }),
-    ((43, 5), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.82518880, y: 0.12762962, z: 2.67213000 } }),
-    ((30, 16), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.28448300, y: 0.29182735, z: 2.95851700 } }),
-    ((36, 446), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.55772660, y: -0.08709044, z: 2.83791920 } }),
-    ((27, 444), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.18799030, y: -0.09975833, z: 3.01108120 } }),
-    ((14, 423), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.58485540, y: -0.23156063, z: 3.17682340 } }),
-    ((39, 437), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.65006950, y: -0.30284440, z: 2.77009730 } }),
-    ((30, 26), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.23136800, y: 0.46775278, z: 2.95851700 } }),
-    ((29, 14), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.25148380, y: 0.24780047, z: 2.97661970 } }),
-    ((33, 425), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.35311140, y: -0.49249226, z: 2.90076260 } }),
-    ((24, 0), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.06503460, y: 0.00000000, z: 3.05836300 } }),
-    ((23, 35), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.90257500, y: 0.47990763, z: 3.07293490 } }),
-    ((32, 27), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.30104530, y: 0.51512027, z: 2.92058470 } }),
-    ((39, 8), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.66717530, y: 0.18700367, z: 2.77009730 } }),
-    ((38, 20), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.57530640, y: 0.45171183, z: 2.79325060 } }),
-    ((16, 18), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.69494370, y: 0.17843121, z: 3.15802120 } }),
-    ((37, 25), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.50316120, y: 0.54710590, z: 2.81585930 } }),
-    ((42, 9), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.77802680, y: 0.22461702, z: 2.69741540 } }),
-    ((50, 444), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 2.07436700, y: -0.17418946, z: 2.48083500 } }),
-    ((26, 0), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.15001390, y: 0.00000000, z: 3.02743300 } }),
-    ((22, 30), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.89456650, y: 0.39828664, z: 3.08690790 } }),
-    ((19, 443), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.84504724, y: -0.08285761, z: 3.12520600 } }),
-    ((20, 443), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 0.88839130, y: -0.08710753, z: 3.11304600 } }),
-    ((26, 7), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.14452530, y: 0.11222169, z: 3.02743300 } }),
-    ((45, 12), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.87688540, y: 0.31745142, z: 2.62000160 } }),
-    ((35, 4), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.51801300, y: 0.08487011, z: 2.85942580 } }),
-    ((34, 21), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.41713120, y: 0.42785713, z: 2.88037500 } }),
-    ((16, 441), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.71182720, y: -0.08992469, z: 3.15802120 } }),
-    ((16, 57), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.50199780, y: 0.51262330, z: 3.15802120 } }),
-    ((13, 438), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.57642436, y: -0.09749489, z: 3.18529630 } }),
-    ((18, 4), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.80412644, y: 0.04495764, z: 3.13675670 } }),
-    ((32, 440), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.38569180, y: -0.19474627, z: 2.92058470 } }),
-    ((24, 442), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.05839730, y: -0.11871827, z: 3.05836300 } }),
-    ((14, 441), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.62406790, y: -0.07883811, z: 3.17682340 } }),
-    ((49, 439), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 2.02273250, y: -0.31313553, z: 2.50965760 } }),
-    ((16, 54), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.52302390, y: 0.49115214, z: 3.15802120 } }),
-    ((32, 443), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.39263140, y: -0.13654870, z: 2.92058470 } }),
-    ((25, 430), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.06472440, y: -0.30530483, z: 3.04319450 } }),
-    ((32, 9), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.38827570, y: 0.17538000, z: 2.92058470 } }),
-    ((28, 33), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.10539470, y: 0.54872270, z: 2.99414230 } }),
-    ((19, 21), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.81285965, y: 0.24541680, z: 3.12520600 } }),
-    ((32, 26), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.30811060, y: 0.49690460, z: 2.92058470 } }),
-    ((12, 443), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.53750300, y: -0.05270263, z: 3.19314810 } }),
-    ((21, 444), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 0.93274670, y: -0.07832493, z: 3.10027900 } }),
-    ((28, 439), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.21956910, y: -0.18879928, z: 2.99414230 } }),
-    ((27, 26), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.11447240, y: 0.42334834, z: 3.01108120 } }),
-    ((20, 438), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.88015090, y: -0.14886639, z: 3.11304600 } }),
-    ((12, 423), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.50215423, y: -0.19881693, z: 3.19314810 } }),
-    ((27, 34), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.06033670, y: 0.54493904, z: 3.01108120 } }),
-    ((13, 447), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.58409846, y: -0.02448098, z: 3.18529630 } }),
-    ((20, 28), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.82529750, y: 0.34016308, z: 3.11304600 } }),
-    ((29, 28), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.17951810, y: 0.48616230, z: 2.97661970 } }),
-    ((43, 12), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.80402340, y: 0.30512770, z: 2.67213000 } }),
-    ((41, 4), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.75158670, y: 0.09792890, z: 2.72217500 } }),
-    ((20, 7), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.88839130, y: 0.08710753, z: 3.11304600 } }),
-    ((17, 429), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.72900620, y: -0.22009996, z: 3.14769580 } }),
-    ((23, 42), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.85143660, y: 0.56569314, z: 3.07293490 } }),
-    ((18, 35), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.71111030, y: 0.37810403, z: 3.13675670 } }),
-    ((22, 1), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 0.97912943, y: 0.01367211, z: 3.08690790 } }),
-    ((20, 26), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.83447360, y: 0.31698680, z: 3.11304600 } }),
-    ((35, 443), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.51312740, y: -0.14836344, z: 2.85942580 } }),
-    ((40, 15), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.67864170, y: 0.35680630, z: 2.74640370 } }),
-    ((18, 32), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.72631985, y: 0.34799418, z: 3.13675670 } }),
-    ((33, 436), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.41252740, y: -0.27968794, z: 2.90076260 } }),
-    ((29, 433), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.24000930, y: -0.29998982, z: 2.97661970 } }),
-    ((35, 1), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.52023540, y: 0.02122787, z: 2.85942580 } }),
-    ((40, 444), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.71012470, y: -0.14360319, z: 2.74640370 } }),
-    ((24, 32), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.96048290, y: 0.46018630, z: 3.05836300 } }),
-    ((34, 18), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.43380490, y: 0.36813852, z: 2.88037500 } }),
-    ((39, 447), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.67615890, y: -0.07025188, z: 2.77009730 } }),
-    ((30, 33), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.17984640, y: 0.58568084, z: 2.95851700 } }),
-    ((25, 5), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.10493410, y: 0.07726452, z: 3.04319450 } }),
-    ((28, 25), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.15967130, y: 0.42208582, z: 2.99414230 } }),
-    ((18, 426), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.76058394, y: -0.26486334, z: 3.13675670 } }),
-    ((46, 11), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.91710170, y: 0.29678303, z: 2.59316850 } }),
-    ((20, 445), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 0.89047710, y: -0.06226823, z: 3.11304600 } }),
-    ((27, 442), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.18474160, y: -0.13289008, z: 3.01108120 } }),
-    ((24, 42), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.88708997, y: 0.58938116, z: 3.05836300 } }),
-    ((22, 433), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 0.95176840, y: -0.23025700, z: 3.08690790 } }),
-    ((17, 0), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.76150775, y: 0.00000000, z: 3.14769580 } }),
-    ((24, 430), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.02377700, y: -0.29356334, z: 3.05836300 } }),
-    ((18, 423), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.74882543, y: -0.29648095, z: 3.13675670 } }),
-    ((26, 23), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.09122060, y: 0.36300078, z: 3.02743300 } }),
-    ((18, 10), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.79754430, y: 0.11208754, z: 3.13675670 } }),
-    ((46, 3), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.93823620, y: 0.08123617, z: 2.59316850 } }),
-    ((35, 436), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.49142810, y: -0.29531074, z: 2.85942580 } }),
-    ((17, 439), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.75254357, y: -0.11649990, z: 3.14769580 } }),
-    ((45, 1), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.90335700, y: 0.02657761, z: 2.62000160 } }),
-    ((30, 7), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.31093000, y: 0.12853782, z: 2.95851700 } }),
-    ((31, 440), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.34517570, y: -0.18905212, z: 2.93983750 } }),
-    ((18, 429), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.77100810, y: -0.23278107, z: 3.13675670 } }),
-    ((23, 32), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.92187970, y: 0.44169077, z: 3.07293490 } }),
-    ((27, 4), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.19031250, y: 0.06654880, z: 3.01108120 } }),
-    ((34, 439), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.46288600, y: -0.22646672, z: 2.88037500 } }),
-    ((29, 34), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.13470040, y: 0.58315676, z: 2.97661970 } }),
-    ((18, 16), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.78536790, y: 0.17843121, z: 3.13675670 } }),
-    ((19, 30), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.77569115, y: 0.34535995, z: 3.12520600 } }),
-    ((19, 23), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.80569030, y: 0.26801750, z: 3.12520600 } }),
-    ((15, 446), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.67227215, y: -0.03758585, z: 3.16773100 } }),
-    ((30, 425), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.23777880, y: -0.45051460, z: 2.95851700 } }),
-    ((42, 14), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.75802720, y: 0.34809875, z: 2.69741540 } }),
-    ((42, 435), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.75299560, y: -0.37261072, z: 2.69741540 } }),
-    ((12, 436), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.52979480, y: -0.10490220, z: 3.19314810 } }),
-    ((41, 15), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.71598590, y: 0.36474407, z: 2.72217500 } }),
-    ((35, 449), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.52023540, y: -0.02122787, z: 2.85942580 } }),
-    ((25, 428), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.05578470, y: -0.33491462, z: 3.04319450 } }),
-    ((33, 29), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.32350990, y: 0.56725705, z: 2.90076260 } }),
-    ((25, 35), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.97798120, y: 0.52000180, z: 3.04319450 } }),
-    ((15, 427), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.63889910, y: -0.21253344, z: 3.16773100 } }),
-    ((32, 15), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.36873140, y: 0.29093283, z: 2.92058470 } }),
-    ((24, 429), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.01957850, y: -0.30782887, z: 3.05836300 } }),
-    ((24, 26), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.99562174, y: 0.37820122, z: 3.05836300 } }),
-    ((19, 445), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.84703130, y: -0.05923020, z: 3.12520600 } }),
-    ((33, 429), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.37849320, y: -0.41619164, z: 2.90076260 } }),
-    ((36, 442), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.55043630, y: -0.17390929, z: 2.83791920 } }),
-    ((37, 449), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.59947480, y: -0.02233433, z: 2.81585930 } }),
-    ((32, 4), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.39712790, y: 0.07811157, z: 2.92058470 } }),
-    ((29, 3), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.27466180, y: 0.05342416, z: 2.97661970 } }),
-    ((17, 18), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.73758360, y: 0.18937927, z: 3.14769580 } }),
-    ((30, 446), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.31516280, y: -0.07352901, z: 2.95851700 } }),
-    ((45, 438), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.87688540, y: -0.31745142, z: 2.62000160 } }),
-    ((19, 40), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.72007734, y: 0.44995427, z: 3.12520600 } }),
-    ((25, 444), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.10374760, y: -0.09268428, z: 3.04319450 } }),
-    ((20, 440), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.88396436, y: -0.12423309, z: 3.11304600 } }),
-    ((20, 21), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.85455275, y: 0.25800470, z: 3.11304600 } }),
-    ((41, 447), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.75278320, y: -0.07346338, z: 2.72217500 } }),
-    ((32, 437), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.37632110, y: -0.25260220, z: 2.92058470 } }),
-    ((27, 434), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.16254510, y: -0.26412377, z: 3.01108120 } }),
-    ((48, 444), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 2.00453200, y: -0.16832528, z: 2.53799130 } }),
-    ((21, 15), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.91557497, y: 0.19461147, z: 3.10027900 } }),
-    ((44, 437), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.83610760, y: -0.33698880, z: 2.64632370 } }),
-    ((33, 428), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.37254790, y: -0.43539783, z: 2.90076260 } }),
-    ((18, 438), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.79410370, y: -0.13431258, z: 3.13675670 } }),
-    ((25, 443), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.10234600, y: -0.10808597, z: 3.04319450 } }),
-    ((23, 433), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 0.99356710, y: -0.24036919, z: 3.07293490 } }),
-    ((25, 22), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.05578470, y: 0.33491462, z: 3.04319450 } }),
-    ((19, 7), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.84504724, y: 0.08285761, z: 3.12520600 } }),
-    ((26, 437), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.13112090, y: -0.20759952, z: 3.02743300 } }),
-    ((15, 439), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.66539590, y: -0.10300873, z: 3.16773100 } }),
-    ((13, 414), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.51229876, y: -0.28163862, z: 3.18529630 } }),
-    ((41, 434), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.71072600, y: -0.38866740, z: 2.72217500 } }),
-    ((35, 438), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.49909220, y: -0.25355247, z: 2.85942580 } }),
-    ((20, 430), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.85807174, y: -0.24604812, z: 3.11304600 } }),
-    ((35, 431), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.46719530, y: -0.39862815, z: 2.85942580 } }),
-    ((36, 3), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.55879080, y: 0.06533269, z: 2.83791920 } }),
-    ((40, 17), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.66802450, y: 0.40353763, z: 2.74640370 } }),
-    ((27, 3), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.19112560, y: 0.04992295, z: 3.01108120 } }),
-    ((32, 3), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.39808230, y: 0.05859701, z: 2.92058470 } }),
-    ((43, 446), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.82679280, y: -0.10213357, z: 2.67213000 } }),
-    ((30, 441), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.30682990, y: -0.16509102, z: 2.95851700 } }),
-    ((13, 426), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.55209300, y: -0.19225915, z: 3.18529630 } }),
-    ((22, 44), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.80016863, y: 0.56445690, z: 3.08690790 } }),
-    ((18, 43), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.66453170, y: 0.45501438, z: 3.13675670 } }),
-    ((25, 15), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.08342780, y: 0.23028970, z: 3.04319450 } }),
-    ((17, 20), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.73200820, y: 0.20989998, z: 3.14769580 } }),
-    ((33, 439), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.42300050, y: -0.22029210, z: 2.90076260 } }),
-    ((47, 436), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.93832340, y: -0.38379836, z: 2.56583000 } }),
-    ((39, 20), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.61264190, y: 0.46241763, z: 2.77009730 } }),
-    ((22, 38), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.84459484, y: 0.49552086, z: 3.08690790 } }),
-    ((19, 36), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.74407170, y: 0.40905687, z: 3.12520600 } }),
-    ((50, 448), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 2.08085600, y: -0.05812357, z: 2.48083500 } }),
-    ((36, 5), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.55635880, y: 0.10883121, z: 2.83791920 } }),
-    ((25, 11), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.09459360, y: 0.16945204, z: 3.04319450 } }),
-    ((16, 440), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.71050230, y: -0.09985459, z: 3.15802120 } }),
-    ((41, 11), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.73367080, y: 0.26838642, z: 2.72217500 } }),
-    ((27, 30), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.08910270, y: 0.48489980, z: 3.01108120 } }),
-    ((37, 445), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.59573410, y: -0.11158460, z: 2.81585930 } }),
-    ((23, 443), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.01735070, y: -0.09975211, z: 3.07293490 } }),
-    ((32, 7), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.39263140, y: 0.13654870, z: 2.92058470 } }),
-    ((28, 36), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.08144690, y: 0.59453046, z: 2.99414230 } }),
-    ((21, 30), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.85510546, y: 0.38071750, z: 3.10027900 } }),
-    ((30, 439), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.30171080, y: -0.20151548, z: 2.95851700 } }),
-    ((49, 7), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 2.03705800, y: 0.19973530, z: 2.50965760 } }),
-    ((19, 29), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.78043750, y: 0.33449593, z: 3.12520600 } }),
-    ((17, 30), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.69567190, y: 0.30973310, z: 3.14769580 } }),
-    ((22, 427), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.92916304, y: -0.30909140, z: 3.08690790 } }),
-    ((33, 448), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.43938960, y: -0.04020579, z: 2.90076260 } }),
-    ((23, 43), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.84345526, y: 0.57752590, z: 3.07293490 } }),
-    ((17, 449), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.76143350, y: -0.01063231, z: 3.14769580 } }),
-    ((23, 36), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.89578646, y: 0.49246278, z: 3.07293490 } }),
-    ((37, 443), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.59199630, y: -0.15609660, z: 2.81585930 } }),
-    ((12, 438), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.53251730, y: -0.09006856, z: 3.19314810 } }),
-    ((19, 449), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.84901690, y: -0.01185528, z: 3.12520600 } }),
-    ((39, 439), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.65788210, y: -0.25665370, z: 2.77009730 } }),
-    ((30, 32), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.18790880, y: 0.56915050, z: 2.95851700 } }),
-    ((28, 7), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.22820650, y: 0.12042671, z: 2.99414230 } }),
-    ((43, 449), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.82946730, y: -0.02554584, z: 2.67213000 } }),
-    ((23, 440), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.01228120, y: -0.14226684, z: 3.07293490 } }),
-    ((32, 28), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.29372620, y: 0.53323550, z: 2.92058470 } }),
-    ((17, 48), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.59678864, y: 0.47300884, z: 3.14769580 } }),
-    ((20, 29), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.82046760, y: 0.35165286, z: 3.11304600 } }),
-    ((24, 436), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.04475120, y: -0.20686631, z: 3.05836300 } }),
-    ((42, 446), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.78936410, y: -0.10004099, z: 2.69741540 } }),
-    ((14, 437), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.61869395, y: -0.11355159, z: 3.17682340 } }),
-    ((49, 5), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 2.04184100, y: 0.14277942, z: 2.50965760 } }),
-    ((32, 444), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.39440210, y: -0.11709122, z: 2.92058470 } }),
-    ((31, 444), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.35363150, y: -0.11366761, z: 2.93983750 } }),
-    ((15, 61), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.44351012, y: 0.50661755, z: 3.16773100 } }),
-    ((40, 448), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.71547450, y: -0.04791754, z: 2.74640370 } }),
-    ((30, 434), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.28448300, y: -0.29182735, z: 2.95851700 } }),
-    ((41, 437), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.72550110, y: -0.31668872, z: 2.72217500 } }),
-    ((35, 6), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.51505150, y: 0.12722242, z: 2.85942580 } }),
-    ((31, 31), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.23312090, y: 0.56978184, z: 2.93983750 } }),
-    ((46, 6), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.93313430, y: 0.16232982, z: 2.59316850 } }),
-    ((49, 443), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 2.03705800, y: -0.19973530, z: 2.50965760 } }),
-    ((20, 432), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.86460730, y: -0.22199342, z: 3.11304600 } }),
-    ((42, 445), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.78779290, y: -0.12501466, z: 2.69741540 } }),
-    ((29, 435), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.24790200, y: -0.26524976, z: 2.97661970 } }),
-    ((22, 430), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 0.94129140, y: -0.26991096, z: 3.08690790 } }),
-    ((42, 442), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.78098960, y: -0.19977000, z: 2.69741540 } }),
-    ((44, 448), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.86604820, y: -0.05212345, z: 2.64632370 } }),
-    ((47, 435), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.93277570, y: -0.41082418, z: 2.56583000 } }),
-    ((15, 428), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.64180430, y: -0.20359230, z: 3.16773100 } }),
-    ((23, 3), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.02133270, y: 0.04280652, z: 3.07293490 } }),
-    ((16, 444), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.71496850, y: -0.06003758, z: 3.15802120 } }),
-    ((30, 438), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.29877030, y: -0.21967056, z: 2.95851700 } }),
-    ((16, 4), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.71636605, y: 0.04005108, z: 3.15802120 } }),
-    ((31, 8), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.34992990, y: 0.15141891, z: 2.93983750 } }),
-    ((25, 447), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.10666060, y: -0.04638282, z: 3.04319450 } }),
-    ((29, 5), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.27267310, y: 0.08899397, z: 2.97661970 } }),
-    ((28, 39), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.05560200, y: 0.63929530, z: 2.99414230 } }),
-    ((30, 440), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.30439760, y: -0.18332112, z: 2.95851700 } }),
-    ((15, 51), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.50970143, y: 0.43996248, z: 3.16773100 } }),
-    ((28, 431), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.19092350, y: -0.32356673, z: 2.99414230 } }),
-    ((43, 447), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.82804070, y: -0.07661761, z: 2.67213000 } }),
-    ((32, 428), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.33380890, y: -0.42310910, z: 2.92058470 } }),
-    ((36, 438), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.53831090, y: -0.26018580, z: 2.83791920 } }),
-    ((27, 439), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.17813750, y: -0.18238534, z: 3.01108120 } }),
-    ((21, 16), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.91276854, y: 0.20737593, z: 3.10027900 } }),
-    ((27, 15), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.16611960, y: 0.24786636, z: 3.01108120 } }),
-    ((23, 25), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.96058140, y: 0.34962305, z: 3.07293490 } }),
-    ((41, 433), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.70513260, y: -0.41251498, z: 2.72217500 } }),
-    ((36, 28), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.44243910, y: 0.59453046, z: 2.83791920 } }),
-    ((51, 447), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 2.11424640, y: -0.08861318, z: 2.45152850 } }),
-    ((38, 441), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.62586800, y: -0.20539490, z: 2.79325060 } }),
-    ((20, 435), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.87314500, y: -0.18559270, z: 3.11304600 } }),
-    ((18, 430), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.77418303, y: -0.22199342, z: 3.13675670 } }),
-    ((39, 436), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.64568030, y: -0.32585347, z: 2.77009730 } }),
-    ((21, 13), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.92065190, y: 0.16897124, z: 3.10027900 } }),
-    ((40, 8), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.70544840, y: 0.19129668, z: 2.74640370 } }),
-    ((32, 0), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.39930980, y: 0.00000000, z: 2.92058470 } }),
-    ((23, 34), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.90918756, y: 0.46725893, z: 3.07293490 } }),
-    ((46, 2), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.93918160, y: 0.05416625, z: 2.59316850 } }),
-    ((30, 8), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.30900760, y: 0.14682873, z: 2.95851700 } }),
-    ((44, 439), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.84480110, y: -0.28559030, z: 2.64632370 } }),
-    ((40, 1), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.71597620, y: 0.02396111, z: 2.74640370 } }),
-    ((29, 426), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.20481730, y: -0.41956177, z: 2.97661970 } }),
-    ((21, 42), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.77963880, y: 0.51799080, z: 3.10027900 } }),
-    ((34, 23), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.40463220, y: 0.46725893, z: 2.88037500 } }),
-    ((39, 14), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.64568030, y: 0.32585347, z: 2.77009730 } }),
-    ((20, 446), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 0.89125973, y: -0.04982915, z: 3.11304600 } }),
-    ((25, 445), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.10493410, y: -0.07726452, z: 3.04319450 } }),
-    ((22, 12), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.96551186, y: 0.16330410, z: 3.08690790 } }),
-    ((39, 443), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.66962390, y: -0.16370805, z: 2.77009730 } }),
-    ((14, 435), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.61528220, y: -0.13078226, z: 3.17682340 } }),
-    ((24, 38), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.91860694, y: 0.53894347, z: 3.05836300 } }),
-    ((37, 15), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.56467500, y: 0.33258194, z: 2.81585930 } }),
-    ((15, 433), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.65444270, y: -0.15832636, z: 3.16773100 } }),
-    ((37, 6), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.59402060, y: 0.13385364, z: 2.81585930 } }),
-    ((16, 10), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.71050230, y: 0.09985459, z: 3.15802120 } }),
-    ((18, 17), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.78280010, y: 0.18937927, z: 3.13675670 } }),
-    ((38, 4), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.63623510, y: 0.09147974, z: 2.79325060 } }),
-    ((22, 444), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 0.97579060, y: -0.08193943, z: 3.08690790 } }),
-    ((13, 449), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.58455426, y: -0.00816245, z: 3.18529630 } }),
-    ((34, 7), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.47324670, y: 0.14445311, z: 2.88037500 } }),
-    ((29, 429), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.22132990, y: -0.36874124, z: 2.97661970 } }),
-    ((28, 3), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.23301390, y: 0.05167859, z: 2.99414230 } }),
-    ((33, 27), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.33883260, y: 0.53008133, z: 2.90076260 } }),
-    ((19, 14), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.83292866, y: 0.16492432, z: 3.12520600 } }),
-    ((40, 2), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.71547450, y: 0.04791754, z: 2.74640370 } }),
-    ((15, 423), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.62603897, y: -0.24786636, z: 3.16773100 } }),
-    ((27, 1), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.19205510, y: 0.01664531, z: 3.01108120 } }),
-    ((13, 433), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.56821936, y: -0.13746673, z: 3.18529630 } }),
-    ((20, 11), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.88214360, y: 0.13656305, z: 3.11304600 } }),
-    ((26, 30), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.05058990, y: 0.46775278, z: 3.02743300 } }),
-    ((32, 18), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.35534780, y: 0.34799418, z: 2.92058470 } }),
-    ((48, 5), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 2.00668700, y: 0.14032122, z: 2.53799130 } }),
-    ((39, 441), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.66440190, y: -0.21026285, z: 2.77009730 } }),
-    ((28, 436), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.21059320, y: -0.23970392, z: 2.99414230 } }),
-    ((24, 43), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.87877446, y: 0.60170940, z: 3.05836300 } }),
-    ((36, 434), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.52138830, y: -0.34565094, z: 2.83791920 } }),
-    ((28, 23), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.17100450, y: 0.38954136, z: 2.99414230 } }),
-    ((31, 438), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.33937260, y: -0.22653793, z: 2.93983750 } }),
-    ((25, 27), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.02985040, y: 0.40774660, z: 3.04319450 } }),
-    ((43, 13), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.79958720, y: 0.33028606, z: 2.67213000 } }),
-    ((23, 9), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.01416890, y: 0.12811932, z: 3.07293490 } }),
-    ((15, 16), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.65658950, y: 0.14917348, z: 3.16773100 } }),
-    ((21, 426), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.88396410, y: -0.30782887, z: 3.10027900 } }),
-    ((48, 446), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 2.00845050, y: -0.11228982, z: 2.53799130 } }),
-    ((27, 443), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.18648160, y: -0.11633554, z: 3.01108120 } }),
-    ((15, 432), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.65216833, y: -0.16744837, z: 3.16773100 } }),
-    ((42, 3), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.79058650, y: 0.07504781, z: 2.69741540 } }),
-    ((44, 0), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.86677610, y: 0.00000000, z: 2.64632370 } }),
-    ((26, 33), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.03008090, y: 0.51133660, z: 3.02743300 } }),
-    ((30, 19), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.27113580, y: 0.34535995, z: 2.95851700 } }),
-    ((27, 9), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.18277070, y: 0.14941870, z: 3.01108120 } }),
-    ((33, 8), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.43097710, y: 0.16050981, z: 2.90076260 } }),
-    ((34, 441), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.46863890, y: -0.18553224, z: 2.88037500 } }),
-    ((45, 437), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.87227010, y: -0.34362587, z: 2.62000160 } }),
-    ((19, 427), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.80569030, y: -0.26801750, z: 3.12520600 } }),
-    ((26, 5), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.14721250, y: 0.08022092, z: 3.02743300 } }),
-    ((45, 6), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.89686660, y: 0.15928434, z: 2.62000160 } }),
-    ((31, 433), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.32030750, y: -0.31941602, z: 2.93983750 } }),
-    ((14, 6), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.62682190, y: 0.05263570, z: 3.17682340 } }),
-    ((25, 7), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.10234600, y: 0.10808597, z: 3.04319450 } }),
-    ((43, 14), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.79480030, y: 0.35538003, z: 2.67213000 } }),
-    ((13, 417), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.52364320, y: -0.25993872, z: 3.18529630 } }),
-    ((18, 6), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.80255760, y: 0.06739265, z: 3.13675670 } }),
-    ((12, 427), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.51246953, y: -0.17047592, z: 3.19314810 } }),
-    ((27, 17), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.15874410, y: 0.28032970, z: 3.01108120 } }),
-    ((21, 41), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.78679514, y: 0.50705487, z: 3.10027900 } }),
-    ((27, 428), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.13636660, y: -0.36047673, z: 3.01108120 } }),
-    ((45, 442), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.89167950, y: -0.21218586, z: 2.62000160 } }),
-    ((20, 45), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.72217030, y: 0.52468740, z: 3.11304600 } }),
-    ((19, 444), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.84612170, y: -0.07105083, z: 3.12520600 } }),
-    ((30, 23), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.24987530, y: 0.41577816, z: 2.95851700 } }),
-    ((21, 441), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 0.92864860, y: -0.11731560, z: 3.10027900 } }),
-    ((25, 8), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.10072930, y: 0.12346658, z: 3.04319450 } }),
-    ((24, 28), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.98467356, y: 0.40585315, z: 3.05836300 } }),
-    ((35, 19), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.46719530, y: 0.39862815, z: 2.85942580 } }),
-    ((23, 10), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.01228120, y: 0.14226684, z: 3.07293490 } }),
-    ((37, 434), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.55987880, y: -0.35439578, z: 2.81585930 } }),
-    ((22, 34), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.87093866, y: 0.44760168, z: 3.08690790 } }),
-    ((48, 443), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 2.00198650, y: -0.19629650, z: 2.53799130 } }),
-    ((38, 7), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.63096900, y: 0.15991792, z: 2.79325060 } }),
-    ((32, 438), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.37971380, y: -0.23336112, z: 2.92058470 } }),
-    ((23, 434), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 0.99682635, y: -0.22647339, z: 3.07293490 } }),
-    ((12, 439), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.53372294, y: -0.08262468, z: 3.19314810 } }),
-    ((14, 431), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.60702235, y: -0.16492432, z: 3.17682340 } }),
-    ((37, 444), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.59402060, y: -0.13385364, z: 2.81585930 } }),
-    ((21, 45), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.75726380, y: 0.55018430, z: 3.10027900 } }),
-    ((25, 446), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.10590520, y: -0.06182970, z: 3.04319450 } }),
-    ((30, 28), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.21782740, y: 0.50195223, z: 2.95851700 } }),
-    ((24, 439), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.05249750, y: -0.16293521, z: 3.05836300 } }),
-    ((24, 447), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.06410040, y: -0.04459902, z: 3.05836300 } }),
-    ((23, 445), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.01973930, y: -0.07130712, z: 3.07293490 } }),
-    ((18, 25), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.75681174, y: 0.27545694, z: 3.13675670 } }),
-    ((23, 38), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.88168680, y: 0.51728260, z: 3.07293490 } }),
-    ((40, 10), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.69944210, y: 0.23884101, z: 2.74640370 } }),
-    ((22, 31), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.88891830, y: 0.41073793, z: 3.08690790 } }),
-    ((36, 430), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.49972140, y: -0.43003818, z: 2.83791920 } }),
-    ((33, 14), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.41252740, y: 0.27968794, z: 2.90076260 } }),
-    ((32, 430), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.34510280, y: -0.38570200, z: 2.92058470 } }),
-    ((39, 448), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.67697640, y: -0.04684219, z: 2.77009730 } }),
-    ((14, 419), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.57101740, y: -0.26384708, z: 3.17682340 } }),
-    ((24, 31), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.96681446, y: 0.44673100, z: 3.05836300 } }),
-    ((32, 21), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.33958650, y: 0.40444500, z: 2.92058470 } }),
-    ((32, 432), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.35534780, y: -0.34799418, z: 2.92058470 } }),
-    ((28, 21), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.18142460, y: 0.35669312, z: 2.99414230 } }),
-    ((51, 439), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 2.09119270, y: -0.32373372, z: 2.45152850 } }),
-    ((19, 9), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.84240425, y: 0.10642040, z: 3.12520600 } }),
-    ((17, 23), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.72257644, y: 0.24036919, z: 3.14769580 } }),
-    ((12, 448), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.53987000, y: -0.01507993, z: 3.19314810 } }),
-    ((15, 7), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.67010850, y: 0.06570471, z: 3.16773100 } }),
-    ((20, 25), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.83881810, y: 0.30530483, z: 3.11304600 } }),
-    ((31, 22), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.29480990, y: 0.41073793, z: 2.93983750 } }),
-    ((23, 6), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.01864430, y: 0.08553795, z: 3.07293490 } }),
-    ((16, 7), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.71406054, y: 0.07001425, z: 3.15802120 } }),
-    ((28, 445), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.23109020, y: -0.08608621, z: 2.99414230 } }),
-    ((17, 432), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.73758360, y: -0.18937927, z: 3.14769580 } }),
-    ((22, 445), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 0.97683954, y: -0.06830727, z: 3.08690790 } }),
-    ((17, 1), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.76143350, y: 0.01063231, z: 3.14769580 } }),
-    ((22, 2), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 0.97884310, y: 0.02734156, z: 3.08690790 } }),
-    ((31, 436), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.33252510, y: -0.26384708, z: 2.93983750 } }),
-    ((39, 10), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.66130390, y: 0.23348103, z: 2.77009730 } }),
-    ((16, 436), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.70382040, y: -0.13936019, z: 3.15802120 } }),
-    ((13, 442), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.58096790, y: -0.06516599, z: 3.18529630 } }),
-    ((16, 50), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.54962530, y: 0.46119034, z: 3.15802120 } }),
-    ((18, 34), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.71632010, y: 0.36813852, z: 3.13675670 } }),
-    ((16, 423), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.66710050, y: -0.26412377, z: 3.15802120 } }),
-    ((12, 447), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.53960687, y: -0.02261623, z: 3.19314810 } }),
-    ((36, 8), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.55043630, y: 0.17390929, z: 2.83791920 } }),
-    ((29, 31), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.15812520, y: 0.53512895, z: 2.97661970 } }),
-    ((50, 439), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 2.05716300, y: -0.31846568, z: 2.48083500 } }),
-    ((18, 436), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.79004383, y: -0.15643288, z: 3.13675670 } }),
-    ((44, 441), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.85205600, y: -0.23396908, z: 2.64632370 } }),
-    ((36, 26), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.45847690, y: 0.55402344, z: 2.83791920 } }),
-    ((21, 439), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 0.92501090, y: -0.14319925, z: 3.10027900 } }),
-    ((45, 10), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.88501740, y: 0.26492190, z: 2.62000160 } }),
-    ((38, 18), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.58730470, y: 0.40755057, z: 2.79325060 } }),
-    ((29, 436), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.25148380, y: -0.24780047, z: 2.97661970 } }),
-    ((33, 438), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.41978600, y: -0.24013883, z: 2.90076260 } }),
-    ((50, 435), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 2.03617840, y: -0.43280303, z: 2.48083500 } }),
-    ((13, 2), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.58438330, y: 0.01632330, z: 3.18529630 } }),
-    ((24, 446), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.06337400, y: -0.05945184, z: 3.05836300 } }),
-    ((26, 42), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.95787100, y: 0.63640800, z: 3.02743300 } }),
-    ((26, 32), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.03712000, y: 0.49690460, z: 3.02743300 } }),
-    ((17, 11), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.75254357, y: 0.11649990, z: 3.14769580 } }),
-    ((36, 441), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.54785690, y: -0.19553980, z: 2.83791920 } }),
-    ((33, 16), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.40416730, y: 0.31901900, z: 2.90076260 } }),
-    ((22, 41), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.82310370, y: 0.53045416, z: 3.08690790 } }),
-    ((31, 7), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.35191250, y: 0.13255619, z: 2.93983750 } }),
-    ((31, 15), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.32871140, y: 0.28242633, z: 2.93983750 } }),
-    ((28, 32), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.11294830, y: 0.53323550, z: 2.99414230 } }),
-    ((24, 27), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.99024415, y: 0.39206540, z: 3.05836300 } }),
-    ((19, 8), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.84380800, y: 0.09464823, z: 3.12520600 } }),
-    ((42, 434), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.74762230, y: -0.39705002, z: 2.69741540 } }),
-    ((48, 448), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 2.01080270, y: -0.05616681, z: 2.53799130 } }),
-    ((19, 16), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.82799900, y: 0.18811676, z: 3.12520600 } }),
-    ((29, 32), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.15054070, y: 0.55124676, z: 2.97661970 } }),
-    ((44, 446), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.86386530, y: -0.10420625, z: 2.64632370 } }),
-    ((47, 4), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.97287420, y: 0.11030079, z: 2.56583000 } }),
-    ((25, 437), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.08943550, y: -0.19994882, z: 3.04319450 } }),
-    ((34, 446), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.47800350, y: -0.08263323, z: 2.88037500 } }),
-    ((24, 17), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.03517210, y: 0.25043450, z: 3.05836300 } }),
-    ((25, 3), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.10666060, y: 0.04638282, z: 3.04319450 } }),
-    ((39, 433), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.63059140, y: -0.39448157, z: 2.77009730 } }),
-    ((39, 434), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.63594030, y: -0.37167650, z: 2.77009730 } }),
-    ((26, 441), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.14094570, y: -0.14413495, z: 3.02743300 } }),
-    ((25, 32), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.99889880, y: 0.47859210, z: 3.04319450 } }),
-    ((16, 432), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.69494370, y: -0.17843121, z: 3.15802120 } }),
-    ((34, 6), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.47512000, y: 0.12386928, z: 2.88037500 } }),
-    ((34, 445), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.47670570, y: -0.10326132, z: 2.88037500 } }),
-    ((17, 10), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.75409680, y: 0.10598140, z: 3.14769580 } }),
-    ((32, 449), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.39917330, y: -0.01953741, z: 2.92058470 } }),
-    ((16, 15), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.70180607, y: 0.14917348, z: 3.15802120 } }),
-    ((43, 6), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.82322880, y: 0.15310080, z: 2.67213000 } }),
-    ((29, 439), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.26076280, y: -0.19517641, z: 2.97661970 } }),
-    ((30, 432), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.27583380, y: -0.32757846, z: 2.95851700 } }),
-    ((20, 41), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.75033313, y: 0.48355670, z: 3.11304600 } }),
-    ((16, 21), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.68686220, y: 0.20737593, z: 3.15802120 } }),
-    ((19, 18), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.82242364, y: 0.21116250, z: 3.12520600 } }),
-    ((23, 30), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.93385303, y: 0.41577816, z: 3.07293490 } }),
-    ((31, 446), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.35627750, y: -0.07582769, z: 2.93983750 } }),
-    ((34, 447), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.47901320, y: -0.06198902, z: 2.88037500 } }),
-    ((19, 434), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.82799900, y: -0.18811676, z: 3.12520600 } }),
-    ((27, 22), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.13636660, y: 0.36047673, z: 3.01108120 } }),
-    ((37, 7), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.59199630, y: 0.15609660, z: 2.81585930 } }),
-    ((13, 429), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.55965980, y: -0.16897124, z: 3.18529630 } }),
-    ((29, 7), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.26969210, y: 0.12449440, z: 2.97661970 } }),
-    ((28, 11), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.21956910, y: 0.18879928, z: 2.99414230 } }),
-    ((44, 434), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.82038550, y: -0.41358143, z: 2.64632370 } }),
-    ((37, 12), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.57722960, y: 0.26676843, z: 2.81585930 } }),
-    ((19, 12), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.83720887, y: 0.14160329, z: 3.12520600 } }),
-    ((26, 433), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.11776860, y: -0.27041670, z: 3.02743300 } }),
-    ((24, 448), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.06461940, y: -0.02973751, z: 3.05836300 } }),
-    ((24, 23), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.01058580, y: 0.33617717, z: 3.05836300 } }),
-    ((14, 429), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.60218070, y: -0.18180908, z: 3.17682340 } }),
-    ((12, 445), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.53876495, y: -0.03767412, z: 3.19314810 } }),
-    ((46, 434), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.89172920, y: -0.42979032, z: 2.59316850 } }),
-    ((22, 39), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.83759400, y: 0.50726490, z: 3.08690790 } }),
-    ((35, 22), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.44921550, y: 0.45971823, z: 2.85942580 } }),
-    ((18, 47), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.63809600, y: 0.49140006, z: 3.13675670 } }),
-    ((22, 17), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.95176840, y: 0.23025700, z: 3.08690790 } }),
-    ((34, 22), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.41101920, y: 0.44760168, z: 2.88037500 } }),
-    ((19, 429), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.81285965, y: -0.24541680, z: 3.12520600 } }),
-    ((23, 24), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.96536930, y: 0.33617717, z: 3.07293490 } }),
-    ((14, 1), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.62896660, y: 0.00878260, z: 3.17682340 } }),
-    ((21, 447), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 0.93520844, y: -0.03919685, z: 3.10027900 } }),
-    ((20, 434), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.87046860, y: -0.19776562, z: 3.11304600 } }),
-    ((27, 436), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.16946660, y: -0.23156063, z: 3.01108120 } }),
-    ((29, 438), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.25791490, y: -0.21276037, z: 2.97661970 } }),
-    ((23, 447), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.02133270, y: -0.04280652, z: 3.07293490 } }),
-    ((36, 16), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.52138830, y: 0.34565094, z: 2.83791920 } }),
-    ((26, 28), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.06324080, y: 0.43823624, z: 3.02743300 } }),
-    ((34, 2), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.47973450, y: 0.04133273, z: 2.88037500 } }),
-    ((15, 12), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.66389280, y: 0.11228907, z: 3.16773100 } }),
-    ((48, 438), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.98341690, y: -0.33546987, z: 2.53799130 } }),
-    ((19, 17), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.82529175, y: 0.19965910, z: 3.12520600 } }),
-    ((14, 14), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.61704820, y: 0.12217884, z: 3.17682340 } }),
-    ((29, 25), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.19884190, y: 0.43634275, z: 2.97661970 } }),
-    ((29, 1), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.27565650, y: 0.01781268, z: 2.97661970 } }),
-    ((12, 3), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.53960687, y: 0.02261623, z: 3.19314810 } }),
-    ((20, 433), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.86762250, y: -0.20989998, z: 3.11304600 } }),
-    ((20, 47), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.70723860, y: 0.54464704, z: 3.11304600 } }),
-    ((45, 3), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.90187280, y: 0.07971209, z: 2.62000160 } }),
-    ((18, 2), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.80506820, y: 0.02248759, z: 3.13675670 } }),
-    ((27, 431), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.15046500, y: -0.31257442, z: 3.01108120 } }),
-    ((26, 19), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.10978230, y: 0.30152120, z: 3.02743300 } }),
-    ((36, 432), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.51114400, y: -0.38799584, z: 2.83791920 } }),
-    ((23, 12), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.00791420, y: 0.17047592, z: 3.07293490 } }),
-    ((29, 30), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.16548380, y: 0.51890683, z: 2.97661970 } }),
-    ((45, 4), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.90057440, y: 0.10625861, z: 2.62000160 } }),
-    ((22, 40), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.83042980, y: 0.51891010, z: 3.08690790 } }),
-    ((33, 24), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.35985580, y: 0.47355187, z: 2.90076260 } }),
-    ((38, 431), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.58145980, y: -0.42967310, z: 2.79325060 } }),
-    ((40, 446), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.71346770, y: -0.09579772, z: 2.74640370 } }),
-    ((20, 35), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.78816456, y: 0.41907454, z: 3.11304600 } }),
-    ((41, 16), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.71072600, y: 0.38866740, z: 2.72217500 } }),
-    ((41, 438), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.72975460, y: -0.29256610, z: 2.72217500 } }),
-    ((22, 432), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 0.94846076, y: -0.24352333, z: 3.08690790 } }),
-    ((32, 29), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.28615500, y: 0.55124676, z: 2.92058470 } }),
-    ((47, 446), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.97287420, y: -0.11030079, z: 2.56583000 } }),
-    ((22, 33), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.87710327, y: 0.43539783, z: 3.08690790 } }),
-    ((18, 0), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.80538220, y: 0.00000000, z: 3.13675670 } }),
-    ((17, 17), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.74015580, y: 0.17906253, z: 3.14769580 } }),
-    ((36, 6), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.55468760, y: 0.13055076, z: 2.83791920 } }),
-    ((16, 14), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.70382040, y: 0.13936019, z: 3.15802120 } }),
-    ((47, 5), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.97114180, y: 0.13783567, z: 2.56583000 } }),
-    ((37, 26), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.49537590, y: 0.56804000, z: 2.81585930 } }),
-    ((33, 5), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.43644330, y: 0.10044590, z: 2.90076260 } }),
-    ((21, 434), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.91276854, y: -0.20737593, z: 3.10027900 } }),
-    ((25, 28), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.02405700, y: 0.42208582, z: 3.04319450 } }),
-    ((12, 442), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.53671473, y: -0.06020221, z: 3.19314810 } }),
-    ((44, 2), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.86604820, y: 0.05212345, z: 2.64632370 } }),
-    ((21, 34), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.83251995, y: 0.42785713, z: 3.10027900 } }),
-    ((40, 437), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.68794980, y: -0.30979675, z: 2.74640370 } }),
-    ((19, 27), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.78947290, y: 0.31257442, z: 3.12520600 } }),
-    ((18, 13), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.79215100, y: 0.14538690, z: 3.13675670 } }),
-    ((14, 418), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.56727785, y: -0.27179402, z: 3.17682340 } }),
-    ((22, 46), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.78409606, y: 0.58657885, z: 3.08690790 } }),
-    ((43, 2), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.82893230, y: 0.05108671, z: 2.67213000 } }),
-    ((19, 28), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.78503174, y: 0.32356673, z: 3.12520600 } }),
-    ((18, 445), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.80342030, y: -0.05618062, z: 3.13675670 } }),
-    ((14, 449), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.62896660, y: -0.00878260, z: 3.17682340 } }),
-    ((31, 19), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.31087410, y: 0.35615662, z: 2.93983750 } }),
-    ((35, 446), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.51801300, y: -0.08487011, z: 2.85942580 } }),
-    ((17, 446), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.76032037, y: -0.04250851, z: 3.14769580 } }),
-    ((16, 429), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.68686220, y: -0.20737593, z: 3.15802120 } }),
-    ((29, 18), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.23569990, y: 0.31727380, z: 2.97661970 } }),
-    ((39, 7), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.66962390, y: 0.16370805, z: 2.77009730 } }),
-    ((20, 20), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.85807174, y: 0.24604812, z: 3.11304600 } }),
-    ((22, 4), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 0.97769800, y: 0.05466181, z: 3.08690790 } }),
-    ((21, 3), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.93520844, y: 0.03919685, z: 3.10027900 } }),
-    ((24, 4), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.06337400, y: 0.05945184, z: 3.05836300 } }),
-    ((22, 10), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.96969515, y: 0.13628176, z: 3.08690790 } }),
-    ((18, 19), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.77720714, y: 0.21116250, z: 3.13675670 } }),
-    ((41, 2), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.75363800, y: 0.04898355, z: 2.72217500 } }),
-    ((18, 11), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.79590154, y: 0.12321207, z: 3.13675670 } }),
-    ((38, 16), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.59806540, y: 0.36307153, z: 2.79325060 } }),
-    ((18, 8), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.80036300, y: 0.08977509, z: 3.13675670 } }),
-    ((37, 448), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.59900700, y: -0.04466431, z: 2.81585930 } }),
-    ((13, 416), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.51996285, y: -0.26722458, z: 3.18529630 } }),
-    ((19, 43), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.70060360, y: 0.47971330, z: 3.12520600 } }),
-    ((26, 1), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.14990170, y: 0.01605670, z: 3.02743300 } }),
-    ((12, 437), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.53120786, y: -0.09749489, z: 3.19314810 } }),
-    ((33, 4), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.43770580, y: 0.08038023, z: 2.90076260 } }),
-    ((15, 1), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.67325640, y: 0.00940104, z: 3.16773100 } }),
-    ((21, 24), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.88396410, y: 0.30782887, z: 3.10027900 } }),
-    ((50, 440), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 2.06140900, y: -0.28971213, z: 2.48083500 } }),
-    ((45, 448), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.90280030, y: -0.05315003, z: 2.62000160 } }),
-    ((29, 22), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.21606240, y: 0.38575770, z: 2.97661970 } }),
-    ((35, 27), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.41361700, y: 0.55969054, z: 2.85942580 } }),
-    ((17, 434), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.74258375, y: -0.16871089, z: 3.14769580 } }),
-    ((26, 434), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.12143530, y: -0.25478384, z: 3.02743300 } }),
-    ((18, 7), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.80153840, y: 0.07859153, z: 3.13675670 } }),
-    ((21, 27), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.87029820, y: 0.34457543, z: 3.10027900 } }),
-    ((30, 25), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.23777880, y: 0.45051460, z: 2.95851700 } }),
-    ((15, 445), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.67168180, y: -0.04696857, z: 3.16773100 } }),
-    ((22, 0), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 0.97922490, y: 0.00000000, z: 3.08690790 } }),
-    ((31, 11), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.34240510, y: 0.20781529, z: 2.93983750 } }),
-    ((22, 448), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 0.97884310, y: -0.02734156, z: 3.08690790 } }),
-    ((26, 440), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.13882210, y: -0.16005100, z: 3.02743300 } }),
-    ((15, 3), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.67273140, y: 0.02819580, z: 3.16773100 } }),
-    ((30, 442), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.30900760, y: -0.14682873, z: 2.95851700 } }),
-    ((43, 9), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.81521830, y: 0.22931540, z: 2.67213000 } }),
-    ((24, 14), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.04475120, y: 0.20686631, z: 3.05836300 } }),
-    ((40, 19), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.65610680, y: 0.44995427, z: 2.74640370 } }),
-    ((22, 47), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.77582973, y: 0.59746933, z: 3.08690790 } }),
-    ((25, 435), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.08342780, y: -0.23028970, z: 3.04319450 } }),
-    ((20, 428), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.85086715, y: -0.26991096, z: 3.11304600 } }),
-    ((14, 9), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.62406790, y: 0.07883811, z: 3.17682340 } }),
-    ((18, 431), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.77720714, y: -0.21116250, z: 3.13675670 } }),
-    ((21, 435), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 0.91557497, y: -0.19461147, z: 3.10027900 } }),
-    ((12, 433), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.52493730, y: -0.12699570, z: 3.19314810 } }),
-    ((44, 445), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.86222860, y: -0.13021971, z: 2.64632370 } }),
-    ((28, 24), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.16545150, y: 0.40585315, z: 2.99414230 } }),
-    ((35, 444), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.51505150, y: -0.12722242, z: 2.85942580 } }),
-    ((18, 28), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.74461293, y: 0.30690730, z: 3.13675670 } }),
-    ((33, 17), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.39957620, y: 0.33859313, z: 2.90076260 } }),
-    ((31, 30), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.24095610, y: 0.55250925, z: 2.93983750 } }),
-    ((28, 6), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.22976830, y: 0.10326653, z: 2.99414230 } }),
-    ((40, 7), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.70795310, y: 0.16746627, z: 2.74640370 } }),
-    ((39, 15), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.64097020, y: 0.34879900, z: 2.77009730 } }),
-    ((48, 435), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.96762910, y: -0.41823247, z: 2.53799130 } }),
-    ((37, 439), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.58080050, y: -0.24472085, z: 2.81585930 } }),
-    ((21, 44), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.76487170, y: 0.53955764, z: 3.10027900 } }),
-    ((40, 434), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.67349620, y: -0.38020903, z: 2.74640370 } }),
-    ((23, 22), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.97437954, y: 0.30909140, z: 3.07293490 } }),
-    ((35, 11), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.50248620, y: 0.23259717, z: 2.85942580 } }),
-    ((51, 443), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 2.10600330, y: -0.20649543, z: 2.45152850 } }),
-    ((41, 3), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.75278320, y: 0.07346338, z: 2.72217500 } }),
-    ((37, 432), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.54937540, y: -0.39781198, z: 2.81585930 } }),
-    ((17, 433), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.74015580, y: -0.17906253, z: 3.14769580 } }),
-    ((38, 5), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.63479830, y: 0.11431623, z: 2.79325060 } }),
-    ((28, 2), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.23361530, y: 0.03445799, z: 2.99414230 } }),
-    ((13, 430), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.56196440, y: -0.16114070, z: 3.18529630 } }),
-    ((25, 439), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.09459360, y: -0.16945204, z: 3.04319450 } }),
-    ((28, 437), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.21382200, y: -0.22277804, z: 2.99414230 } }),
-    ((14, 446), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.62804717, y: -0.03511329, z: 3.17682340 } }),
-    ((22, 16), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.95489055, y: 0.21694580, z: 3.08690790 } }),
-    ((50, 434), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 2.02993680, y: -0.46119034, z: 2.48083500 } }),
-    ((17, 437), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.74899730, y: -0.13746673, z: 3.14769580 } }),
-    ((19, 11), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.83910435, y: 0.12990022, z: 3.12520600 } }),
-    ((38, 23), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.55500890, y: 0.51728260, z: 2.79325060 } }),
-    ((41, 449), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.75415100, y: -0.02449416, z: 2.72217500 } }),
-    ((29, 13), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.25482170, y: 0.23030287, z: 2.97661970 } }),
-    ((23, 7), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.01735070, y: 0.09975211, z: 3.07293490 } }),
-    ((24, 11), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.05249750, y: 0.16293521, z: 3.05836300 } }),
-    ((33, 437), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.41629470, y: -0.25993872, z: 2.90076260 } }),
-    ((21, 1), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 0.93593824, y: 0.01306901, z: 3.10027900 } }),
-    ((27, 14), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.16946660, y: 0.23156063, z: 3.01108120 } }),
-    ((49, 0), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 2.04682680, y: 0.00000000, z: 2.50965760 } }),
-    ((36, 29), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.43399750, y: 0.61461210, z: 2.83791920 } }),
-    ((33, 11), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.42300050, y: 0.22029210, z: 2.90076260 } }),
-    ((14, 10), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.62290627, y: 0.08754377, z: 3.17682340 } }),
-    ((32, 431), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.35035690, y: -0.36688387, z: 2.92058470 } }),
-    ((26, 439), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.13647640, y: -0.17593583, z: 3.02743300 } }),
-    ((29, 10), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.26336500, y: 0.17755438, z: 2.97661970 } }),
-    ((16, 48), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.56228817, y: 0.44566410, z: 3.15802120 } }),
-    ((25, 41), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.93103870, y: 0.60001350, z: 3.04319450 } }),
-    ((14, 445), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.62749565, y: -0.04387877, z: 3.17682340 } }),
-    ((23, 44), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.83530957, y: 0.58924603, z: 3.07293490 } }),
-    ((21, 40), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.79379800, y: 0.49602005, z: 3.10027900 } }),
-    ((23, 46), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.81853116, y: 0.61233956, z: 3.07293490 } }),
-    ((38, 13), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.61186740, y: 0.29583302, z: 2.79325060 } }),
-    ((15, 9), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.66801270, y: 0.08438963, z: 3.16773100 } }),
-    ((32, 16), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.36453590, y: 0.31001496, z: 2.92058470 } }),
-    ((13, 424), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.54650960, y: -0.20759952, z: 3.18529630 } }),
-    ((37, 10), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.58406330, y: 0.22262557, z: 2.81585930 } }),
-    ((44, 12), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.84063370, y: 0.31131992, z: 2.64632370 } }),
-    ((51, 448), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 2.11527750, y: -0.05908505, z: 2.45152850 } }),
-    ((20, 1), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.89256454, y: 0.01246336, z: 3.11304600 } }),
-    ((50, 445), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 2.07659670, y: -0.14520979, z: 2.48083500 } }),
-    ((27, 37), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.03658700, y: 0.58886340, z: 3.01108120 } }),
-    ((30, 445), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.31400800, y: -0.09188439, z: 2.95851700 } }),
-    ((31, 3), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.35720410, y: 0.05688370, z: 2.93983750 } }),
-    ((22, 43), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.80797166, y: 0.55322975, z: 3.08690790 } }),
-    ((48, 9), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.99572520, y: 0.25211870, z: 2.53799130 } }),
-    ((28, 14), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.21059320, y: 0.23970392, z: 2.99414230 } }),
-    ((21, 448), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 0.93566453, y: -0.02613548, z: 3.10027900 } }),
-    ((20, 4), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.89125973, y: 0.04982915, z: 3.11304600 } }),
-    ((23, 41), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.85925190, y: 0.55375004, z: 3.07293490 } }),
-    ((28, 447), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.23301390, y: -0.05167859, z: 2.99414230 } }),
-    ((24, 7), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.05995170, y: 0.10392917, z: 3.05836300 } }),
-    ((25, 20), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.06472440, y: 0.30530483, z: 3.04319450 } }),
-    ((31, 429), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.30041860, y: -0.39261952, z: 2.93983750 } }),
-    ((19, 430), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.81620700, y: -0.23404358, z: 3.12520600 } }),
-    ((28, 440), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.22208630, y: -0.17175302, z: 2.99414230 } }),
-    ((19, 25), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.79789270, y: 0.29040918, z: 3.12520600 } }),
-    ((49, 442), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 2.03407100, y: -0.22815761, z: 2.50965760 } }),
-    ((12, 4), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.53923845, y: 0.03014811, z: 3.19314810 } }),
-    ((40, 0), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.71614350, y: 0.00000000, z: 2.74640370 } }),
-    ((48, 10), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.99201050, y: 0.27995880, z: 2.53799130 } }),
-    ((33, 447), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.43868790, y: -0.06029889, z: 2.90076260 } }),
-    ((43, 11), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.80810770, y: 0.27990988, z: 2.67213000 } }),
-    ((27, 2), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.19170650, y: 0.03328738, z: 3.01108120 } }),
-    ((32, 10), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.38569180, y: 0.19474627, z: 2.92058470 } }),
-    ((13, 437), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.57500696, y: -0.10553353, z: 3.18529630 } }),
-    ((38, 19), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.58145980, y: 0.42967310, z: 2.79325060 } }),
-    ((44, 4), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.86386530, y: 0.10420625, z: 2.64632370 } }),
-    ((40, 439), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.69594180, y: -0.26254565, z: 2.74640370 } }),
-    ((33, 435), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.40848470, y: -0.29938266, z: 2.90076260 } }),
-    ((24, 19), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.02777600, y: 0.27924055, z: 3.05836300 } }),
-    ((20, 31), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.81032900, y: 0.37442458, z: 3.11304600 } }),
-    ((22, 15), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.95782650, y: 0.20359230, z: 3.08690790 } }),
-    ((27, 18), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.15471710, y: 0.29648095, z: 3.01108120 } }),
-    ((32, 441), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.38827570, y: -0.17538000, z: 2.92058470 } }),
-    ((24, 16), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.03856780, y: 0.23595682, z: 3.05836300 } }),
-    ((31, 430), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.30577360, y: -0.37442458, z: 2.93983750 } }),
-    ((47, 439), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.95269490, y: -0.30229315, z: 2.56583000 } }),
-    ((14, 3), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.62847620, y: 0.02634096, z: 3.17682340 } }),
-    ((36, 448), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.55955100, y: -0.04356220, z: 2.83791920 } }),
-    ((26, 36), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.00776480, y: 0.55402344, z: 3.02743300 } }),
-    ((46, 4), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.93691310, y: 0.10829026, z: 2.59316850 } }),
-    ((47, 437), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.94349310, y: -0.35669774, z: 2.56583000 } }),
-    ((35, 3), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.51905000, y: 0.06366706, z: 2.85942580 } }),
-    ((25, 448), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.10720040, y: -0.03092691, z: 3.04319450 } }),
-    ((28, 444), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.22976830, y: -0.10326653, z: 2.99414230 } }),
-    ((43, 444), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.82322880, y: -0.15310080, z: 2.67213000 } }),
-    ((41, 435), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.71598590, y: -0.36474407, z: 2.72217500 } }),
-    ((25, 9), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.09889820, y: 0.13882314, z: 3.04319450 } }),
-    ((20, 3), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.89186860, y: 0.03738037, z: 3.11304600 } }),
-    ((24, 36), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.93329700, y: 0.51308435, z: 3.05836300 } }),
-    ((36, 439), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.54179370, y: -0.23868227, z: 2.83791920 } }),
-    ((26, 20), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.10546430, y: 0.31698680, z: 3.02743300 } }),
-    ((21, 442), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 0.93019605, y: -0.10433821, z: 3.10027900 } }),
-    ((43, 8), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.81824310, y: 0.20394865, z: 2.67213000 } }),
-    ((17, 436), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.74700490, y: -0.14791097, z: 3.14769580 } }),
-    ((30, 14), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.29213050, y: 0.25584870, z: 2.95851700 } }),
-    ((28, 438), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.21681420, y: -0.20580871, z: 2.99414230 } }),
-    ((36, 436), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.53044630, y: -0.30303650, z: 2.83791920 } }),
-    ((47, 448), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.97518470, y: -0.05517190, z: 2.56583000 } }),
-    ((14, 447), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.62847620, y: -0.02634096, z: 3.17682340 } }),
-    ((21, 443), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 0.93156220, y: -0.09134047, z: 3.10027900 } }),
-    ((46, 445), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.93521240, y: -0.13532323, z: 2.59316850 } }),
-    ((34, 444), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.47512000, y: -0.12386928, z: 2.88037500 } }),
-    ((25, 10), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.09685280, y: 0.15415262, z: 3.04319450 } }),
-    ((21, 438), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 0.92292136, y: -0.15610047, z: 3.10027900 } }),
-    ((41, 436), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.72091130, y: -0.34074960, z: 2.72217500 } }),
-    ((37, 16), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.55987880, y: 0.35439578, z: 2.81585930 } }),
-    ((22, 443), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 0.97455144, y: -0.09555560, z: 3.08690790 } }),
-    ((31, 1), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.35826310, y: 0.01896616, z: 2.93983750 } }),
-    ((24, 434), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.03856780, y: -0.23595682, z: 3.05836300 } }),
-    ((20, 436), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.87565120, y: -0.17338360, z: 3.11304600 } }),
-    ((48, 442), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.99905070, y: -0.22422947, z: 2.53799130 } }),
-    ((17, 426), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.71914990, y: -0.25043450, z: 3.14769580 } }),
-    ((19, 440), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.84083630, y: -0.11817183, z: 3.12520600 } }),
-    ((25, 434), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.08010690, y: -0.24539426, z: 3.04319450 } }),
-    ((18, 442), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.80036300, y: -0.08977509, z: 3.13675670 } }),
-    ((13, 427), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.55472360, y: -0.18453197, z: 3.18529630 } }),
-    ((15, 447), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.67273140, y: -0.02819580, z: 3.16773100 } }),
-    ((25, 17), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.07657530, y: 0.26045100, z: 3.04319450 } }),
-    ((17, 444), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.75883704, y: -0.06372132, z: 3.14769580 } }),
-    ((42, 433), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.74190820, y: -0.42141193, z: 2.69741540 } }),
-    ((43, 442), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.81824310, y: -0.20394865, z: 2.67213000 } }),
-    ((17, 430), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.73200820, y: -0.20989998, z: 3.14769580 } }),
-    ((16, 19), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.69238466, y: 0.18811676, z: 3.15802120 } }),
-    ((26, 430), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.10546430, y: -0.31698680, z: 3.02743300 } }),
-    ((34, 432), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.43380490, y: -0.36813852, z: 2.88037500 } }),
-    ((41, 439), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.73367080, y: -0.26838642, z: 2.72217500 } }),
-    ((18, 29), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.74025524, y: 0.31727380, z: 3.13675670 } }),
-    ((17, 7), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.75787336, y: 0.07431013, z: 3.14769580 } }),
-    ((23, 28), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.94509816, y: 0.38954136, z: 3.07293490 } }),
-    ((29, 444), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.27130650, y: -0.10675459, z: 2.97661970 } }),
-    ((40, 435), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.67864170, y: -0.35680630, z: 2.74640370 } }),
-    ((13, 418), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.52722144, y: -0.25260220, z: 3.18529630 } }),
-    ((41, 443), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.74594940, y: -0.17119183, z: 2.72217500 } }),
-    ((35, 433), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.47775360, y: -0.35750622, z: 2.85942580 } }),
-    ((18, 1), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.80530370, y: 0.01124489, z: 3.13675670 } }),
-    ((23, 432), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 0.99011420, y: -0.25421810, z: 3.07293490 } }),
-    ((50, 443), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 2.07173280, y: -0.20313516, z: 2.48083500 } }),
-    ((27, 10), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.18056930, y: 0.16591819, z: 3.01108120 } }),
-    ((28, 5), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.23109020, y: 0.08608621, z: 2.99414230 } }),
-    ((15, 442), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.66912580, y: -0.07505449, z: 3.16773100 } }),
-    ((21, 437), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 0.92065190, y: -0.16897124, z: 3.10027900 } }),
-    ((39, 11), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.65788210, y: 0.25665370, z: 2.77009730 } }),
-    ((12, 425), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 0.50750977, y: -0.18471844, z: 3.19314810 } }),
-    ((26, 2), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.14956560, y: 0.03211027, z: 3.02743300 } }),
-    ((34, 425), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.39103800, y: -0.50629640, z: 2.88037500 } }),
-    ((26, 31), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.04395680, y: 0.48237570, z: 3.02743300 } }),
-    ((35, 0), CoordinateMetadata { territory_id: Some("amazon".to_string()), cartesian: Vector3 { x: 1.52038370, y: 0.00000000, z: 2.85942580 } }),
-    ((27, 33), CoordinateMetadata { territory_id: Some("latinos".to_string()), cartesian: Vector3 { x: 1.06784190, y: 0.53008133, z: 3.01108120 } }),
-    
-  ].into_iter().collect();
-
-  let virtual_planet = VirtualPlanet {
-    coordinate_map: coordinate_map_with_amazon_and_latinos_only,
-  };
-
-  get_in_the_frontiers_trajectory(&virtual_planet);
-  get_in_the_frontiers_trajectory(&virtual_planet);
-  get_in_the_frontiers_trajectory(&virtual_planet);
-
-  println!("Check ./output/ for the output!");
-}
\ No newline at end of file
@@ -1,10 +1,9 @@
 
-use godot::{classes::{Area3D, IArea3D, MeshInstance3D, PhysicsRayQueryParameters3D, World3D}, prelude::*};
-use crate::{globe::territories::territory::TerritoryId, troops::troop::Troop};
+use godot::{classes::{Area3D, IArea3D}, prelude::*};
+use simulation::globe::coordinates::Planet;
+use crate::{bridge::vector::IntoVec3, globe::territories::territory::TerritoryId};
 
-type Latitude = i16;
-type Longitude = i16;
-pub type Coordinates = (Latitude, Longitude);
+pub use simulation::globe::coordinates::Coordinates;
 
 #[derive(Debug, Clone)]
 pub struct SurfacePointMetadata {
@@ -14,7 +13,8 @@ pub struct SurfacePointMetadata {
 }
 
 /// Represents a point on the surface of the virtual planet
-/// It's used, for instance, to trace rays from the origin of the world to the position of the troops
+/// Its area is overlapped with the territory meshes to find out which territory every coordinate belongs to,
+/// lookups from a position are done with math instead, check `Planet::get_coordinates_from_cartesian`
 #[derive(Debug, GodotClass)]
 #[class(base=Area3D)]
 pub struct SurfacePoint {
@@ -56,99 +56,8 @@ impl SurfacePoint {
     &mut self.surface_point_metadata
   }
 
-  /// Traces a ray from the origin of the world to the position of the troop
-  /// Returns the SurfacePoint where the ray collides with the virtual planet
-  pub fn get_troop_surface_point(troop: &Troop) -> Option<Gd<SurfacePoint>> {
-    // default_mesh is the child of the troop node that lives on top of the troop itself
-    // it's used to be sure that the ray passed by a surface point
-    let troop_position = troop.base()
-      .find_child("default_mesh")
-      .expect("Expected to find default mesh")
-      .cast::<MeshInstance3D>()
-      .get_global_position();
-
-    let mut world = troop
-      .base()
-      .get_world_3d()
-      .expect("World to exist");
-
-    Self::get_surface_point(
-      troop_position,
-      &mut world,
-      None,
-    )
-  }
-
-  /// Traces a ray from the origin of the world to the target position
-  /// 
-  /// # Arguments
-  /// * `scale_factor` - To decrease the odds of the ray not collinding with any surface_point
-  /// we're pushing the target_position a bit further from the actual surface
-  /// That problem was happening when the target_position was too close to the actual surface.
-  /// 
-  /// # Returns
-  /// * `Option<Gd<SurfacePoint>>` - The SurfacePoint where the ray collides with the virtual planet,
-  /// to avoid panicking it returns None if it doesn't find any
-  pub fn get_surface_point(target_position: Vector3, world: &mut Gd<World3D>, scale_factor: Option<f32>) -> Option<Gd<SurfacePoint>> {
-    let world_origin = Vector3::new(0.0, 0.0, 0.0);
-    let scale_factor = scale_factor.unwrap_or(1.);
-
-    let mut space_state = world
-      .get_direct_space_state()
-      .expect("Expected to get direct space state");
-
-    let direction = target_position.normalized();
-    let target_position = direction * (target_position.length() * scale_factor);
-
-    let mut query = PhysicsRayQueryParameters3D::create(
-      world_origin,
-      target_position,
-    ).expect("Expected to create ray query");
-
-    query.set_collide_with_areas(true);
-    query.set_collide_with_bodies(false);
-
-    let collision_dict = space_state.intersect_ray(&query);
-    let collider = collision_dict
-      .get("collider");
-
-    if collider.is_none() {
-      godot_error!("{}", format!("Expected 'collider' key to exist in the ray from origin to {:?} collision dictionary: {:?}",
-        target_position,
-        collision_dict
-      ));
-      return None;
-    }
-
-    let collider = collider.unwrap();
-    // The collided area has to be a SurfacePoint
-    let surface_point: Result<Gd<SurfacePoint>, ConvertError> = collider
-      .try_to::<Gd<SurfacePoint>>();
-
-    match surface_point {
-      Ok(surface_point) => Some(surface_point),
-      Err(err) => {
-        godot_error!("surface_point ConvertError: {:?}", err);
-        return None
-      },
-    }
-    // surface_point
-  }
-
-
-  pub fn get_lat_long_from_vec3(vec3: Vector3, world: &mut Gd<World3D>,) -> Option<Coordinates> {
-    let Some(surface_point) = SurfacePoint::get_surface_point(
-      vec3,
-      world,
-      None
-    ) else {
-      return None;
-    };
-
-    let surface_point = surface_point.bind();
-    let surface_point_metadata = surface_point.get_surface_point_metadata();
-    let coordinate = surface_point_metadata.lat_long;
-
-    Some(coordinate)
+  /// Returns the lat/long under the given position, without any physics query
+  pub fn get_lat_long_from_vec3(vec3: Vector3) -> Option<Coordinates> {
+    Planet::get_coordinates_from_cartesian(vec3.to_vec3())
   }
 }
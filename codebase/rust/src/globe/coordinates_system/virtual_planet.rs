@@ -1,20 +1,23 @@
-
-use std::{collections::HashMap, f64::consts::PI};
-use godot::{classes::{BoxMesh, BoxShape3D, CollisionShape3D, MeshInstance3D, StandardMaterial3D}, prelude::*};
-use fastrand;
+use std::collections::HashMap;
+use godot::{classes::{
+  file_access::ModeFlags, BoxMesh, BoxShape3D, CollisionShape3D, FileAccess, MeshInstance3D, Os, StandardMaterial3D
+}, prelude::*};
+use simulation::{
+  globe::{baked_map::BakedCoordinateMap, coordinates::Planet}, random::MatchRng, rules::Rules,
+  territories::{adjacency::AdjacencyGraph, realm::Realm}, troops::route::RoutePlanner
+};
 
 use crate::{
+  bridge::vector::{IntoVec3, IntoVector3},
   globe::territories::{
-    land::Land, territory::{
-      Territories, Territory, TerritoryId, TerritoryState
+    land::Land, realm::SceneRealm, territory::{
+      Territories, Territory, TerritoryId, TerritoryPainter
     }
   },
-  player::{
-    color::PlayerColor, player::Player
-  },
+  player::player::PlayerId,
   root::root::RootScene,
   troops::{
-    spawner_engine::spawn_troop, surface::surface::Surface
+    surface::surface::Surface, troop::TroopId
   }
 };
 use super::{
@@ -34,8 +37,13 @@ pub struct VirtualPlanet {
   pub are_surface_points_matched: bool,
   pub has_surface_points_matching_started: bool,
   pub territories: Territories,
-  pub surface_points_metadata: Vec<SurfacePointMetadata>,
+  /// Empty until the surface points are matched with the territories
+  pub adjacency: AdjacencyGraph,
   pub coordinate_map: CoordinateMap,
+  /// Used to set the territories' stats once their coordinates are matched, check `VirtualPlanet::set_rules`
+  pub rules: Rules,
+  /// Troops spawned by the territories since `take_spawned_troops` was last called
+  pub(crate) spawned_troops: Vec<(TroopId, PlayerId, TerritoryId)>,
 }
 
 #[godot_api]
@@ -48,8 +56,10 @@ impl INode3D for VirtualPlanet {
       are_surface_points_matched: false,
       has_surface_points_matching_started: false,
       territories: Territory::get_map(),
-      surface_points_metadata: vec![],
+      adjacency: AdjacencyGraph::default(),
       coordinate_map: HashMap::new(),
+      rules: Rules::default(),
+      spawned_troops: Vec::new(),
     }
   }
 
@@ -57,68 +67,60 @@ impl INode3D for VirtualPlanet {
     // By default, the VirtualPlanet is not visible. It's only used for physics and collision calculations
     self.base_mut().set_visible(false);
 
-    self.populate_surface_points_and_coordinate_map();
-    self.create_virtual_sphere();
-    self.is_ready_for_physics = true;
-  }
+    self.populate_coordinate_map();
 
-  fn process(&mut self, delta: f64) {
-    if self.is_ready_for_physics == true {
-      self.match_surface_points_and_territories();
-      self.spawner_troop_engine_checker(delta);
-      self.occupation_checker(delta);
-      self.check_territory_under_conflict();
+    // The surface points are only there to match the territories with physics,
+    // so they're only created to bake the map, or when there's no usable baked map
+    let loaded_baked_map = !Self::is_baking_coordinate_map() && self.load_baked_coordinate_map();
+    if !loaded_baked_map {
+      self.create_virtual_sphere();
     }
+    self.is_ready_for_physics = true;
   }
 }
 
+impl VirtualPlanet {
+  /// Written by the bake step, check `BakedCoordinateMap`
+  pub const BAKED_COORDINATE_MAP_PATH: &'static str = "res://data/coordinate_map.json";
+  const BAKE_COORDINATE_MAP_ARG: &'static str = "--bake-coordinate-map";
+}
+
 #[godot_api]
 impl VirtualPlanet {
-  /// Following inline functions have pseudo-arbitrary numbers defined after checking the globe mesh size
-  /// that's the reason they all seem to be magic numbers
-  #[inline] pub fn get_planet_radius() -> f64 { 1.0795 * 3.0 }
-  #[inline] pub fn get_num_of_latitudes() -> i16 { (90. * 2.5) as i16 }
-  #[inline] pub fn get_num_of_longitudes() -> i16 { (180. * 2.5) as i16 }
+  /// The planet dimensions live in the simulation crate, see `Planet`
+  #[inline] pub fn get_planet_radius() -> f64 { Planet::get_planet_radius() }
+  #[inline] pub fn get_num_of_latitudes() -> i16 { Planet::get_num_of_latitudes() }
+  #[inline] pub fn get_num_of_longitudes() -> i16 { Planet::get_num_of_longitudes() }
   #[inline] pub fn get_surface_mesh_and_collider_size() -> Vector3 { Vector3::new(0.07, 0.07, 0.08) }
+
+  /// Territories step of the simulation clock, called by `RootScene::tick`
+  /// before the troops and projectiles steps
+  pub fn tick(&mut self, delta: f64) {
+    if self.is_ready_for_physics == true {
+      self.match_surface_points_and_territories();
+      self.get_realm().tick_territories(delta);
+    }
+  }
   
-  pub fn populate_surface_points_and_coordinate_map(&mut self) {
-    let planet_radius = Self::get_planet_radius();
-    let num_latitudes = Self::get_num_of_latitudes();
-    let num_longitudes = Self::get_num_of_longitudes();
-
-    for lat in 0..num_latitudes {
-      let theta = (lat as f64) * PI / (num_latitudes as f64); // Latitude angle (0 to pi)
-
-      for long in 0..num_longitudes {
-        let phi = (long as f64) * 2.0 * PI / (num_longitudes as f64);
-        let x = (planet_radius * theta.sin() * phi.cos()) as f32;
-        let y = (planet_radius * theta.sin() * phi.sin()) as f32;
-        let z = (planet_radius * theta.cos()) as f32;
-
-        let lat_long = (lat, long);
-        let cartesian = Vector3::new(x, y, z);
-
-        self.coordinate_map.insert(lat_long, CoordinateMetadata {
-          cartesian,
-          // territory_id is set at self.match_surface_points_and_territories()
-          territory_id: None,
-        });
-
-        self.surface_points_metadata.push(SurfacePointMetadata {
-          cartesian,
-          lat_long,
-          // territory_id is set at self.match_surface_points_and_territories()
-          territory_id: None,
-        });
-      }
+  pub fn populate_coordinate_map(&mut self) {
+    for lat_long in Planet::all_coordinates() {
+      self.coordinate_map.insert(lat_long, CoordinateMetadata {
+        cartesian: Planet::get_cartesian_from_coordinates(lat_long),
+        // territory_id is set at self.match_surface_points_and_territories() or self.load_baked_coordinate_map()
+        territory_id: None,
+      });
     }
   }
 
+  /// Creates a SurfacePoint per coordinate, in the same order every run
   pub fn create_virtual_sphere(&mut self) {
-    for surface_point_metadata in self.surface_points_metadata.clone() {
-      let surface_point = VirtualPlanet::create_surface_point_area(
-        surface_point_metadata
-      );
+    for lat_long in Planet::all_coordinates() {
+      let surface_point = VirtualPlanet::create_surface_point_area(SurfacePointMetadata {
+        cartesian: Planet::get_cartesian_from_coordinates(lat_long).to_vector3(),
+        lat_long,
+        // territory_id is set at self.match_surface_points_and_territories()
+        territory_id: None,
+      });
       self.base_mut().add_child(&surface_point);
     }
   }
@@ -174,9 +176,11 @@ impl VirtualPlanet {
 
   /// Matches surface points with territories and
   /// sets the territory_id into SurfacePointMetadata, CoordinateMetadata, and Territory.coordinates
+  /// It's skipped when `load_baked_coordinate_map` already did it
   pub fn match_surface_points_and_territories(&mut self) {
     if self.has_surface_points_matching_started == false {
-      for surface_point_node in self.base().get_children().iter_shared() {
+      let surface_point_nodes = self.base().get_children();
+      for surface_point_node in surface_point_nodes.iter_shared() {
         let mut surface_point = surface_point_node.cast::<SurfacePoint>();
         let bodies_overlapping_with_surface_point = &surface_point.get_overlapping_bodies();
         
@@ -194,64 +198,152 @@ impl VirtualPlanet {
                 .get_name()
                 .to_string();
 
-              if let Some(overlapped_territory) = self.territories.get_mut(&territory_id) {
-                // Self::paint_surface_point(&surface_point, overlapped_territory);
-
-                surface_point.add_to_group(&territory_id);
-                surface_point.add_to_group(&Surface::Land.to_string());
-                let mut surface_point_bind = surface_point.bind_mut();
-                let surface_point_metadata = surface_point_bind.get_surface_point_metadata_mut();
-
-                overlapped_territory.coordinates.push(surface_point_metadata.lat_long);
-
-                let coordinates: Coordinates = VirtualPlanet::get_spawner_territory_coordinate(overlapped_territory);
-                let cartesian = self
-                  .coordinate_map
-                  .get(&coordinates)
-                  .expect("Coordinate expected to exist")
-                  .cartesian;
-                overlapped_territory.spawner_location = cartesian;
-
-                self.coordinate_map.insert(
-                  surface_point_metadata.lat_long,
-                  CoordinateMetadata {
-                    territory_id: Some(territory_id.clone()),
-                    cartesian: surface_point_metadata.cartesian,
-                  }
-                );
-
-                // Apart from coordinate_map, we also create the same dictionary as metadata in the
-                // VirtualPlanet Godot Entity. The reason behind it is better explained
-                // at `src/globe/coordinates_system/rust_populate_heat_map/readme.md`
-                let dic_coordinates_map = self.base().get_meta("coordinates_map");
-                let mut dic_coordinates_map = dic_coordinates_map.to::<Dictionary>();
-
-                let mut coord_meta = Dictionary::new();
-                let _ = coord_meta.insert("territory_id", territory_id.clone());
-                let _ = coord_meta.insert("cartesian", surface_point_metadata.cartesian);
-                let _ = dic_coordinates_map.insert(
-                  format!("{:?}", surface_point_metadata.lat_long),
-                  coord_meta
-                );
-
-                surface_point_metadata.territory_id = Some(territory_id);
-              }
+              self.match_surface_point(&mut surface_point, territory_id);
             }
           }
+        }
+      }
 
-          self.territories.iter_mut().for_each(|(_, territory)| {
-            territory.set_territory_size();
-            territory.set_troops_growth_velocity_and_secs_to_spawn();
-            territory.set_organic_max_troops();
-            territory.set_time_to_be_conquered();
-          });
+      if self.has_surface_points_matching_started {
+        self.finish_surface_points_matching();
 
+        if Self::is_baking_coordinate_map() {
+          self.bake_coordinate_map();
         }
       }
       self.are_surface_points_matched = true;
     }
   }
 
+  fn match_surface_point(&mut self, surface_point: &mut Gd<SurfacePoint>, territory_id: TerritoryId) {
+    if !self.territories.contains_key(&territory_id) {
+      return;
+    }
+    // Self::paint_surface_point(&surface_point, overlapped_territory);
+
+    surface_point.add_to_group(&territory_id);
+    surface_point.add_to_group(&Surface::Land.to_string());
+    let mut surface_point_bind = surface_point.bind_mut();
+    let surface_point_metadata = surface_point_bind.get_surface_point_metadata_mut();
+
+    self.match_coordinate(surface_point_metadata.lat_long, &territory_id);
+    surface_point_metadata.territory_id = Some(territory_id);
+  }
+
+  /// Adds the coordinate to the territory, it's ignored when there's no such territory
+  fn match_coordinate(&mut self, lat_long: Coordinates, territory_id: &TerritoryId) {
+    let Some(overlapped_territory) = self.territories.get_mut(territory_id) else {
+      return;
+    };
+
+    overlapped_territory.coordinates.push(lat_long);
+
+    let coordinates: Coordinates = overlapped_territory
+      .get_spawner_coordinate()
+      .expect("Expected territory_coordinates to have at least one element");
+    let cartesian = self
+      .coordinate_map
+      .get(&coordinates)
+      .expect("Coordinate expected to exist")
+      .cartesian;
+    overlapped_territory.spawner_location = cartesian;
+
+    self.coordinate_map
+      .get_mut(&lat_long)
+      .expect("Coordinate expected to exist")
+      .territory_id = Some(territory_id.clone());
+  }
+
+  /// A baked map is loaded as soon as the planet is ready, before `RootScene` reads the match setup,
+  /// so the territories' stats and the adjacency are set again if the coordinates were already matched
+  pub fn set_rules(&mut self, rules: Rules) {
+    self.rules = rules;
+
+    if self.has_surface_points_matching_started {
+      self.finish_surface_points_matching();
+    }
+  }
+
+  /// Sets everything depending on the territories' coordinates once they're all matched
+  fn finish_surface_points_matching(&mut self) {
+    let rules = &self.rules;
+    self.territories.iter_mut().for_each(|(_, territory)| {
+      territory.set_coordinates_dependent_stats(rules);
+    });
+
+    self.build_adjacency_graph();
+  }
+
+  /// Matches the coordinates with the territories written by the bake step,
+  /// so the match doesn't wait on the physics and every run gets the same map
+  /// Returns false without a usable baked map, `match_surface_points_and_territories` falls back to the physics then
+  fn load_baked_coordinate_map(&mut self) -> bool {
+    let path = Self::BAKED_COORDINATE_MAP_PATH;
+    if !FileAccess::file_exists(path) {
+      godot_warn!("There's no {path}, the territories are matched with physics, see how to bake it at the README");
+      return false;
+    }
+
+    let content = FileAccess::get_file_as_string(path).to_string();
+    let baked_coordinate_map = match BakedCoordinateMap::load(&content, &self.territories) {
+      Ok(baked_map) => baked_map.get_coordinate_map(),
+      Err(error) => {
+        godot_warn!("Couldn't use {path}, the territories are matched with physics: {error}");
+        return false;
+      },
+    };
+
+    // Same order the surface points would be matched in, so the spawners land on the same coordinates
+    for lat_long in Planet::all_coordinates() {
+      let Some(territory_id) = baked_coordinate_map
+        .get(&lat_long)
+        .and_then(|coordinate_metadata| coordinate_metadata.territory_id.as_ref()) else {
+        continue;
+      };
+      self.match_coordinate(lat_long, territory_id);
+    }
+
+    self.has_surface_points_matching_started = true;
+    self.finish_surface_points_matching();
+    self.are_surface_points_matched = true;
+    true
+  }
+
+  /// Running the game with `-- --bake-coordinate-map` bakes the map and quits, check `bake_coordinate_map`
+  fn is_baking_coordinate_map() -> bool {
+    Os::singleton()
+      .get_cmdline_user_args()
+      .contains(&GString::from(Self::BAKE_COORDINATE_MAP_ARG))
+  }
+
+  /// Writes the coordinate map matched with physics to `BAKED_COORDINATE_MAP_PATH` and quits the game
+  fn bake_coordinate_map(&mut self) {
+    let path = Self::BAKED_COORDINATE_MAP_PATH;
+    let baked_map = BakedCoordinateMap::from_coordinate_map(&self.coordinate_map);
+
+    if let Err(error) = baked_map.validate(&self.territories) {
+      godot_error!("The coordinate map can't be baked: {error}");
+    } else if let Some(mut file) = FileAccess::open(path, ModeFlags::WRITE) {
+      file.store_string(&baked_map.to_json());
+      godot_print!("Coordinate map baked at {path}");
+    } else {
+      godot_error!("Couldn't open {path}: {:?}", FileAccess::get_open_error());
+    }
+
+    self.base()
+      .get_tree()
+      .expect("Expected VirtualPlanet to be in the scene tree")
+      .quit();
+  }
+
+  /// Finds out which territories border each other from the matched coordinates
+  fn build_adjacency_graph(&mut self) {
+    self.adjacency = AdjacencyGraph::from_coordinate_map(
+      &self.coordinate_map,
+      self.rules.max_sea_connection_distance
+    );
+  }
+
   #[allow(dead_code)]
   /// Paints the surface point with the continent/territory color
   /// useful for debugging
@@ -274,59 +366,52 @@ impl VirtualPlanet {
   }
 
 
-  /// Receives a territory_id and returns a random coordinate from the territory
-  pub fn get_an_random_territory_coordinate(&self, territory_id: &str) -> Coordinates {
+  /// Receives a territory_id and returns a random coordinate from the territory,
+  /// drawn from the match rng so it's the same on every run with the same seed
+  pub fn get_an_random_territory_coordinate(&self, territory_id: &str, rng: &mut MatchRng) -> Coordinates {
     let territory = self.territories.get(territory_id).expect("Expected territory to exist");
-    let territory_coordinates = &territory.coordinates;
-    if territory_coordinates.len() == 0 {
-      panic!("Expected territory_coordinates to have at least one element");
-    }
-    let random_index = fastrand::usize(0..(territory_coordinates.len()-1));
-    territory_coordinates[random_index]
-  }
-
-  /// Receives a territory_id and returns a random coordinate from the territory
-  pub fn get_spawner_territory_coordinate(territory: &Territory) -> Coordinates {
-    let territory_coordinates = &territory.coordinates;
-    if territory_coordinates.len() == 0 {
-      panic!("Expected territory_coordinates to have at least one element");
-    }
-
-    // TICKET: #50 this "divided by 4" is a hack to get a coordinate in the territory not close to the border
-    // Sometimes it does not work, but it's good enough for now
-    let territory_point = territory_coordinates.len() / 1/4;
-    territory_coordinates[territory_point]
+    territory
+      .get_random_coordinate(rng)
+      .expect("Expected territory_coordinates to have at least one element")
   }
 
   /// Receives a latitude and longitude and returns the cartesian coordinates
   pub fn get_cartesian_from_coordinates(&self, given_coordinates: &Coordinates) -> Vector3 {
     let coordinate_metadata = self.coordinate_map.get(&given_coordinates).expect("Expected coordinates to exist");
-    coordinate_metadata.cartesian.clone()
+    coordinate_metadata.cartesian.to_vector3()
   }
 
-  pub fn set_new_territory_ruler(territory: &mut Territory, player: &mut Gd<Player>) {
-    let territory_id = territory.territory_id.clone();
-    let mut player_bind = player.bind_mut();
-    player_bind.max_troop_allowed += territory.organic_max_troops;
-    player_bind.register_territory_occupation(territory_id.clone());
-    let player_static_info = &player_bind.static_info;
-
-    let color = PlayerColor::get_land_color(&player_static_info.color);
-
-    territory.player_trying_to_conquer = None;
-    territory.current_ruler = Some(player_static_info.clone());
-    territory.territory_states.remove(&TerritoryState::Unoccupied);
-    territory.territory_states.remove(&TerritoryState::OccupationInProgress);
-    territory.territory_states.remove(&TerritoryState::OccupiedUnderConflict);
-    territory.territory_states.remove(&TerritoryState::UnoccupiedUnderConflict);
-    territory.territory_states.insert(TerritoryState::Occupied);
-
-    let mut territory_mesh = player_bind
-      .get_root_from_player()
-      .get_node_as::<MeshInstance3D>(&format!("globe_scene/territories/{territory_id}"));
-
-    territory_mesh.set_meta("current_base_color", &color.to_variant());
-    Territory::set_color_to_active_material(&territory_mesh, color);
+  /// Returns the surface point under the given position, computed from its direction
+  /// instead of raycasting against the `SurfacePoint` areas, check `Planet::get_coordinate_metadata_from_cartesian`
+  pub fn get_surface_point_metadata_from_cartesian(&self, position: Vector3) -> Option<SurfacePointMetadata> {
+    let (lat_long, coordinate_metadata) = Planet::get_coordinate_metadata_from_cartesian(
+      &self.coordinate_map,
+      position.to_vec3()
+    )?;
+
+    Some(SurfacePointMetadata {
+      cartesian: coordinate_metadata.cartesian.to_vector3(),
+      lat_long,
+      territory_id: coordinate_metadata.territory_id.clone(),
+    })
+  }
+
+  /// Plans deploy routes over the matched coordinates, check `RoutePlanner`
+  pub fn get_route_planner(&self) -> RoutePlanner<'_> {
+    RoutePlanner {
+      coordinate_map: &self.coordinate_map,
+      territories: &self.territories,
+      adjacency: &self.adjacency,
+      rules: &self.rules,
+    }
+  }
+
+  /// The scene tree around the territories, for them to be stepped or a troop to die
+  pub(crate) fn get_realm(&mut self) -> SceneRealm<'_> {
+    SceneRealm {
+      root: self.get_root_from_virtual_planet(),
+      virtual_planet: self,
+    }
   }
 
   pub fn get_root_from_virtual_planet(&self) -> Gd<RootScene> {
@@ -337,61 +422,9 @@ impl VirtualPlanet {
       .cast::<RootScene>()
   }
 
-  fn spawner_troop_engine_checker(&mut self, delta: f64) {
-    let root_scene: Gd<RootScene> = self.get_root_from_virtual_planet();
-
-    let territories_with_rulers = self.get_mut_territories_with_ruler();
-
-    // todo: Should get peaceful territories only
-    // todo: Also, shouldn't get territories on aftercombat situation
-    for (_territory_id, territory) in territories_with_rulers {
-
-      let player_id = territory.current_ruler.as_ref().unwrap().player_id;
-      let mut player = Player::get_player_by_id(root_scene.clone(), player_id);
-
-      if player.bind().troops_counter < player.bind().max_troop_allowed &&
-        (territory.all_troops_deployed_and_arrived.len() as u32) < territory.organic_max_troops {
-
-        territory.valid_seconds_elasped_since_last_troop += delta;
-
-        if territory.next_troop_progress >= 100. {
-
-          territory.next_troop_progress = 0.;
-          territory.valid_seconds_elasped_since_last_troop = 0.;
-          
-          let mut root_scene = root_scene.clone();
-          let mut root_scene = root_scene.bind_mut();
-
-          spawn_troop(
-            &mut root_scene,
-            &mut player,
-            territory,
-          );
-        } else {
-        // Should represent how many seconds should take for a troop to be spawned at the territory
-          territory.next_troop_progress = 100. * territory.valid_seconds_elasped_since_last_troop / territory.seconds_to_spawn_troop;
-        }
-      }
-    }
-  }
-
-  fn get_mut_territories_with_ruler(&mut self) -> Vec<(&TerritoryId, &mut Territory)> {
-    self
-      .territories
-      .iter_mut()
-      .filter(|(_, territory)| territory.current_ruler.is_some())
-      .collect()
-  }
-
-  fn get_mut_territories_under_conflict(&mut self) -> Vec<(&TerritoryId, &mut Territory)> {
-    self
-      .territories
-      .iter_mut()
-      .filter(|(_, territory)|
-        territory.territory_states.contains(&TerritoryState::OccupiedUnderConflict) ||
-        territory.territory_states.contains(&TerritoryState::UnoccupiedUnderConflict)
-      )
-      .collect()
+  /// Lets `RootScene` know which troops were spawned, so the replay can keep track of them
+  pub fn take_spawned_troops(&mut self) -> Vec<(TroopId, PlayerId, TerritoryId)> {
+    std::mem::take(&mut self.spawned_troops)
   }
 
   pub fn get_mut_territory_from_virtual_planet(&mut self, territory_id: &TerritoryId) -> &mut Territory {
@@ -411,60 +444,4 @@ impl VirtualPlanet {
         &format!("Expected territory {territory_id} to exist: {:?}", territory_id)
       )
   }
-
-  fn get_mut_territories_with_occupation_ongoing(&mut self) -> Vec<(&TerritoryId, &mut Territory)> {
-    self
-      .territories
-      .iter_mut()
-      .filter(|(_, territory)| territory.territory_states.contains(&TerritoryState::OccupationInProgress))
-      .collect()
-  }
-
-  pub fn occupation_checker(&mut self, delta: f64) {
-    let root_scene: Gd<RootScene> = self.get_root_from_virtual_planet();
-
-    let territories_with_occupation_on_going = self.get_mut_territories_with_occupation_ongoing();
-    for (territory_id, territory) in territories_with_occupation_on_going {
-
-      let Some(ref player_static_info) = territory.player_trying_to_conquer else {
-        godot_error!("'player_trying_to_conquer' doesn't exist during the Occupation Checker");
-        return;
-      };
-      
-      let base_land_color = PlayerColor::get_land_color(&player_static_info.color);
-      let color = PlayerColor::get_occupying_land_color(&player_static_info.color);
-      let mut territory_mesh = root_scene
-        .get_node_as::<MeshInstance3D>(&format!("globe_scene/territories/{territory_id}"));
-      territory_mesh.set_meta("current_base_color", &base_land_color.to_variant());
-
-      Territory::set_color_to_active_material(&territory_mesh, color);
-
-      let num_of_troops_in_the_territory = territory
-        .all_troops_deployed_and_arrived_by_player
-        .get(&player_static_info.player_id)
-        .expect("Expected player to have troops in the territory")
-        .len();
-
-      territory.conquering_progress_per_second += delta * (num_of_troops_in_the_territory as f64);
-      // godot_print!("time_to_be_conquered: {:.2} ... conquering_progress_per_second: {:.2}", territory.time_to_be_conquered, territory.conquering_progress_per_second);
-
-      if territory.conquering_progress_per_second >= territory.time_to_be_conquered {
-        territory.conquering_progress_per_second = 0.;
-
-        let root_scene = root_scene.clone();
-        let mut player = Player::get_player_by_id(root_scene, player_static_info.player_id);
-        Self::set_new_territory_ruler(territory, &mut player);
-      }
-    }
-  }
-  
-  fn check_territory_under_conflict(&mut self) {
-    self.get_mut_territories_under_conflict().iter_mut().for_each(|(_, territory)| {
-
-      if !territory.has_troops_from_different_players {
-        territory.territory_states.remove(&TerritoryState::OccupiedUnderConflict);
-        territory.territory_states.remove(&TerritoryState::UnoccupiedUnderConflict);
-      }
-    });
-  }
 }
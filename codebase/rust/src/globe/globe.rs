@@ -1,7 +1,10 @@
 use godot::classes::{INode3D, Node3D, StandardMaterial3D};
 use godot::{classes::MeshInstance3D, prelude::*};
+use simulation::territories::data::TerritoryIdsMismatch;
 
-use super::territories::territory::{Territory, Territories};
+use crate::i18n::base::AvailableLanguage;
+
+use super::territories::territory::{Territory, TerritoryPainter, Territories};
 
 #[derive(GodotClass)]
 #[class(base=Node3D)]
@@ -26,19 +29,24 @@ impl INode3D for GlobeScene {
       .expect("'territories' to exist");
 
     let territories = territories_node.get_children();
+    let territory_names: Vec<String> = territories
+      .iter_shared()
+      .map(|node_territory| node_territory.get_name().to_string())
+      .collect();
+    self.check_territory_ids(&territory_names);
+
     for node_territory in territories.iter_shared() {
       let mut territory = node_territory.cast::<MeshInstance3D>();
       let territory_name = territory.get_name();
 
-      let territory_data = self.territories.get(&territory_name.to_string());
-      if territory_data.is_none() {
-        godot_print!("No data for territory: {:?}", territory_name);
+      // Already reported by check_territory_ids
+      let Some(territory_data) = self.territories.get(&territory_name.to_string()) else {
         continue;
-      }
+      };
 
       let color = Territory::get_territory_color(
-        &territory_data.unwrap().location.sub_continent,
-        &territory_data.unwrap().location.continent
+        &territory_data.location.sub_continent,
+        &territory_data.location.continent
       );
 
       let mut material = StandardMaterial3D::new_gd();
@@ -48,3 +56,23 @@ impl INode3D for GlobeScene {
     }
   }
 }
+
+impl GlobeScene {
+  /// The ids of `res://data/territories.ron` should be the same as the territory meshes'
+  /// and the ones every language translates
+  fn check_territory_ids(&self, territory_names: &[String]) {
+    let mesh_ids = territory_names.iter().map(String::as_str);
+    if let Some(mismatch) = TerritoryIdsMismatch::compare(&self.territories, mesh_ids) {
+      godot_error!("Territory meshes don't match res://data/territories.ron: {mismatch}");
+    }
+
+    for language in AvailableLanguage::all() {
+      let territories_dictionary = language.get_translations().get_territory_dictionary();
+      let translated_ids = territories_dictionary.keys().copied();
+
+      if let Some(mismatch) = TerritoryIdsMismatch::compare(&self.territories, translated_ids) {
+        godot_error!("{language:?} territory translations don't match res://data/territories.ron: {mismatch}");
+      }
+    }
+  }
+}
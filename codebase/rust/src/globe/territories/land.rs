@@ -2,8 +2,8 @@
 use godot::{classes::{IStaticBody3D, InputEvent, InputEventMouseButton, MeshInstance3D, StaticBody3D}, global::MouseButton, prelude::*};
 use crate::{
   globe::{
-    coordinates_system::{surface_point::SurfacePoint, virtual_planet::VirtualPlanet},
-    territories::territory::Territory
+    coordinates_system::virtual_planet::VirtualPlanet,
+    territories::territory::{Territory, TerritoryPainter}
   },
   heads_up_display::territory_hud::TerritoryHUD,
 };
@@ -69,8 +69,10 @@ impl IStaticBody3D for Land {
       .expect("Expected to find territory");
 
     territory_hud.bind_mut().activate_hud(territory);
+    let territory_id = territory.territory_id.clone();
 
     Territory::checking_territory(territory_mesh);
+    self.base_mut().emit_signal(Self::LAND_HOVERED, &[territory_id.to_variant()]);
   }
 
   fn mouse_exit(&mut self) {
@@ -91,10 +93,14 @@ impl IStaticBody3D for Land {
 impl Land {
   pub const LAND_CLASS_NAME: &'static str = "is_a_land";
   pub const LAND_RIGHT_CLICKED: &'static str = "land_right_clicked";
+  pub const LAND_HOVERED: &'static str = "land_hovered";
 
   #[signal]
   fn land_right_clicked(&self) {}
 
+  #[signal]
+  fn land_hovered(&self) {}
+
   fn catch_clicks(&mut self, event: Option<Gd<InputEvent>>, event_position: Vector3) {
     if let Some(event) = event {
       if let Ok(mouse_click) = event.try_cast::<InputEventMouseButton>() {
@@ -110,16 +116,12 @@ impl Land {
             Territory::checking_territory(territory);
           },
           (MouseButton::RIGHT, false) => {
-            let surface_point = SurfacePoint::get_surface_point(
-              event_position,
-              &mut self.base().get_world_3d().expect("World to exist"),
-              Some(1.3)
-            );
-
-            if let Some(surface_point) = surface_point {
-              let bind = surface_point.bind();
-              let surface_point_metadata = bind.get_surface_point_metadata();
+            let surface_point_metadata = self
+              .get_virtual_planet_from_land()
+              .bind()
+              .get_surface_point_metadata_from_cartesian(event_position);
 
+            if let Some(surface_point_metadata) = surface_point_metadata {
               let territory_id = surface_point_metadata.territory_id
                 .clone()
                 .unwrap_or_else(|| "".to_string());
@@ -1,4 +1,4 @@
 pub mod territory;
 pub mod ocean;
 pub mod land;
-pub mod data;
+pub mod realm;
\ No newline at end of file
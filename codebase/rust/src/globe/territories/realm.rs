@@ -0,0 +1,165 @@
+use godot::{classes::MeshInstance3D, prelude::*};
+
+use simulation::events::game_event::GameEvent;
+use simulation::players::player::PlayerState;
+use simulation::rules::Rules;
+use simulation::territories::realm::Realm;
+use simulation::troops::battlefield::TroopSighting;
+use simulation::troops::mesh_map::TroopMeshes;
+use simulation::troops::troop::Troop as TroopRules;
+
+use crate::{
+  bridge::vector::IntoVector3,
+  events::game_events::GameEvents,
+  globe::coordinates_system::virtual_planet::VirtualPlanet,
+  player::{
+    color::{PlayerColor, PlayerColorPalette},
+    player::{Player, PlayerId}
+  },
+  root::root::RootScene,
+  troops::{
+    battlefield::SceneBattlefield,
+    combat::projectile::Projectile,
+    spawner_engine::instantiate_troop,
+    troop::{Troop, TroopId}
+  },
+};
+
+use super::territory::{Territories, Territory, TerritoryId, TerritoryPainter, TerritoryState};
+
+/// The scene tree around the territories while they're stepped or a troop dies,
+/// the players and troops are nodes, so any of them can be bound but the troop dying
+pub struct SceneRealm<'a> {
+  pub virtual_planet: &'a mut VirtualPlanet,
+  pub root: Gd<RootScene>,
+}
+
+impl SceneRealm<'_> {
+  /// Troops in the scene tree order, the ones already dead are left out
+  fn get_troop_nodes(&self) -> Vec<Gd<Troop>> {
+    self.root
+      .get_node_as::<Node3D>("troops")
+      .get_children()
+      .iter_shared()
+      .filter_map(|child| child.try_cast::<Troop>().ok())
+      .filter(|troop| !troop.is_queued_for_deletion())
+      .collect()
+  }
+
+  fn get_player_color(&self, player_id: PlayerId) -> PlayerColor {
+    Player::get_player_by_id(self.root.clone(), player_id)
+      .bind()
+      .static_info
+      .color
+      .clone()
+  }
+}
+
+impl Realm for SceneRealm<'_> {
+  fn get_territories(&self) -> &Territories {
+    &self.virtual_planet.territories
+  }
+
+  fn get_territories_mut(&mut self) -> &mut Territories {
+    &mut self.virtual_planet.territories
+  }
+
+  fn with_player<R>(&mut self, player_id: PlayerId, lend: impl FnOnce(&mut PlayerState, &mut Territories, &Rules) -> R) -> R {
+    let mut player = Player::get_player_by_id(self.root.clone(), player_id);
+    let mut player = player.bind_mut();
+    lend(&mut player.state, &mut self.virtual_planet.territories, &self.virtual_planet.rules)
+  }
+
+  fn get_troop_meshes(&self, player_id: PlayerId) -> TroopMeshes {
+    Player::get_player_by_id(self.root.clone(), player_id)
+      .bind()
+      .static_info
+      .troop_meshes
+      .clone()
+  }
+
+  fn get_troops(&self) -> Vec<TroopSighting> {
+    self.get_troop_nodes()
+      .iter()
+      .map(SceneBattlefield::get_troop_sighting)
+      .collect()
+  }
+
+  /// The troop is put under /root_scene/troops, and `RootScene` gets to know about it by `VirtualPlanet::take_spawned_troops`
+  fn add_troop(&mut self, troop: TroopRules) {
+    let owner_static_info = Player::get_player_by_id(self.root.clone(), troop.owner).bind().static_info.clone();
+
+    self.virtual_planet.spawned_troops.push((troop.troop_id.clone(), troop.owner, troop.deployed_to_territory.clone()));
+
+    let mut new_troop = instantiate_troop(&self.root, &owner_static_info, Some(&troop.troop_id));
+    new_troop.set_position(troop.position.to_vector3());
+    new_troop.bind_mut().state = troop;
+    new_troop.emit_signal(Troop::EVENT_TROOP_SPAWNED, &[]);
+  }
+
+  fn forget_troop(&mut self, troop_id: &TroopId) {
+    for mut troop in self.get_troop_nodes() {
+      troop.bind_mut().state.combat_stats.forget_troop(troop_id);
+    }
+  }
+
+  /// The projectile that killed the troop is already queued for deletion, so it isn't bound again here
+  fn retarget_projectiles(&mut self, troop_id: &TroopId, new_target: Option<&TroopSighting>) {
+    let new_target = new_target.map(|new_target| {
+      self.root.get_node_as::<Troop>(&format!("troops/{}", new_target.troop_id))
+    });
+
+    let projectiles = self.root
+      .get_node_as::<Node3D>("troops/projectiles")
+      .get_children()
+      .iter_shared()
+      .filter_map(|child| child.try_cast::<Projectile>().ok())
+      .filter(|projectile| !projectile.is_queued_for_deletion());
+
+    for mut projectile in projectiles {
+      let targets_the_troop = projectile.bind().target
+        .as_ref()
+        .is_some_and(|target| target.get_name().to_string() == *troop_id);
+      if !targets_the_troop {
+        continue;
+      }
+
+      match &new_target {
+        Some(new_target) => projectile.bind_mut().retarget(new_target.clone()),
+        None => projectile.queue_free(),
+      }
+    }
+  }
+
+  /// An occupation in progress shows the occupier color, otherwise it's the ruler one,
+  /// or the base color of the territory if no one rules it
+  fn paint_territory(&mut self, territory_id: &TerritoryId) {
+    let territory = self.virtual_planet.get_territory_from_virtual_planet(territory_id);
+    let occupier_id = territory.player_trying_to_conquer
+      .filter(|_| territory.territory_states.contains(&TerritoryState::OccupationInProgress));
+
+    let (base_color, color) = match (occupier_id, territory.current_ruler) {
+      (Some(occupier_id), _) => {
+        let occupier_color = self.get_player_color(occupier_id);
+        (PlayerColor::get_land_color(&occupier_color), PlayerColor::get_occupying_land_color(&occupier_color))
+      },
+      (None, Some(ruler_id)) => {
+        let ruler_color = PlayerColor::get_land_color(&self.get_player_color(ruler_id));
+        (ruler_color, ruler_color)
+      },
+      (None, None) => {
+        let territory_color = Territory::get_territory_color(&territory.location.sub_continent, &territory.location.continent);
+        (territory_color, territory_color)
+      },
+    };
+
+    let mut territory_mesh = self.root
+      .get_node_as::<MeshInstance3D>(&format!("globe_scene/territories/{territory_id}"));
+    territory_mesh.set_meta("current_base_color", &base_color.to_variant());
+    Territory::set_color_to_active_material(&territory_mesh, color);
+  }
+
+  fn emit(&mut self, event: GameEvent) {
+    GameEvents::emit_from_root(&self.root, event);
+  }
+}
@@ -0,0 +1,102 @@
+use godot::classes::{ILabel, Label};
+use godot::prelude::*;
+use simulation::territories::bonuses::Region;
+
+use crate::i18n::base::{AvailableLanguage, ILanguage};
+use crate::player::player::Player;
+use crate::root::root::RootScene;
+
+/// Lists the continents and sub-continents every player rules entirely, the ones giving a bonus,
+/// check `RegionBonus`
+#[derive(GodotClass)]
+#[class(base=Label)]
+pub struct ContinentsHUD {
+  base: Base<Label>,
+  chosen_language: Option<AvailableLanguage>,
+}
+
+#[godot_api]
+impl ILabel for ContinentsHUD {
+  fn init(base: Base<Label>) -> ContinentsHUD {
+
+    ContinentsHUD {
+      base: base,
+      chosen_language: None,
+    }
+  }
+
+  fn process(&mut self, _delta: f64) {
+    let root_scene = self.get_root_from_continents_hud();
+    let Some(players_node) = root_scene.try_get_node_as::<Node3D>("players") else {
+      return;
+    };
+
+    let players: Vec<Gd<Player>> = players_node
+      .get_children()
+      .iter_shared()
+      .filter_map(|child| child.try_cast::<Player>().ok())
+      .filter(|player| !player.is_queued_for_deletion())
+      .collect();
+
+    // TODO: remove hardcoded player id
+    const MAIN_PLAYER_ID: u32 = 1;
+    if self.chosen_language.is_none() &&
+      players.iter().any(|player| player.bind().static_info.player_id == MAIN_PLAYER_ID) {
+      self.chosen_language = Some(Player::get_player_language(root_scene, MAIN_PLAYER_ID));
+    }
+    let Some(chosen_language) = &self.chosen_language else {
+      return;
+    };
+
+    let translations = chosen_language.get_translations();
+    let mut lines: Vec<String> = Vec::new();
+    for player in &players {
+      let player = player.bind();
+      if player.state.region_bonus.regions.is_empty() {
+        continue;
+      }
+
+      let regions: Vec<&str> = player.state.region_bonus.regions
+        .iter()
+        .map(|region| Self::get_translated_region(translations.as_ref(), region))
+        .collect();
+      lines.push(format!("{}: {}", player.static_info.user_name, regions.join(", ")));
+    }
+
+    if lines.is_empty() {
+      self.base_mut().set_visible(false);
+      return;
+    }
+
+    let general_dictionary = translations.get_general_dictionary();
+    let translated_title = general_dictionary
+      .get("regions_held")
+      .expect("Expected general_dictionary to have regions_held");
+
+    self.base_mut().set_visible(true);
+    self.base_mut().set_text(&format!("{translated_title}\n{}", lines.join("\n")));
+  }
+}
+
+impl ContinentsHUD {
+  fn get_translated_region(translations: &dyn ILanguage, region: &Region) -> &'static str {
+    match region {
+      Region::Continent(continent) => *translations
+        .get_continents()
+        .get(continent)
+        .expect("Expected to find continent in dictionary"),
+      Region::SubContinent(sub_continent) => *translations
+        .get_sub_continents()
+        .get(sub_continent)
+        .expect("Expected to find sub_continent in dictionary"),
+    }
+  }
+
+  fn get_root_from_continents_hud(&self) -> Gd<RootScene> {
+    self
+      .base()
+      .get_parent().expect("Expected ContinentsHUD to have ui as parent")
+      .get_parent().expect("Expected ui to have root as parent")
+      .cast::<RootScene>()
+  }
+}
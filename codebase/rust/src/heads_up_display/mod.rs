@@ -1,4 +1,5 @@
 pub mod debugger_hud;
 pub mod territory_hud;
 pub mod selection_hud;
-pub mod text_labels;
\ No newline at end of file
+pub mod text_labels;
+pub mod continents_hud;
\ No newline at end of file
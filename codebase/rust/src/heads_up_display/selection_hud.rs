@@ -7,6 +7,17 @@ use super::text_labels::TextLabels;
 #[class(base=Control)]
 pub struct SelectionHUD {
   base: Base<Control>,
+  num_of_troops: usize,
+  /// The route the selected troops would take to the hovered territory, see `UiDragBox::preview_route`
+  route_preview: Option<String>,
+  /// The formation the selected troops would be sent in, see `UiDragBox::preview_formation`
+  formation_preview: Option<String>,
+  /// The orders the selected troops have yet to carry out, see `UiDragBox::preview_order_queue`
+  order_queue_preview: Option<String>,
+  /// The stance of the selected troops, see `UiDragBox::preview_stance`
+  stance_preview: Option<String>,
+  /// Where the first selected troop is and what it gets from it, see `UiDragBox::preview_location`
+  location_preview: Option<String>,
 }
 
 #[godot_api]
@@ -15,6 +26,12 @@ impl IControl for SelectionHUD {
 
     SelectionHUD {
       base: base,
+      num_of_troops: 0,
+      route_preview: None,
+      formation_preview: None,
+      order_queue_preview: None,
+      stance_preview: None,
+      location_preview: None,
     }
   }
 
@@ -29,13 +46,64 @@ impl SelectionHUD {
   }
 
   pub fn set_text_with_num_of_troops(&mut self, num_of_troops: usize) {
+    self.num_of_troops = num_of_troops;
+    self.refresh_text();
+  }
+
+  pub fn set_route_preview(&mut self, route_preview: Option<String>) {
+    self.route_preview = route_preview;
+    self.refresh_text();
+  }
+
+  pub fn set_formation_preview(&mut self, formation_preview: Option<String>) {
+    self.formation_preview = formation_preview;
+    self.refresh_text();
+  }
+
+  /// It's checked every frame, so the text is only refreshed when the queue changes
+  pub fn set_order_queue_preview(&mut self, order_queue_preview: Option<String>) {
+    if self.order_queue_preview == order_queue_preview {
+      return;
+    }
+
+    self.order_queue_preview = order_queue_preview;
+    self.refresh_text();
+  }
+
+  pub fn set_stance_preview(&mut self, stance_preview: Option<String>) {
+    self.stance_preview = stance_preview;
+    self.refresh_text();
+  }
+
+  /// It's checked every frame, so the text is only refreshed when the location changes
+  pub fn set_location_preview(&mut self, location_preview: Option<String>) {
+    if self.location_preview == location_preview {
+      return;
+    }
+
+    self.location_preview = location_preview;
+    self.refresh_text();
+  }
+
+  fn refresh_text(&mut self) {
     let path: &str = "MarginContainer/PanelContainer/MarginContainer/HBoxContainer/TextLabels";
     let mut text_label = self
       .base_mut()
       .get_node_as::<TextLabels>(path);
-    text_label.set_text(
-      &format!(" {num_of_troops}x Troops Selected")
-    );
+
+    let num_of_troops = self.num_of_troops;
+    let mut text = format!(" {num_of_troops}x Troops Selected");
+    let previews = [
+      &self.formation_preview,
+      &self.route_preview,
+      &self.order_queue_preview,
+      &self.stance_preview,
+      &self.location_preview,
+    ];
+    for preview in previews.into_iter().flatten() {
+      text.push_str(&format!("\n {preview}"));
+    }
+    text_label.set_text(&text);
   }
 
   pub fn deactivate_hud(&mut self) {
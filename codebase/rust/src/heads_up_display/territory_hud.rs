@@ -4,8 +4,8 @@ use godot::prelude::*;
 use crate::globe::coordinates_system::virtual_planet::VirtualPlanet;
 use crate::globe::territories::territory::{Territory, TerritoryId, TerritoryState};
 use crate::i18n::base::{AvailableLanguage, I18nDefaultDictionary};
-use crate::player::color::PlayerColor;
-use crate::player::player::Player;
+use crate::player::color::{PlayerColor, PlayerColorPalette};
+use crate::player::player::{Player, PlayerId, PlayerStaticInfo};
 use crate::root::root::RootScene;
 
 use super::text_labels::TextLabels;
@@ -86,9 +86,11 @@ impl TerritoryHUD {
 
     let base_dictionaries = self.chosen_language.get_translations();
     let territories_dictionary = &base_dictionaries.get_territory_dictionary();
+    // Missing translations are reported by GlobeScene::check_territory_ids, the id is better than nothing
     let translated_territory = territories_dictionary
       .get(&territory.territory_id as &str)
-      .expect("Expected to find territory_id in dictionary");
+      .copied()
+      .unwrap_or(territory.territory_id.as_str());
     
     let sizes_dictionary = &base_dictionaries.get_sizes();
     let translated_size = sizes_dictionary
@@ -137,6 +139,13 @@ impl TerritoryHUD {
     let base_dictionaries = self.chosen_language.get_translations();
     let general_dictionary = &base_dictionaries.get_general_dictionary();
 
+    // Territories only keep the players' ids, names and colors come from the Player nodes
+    let root_scene = self.get_root_from_territory_hud();
+    let ruler = territory.current_ruler
+      .map(|player_id| Self::get_player_static_info(root_scene.clone(), player_id));
+    let player_trying_to_conquer = territory.player_trying_to_conquer
+      .map(|player_id| Self::get_player_static_info(root_scene.clone(), player_id));
+
     if territory.territory_states.contains(&TerritoryState::UnoccupiedUnderConflict) {
       Self::show_updated_unoccupied_under_conflict_ruler_hud(
         &mut under_conflict,
@@ -150,6 +159,7 @@ impl TerritoryHUD {
         &mut ruler_label,
         general_dictionary,
         &territory,
+        player_trying_to_conquer.as_ref(),
       );
 
     } else if territory.territory_states.contains(&TerritoryState::Unoccupied) {
@@ -165,6 +175,8 @@ impl TerritoryHUD {
         &mut ruler_label,
         general_dictionary,
         &territory,
+        ruler.as_ref(),
+        player_trying_to_conquer.as_ref(),
       );
 
     } else if territory.territory_states.contains(&TerritoryState::Occupied) {
@@ -173,6 +185,7 @@ impl TerritoryHUD {
         &mut ruler_label,
         general_dictionary,
         &territory,
+        ruler.as_ref(),
       );
     }
   }
@@ -190,6 +203,12 @@ impl TerritoryHUD {
       .cast::<RootScene>()
   }
 
+  fn get_player_static_info(root_scene: Gd<RootScene>, player_id: PlayerId) -> PlayerStaticInfo {
+    let player = Player::get_player_by_id(root_scene, player_id);
+    let static_info = player.bind().static_info.clone();
+    static_info
+  }
+
   fn get_virtual_planet_from_territory_hud(&mut self) -> Gd<VirtualPlanet> {
     let virtual_planet = self
       .get_root_from_territory_hud()
@@ -244,7 +263,7 @@ impl TerritoryHUD {
     );
   }
 
-  fn show_updated_occupation_in_progress_ruler_hud(occupation_in_progress: &mut Gd<HBoxContainer>, ruler_label: &mut Gd<TextLabels>, general_dictionary: &I18nDefaultDictionary, territory: &Territory) {
+  fn show_updated_occupation_in_progress_ruler_hud(occupation_in_progress: &mut Gd<HBoxContainer>, ruler_label: &mut Gd<TextLabels>, general_dictionary: &I18nDefaultDictionary, territory: &Territory, player_trying_to_conquer: Option<&PlayerStaticInfo>) {
     occupation_in_progress.set_visible(true);
     ruler_label.set_text(
       *general_dictionary
@@ -252,7 +271,7 @@ impl TerritoryHUD {
       .expect("Expected general_dictionary to have occupation_in_progress")
     );
 
-    let Some(player_trying_to_conquer) = &territory.player_trying_to_conquer else { return; };
+    let Some(player_trying_to_conquer) = player_trying_to_conquer else { return; };
     let player_id = player_trying_to_conquer.player_id;
     let occupier_color = PlayerColor::get_banner_player_color(&player_trying_to_conquer.color);
     let mut occupier_banner = occupation_in_progress.get_node_as::<ColorRect>("banner");
@@ -302,9 +321,9 @@ impl TerritoryHUD {
     );
   }
 
-  fn show_updated_occupied_ruler_hud(occupied: &mut Gd<HBoxContainer>, ruler_label: &mut Gd<TextLabels>, general_dictionary: &I18nDefaultDictionary, territory: &Territory) {
+  fn show_updated_occupied_ruler_hud(occupied: &mut Gd<HBoxContainer>, ruler_label: &mut Gd<TextLabels>, general_dictionary: &I18nDefaultDictionary, territory: &Territory, ruler: Option<&PlayerStaticInfo>) {
     occupied.set_visible(true);
-    let ruler = territory.current_ruler.as_ref().unwrap();
+    let ruler = ruler.expect("Expected occupied territory to have a ruler");
     let ruler_color = PlayerColor::get_banner_player_color(&ruler.color);
     let mut ruler_banner = occupied.get_node_as::<ColorRect>("banner");
     
@@ -336,10 +355,10 @@ impl TerritoryHUD {
     );
   }
 
-  fn show_updated_occupied_under_conflict_ruler_hud(under_conflict: &mut Gd<VBoxContainer>, ruler_label: &mut Gd<TextLabels>, _general_dictionary: &I18nDefaultDictionary, territory: &Territory) {
+  fn show_updated_occupied_under_conflict_ruler_hud(under_conflict: &mut Gd<VBoxContainer>, ruler_label: &mut Gd<TextLabels>, _general_dictionary: &I18nDefaultDictionary, territory: &Territory, ruler: Option<&PlayerStaticInfo>, player_trying_to_conquer: Option<&PlayerStaticInfo>) {
     under_conflict.set_visible(true);
 
-    let Some(player_trying_to_conquer) = &territory.player_trying_to_conquer else { return; };
+    let Some(player_trying_to_conquer) = player_trying_to_conquer else { return; };
     let player_trying_to_conquer_id = player_trying_to_conquer.player_id;
     let player_trying_to_conquer_color = PlayerColor::get_banner_player_color(&player_trying_to_conquer.color);
     let Some(all_troops_deployed_and_arrived_by_player_trying_to_conquer) = territory.all_troops_deployed_and_arrived_by_player.get(&player_trying_to_conquer_id) else {
@@ -352,7 +371,7 @@ impl TerritoryHUD {
     let num_of_troops_of_player_trying_to_conquer = all_troops_deployed_and_arrived_by_player_trying_to_conquer.len() as f32;
 
 
-    let Some(ruler) = &territory.current_ruler else { return; };
+    let Some(ruler) = ruler else { return; };
     let ruler_id = ruler.player_id;
     let ruler_color = PlayerColor::get_banner_player_color(&ruler.color);
     let Some(all_troops_deployed_and_arrived_by_ruler) = territory.all_troops_deployed_and_arrived_by_player.get(&ruler_id) else {
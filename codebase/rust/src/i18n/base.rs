@@ -51,6 +51,24 @@ pub enum AvailableLanguage {
 }
 
 impl AvailableLanguage {
+  /// Maps the language codes used on match setup files, which are also the names of the i18n folders
+  pub fn from_code(code: &str) -> Option<AvailableLanguage> {
+    match code {
+      "en_international" => Some(AvailableLanguage::InternationalEnglish),
+      "pt_br" => Some(AvailableLanguage::BrazilianPortuguese),
+      "es_us" => Some(AvailableLanguage::AmericanSpanish),
+      _ => None,
+    }
+  }
+
+  pub fn all() -> [AvailableLanguage; 3] {
+    [
+      AvailableLanguage::InternationalEnglish,
+      AvailableLanguage::BrazilianPortuguese,
+      AvailableLanguage::AmericanSpanish,
+    ]
+  }
+
   pub fn get_translations(&self) -> Box<dyn ILanguage> {
     match self {
       AvailableLanguage::InternationalEnglish => Box::new(InternationalEnglish {}),
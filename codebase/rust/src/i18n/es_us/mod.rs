@@ -149,6 +149,30 @@ impl ILanguage for AmericanSpanish {
       ("ruler", "Ruler"),
       ("every_x_secs", "Every {x} secs"),
       ("max_troops", "Max Troops"),
+      ("regions_held", "Continents Held"),
+      ("route", "Route ({x})"),
+      ("route_shortest", "Shortest"),
+      ("route_friendly_only", "Friendly Only"),
+      ("route_avoid_contested", "Avoid Contested"),
+      ("no_route", "no way through, going straight by"),
+      ("formation", "Formation ({x})"),
+      ("formation_blob", "Blob"),
+      ("formation_line", "Line"),
+      ("formation_wedge", "Wedge"),
+      ("queued_orders", "Next ({x})"),
+      ("patrolling_orders", "Patrolling ({x})"),
+      ("holding_position", "Holding Position"),
+      ("stance", "Stance ({x})"),
+      ("stance_beligerent", "Belligerent"),
+      ("stance_pacifist", "Pacifist"),
+      ("stance_hold_fire", "Hold Fire"),
+      ("location", "{x} (Damage x{damage}, Defence x{defence}, Fire Rate x{fire_rate}, Morale x{morale})"),
+      ("location_self_land", "Home Land"),
+      ("location_ally_land", "Ally Land"),
+      ("location_neutral_land", "Neutral Land"),
+      ("location_enemy_land", "Enemy Land"),
+      ("location_sea", "Sea"),
+      ("location_deep_invasion", "Deep Invasion"),
     ])
   }
 
@@ -148,6 +148,30 @@ impl ILanguage for BrazilianPortuguese {
       ("ruler", "Regente"),
       ("every_x_secs", "A cada {x} segundos"),
       ("max_troops", "Max Troops"),
+      ("regions_held", "Continentes Dominados"),
+      ("route", "Rota ({x})"),
+      ("route_shortest", "Mais Curta"),
+      ("route_friendly_only", "Só Aliados"),
+      ("route_avoid_contested", "Evitar Conflitos"),
+      ("no_route", "sem caminho, indo direto por"),
+      ("formation", "Formação ({x})"),
+      ("formation_blob", "Bloco"),
+      ("formation_line", "Linha"),
+      ("formation_wedge", "Cunha"),
+      ("queued_orders", "Próximos ({x})"),
+      ("patrolling_orders", "Patrulhando ({x})"),
+      ("holding_position", "Mantendo Posição"),
+      ("stance", "Postura ({x})"),
+      ("stance_beligerent", "Beligerante"),
+      ("stance_pacifist", "Pacifista"),
+      ("stance_hold_fire", "Cessar Fogo"),
+      ("location", "{x} (Dano x{damage}, Defesa x{defence}, Cadência x{fire_rate}, Moral x{morale})"),
+      ("location_self_land", "Terra Natal"),
+      ("location_ally_land", "Terra Aliada"),
+      ("location_neutral_land", "Terra Neutra"),
+      ("location_enemy_land", "Terra Inimiga"),
+      ("location_sea", "Mar"),
+      ("location_deep_invasion", "Invasão Profunda"),
     ])
   }
 
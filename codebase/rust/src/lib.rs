@@ -2,7 +2,9 @@
 use godot::prelude::*;
 
 pub mod macros;
+pub mod bridge;
 pub mod root;
+pub mod events;
 pub mod i18n;
 pub mod globe;
 pub mod camera;
@@ -1,20 +1,11 @@
 use godot::builtin::Color;
 
-#[derive(Debug, Clone)]
-pub enum PlayerColor {
-  Red,
-  Blue,
-  Green,
-  Yellow,
-  Purple,
-  Orange,
-  Black,
-  White,
-  LightBlue,
-}
+pub use simulation::players::color::PlayerColor;
 
-impl PlayerColor {
-  pub fn get_player_color(color: &PlayerColor) -> Color {
+/// Maps the `PlayerColor`s to the actual Godot `Color`s
+/// it's implemented for `PlayerColor`, so it can be used as `PlayerColor::get_land_color(color)`
+pub trait PlayerColorPalette {
+  fn get_player_color(color: &PlayerColor) -> Color {
     match color {
       PlayerColor::Red => Color::RED,
       PlayerColor::Blue => Color::BLUE,
@@ -28,35 +19,35 @@ impl PlayerColor {
     }
   }
 
-  pub fn get_troop_player_color(color: &PlayerColor) -> Color {
+  fn get_troop_player_color(color: &PlayerColor) -> Color {
     let color = Self::get_player_color(color);
     let color = color.darkened(0.2);
 
     color
   }
 
-  pub fn get_banner_player_color(color: &PlayerColor) -> Color {
+  fn get_banner_player_color(color: &PlayerColor) -> Color {
     let color = Self::get_player_color(color);
     let color = color.lightened(0.2);
 
     color
   }
 
-  pub fn get_land_color(color: &PlayerColor) -> Color {
+  fn get_land_color(color: &PlayerColor) -> Color {
     let color = Self::get_player_color(color);
     let color = color.lightened(0.5);
 
     color
   }
 
-  pub fn get_occupying_land_color(color: &PlayerColor) -> Color {
+  fn get_occupying_land_color(color: &PlayerColor) -> Color {
     let color = Self::get_player_color(color);
     let color = color.lightened(0.75);
 
     color
   }
 
-  pub fn get_troop_selected_color(color: &PlayerColor) -> Color {
+  fn get_troop_selected_color(color: &PlayerColor) -> Color {
     let color = Self::get_player_color(color);
     let mut color = color.lightened(0.5);
     color.set_a8(55);
@@ -66,3 +57,5 @@ impl PlayerColor {
 
 }
 
+impl PlayerColorPalette for PlayerColor {}
+
@@ -2,9 +2,14 @@ use std::collections::{HashMap, HashSet};
 
 use godot::{classes::INode3D, prelude::*};
 
+use simulation::players::player::PlayerState;
+
 use crate::{globe::{coordinates_system::virtual_planet::VirtualPlanet, territories::territory::TerritoryId}, i18n::base::AvailableLanguage, root::root::RootScene, troops::mesh_map::MeshId};
 use super::color::PlayerColor;
 
+pub use simulation::players::player::{PlayerId, PlayerType};
+pub use simulation::troops::mesh_map::TroopMeshes;
+
 /// Defines
 /// troop colors,
 /// allyship,
@@ -27,28 +32,15 @@ struct EnemyStats {
   territories_taken_by_player: u32,
 }
 
-#[derive(Debug, Clone)]
-pub struct TroopMeshes {
-  pub land: MeshId,
-  pub sea: MeshId,
-}
-
-pub type PlayerId = u32;
-
 #[derive(GodotClass)]
 #[class(base=Node3D)]
 pub struct Player {
   base: Base<Node3D>,
   pub static_info: PlayerStaticInfo,
-  pub troops_counter: u32,
-  territory_counter: u32,
 
-  /// it's the sum of every territory's organic_max_troops being ruled by the player
-  pub max_troop_allowed: u32,
+  /// troops and territories counters, they're handled by the simulation crate
+  pub state: PlayerState,
 
-  #[allow(dead_code)] //TODO: remove dead_code
-  alive: bool,
-  
   #[allow(dead_code)] //TODO: remove dead_code
   in_combat_with: HashSet<Player>,
   
@@ -59,14 +51,6 @@ pub struct Player {
   enemies_stats: HashMap<PlayerId, EnemyStats>,
 }
 
-#[derive(PartialEq, Debug, Clone, GodotConvert)]
-#[godot(via = i64)]
-pub enum PlayerType {
-  MainPlayer,
-  OtherPlayers,
-  Bot,
-}
-
 #[derive(Debug, Clone)]
 pub struct PlayerStaticInfo {
   pub player_id: PlayerId,
@@ -85,10 +69,7 @@ impl INode3D for Player {
     Player {
       base: base,
       static_info: Self::get_blank_static_info(),
-      troops_counter: 0,
-      territory_counter: 0,
-      max_troop_allowed: 0,
-      alive: true, 
+      state: PlayerState::new(0),
       in_combat_with: HashSet::new(),
       allied_with: HashSet::new(),
       enemies_stats: HashMap::new(),
@@ -109,6 +90,7 @@ impl Player {
     troop_meshes: TroopMeshes,
     chosen_language: AvailableLanguage,
   ) {
+    self.state.player_id = player_id;
     self.static_info = PlayerStaticInfo {
       player_id,
       user_name,
@@ -146,23 +128,6 @@ impl Player {
     }
   }
 
-  pub fn register_troop_spawning(&mut self) {
-    self.troops_counter += 1;
-  }
-
-  pub fn register_territory_occupation(&mut self, _territory_id: TerritoryId) {
-    self.territory_counter += 1;
-  }
-
-  #[allow(dead_code)] //TODO: remove dead_code
-  fn register_territory_loss(&mut self) {
-    self.territory_counter -= 1;
-
-    if self.territory_counter <= 0 {
-      self.territory_counter = 0;
-    }
-  }
-
   /// expects the following hierarchy:
   /// ```
   /// root_scene
@@ -1,5 +1,21 @@
 use godot::{classes::{INinePatchRect, InputEvent, InputEventMouseButton, NinePatchRect}, global::MouseButton, prelude::*};
-use crate::{camera::player_camera::PlayerCamera, globe::territories::{land::Land, territory::TerritoryId}, heads_up_display::selection_hud::SelectionHUD, troops::troop::Troop};
+use simulation::troops::{
+  combat::{combat_stats::{FighthingBehavior, LocationSituation}, location::Location},
+  formation::{Formation, FormationTroop},
+  orders::OrderMode,
+  route::RouteOption,
+  speed::SpeedType,
+};
+use crate::{
+  bridge::vector::{IntoVec3, IntoVector3},
+  camera::player_camera::PlayerCamera,
+  globe::{coordinates_system::virtual_planet::VirtualPlanet, territories::{land::Land, territory::TerritoryId}},
+  heads_up_display::selection_hud::SelectionHUD,
+  i18n::base::AvailableLanguage,
+  player::player::Player,
+  root::root::RootScene,
+  troops::troop::Troop
+};
 
 #[derive(GodotClass)]
 #[class(base=NinePatchRect)]
@@ -11,6 +27,11 @@ pub struct UiDragBox {
   released_at: Vector2,
   positive_x: bool,
   positive_y: bool,
+  /// How the selected troops get to where they're sent, cycled with `cycle_route_option`
+  route_option: RouteOption,
+  /// How the selected troops spread around where they're sent, cycled with `cycle_formation`
+  formation: Formation,
+  hovered_territory_id: Option<TerritoryId>,
 }
 
 #[godot_api]
@@ -24,6 +45,9 @@ impl INinePatchRect for UiDragBox {
       released_at: Vector2::ZERO,
       positive_x: true,
       positive_y: true,
+      route_option: RouteOption::default(),
+      formation: Formation::default(),
+      hovered_territory_id: None,
     }
   }
 
@@ -33,6 +57,24 @@ impl INinePatchRect for UiDragBox {
   }
 
   fn input(&mut self, event: Gd<InputEvent>) {
+    if event.is_action_pressed("cycle_route_option") {
+      self.route_option = self.route_option.get_next();
+      self.preview_route();
+    }
+
+    if event.is_action_pressed("cycle_formation") {
+      self.formation = self.formation.get_next();
+      self.preview_formation();
+    }
+
+    if event.is_action_pressed("hold_position") {
+      self.hold_selected_troops();
+    }
+
+    if event.is_action_pressed("cycle_stance") {
+      self.cycle_selected_troops_stance();
+    }
+
     if let Ok(mouse_click) = event.clone().try_cast::<InputEventMouseButton>() {
       let mouse_button = mouse_click.get_button_index();
       let pressed = mouse_click.is_pressed();
@@ -58,6 +100,12 @@ impl INinePatchRect for UiDragBox {
   }
 
   fn process(&mut self, _delta: f64) {
+    // Queued orders are taken and territories are crossed as the troops go, so the HUD keeps up with them
+    if !self.in_rect_troops.is_empty() {
+      self.preview_order_queue();
+      self.preview_location();
+    }
+
     if self.dragging {
       let mouse_pos = self.base_mut().get_global_mouse_position();
       let size = mouse_pos - self.start_pos;
@@ -112,6 +160,11 @@ impl UiDragBox {
       }
     }
 
+    self.preview_route();
+    self.preview_formation();
+    self.preview_order_queue();
+    self.preview_stance();
+    self.preview_location();
   }
 
   fn deselect_troops(&mut self) {
@@ -142,6 +195,167 @@ impl UiDragBox {
     selectable_troops
   }
 
+  /// Shows the territories the first selected troop would go through to the hovered territory,
+  /// so the player sees the route before sending the troops
+  fn preview_route(&mut self) {
+    self.in_rect_troops.retain(|troop| troop.is_instance_valid());
+    let route_preview = match (self.in_rect_troops.first().cloned(), self.hovered_territory_id.clone()) {
+      (Some(troop), Some(hovered_territory_id)) => Some(self.get_route_preview(&troop, &hovered_territory_id)),
+      _ => None,
+    };
+
+    let mut selection_hud = self.get_hud_from_ui_drag_box();
+    selection_hud.bind_mut().set_route_preview(route_preview);
+  }
+
+  fn get_route_preview(&mut self, troop: &Gd<Troop>, destination_territory_id: &TerritoryId) -> String {
+    // TODO: remove hardcoded player id
+    const MAIN_PLAYER_ID: u32 = 1;
+    let root_scene = self.get_root_from_ui_drag_box().cast::<RootScene>();
+    let chosen_language: AvailableLanguage = Player::get_player_language(root_scene, MAIN_PLAYER_ID);
+    let translations = chosen_language.get_translations();
+    let general_dictionary = translations.get_general_dictionary();
+    let territories_dictionary = translations.get_territory_dictionary();
+
+    let virtual_planet = self
+      .get_root_from_ui_drag_box()
+      .get_node_as::<VirtualPlanet>("virtual_planet");
+    let virtual_planet = virtual_planet.bind();
+    let troop = troop.bind();
+
+    let destination = virtual_planet
+      .get_territory_from_virtual_planet(destination_territory_id)
+      .spawner_location;
+    let route = virtual_planet.get_route_planner().plan_or_shortest(
+      self.route_option,
+      troop.owner.player_id,
+      troop.state.position,
+      &troop.state.deployed_to_territory,
+      destination,
+      destination_territory_id,
+    );
+
+    let route_option_key = match self.route_option {
+      RouteOption::Shortest => "route_shortest",
+      RouteOption::FriendlyOnly => "route_friendly_only",
+      RouteOption::AvoidContested => "route_avoid_contested",
+    };
+    let translated_route_option = general_dictionary
+      .get(route_option_key)
+      .expect("Expected general_dictionary to have the route option");
+    let translated_route = general_dictionary
+      .get("route")
+      .expect("Expected general_dictionary to have route")
+      .replace("{x}", translated_route_option);
+
+    // Missing translations are reported by GlobeScene::check_territory_ids, the id is better than nothing
+    let translated_territories: Vec<&str> = route.territories
+      .iter()
+      .map(|territory_id| territories_dictionary
+        .get(territory_id as &str)
+        .copied()
+        .unwrap_or(territory_id.as_str()))
+      .collect();
+
+    if route.option != self.route_option {
+      let translated_no_route = general_dictionary
+        .get("no_route")
+        .expect("Expected general_dictionary to have no_route");
+      return format!("{translated_route}: {translated_no_route} {}", translated_territories.join(" > "));
+    }
+
+    format!("{translated_route}: {}", translated_territories.join(" > "))
+  }
+
+  /// Shows the formation the selected troops would be sent in, only when there's a group of them
+  fn preview_formation(&mut self) {
+    self.in_rect_troops.retain(|troop| troop.is_instance_valid());
+    let formation_preview = (self.in_rect_troops.len() > 1).then(|| self.get_formation_preview());
+
+    let mut selection_hud = self.get_hud_from_ui_drag_box();
+    selection_hud.bind_mut().set_formation_preview(formation_preview);
+  }
+
+  fn get_formation_preview(&mut self) -> String {
+    // TODO: remove hardcoded player id
+    const MAIN_PLAYER_ID: u32 = 1;
+    let root_scene = self.get_root_from_ui_drag_box().cast::<RootScene>();
+    let chosen_language: AvailableLanguage = Player::get_player_language(root_scene, MAIN_PLAYER_ID);
+    let translations = chosen_language.get_translations();
+    let general_dictionary = translations.get_general_dictionary();
+
+    let formation_key = match self.formation {
+      Formation::Blob => "formation_blob",
+      Formation::Line => "formation_line",
+      Formation::Wedge => "formation_wedge",
+    };
+    let translated_formation = general_dictionary
+      .get(formation_key)
+      .expect("Expected general_dictionary to have the formation");
+
+    general_dictionary
+      .get("formation")
+      .expect("Expected general_dictionary to have formation")
+      .replace("{x}", translated_formation)
+  }
+
+  /// Shows the territories the first selected troop still has to go to, in the order it goes to them
+  fn preview_order_queue(&mut self) {
+    self.in_rect_troops.retain(|troop| troop.is_instance_valid());
+    let order_queue_preview = self.in_rect_troops
+      .first()
+      .cloned()
+      .and_then(|troop| self.get_order_queue_preview(&troop));
+
+    let mut selection_hud = self.get_hud_from_ui_drag_box();
+    selection_hud.bind_mut().set_order_queue_preview(order_queue_preview);
+  }
+
+  fn get_order_queue_preview(&mut self, troop: &Gd<Troop>) -> Option<String> {
+    let troop = troop.bind();
+    if troop.state.order_queue.is_empty() {
+      return None;
+    }
+
+    // TODO: remove hardcoded player id
+    const MAIN_PLAYER_ID: u32 = 1;
+    let root_scene = self.get_root_from_ui_drag_box().cast::<RootScene>();
+    let chosen_language: AvailableLanguage = Player::get_player_language(root_scene, MAIN_PLAYER_ID);
+    let translations = chosen_language.get_translations();
+    let general_dictionary = translations.get_general_dictionary();
+    let territories_dictionary = translations.get_territory_dictionary();
+
+    if troop.state.order_queue.orders.is_empty() {
+      let translated_holding = general_dictionary
+        .get("holding_position")
+        .expect("Expected general_dictionary to have holding_position");
+      return Some(translated_holding.to_string());
+    }
+
+    // Missing translations are reported by GlobeScene::check_territory_ids, the id is better than nothing
+    let translated_territories: Vec<&str> = troop.state.order_queue
+      .get_territories()
+      .map(|territory_id| territories_dictionary
+        .get(territory_id as &str)
+        .copied()
+        .unwrap_or(territory_id.as_str()))
+      .collect();
+
+    let order_queue_key = if troop.state.order_queue.looping { "patrolling_orders" } else { "queued_orders" };
+    let translated_order_queue = general_dictionary
+      .get(order_queue_key)
+      .expect("Expected general_dictionary to have the order queue")
+      .replace("{x}", &translated_territories.join(" > "));
+
+    Some(translated_order_queue)
+  }
+
+  #[func]
+  fn hover_land(&mut self, territory_id: TerritoryId) {
+    self.hovered_territory_id = Some(territory_id);
+    self.preview_route();
+  }
+
   fn set_reception_for_right_click_on_lands_signal(&mut self) {
     let all_territory_lands = self.get_root_from_ui_drag_box()
       .get_tree()
@@ -154,18 +368,221 @@ impl UiDragBox {
         "move_selected_troops"
       );
       land.connect(Land::LAND_RIGHT_CLICKED, &callable);
+
+      let callable = self.base_mut().callable(
+        "hover_land"
+      );
+      land.connect(Land::LAND_HOVERED, &callable);
+    }
+  }
+
+  /// Shift queues the order after the ones the troops already have, ctrl patrols
+  /// between where they are and where they're sent
+  fn get_order_mode() -> OrderMode {
+    let input = Input::singleton();
+
+    if input.is_action_pressed("queue_order") {
+      OrderMode::Queued
+    } else if input.is_action_pressed("patrol_order") {
+      OrderMode::Patrol
+    } else {
+      OrderMode::Now
     }
   }
 
   #[func]
   fn move_selected_troops(&mut self, moving_to: Vector3, territory_id: TerritoryId) {
+    // Selected troops may have been freed since, such as when a match is loaded
+    self.in_rect_troops.retain(|troop| troop.is_instance_valid());
+    let order_mode = Self::get_order_mode();
+
+    let Some(player_id) = self.in_rect_troops.first().map(|troop| troop.bind().owner.player_id) else {
+      return;
+    };
+
+    // The group spreads around where it was sent instead of piling up on it
+    let formation_slots = {
+      let virtual_planet = self
+        .get_root_from_ui_drag_box()
+        .get_node_as::<VirtualPlanet>("virtual_planet");
+      let virtual_planet = virtual_planet.bind();
+      // Queued orders start from where the previous ones end
+      let formation_troops: Vec<_> = self.in_rect_troops
+        .iter()
+        .map(|troop| {
+          let global_position = troop.get_global_position();
+          let troop = troop.bind();
+          let (origin, origin_territory_id) = match order_mode {
+            OrderMode::Queued => (troop.state.get_last_order_destination(), troop.state.get_last_order_territory_id().clone()),
+            _ => (global_position.to_vec3(), troop.state.deployed_to_territory.clone()),
+          };
+          FormationTroop {
+            origin,
+            origin_territory_id,
+            speed: SpeedType::FightOrFlight.get_speed(&troop.state.archetype),
+          }
+        })
+        .collect();
+
+      self.formation.get_group_orders(
+        &virtual_planet.get_route_planner(),
+        self.route_option,
+        player_id,
+        virtual_planet.get_territory_from_virtual_planet(&territory_id),
+        &formation_troops,
+        moving_to.to_vec3(),
+      )
+    };
+
+    // Orders go through the root so they end up in the replay
+    let mut root_scene = self.get_root_from_ui_drag_box().cast::<RootScene>();
+    let mut root_scene = root_scene.bind_mut();
+    let route_option = self.route_option;
+
+    self.in_rect_troops
+      .iter_mut()
+      .zip(formation_slots)
+      .for_each(|(troop, formation_slot)| {
+        root_scene.give_deploy_order(
+          troop,
+          formation_slot.destination.to_vector3(),
+          &territory_id,
+          route_option,
+          formation_slot.pace,
+          order_mode);
+      });
+    drop(root_scene);
+
+    self.preview_order_queue();
+  }
+
+  /// The selected troops stop patrolling around their territory, the ones on their way somewhere hold once they get there
+  fn hold_selected_troops(&mut self) {
+    self.in_rect_troops.retain(|troop| troop.is_instance_valid());
+
+    let mut root_scene = self.get_root_from_ui_drag_box().cast::<RootScene>();
+    let mut root_scene = root_scene.bind_mut();
+    let route_option = self.route_option;
+
     self.in_rect_troops
       .iter_mut()
       .for_each(|troop| {
-        troop.bind_mut().set_order_to_move_to(
-          moving_to,
-          &territory_id);
+        let position = troop.get_global_position();
+        let territory_id = troop.bind().state.deployed_to_territory.clone();
+        root_scene.give_deploy_order(troop, position, &territory_id, route_option, None, OrderMode::Hold);
       });
+    drop(root_scene);
+
+    self.preview_order_queue();
+  }
+
+  /// The selected troops all switch to the stance after the one of the first selected troop
+  fn cycle_selected_troops_stance(&mut self) {
+    self.in_rect_troops.retain(|troop| troop.is_instance_valid());
+    let Some(first_troop) = self.in_rect_troops.first() else {
+      return;
+    };
+    let fighting_behavior = first_troop.bind().state.combat_stats.fighting_behavior.get_next();
+
+    let mut root_scene = self.get_root_from_ui_drag_box().cast::<RootScene>();
+    let mut root_scene = root_scene.bind_mut();
+    let route_option = self.route_option;
+
+    self.in_rect_troops
+      .iter_mut()
+      .for_each(|troop| {
+        let position = troop.get_global_position();
+        let territory_id = troop.bind().state.deployed_to_territory.clone();
+        root_scene.give_deploy_order(troop, position, &territory_id, route_option, None, OrderMode::Stance(fighting_behavior));
+      });
+    drop(root_scene);
+
+    self.preview_stance();
+  }
+
+  /// Shows the stance of the first selected troop
+  fn preview_stance(&mut self) {
+    self.in_rect_troops.retain(|troop| troop.is_instance_valid());
+    let stance_preview = self.in_rect_troops
+      .first()
+      .map(|troop| troop.bind().state.combat_stats.fighting_behavior)
+      .map(|fighting_behavior| self.get_stance_preview(fighting_behavior));
+
+    let mut selection_hud = self.get_hud_from_ui_drag_box();
+    selection_hud.bind_mut().set_stance_preview(stance_preview);
+  }
+
+  fn get_stance_preview(&mut self, fighting_behavior: FighthingBehavior) -> String {
+    // TODO: remove hardcoded player id
+    const MAIN_PLAYER_ID: u32 = 1;
+    let root_scene = self.get_root_from_ui_drag_box().cast::<RootScene>();
+    let chosen_language: AvailableLanguage = Player::get_player_language(root_scene, MAIN_PLAYER_ID);
+    let translations = chosen_language.get_translations();
+    let general_dictionary = translations.get_general_dictionary();
+
+    let stance_key = match fighting_behavior {
+      FighthingBehavior::Beligerent => "stance_beligerent",
+      FighthingBehavior::Pacifist => "stance_pacifist",
+      FighthingBehavior::HoldFire => "stance_hold_fire",
+    };
+    let translated_stance = general_dictionary
+      .get(stance_key)
+      .expect("Expected general_dictionary to have the stance");
+
+    general_dictionary
+      .get("stance")
+      .expect("Expected general_dictionary to have stance")
+      .replace("{x}", translated_stance)
+  }
+
+  /// Shows where the first selected troop is and the combat modifiers it gets from it
+  fn preview_location(&mut self) {
+    self.in_rect_troops.retain(|troop| troop.is_instance_valid());
+    let location_preview = self.in_rect_troops
+      .first()
+      .map(|troop| troop.bind().state.location)
+      .map(|location| self.get_location_preview(&location));
+
+    let mut selection_hud = self.get_hud_from_ui_drag_box();
+    selection_hud.bind_mut().set_location_preview(location_preview);
+  }
+
+  fn get_location_preview(&mut self, location: &Location) -> String {
+    // TODO: remove hardcoded player id
+    const MAIN_PLAYER_ID: u32 = 1;
+    let root_scene = self.get_root_from_ui_drag_box().cast::<RootScene>();
+    let chosen_language: AvailableLanguage = Player::get_player_language(root_scene, MAIN_PLAYER_ID);
+    let translations = chosen_language.get_translations();
+    let general_dictionary = translations.get_general_dictionary();
+
+    let situation_key = match location.situation {
+      LocationSituation::SelfLand => "location_self_land",
+      LocationSituation::AllyLand => "location_ally_land",
+      LocationSituation::NeutralLand => "location_neutral_land",
+      LocationSituation::EnemyLand => "location_enemy_land",
+      LocationSituation::Sea => "location_sea",
+    };
+    let mut translated_situation = general_dictionary
+      .get(situation_key)
+      .expect("Expected general_dictionary to have the location situation")
+      .to_string();
+
+    if location.deep_invasion {
+      let translated_deep_invasion = general_dictionary
+        .get("location_deep_invasion")
+        .expect("Expected general_dictionary to have location_deep_invasion");
+      translated_situation.push_str(&format!(", {translated_deep_invasion}"));
+    }
+
+    let modifiers = &location.modifiers;
+    general_dictionary
+      .get("location")
+      .expect("Expected general_dictionary to have location")
+      .replace("{x}", &translated_situation)
+      .replace("{damage}", &format!("{:.2}", modifiers.damage))
+      .replace("{defence}", &format!("{:.2}", modifiers.defence))
+      .replace("{fire_rate}", &format!("{:.2}", modifiers.fire_rate))
+      .replace("{morale}", &format!("{:.2}", modifiers.morale))
   }
 
   /// expects the following hierarchy:
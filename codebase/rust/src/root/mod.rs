@@ -1 +1,4 @@
-pub mod root;
\ No newline at end of file
+pub mod replay;
+pub mod root;
+pub mod save;
+pub mod victory;
\ No newline at end of file
@@ -0,0 +1,238 @@
+use godot::classes::{file_access::ModeFlags, FileAccess, InputEvent, Node3D};
+use godot::prelude::*;
+use simulation::replay::playback::{ReplayPlayback, Seek};
+use simulation::replay::record::{OrderSource, Replay, ReplayOrder, TroopSpawn};
+use simulation::replay::recorder::ReplayRecorder;
+use simulation::troops::orders::{DeployOrder, OrderMode};
+use simulation::troops::route::RouteOption;
+
+use crate::bridge::vector::{IntoVec3, IntoVector3};
+use crate::globe::territories::territory::{Territory, TerritoryId};
+use crate::player::player::PlayerId;
+use crate::troops::troop::{Troop, TroopId};
+
+use super::root::RootScene;
+
+impl RootScene {
+  /// Where the replay of the last match is kept, it's written when the game is closed or on save_replay
+  pub const LAST_REPLAY_PATH: &'static str = "user://last_replay.json";
+
+  /// How far replay_seek_backward and replay_seek_forward move the replay
+  const SEEK_SECS: f64 = 10.;
+
+  /// Every deploy order of the players goes through here, so it ends up in the replay
+  /// While a replay is being watched, the orders are ignored
+  pub fn give_deploy_order(
+    &mut self,
+    troop: &mut Gd<Troop>,
+    destination: Vector3,
+    territory_id: &TerritoryId,
+    route: RouteOption,
+    pace: Option<f32>,
+    mode: OrderMode,
+  ) {
+    if self.playback.is_some() {
+      return;
+    }
+
+    let order = DeployOrder {
+      troop_id: troop.get_name().to_string(),
+      territory_id: territory_id.clone(),
+      destination: Some(destination.to_vec3()),
+      route,
+      pace,
+      mode,
+    };
+
+    if let Some(recorder) = self.replay_recorder.as_mut() {
+      let player_id = troop.bind().owner.player_id;
+      recorder.record_order(self.clock.tick, player_id, OrderSource::Player, &order);
+    }
+
+    troop.bind_mut().receive_order(&mut self.rng, destination, territory_id, route, pace, mode);
+  }
+
+  pub(crate) fn start_replay_recording(&mut self) {
+    match self.get_match_save() {
+      Ok(start) => self.replay_recorder = Some(ReplayRecorder::new(start)),
+      Err(error) => godot_error!("Couldn't start recording the replay: {error}"),
+    }
+  }
+
+  pub fn save_replay(&self, path: &str) -> Result<(), String> {
+    let Some(recorder) = &self.replay_recorder else {
+      return Err("there's no match being recorded".to_owned());
+    };
+
+    let Some(mut file) = FileAccess::open(path, ModeFlags::WRITE) else {
+      return Err(format!("couldn't open {path}: {:?}", FileAccess::get_open_error()));
+    };
+    file.store_string(&recorder.get_replay(self.clock.tick).to_json());
+
+    Ok(())
+  }
+
+  /// Replaces the match going on by the beginning of the replay and starts playing it
+  pub fn play_replay(&mut self, path: &str) -> Result<(), String> {
+    if !FileAccess::file_exists(path) {
+      return Err(format!("{path} doesn't exist"));
+    }
+
+    let content = FileAccess::get_file_as_string(path).to_string();
+    let replay = Replay::load(&content, &Territory::get_map())
+      .map_err(|error| format!("{path}: {error}"))?;
+
+    self.restore_match(&replay.start)?;
+    self.replay_recorder = None;
+    self.playback = Some(ReplayPlayback::new(replay));
+
+    Ok(())
+  }
+
+  /// Moves the replay being watched `offset_secs` away, by simulating the match again
+  pub fn seek_replay(&mut self, offset_secs: f64) {
+    let Some(playback) = &self.playback else {
+      return;
+    };
+
+    let ticks_to_run = match playback.get_seek(self.clock.tick, offset_secs) {
+      Seek::Forward(ticks_to_run) => ticks_to_run,
+      Seek::Restart { target_tick } => {
+        let start = playback.replay.start.clone();
+        if let Err(error) = self.restore_match(&start) {
+          godot_error!("Couldn't go back in the replay: {error}");
+          return;
+        }
+
+        if let Some(playback) = self.playback.as_mut() {
+          playback.rewind();
+        }
+        target_tick - start.tick
+      },
+    };
+
+    for _ in 0..ticks_to_run {
+      // The end of the replay is handled by the next physics frame
+      if self.playback.as_ref().is_some_and(|playback| playback.is_finished(self.clock.tick)) {
+        break;
+      }
+      self.step_match();
+    }
+  }
+
+  /// Orders of the replay being watched are given right where they were recorded
+  pub(crate) fn carry_out_replay_orders(&mut self) {
+    let Some(playback) = self.playback.as_mut() else {
+      return;
+    };
+
+    for replay_order in playback.take_orders(self.clock.tick) {
+      self.carry_out_replay_order(&replay_order);
+    }
+  }
+
+  fn carry_out_replay_order(&mut self, replay_order: &ReplayOrder) {
+    let order = &replay_order.order;
+
+    // If the troop isn't there the playback has drifted away, the spawns check tells about it
+    let Some(mut troop) = self.base()
+      .get_node_as::<Node3D>("troops")
+      .try_get_node_as::<Troop>(&order.troop_id)
+      .filter(|troop| !troop.is_queued_for_deletion()) else {
+      return;
+    };
+
+    let destination = match order.destination {
+      Some(destination) => destination.to_vector3(),
+      // Holding and switching stance don't go anywhere, so there's no destination to draw
+      None if matches!(order.mode, OrderMode::Hold | OrderMode::Stance(_)) => troop.get_global_position(),
+      None => {
+        let virtual_planet = self.get_virtual_planet_from_root();
+        let virtual_planet = virtual_planet.bind();
+        let coordinates = virtual_planet.get_an_random_territory_coordinate(&order.territory_id, &mut self.rng);
+        virtual_planet.get_cartesian_from_coordinates(&coordinates)
+      },
+    };
+
+    troop.bind_mut().receive_order(&mut self.rng, destination, &order.territory_id, order.route, order.pace, order.mode);
+  }
+
+  /// Spawns go to the replay being recorded, or are checked against the one being watched
+  pub(crate) fn keep_track_of_spawned_troops(&mut self, spawned_troops: Vec<(TroopId, PlayerId, TerritoryId)>) {
+    for (troop_id, player_id, territory_id) in spawned_troops {
+      let spawn = TroopSpawn {
+        tick: self.clock.tick,
+        troop_id,
+        player_id,
+        territory_id,
+      };
+
+      if let Some(recorder) = self.replay_recorder.as_mut() {
+        recorder.record_spawn(spawn.clone());
+      }
+
+      if let Some(playback) = self.playback.as_mut() {
+        playback.check_spawn(&spawn);
+      }
+    }
+  }
+
+  /// Pauses at the end of the replay and tells whether it was reproduced
+  pub(crate) fn finish_replay(&mut self) {
+    let Some(playback) = self.playback.as_mut() else {
+      return;
+    };
+
+    playback.paused = true;
+    match playback.get_desync() {
+      Some(desync) => godot_warn!("Replay finished, but {desync}"),
+      None => godot_print!("Replay finished"),
+    }
+  }
+
+  pub(crate) fn handle_replay_input(&mut self, event: &Gd<InputEvent>) {
+    if event.is_action_pressed("save_replay") {
+      match self.save_replay(Self::LAST_REPLAY_PATH) {
+        Ok(()) => godot_print!("Replay saved at {}", Self::LAST_REPLAY_PATH),
+        Err(error) => godot_error!("Couldn't save the replay: {error}"),
+      }
+    }
+
+    if event.is_action_pressed("play_replay") {
+      match self.play_replay(Self::LAST_REPLAY_PATH) {
+        Ok(()) => godot_print!("Playing the replay at {}", Self::LAST_REPLAY_PATH),
+        Err(error) => godot_error!("Couldn't play the replay: {error}"),
+      }
+    }
+
+    if self.playback.is_none() {
+      return;
+    }
+
+    if event.is_action_pressed("replay_seek_backward") {
+      self.seek_replay(-Self::SEEK_SECS);
+    }
+
+    if event.is_action_pressed("replay_seek_forward") {
+      self.seek_replay(Self::SEEK_SECS);
+    }
+
+    let Some(playback) = self.playback.as_mut() else {
+      return;
+    };
+
+    if event.is_action_pressed("replay_toggle_pause") {
+      playback.toggle_pause();
+    }
+
+    if event.is_action_pressed("replay_speed_up") {
+      playback.speed_up();
+      godot_print!("Replay speed: {}x", playback.get_speed());
+    }
+
+    if event.is_action_pressed("replay_slow_down") {
+      playback.slow_down();
+      godot_print!("Replay speed: {}x", playback.get_speed());
+    }
+  }
+}
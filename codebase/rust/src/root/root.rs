@@ -1,18 +1,43 @@
-use godot::classes::{INode3D, Node3D};
+use godot::classes::{FileAccess, INode3D, InputEvent, Node3D};
 use godot::prelude::*;
+use simulation::clock::SimulationClock;
+use simulation::random::MatchRng;
+use simulation::replay::playback::ReplayPlayback;
+use simulation::replay::recorder::ReplayRecorder;
+use simulation::setup::MatchSetup;
+use simulation::territories::realm::Realm;
+use simulation::victory::tracker::VictoryTracker;
 
+use crate::events::game_events::GameEvents;
 use crate::globe::coordinates_system::virtual_planet::VirtualPlanet;
+use crate::globe::territories::territory::Territory;
 use crate::i18n::base::AvailableLanguage;
-use crate::player::color::PlayerColor;
-use crate::player::player::{Player, PlayerType, TroopMeshes};
-use crate::troops::mesh_map::MeshId;
-use crate::troops::spawner_engine;
+use crate::player::player::Player;
+use crate::troops::combat::projectile::Projectile;
+use crate::troops::steering::Steering;
+use crate::troops::troop::Troop;
 
 #[derive(GodotClass)]
 #[class(base=Node3D)]
 pub struct RootScene {
   base: Base<Node3D>,
-  initial_spawn_started: bool,
+  pub(crate) initial_spawn_started: bool,
+
+  /// The only clock of the match, game logic doesn't run on the nodes' `process` anymore
+  pub clock: SimulationClock,
+  /// Every random draw of the match should come from here
+  pub rng: MatchRng,
+
+  /// Players and rules of the match, None if `MATCH_SETUP_PATH` couldn't be loaded
+  pub match_setup: Option<MatchSetup>,
+
+  /// Keeps the orders given since the match started or was loaded
+  pub replay_recorder: Option<ReplayRecorder>,
+  /// Set while a replay is being watched, players can't give orders meanwhile
+  pub playback: Option<ReplayPlayback>,
+
+  /// Eliminations and the match outcome, once it's set the match isn't stepped anymore
+  pub victory: VictoryTracker,
 }
 
 #[godot_api]
@@ -22,134 +47,219 @@ impl INode3D for RootScene {
     RootScene {
       base: base,
       initial_spawn_started: false,
+      clock: SimulationClock::new(),
+      rng: MatchRng::new(MatchRng::DEFAULT_SEED),
+      match_setup: None,
+      replay_recorder: None,
+      playback: None,
+      victory: VictoryTracker::new(),
     }
   }
 
-  fn process(&mut self, _delta: f64) {
-    let virtual_planet = self.base()
-      .find_child("virtual_planet")
-      .expect("Expected to find virtual_planet")
-      .cast::<VirtualPlanet>();
+  fn ready(&mut self) {
+    match Self::load_match_setup() {
+      Ok(setup) => {
+        self.rng = MatchRng::new(setup.seed);
+        self.get_virtual_planet_from_root().bind_mut().set_rules(setup.rules.clone());
+        self.match_setup = Some(setup);
+      },
+      Err(error) => godot_error!("Couldn't start the match, invalid match setup at {error}"),
+    }
+  }
 
-    if virtual_planet.bind().are_surface_points_matched && self.base().is_node_ready() {
-      self.startup_troops_spawn();
+  fn unhandled_input(&mut self, event: Gd<InputEvent>) {
+    if event.is_action_pressed("quick_save") {
+      match self.save_match(Self::QUICK_SAVE_PATH) {
+        Ok(()) => godot_print!("Match saved at {}", Self::QUICK_SAVE_PATH),
+        Err(error) => godot_error!("Couldn't save the match: {error}"),
+      }
+    }
+
+    if event.is_action_pressed("quick_load") {
+      match self.load_match(Self::QUICK_SAVE_PATH) {
+        Ok(()) => godot_print!("Match loaded from {}", Self::QUICK_SAVE_PATH),
+        Err(error) => godot_error!("Couldn't load the match: {error}"),
+      }
+    }
+
+    self.handle_replay_input(&event);
+  }
+
+  /// Keeps the replay of the match going on when the game is closed
+  fn exit_tree(&mut self) {
+    if self.replay_recorder.is_some() {
+      if let Err(error) = self.save_replay(Self::LAST_REPLAY_PATH) {
+        godot_error!("Couldn't save the replay: {error}");
+      }
+    }
+  }
+
+  /// Runs on the physics frames, so `move_and_slide` moves the troops
+  /// by the same fixed delta the simulation clock steps
+  fn physics_process(&mut self, delta: f64) {
+    let frame_delta = match &self.playback {
+      Some(playback) => playback.scale_frame_delta(delta),
+      None => delta,
+    };
+
+    let ticks_to_run = self.clock.accumulate(frame_delta);
+    for _ in 0..ticks_to_run {
+      self.step_match();
     }
   }
 }
 
 impl RootScene {
+  /// Match the game starts with, check `MatchSetup` for its format
+  pub const MATCH_SETUP_PATH: &'static str = "res://matches/default_match.ron";
+
+  /// Carries out the orders given since the last tick and steps the match by `SimulationClock::FIXED_DELTA`
+  pub(crate) fn step_match(&mut self) {
+    if self.playback.as_ref().is_some_and(|playback| playback.is_finished(self.clock.tick)) {
+      self.finish_replay();
+      return;
+    }
+
+    if self.victory.is_game_over() {
+      return;
+    }
+
+    self.carry_out_replay_orders();
+    self.clock.step();
+    self.tick(SimulationClock::FIXED_DELTA);
 
-  pub fn hardcoded_players(&mut self) -> Vec<Gd<Player>> {
+    if let Some(playback) = self.playback.as_mut() {
+      playback.check_missing_spawns(self.clock.tick);
+    }
+
+    self.check_victory();
+
+    let mut game_events = self.base().get_node_as::<GameEvents>("game_events");
+    GameEvents::dispatch(&mut game_events);
+  }
+
+  /// Steps the match by a fixed delta, always in the same order:
+  /// territories -> troops -> projectiles
+  fn tick(&mut self, delta: f64) {
+    let mut virtual_planet = self.get_virtual_planet_from_root();
+    virtual_planet.bind_mut().tick(delta);
+
+    let spawned_troops = virtual_planet.bind_mut().take_spawned_troops();
+    self.keep_track_of_spawned_troops(spawned_troops);
+
+    if virtual_planet.bind().are_surface_points_matched && self.base().is_node_ready() {
+      self.startup_troops_spawn();
+    }
+
+    // Every troop steers around where the others were before any of them moved this tick
+    let troops = self.get_troops_to_tick();
+    let steering = Steering::new(troops.iter().map(|troop| troop.bind().state.get_steering_agent()).collect());
+    for (steering_index, mut troop) in troops.into_iter().enumerate() {
+      troop.bind_mut().tick(delta, &mut self.rng, &steering, steering_index);
+    }
+
+    for mut projectile in self.get_projectiles_to_tick() {
+      projectile.bind_mut().tick(delta);
+    }
+  }
+
+  pub(crate) fn get_virtual_planet_from_root(&self) -> Gd<VirtualPlanet> {
+    self.base()
+      .find_child("virtual_planet")
+      .expect("Expected to find virtual_planet")
+      .cast::<VirtualPlanet>()
+  }
+
+  /// Troops in the scene tree order, the ones already dead are left out
+  pub(crate) fn get_troops_to_tick(&self) -> Vec<Gd<Troop>> {
+    self.base()
+      .get_node_as::<Node3D>("troops")
+      .get_children()
+      .iter_shared()
+      .filter_map(|child| child.try_cast::<Troop>().ok())
+      .filter(|troop| !troop.is_queued_for_deletion())
+      .collect()
+  }
+
+  /// Projectiles in the scene tree order, the ones that already hit are left out
+  pub(crate) fn get_projectiles_to_tick(&self) -> Vec<Gd<Projectile>> {
+    self.base()
+      .get_node_as::<Node3D>("troops/projectiles")
+      .get_children()
+      .iter_shared()
+      .filter_map(|child| child.try_cast::<Projectile>().ok())
+      .filter(|projectile| !projectile.is_queued_for_deletion())
+      .collect()
+  }
+
+  /// Reads and validates the match setup file, on error the match just doesn't start
+  fn load_match_setup() -> Result<MatchSetup, String> {
+    let path = Self::MATCH_SETUP_PATH;
+    if !FileAccess::file_exists(path) {
+      return Err(format!("{path} doesn't exist"));
+    }
+
+    let content = FileAccess::get_file_as_string(path).to_string();
+    let setup = MatchSetup::load(path, &content, &Territory::get_map())
+      .map_err(|error| format!("{path}: {error}"))?;
+
+    if let Some(player) = setup.players
+      .iter()
+      .find(|player| AvailableLanguage::from_code(&player.language).is_none()) {
+      return Err(format!("{path}: player {} has an unknown language '{}'", player.player_id, player.language));
+    }
+
+    Ok(setup)
+  }
+
+  pub fn create_players(&mut self, setup: &MatchSetup) -> Vec<Gd<Player>> {
     let mut players_node = self
       .base_mut()
       .find_child("players")
       .expect("Expected players to be found in RootScene");
 
-    let mut player_1 = Player::new_alloc();
-    player_1.bind_mut().set_player(
-      1,
-      "Torque".to_owned(),
-      PlayerColor::Blue,
-      "baffin_bay".to_owned(),
-      PlayerType::MainPlayer,
-      TroopMeshes {
-        land: MeshId::Tank1,
-        sea: MeshId::Boat6,
-      },
-      AvailableLanguage::InternationalEnglish,
-    );
-
-    let mut cpu_2 = Player::new_alloc();
-    cpu_2.bind_mut().set_player(
-      2,
-      "Eastest".to_owned(),
-      PlayerColor::Red,
-      "west_siberia".to_owned(),
-      PlayerType::Bot,
-      TroopMeshes {
-        land: MeshId::Tonk,
-        sea: MeshId::Galleon,
-      },      
-      AvailableLanguage::InternationalEnglish,
-    );
-
-    let mut cpu_3 = Player::new_alloc();
-    cpu_3.bind_mut().set_player(
-      3,
-      "Eagle".to_owned(),
-      PlayerColor::Yellow,
-      "east_savanna".to_owned(),
-      PlayerType::Bot,
-      TroopMeshes {
-        land: MeshId::Truck1,
-        sea: MeshId::Boat6,
-      },      
-      AvailableLanguage::InternationalEnglish,
-    );
-
-    let mut cpu_4 = Player::new_alloc();
-    cpu_4.bind_mut().set_player(
-      4,
-      "Tiger".to_owned(),
-      PlayerColor::Green,
-      "korean_peninsula".to_owned(),
-      PlayerType::Bot,
-      TroopMeshes {
-        land: MeshId::Cannon,
-        sea: MeshId::Boat5,
-      },      
-      AvailableLanguage::InternationalEnglish,
-    );
-
-    players_node.add_child(&player_1);
-    players_node.add_child(&cpu_2);
-    players_node.add_child(&cpu_3);
-    players_node.add_child(&cpu_4);
-
-    let players = [
-      player_1,
-      cpu_2,
-      cpu_3,
-      cpu_4,
-    ].to_vec();
-    players
+    setup.players
+      .iter()
+      .map(|player_setup| {
+        let mut player = Player::new_alloc();
+        player.bind_mut().set_player(
+          player_setup.player_id,
+          player_setup.user_name.clone(),
+          player_setup.color.clone(),
+          player_setup.initial_territory.clone(),
+          player_setup.player_type.clone(),
+          player_setup.troop_meshes.clone(),
+          AvailableLanguage::from_code(&player_setup.language)
+            .expect("Expected language to be checked by load_match_setup"),
+        );
+
+        // `Player::get_player_by_id` expects the PlayerId as name
+        player.set_name(&player_setup.player_id.to_string());
+        players_node.add_child(&player);
+        player
+      })
+      .collect()
   }
 
   pub fn startup_troops_spawn(&mut self) {
     if self.initial_spawn_started == false {
       self.initial_spawn_started = true;
       
-      let hardcoded_players = self.hardcoded_players();
+      let Some(setup) = self.match_setup.clone() else {
+        return;
+      };
 
-      let mut virtual_planet = self.base()
-        .find_child("virtual_planet")
-        .expect("Expected to find virtual_planet")
-        .cast::<VirtualPlanet>();
-      let mut virtual_planet = virtual_planet.bind_mut();
+      self.create_players(&setup);
 
-      for mut player in hardcoded_players {
-        let player_static_info = {
-          let player_binding = player.bind();
-          player_binding.static_info.clone()
-        };
-        player.set_name(&player_static_info.player_id.to_string());
-
-        let territory_id = &player_static_info.initial_territory;
-        let mut territory = virtual_planet.get_mut_territory_from_virtual_planet(territory_id);
-        VirtualPlanet::set_new_territory_ruler(territory, &mut player);
-
-        let mut troops_spawn = 0;
-        let num_of_initial_troops = 1;
-        while troops_spawn < num_of_initial_troops {
-          spawner_engine::spawn_troop(
-            self,
-            &mut player,
-            &mut territory,
-          );
-          troops_spawn+=1;
-        }
+      let mut virtual_planet = self.get_virtual_planet_from_root();
+      let mut virtual_planet = virtual_planet.bind_mut();
+      virtual_planet.get_realm().start_match(&setup.players);
+      // The initial troops are in the save the replay starts from, they aren't spawns to keep track of
+      virtual_planet.take_spawned_troops();
 
-      }
+      // The replay starts once every player has got its troops
+      drop(virtual_planet);
+      self.start_replay_recording();
     }
 
   }
@@ -0,0 +1,224 @@
+use std::collections::BTreeMap;
+
+use godot::classes::{file_access::ModeFlags, FileAccess, Node3D};
+use godot::prelude::*;
+use simulation::clock::SimulationClock;
+use simulation::random::MatchRng;
+use simulation::save::match_save::{MatchSave, PlayerSave};
+use simulation::save::migration::CURRENT_VERSION;
+use simulation::territories::realm::Realm;
+
+use crate::globe::territories::territory::{Territory, TerritoryId};
+use crate::i18n::base::AvailableLanguage;
+use crate::player::player::{Player, PlayerId};
+use crate::troops::combat::projectile::Projectile;
+use crate::troops::spawner_engine;
+use crate::troops::troop::Troop;
+
+use super::root::RootScene;
+
+impl RootScene {
+  /// Where the quick_save action saves the match and quick_load loads it from
+  pub const QUICK_SAVE_PATH: &'static str = "user://quick_save.json";
+
+  /// Writes the whole match state as it is in the current tick, check `MatchSave`
+  pub fn save_match(&self, path: &str) -> Result<(), String> {
+    let save = self.get_match_save()?;
+
+    let Some(mut file) = FileAccess::open(path, ModeFlags::WRITE) else {
+      return Err(format!("couldn't open {path}: {:?}", FileAccess::get_open_error()));
+    };
+    file.store_string(&save.to_json());
+
+    Ok(())
+  }
+
+  /// The match as it is in the current tick, Err if it hasn't started yet
+  pub fn get_match_save(&self) -> Result<MatchSave, String> {
+    let Some(setup) = &self.match_setup else {
+      return Err("there's no match going on".to_owned());
+    };
+
+    if !self.initial_spawn_started {
+      return Err("the match hasn't started yet".to_owned());
+    }
+
+    let virtual_planet = self.get_virtual_planet_from_root();
+    let virtual_planet = virtual_planet.bind();
+
+    let players = setup.players
+      .iter()
+      .map(|player_setup| PlayerSave {
+        setup: player_setup.clone(),
+        state: Player::get_player_by_id(self.to_gd(), player_setup.player_id).bind().state.clone(),
+      })
+      .collect();
+
+    let save = MatchSave {
+      version: CURRENT_VERSION,
+      tick: self.clock.tick,
+      seed: self.rng.get_seed(),
+      rng_state: self.rng.get_state(),
+      rules: virtual_planet.rules.clone(),
+
+      players,
+      territories: virtual_planet.territories.values().map(Territory::get_save).collect(),
+      troops: self.get_troops_to_tick()
+        .iter()
+        .map(|troop| troop.bind().get_save())
+        .collect(),
+      projectiles: self.get_projectiles_to_tick()
+        .iter()
+        .filter_map(|projectile| projectile.bind().get_save())
+        .collect(),
+      victory: self.victory.clone(),
+    };
+
+    Ok(save)
+  }
+
+  /// Replaces the match going on by the saved one, the recording of the replay starts over from it
+  pub fn load_match(&mut self, path: &str) -> Result<(), String> {
+    if !FileAccess::file_exists(path) {
+      return Err(format!("{path} doesn't exist"));
+    }
+
+    let content = FileAccess::get_file_as_string(path).to_string();
+    let save = MatchSave::load(&content, &Territory::get_map())
+      .map_err(|error| format!("{path}: {error}"))?;
+
+    self.restore_match(&save)?;
+    self.playback = None;
+    self.start_replay_recording();
+
+    Ok(())
+  }
+
+  /// Rebuilds the players and troops of the save and repaints every territory,
+  /// expects a save already checked by `MatchSave::validate`
+  pub fn restore_match(&mut self, save: &MatchSave) -> Result<(), String> {
+    let mut virtual_planet = self.get_virtual_planet_from_root();
+    if !virtual_planet.bind().are_surface_points_matched {
+      return Err("the planet is still being mapped, try again in a moment".to_owned());
+    }
+
+    if let Some(player) = save.players
+      .iter()
+      .find(|player| AvailableLanguage::from_code(&player.setup.language).is_none()) {
+      return Err(format!("player {} has an unknown language '{}'", player.setup.player_id, player.setup.language));
+    }
+
+    self.clear_match();
+
+    let setup = save.get_setup();
+    self.initial_spawn_started = true;
+    self.clock = SimulationClock::new();
+    self.clock.tick = save.tick;
+    self.rng = MatchRng::new(save.seed);
+    self.rng.restore_state(save.rng_state);
+    self.victory = save.victory.clone();
+
+    let players: BTreeMap<PlayerId, Gd<Player>> = self.create_players(&setup)
+      .into_iter()
+      .map(|player| {
+        let player_id = player.bind().state.player_id;
+        (player_id, player)
+      })
+      .collect();
+
+    let root_scene = self.to_gd();
+    {
+      let mut virtual_planet = virtual_planet.bind_mut();
+      virtual_planet.set_rules(save.rules.clone());
+      let mut realm = virtual_planet.get_realm();
+
+      let saved_territories: BTreeMap<_, _> = save.territories
+        .iter()
+        .map(|territory_save| (&territory_save.territory_id, territory_save))
+        .collect();
+
+      let territory_ids: Vec<TerritoryId> = realm.get_territories().keys().cloned().collect();
+      for territory_id in &territory_ids {
+        realm.get_territories_mut()
+          .get_mut(territory_id)
+          .expect("Expected territory to exist")
+          .restore_unoccupied();
+        let territory_save = saved_territories.get(territory_id);
+
+        // The ruler's counters are overwritten by the saved ones right after
+        if let Some(ruler_id) = territory_save.and_then(|territory_save| territory_save.current_ruler) {
+          realm.set_ruler(territory_id, ruler_id);
+        }
+
+        if let Some(territory_save) = territory_save {
+          realm.get_territories_mut()
+            .get_mut(territory_id)
+            .expect("Expected territory to exist")
+            .restore_save(territory_save);
+        }
+        realm.paint_territory(territory_id);
+      }
+    }
+
+    for player_save in &save.players {
+      let mut player = players.get(&player_save.setup.player_id).expect("Expected player to exist").clone();
+      player.bind_mut().state = player_save.state.clone();
+    }
+    for troop_save in &save.troops {
+      let owner = players.get(&troop_save.owner).expect("Expected owner to be checked by MatchSave::validate");
+      let owner_static_info = owner.bind().static_info.clone();
+
+      let mut troop = spawner_engine::instantiate_troop(&root_scene, &owner_static_info, Some(&troop_save.troop_id));
+      troop.bind_mut().restore_save(troop_save, &save.rules);
+    }
+
+    let troops_node = self.base().get_node_as::<Node3D>("troops");
+    let mut projectiles_node = self.base().get_node_as::<Node3D>("troops/projectiles");
+    for projectile_save in &save.projectiles {
+      // The target was already gone when the match was saved, the projectile would just fizzle
+      let Some(target) = projectile_save.target
+        .as_ref()
+        .and_then(|target_id| troops_node.try_get_node_as::<Troop>(target_id)) else {
+        continue;
+      };
+
+      let projectile: Gd<PackedScene> = load("res://scenes/troops/combat/projectile.tscn");
+      let mut projectile = projectile.instantiate_as::<Projectile>();
+      projectiles_node.add_child(&projectile);
+      projectile.bind_mut().restore_save(projectile_save, target);
+    }
+
+    self.match_setup = Some(setup);
+    Ok(())
+  }
+
+  /// Takes every player, troop and projectile out of the scene tree right away,
+  /// so the loaded ones can be named the same
+  fn clear_match(&mut self) {
+    let mut projectiles_node = self.base().get_node_as::<Node3D>("troops/projectiles");
+    for child in projectiles_node.get_children().iter_shared() {
+      if let Ok(mut projectile) = child.try_cast::<Projectile>() {
+        projectiles_node.remove_child(&projectile);
+        projectile.queue_free();
+      }
+    }
+
+    let mut troops_node = self.base().get_node_as::<Node3D>("troops");
+    for child in troops_node.get_children().iter_shared() {
+      if let Ok(mut troop) = child.try_cast::<Troop>() {
+        troops_node.remove_child(&troop);
+        troop.queue_free();
+      }
+    }
+
+    let mut players_node = self.base()
+      .find_child("players")
+      .expect("Expected players to be found in RootScene");
+    for child in players_node.get_children().iter_shared() {
+      if let Ok(mut player) = child.try_cast::<Player>() {
+        players_node.remove_child(&player);
+        player.queue_free();
+      }
+    }
+  }
+}
@@ -0,0 +1,78 @@
+use godot::prelude::*;
+use simulation::clock::SimulationClock;
+use simulation::events::game_event::GameEvent;
+use simulation::players::player::PlayerState;
+use simulation::victory::standings::Standing;
+
+use crate::events::game_events::GameEvents;
+use crate::player::player::{Player, PlayerId};
+
+use super::root::RootScene;
+
+impl RootScene {
+  /// Eliminates the defeated players and ends the match once a victory condition is met,
+  /// called by `step_match` once the tick is over
+  pub(crate) fn check_victory(&mut self) {
+    if !self.initial_spawn_started {
+      return;
+    }
+
+    let tick = self.clock.tick;
+    let root_scene = self.to_gd();
+    let mut game_events = GameEvents::get_from_root(&root_scene);
+
+    let mut players: Vec<PlayerState> = Vec::new();
+    for player_id in self.get_player_ids() {
+      let mut player = Player::get_player_by_id(root_scene.clone(), player_id);
+      let mut player = player.bind_mut();
+
+      if self.victory.eliminate_if_defeated(tick, &mut player.state) {
+        godot_print!("Player {} has been eliminated", player.static_info.user_name);
+        game_events.bind_mut().emit(GameEvent::PlayerEliminated { player_id });
+      }
+      players.push(player.state.clone());
+    }
+
+    let virtual_planet = self.get_virtual_planet_from_root();
+    let virtual_planet = virtual_planet.bind();
+    let outcome = self.victory.check_victory(
+      tick,
+      SimulationClock::FIXED_DELTA,
+      &virtual_planet.rules.victory_conditions,
+      &players,
+      &virtual_planet.territories,
+    );
+
+    if let Some(outcome) = outcome {
+      godot_print!("Match over, {:?}: {:?}", outcome.reason, outcome.standings);
+      game_events.bind_mut().emit(GameEvent::MatchEnded { outcome });
+    }
+  }
+
+  /// Final standings once the match is over, the current ones otherwise
+  pub fn get_standings(&self) -> Vec<Standing> {
+    if let Some(outcome) = &self.victory.outcome {
+      return outcome.standings.clone();
+    }
+
+    // Players are created along the initial spawn
+    if !self.initial_spawn_started {
+      return Vec::new();
+    }
+
+    let root_scene = self.to_gd();
+    let players: Vec<PlayerState> = self.get_player_ids()
+      .into_iter()
+      .map(|player_id| Player::get_player_by_id(root_scene.clone(), player_id).bind().state.clone())
+      .collect();
+
+    self.victory.get_standings(&players, None)
+  }
+
+  pub(crate) fn get_player_ids(&self) -> Vec<PlayerId> {
+    self.match_setup
+      .iter()
+      .flat_map(|setup| setup.players.iter().map(|player| player.player_id))
+      .collect()
+  }
+}
@@ -0,0 +1,144 @@
+use godot::{classes::CharacterBody3D, prelude::*};
+
+use simulation::events::game_event::GameEvent;
+use simulation::globe::coordinates::CoordinateMap;
+use simulation::math::Vec3;
+use simulation::random::MatchRng;
+use simulation::rules::Rules;
+use simulation::territories::adjacency::AdjacencyGraph;
+use simulation::territories::territory::Territories;
+use simulation::troops::battlefield::{Battlefield, TroopSighting};
+use simulation::troops::combat::projectile::Projectile as ProjectileRules;
+use simulation::troops::troop::Troop as TroopRules;
+
+use crate::{
+  bridge::vector::{IntoVec3, IntoVector3},
+  events::game_events::GameEvents,
+  globe::{coordinates_system::virtual_planet::VirtualPlanet, territories::territory::TerritoryId},
+  root::root::RootScene,
+};
+
+use super::{combat::projectile::Projectile, surface::surface::Surface, troop::{Troop, TroopId}};
+
+/// The scene tree around a troop while its `TroopRules` is stepped or takes an order,
+/// troops are stepped one at a time, so every other troop can be bound
+pub struct SceneBattlefield<'a> {
+  pub virtual_planet: &'a mut VirtualPlanet,
+  pub rng: &'a mut MatchRng,
+  pub root: Gd<RootScene>,
+  /// Body of the troop being stepped, it can't be bound as its state is borrowed
+  pub body: Gd<CharacterBody3D>,
+}
+
+impl SceneBattlefield<'_> {
+  fn get_other_troop_nodes(&self) -> Vec<Gd<Troop>> {
+    let self_instance_id = self.body.instance_id();
+
+    self.root
+      .get_node_as::<Node3D>("troops")
+      .get_children()
+      .iter_shared()
+      .filter_map(|child| child.try_cast::<Troop>().ok())
+      .filter(|troop| troop.instance_id() != self_instance_id && !troop.is_queued_for_deletion())
+      .collect()
+  }
+
+  /// The physics may have pushed the troop around, so it's seen where its body is
+  pub(crate) fn get_troop_sighting(troop: &Gd<Troop>) -> TroopSighting {
+    TroopSighting {
+      position: troop.get_global_position().to_vec3(),
+      ..troop.bind().state.get_sighting()
+    }
+  }
+
+  /// Every troop scene should have a child node named `projectile_spawner`
+  /// This method returns the >Global position< of the `projectile_spawner` node
+  fn get_projectile_spawner_position(&self, surface: Surface) -> Vector3 {
+    let path = if surface == Surface::Land {
+      "land/composable_mesh/projectile_spawner"
+    } else {
+      "sea/composable_mesh/projectile_spawner"
+    };
+
+    self.body
+      .get_node_as::<Node3D>(path)
+      .get_global_position()
+  }
+}
+
+impl Battlefield for SceneBattlefield<'_> {
+  fn get_rules(&self) -> &Rules {
+    &self.virtual_planet.rules
+  }
+
+  fn get_coordinate_map(&self) -> &CoordinateMap {
+    &self.virtual_planet.coordinate_map
+  }
+
+  fn get_territories(&self) -> &Territories {
+    &self.virtual_planet.territories
+  }
+
+  fn get_territories_mut(&mut self) -> &mut Territories {
+    &mut self.virtual_planet.territories
+  }
+
+  fn get_adjacency(&self) -> &AdjacencyGraph {
+    &self.virtual_planet.adjacency
+  }
+
+  fn get_random_position_in(&mut self, territory_id: &TerritoryId) -> Option<Vec3> {
+    let coordinates = self.virtual_planet.get_an_random_territory_coordinate(territory_id, self.rng);
+    Some(self.virtual_planet.get_cartesian_from_coordinates(&coordinates).to_vec3())
+  }
+
+  fn get_troop(&self, troop_id: &TroopId) -> Option<TroopSighting> {
+    let troop = self.root
+      .try_get_node_as::<Troop>(&format!("troops/{troop_id}"))
+      .filter(|troop| troop.instance_id() != self.body.instance_id() && !troop.is_queued_for_deletion())?;
+
+    Some(Self::get_troop_sighting(&troop))
+  }
+
+  fn get_other_troops(&self) -> Vec<TroopSighting> {
+    self.get_other_troop_nodes()
+      .iter()
+      .map(Self::get_troop_sighting)
+      .collect()
+  }
+
+  fn move_troop(&mut self, _troop: &TroopRules, velocity: Vec3, _delta: f64) -> Vec3 {
+    let velocity = velocity.to_vector3();
+    if let Some(direction) = velocity.try_normalized() {
+      Troop::set_body_orientation(&mut self.body, direction);
+    }
+
+    self.body.set_velocity(velocity);
+    self.body.move_and_slide();
+    self.body.get_global_position().to_vec3()
+  }
+
+  /// The projectile leaves from the cannon of the mesh the troop is showing, facing the enemy
+  fn fire(&mut self, troop: &TroopRules, mut flight: ProjectileRules) {
+    let Some(target) = flight.target
+      .as_ref()
+      .and_then(|target_id| self.root.try_get_node_as::<Troop>(&format!("troops/{target_id}"))) else {
+      return;
+    };
+
+    if let Some(direction) = (flight.up_to_date_target_position - troop.position).try_normalized() {
+      Troop::set_body_orientation(&mut self.body, direction.to_vector3());
+    }
+    flight.position = self.get_projectile_spawner_position(troop.surface).to_vec3();
+
+    let mut projectiles_node = self.root.get_node_as::<Node3D>("troops/projectiles");
+    let projectile: Gd<PackedScene> = load("res://scenes/troops/combat/projectile.tscn");
+    let mut projectile = projectile.instantiate_as::<Projectile>();
+    projectiles_node.add_child(&projectile);
+    projectile.bind_mut().fire(flight, target);
+  }
+
+  fn emit(&mut self, event: GameEvent) {
+    GameEvents::emit_from_root(&self.root, event);
+  }
+}
@@ -1,4 +1,3 @@
 pub mod combat_stats;
-pub mod combat_engager;
 pub mod combatting;
 pub mod projectile;
@@ -1,28 +1,21 @@
 use godot::{classes::{CharacterBody3D, ICharacterBody3D}, prelude::*};
 
-use crate::{globe::coordinates_system::{coordinates_system::CoordinatesSystem, virtual_planet::VirtualPlanet}, troops::troop::{Troop, TroopId}};
+use simulation::math::Vec3;
+use simulation::troops::combat::projectile::Projectile as ProjectileRules;
 
-pub enum TypesOfTarget {
-  Troop,
-}
+use crate::{bridge::vector::{IntoVec3, IntoVector3}, troops::troop::Troop};
+
+pub use simulation::troops::combat::projectile::TypesOfTarget;
 
 #[derive(GodotClass)]
 #[class(base=CharacterBody3D)]
 pub struct Projectile {
   base: Base<CharacterBody3D>,
   showing: bool,
-  
-  pub trajectory: Vec<Vector3>,
-  pub trajectory_is_set: bool,
-  current_trajectory_point: usize,
 
   pub target: Option<Gd<Troop>>,
-  pub damage: i32,
-
-  pub up_to_date_target_position: Vector3,
-  _current_position: Vector3,
-
-  pub fired_by: TroopId,
+  /// Where it is and where it's going, stepped the same way as in the headless matches
+  pub flight: ProjectileRules,
 }
 
 #[godot_api]
@@ -33,17 +26,15 @@ impl ICharacterBody3D for Projectile {
       base: base,
       showing: true,
 
-      trajectory: Vec::new(),
-      trajectory_is_set: false,
-      current_trajectory_point: 0,
-
       target: None,
-      damage: 11,
-
-      up_to_date_target_position: Vector3::ZERO,
-      _current_position: Vector3::ZERO,
-
-      fired_by: "".to_string(),
+      flight: ProjectileRules {
+        fired_by: "".to_string(),
+        fired_by_player: 0,
+        target: None,
+        damage: ProjectileRules::DEFAULT_DAMAGE,
+        position: Vec3::ZERO,
+        up_to_date_target_position: Vec3::ZERO,
+      },
     }
   }
 
@@ -51,74 +42,57 @@ impl ICharacterBody3D for Projectile {
     let showing = self.showing;
     self.base_mut().set_visible(showing);
   }
-
-  fn process(&mut self, delta: f64) {
-    self.maybe_upsert_trajectory();
-    self.move_towards_target(delta);
-  }
 }
 
 impl Projectile {
-  fn maybe_upsert_trajectory(&mut self) {
-    if self.trajectory_is_set {
-      return;
+  /// Projectiles step of the simulation clock, called by `RootScene::tick`
+  /// after every troop has been stepped
+  pub fn tick(&mut self, delta: f64) {
+    let hit = self.flight.move_towards_target(delta);
+    let position = self.flight.position.to_vector3();
+    self.base_mut().set_global_position(position);
+
+    if hit {
+      self.hit_the_target();
     }
+  }
 
-    let trajectory = CoordinatesSystem::get_geodesic_trajectory(
-      self.base().get_global_transform().origin,
-      // todo: was self.up_to_date_target_position alredy set at this point?
-      self.up_to_date_target_position,
-      VirtualPlanet::get_planet_radius() as f32
-    );
-    self.trajectory = trajectory.to_vec();
-    self.trajectory_is_set = true;
+  /// Expects the projectile to be already in the scene tree, as it's placed by its global position
+  pub fn fire(&mut self, flight: ProjectileRules, target: Gd<Troop>) {
+    let position = flight.position.to_vector3();
+    self.flight = flight;
+    self.target = Some(target);
+    self.base_mut().set_global_position(position);
   }
 
-  fn move_towards_target(&mut self, _delta: f64) {
-    let target_position = self.up_to_date_target_position;
-    let current_position = self.base().get_global_transform().origin;
+  /// None if the target is already gone, the projectile would just fizzle
+  pub fn get_save(&self) -> Option<ProjectileRules> {
+    self.target
+      .as_ref()
+      .filter(|target| target.is_instance_valid() && !target.is_queued_for_deletion())?;
 
-    let direction = (target_position - current_position).try_normalized();
-    let on_the_last_waypoint = self.current_trajectory_point == (self.trajectory.len() -1);
+    Some(self.flight.clone())
+  }
 
-    if direction.is_none() && !on_the_last_waypoint {
-      self.current_trajectory_point = self.current_trajectory_point + 1;
-      return;
-    }
+  /// Expects the projectile to be already in the scene tree, as it's placed by its global position
+  pub fn restore_save(&mut self, save: &ProjectileRules, target: Gd<Troop>) {
+    self.fire(save.clone(), target);
+  }
 
-    let Some(direction) = direction else {
-      godot_error!("Expected Projectile direction to be a Vector3");
-      self.base_mut().queue_free();
+  /// Goes after another troop once its target died, check `ProjectileRules::retarget`
+  pub fn retarget(&mut self, new_target: Gd<Troop>) {
+    self.flight.retarget(new_target.get_name().to_string(), new_target.get_global_position().to_vec3());
+    self.target = Some(new_target);
+  }
 
-      // todo: Decide if should deal damage to the enemy troop
+  fn hit_the_target(&mut self) {
+    self.base_mut().queue_free();
+    let Some(ref mut target) = self.target else {
+      godot_error!("Expected Projectile target to be a Troop");
       return
     };
 
-    const PROJECTILE_SPEED: f32 = 0.95;
-    let velocity = direction * PROJECTILE_SPEED;
-
-    // self.set_orientation(direction);
-    self.base_mut().set_velocity(velocity);
-    self.base_mut().move_and_slide();
-
-    let current_distance = current_position.distance_to(target_position);
-    let too_close_to_the_waypoint = current_distance < 0.1;
-
-    if too_close_to_the_waypoint && !on_the_last_waypoint {
-      self.current_trajectory_point = self.current_trajectory_point + 1;
-    }
-
-    if too_close_to_the_waypoint && on_the_last_waypoint {
-      self.base_mut().queue_free();
-      // todo: should deal damage to the enemy troop
-      let Some(ref mut target) = self.target else {
-        godot_error!("Expected Projectile target to be a Troop");
-        return
-      };
-
-      target.bind_mut().take_a_hit(self.damage);
-    }
-
+    target.bind_mut().take_a_hit(self.flight.damage, &self.flight.fired_by, self.flight.fired_by_player);
   }
 
 
@@ -2,21 +2,7 @@ use std::collections::HashMap;
 
 use super::surface::surface::Surface;
 
-#[derive(Debug, Clone, Eq, PartialEq, Hash)]
-pub enum MeshId {
-  Cannon,
-  Tank1,
-  Tonk,
-  Truck1,
-
-  Boat1,
-  Boat2,
-  Boat3,
-  Boat4,
-  Boat5,
-  Boat6,
-  Galleon,
-}
+pub use simulation::troops::mesh_map::MeshId;
 
 pub struct TroopMesh {
   pub scene_name: String,
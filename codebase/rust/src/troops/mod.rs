@@ -2,7 +2,9 @@ pub mod surface;
 pub mod spawner_engine;
 pub mod combat;
 pub mod selection;
-pub mod deployment;
 pub mod speed;
 pub mod mesh_map;
-pub mod troop;
\ No newline at end of file
+pub mod troop;
+pub mod persistence;
+pub mod steering;
+pub mod battlefield;
\ No newline at end of file
@@ -0,0 +1,22 @@
+use godot::prelude::*;
+use simulation::rules::Rules;
+use simulation::save::match_save::TroopSave;
+use simulation::troops::troop::Troop as TroopRules;
+
+use crate::bridge::vector::IntoVector3;
+
+use super::troop::Troop;
+
+impl Troop {
+  pub fn get_save(&self) -> TroopSave {
+    self.state.get_save()
+  }
+
+  /// Expects the troop to be already in the scene tree, as it's placed by its global position,
+  /// and its ownership set, as the archetype isn't saved but picked from the owner meshes
+  pub fn restore_save(&mut self, save: &TroopSave, rules: &Rules) {
+    self.base_mut().set_global_position(save.position.to_vector3());
+    self.state = TroopRules::from_save(save, &self.owner.troop_meshes, rules);
+    self.set_combatting_group();
+  }
+}
@@ -1,68 +1,45 @@
 use crate::{
-  globe::{coordinates_system::{coordinates_system::CoordinatesSystem, virtual_planet::VirtualPlanet},
-  territories::territory::{Territory, TerritoryId}},
-  troops::{speed::SpeedType, troop::{Troop, TroopState}}
+  bridge::vector::IntoVec3,
+  globe::territories::territory::TerritoryId,
+  troops::troop::{Troop, TroopState}
 };
 use godot::{classes::Sprite3D, prelude::*};
+use simulation::random::MatchRng;
+use simulation::troops::{orders::{OrderMode, QueuedOrder}, route::RouteOption};
 
 
 impl Troop {
-  pub fn set_order_to_move_to(&mut self, destination: Vector3, dest_territory_id: &TerritoryId) {
-    self.no_combat_reset_trajectory(false);
-    self.troop_activities.insert(TroopState::Moving);
-    self.troop_activities.insert(TroopState::Deploying);
-    self.troop_activities.remove(&TroopState::Patrolling);
-
-    let geodesic_trajectory = CoordinatesSystem::get_geodesic_trajectory(
-      self.touching_surface_point.cartesian,
-      destination,
-      VirtualPlanet::get_planet_radius() as f32
-    );
+  /// Goes right away or once it's done with its previous orders, depending on `mode`,
+  /// check `TroopRules::receive_order`
+  pub fn receive_order(
+    &mut self,
+    rng: &mut MatchRng,
+    destination: Vector3,
+    dest_territory_id: &TerritoryId,
+    route_option: RouteOption,
+    pace: Option<f32>,
+    mode: OrderMode,
+  ) {
+    let order = QueuedOrder {
+      territory_id: dest_territory_id.clone(),
+      destination: destination.to_vec3(),
+      route: route_option,
+    };
 
     let mut virtual_planet = self.get_virtual_planet_from_troop_scope();
-    let mut virtual_planet = &mut virtual_planet.bind_mut();
-
-    let origin_territory = self.get_territory(
-      self.deployed_to_territory.clone(), &mut virtual_planet
-    );
-
-    origin_territory.inform_territory_departure(
-      &self.base().get_name().to_string(),
-      self.owner.player_id.clone()
-    );
-
-    self.arrived_to_territory = false;
-    self.moving_trajectory_points = geodesic_trajectory.to_vec();
-    self.moving_trajectory_is_set = true;
-    self.adopted_speed = SpeedType::FightOrFlight;
-    self.deployed_to_territory = dest_territory_id.clone();
-
-    let destination_territory = self.get_territory(
-      self.deployed_to_territory.clone(), &mut virtual_planet
-    );
-    destination_territory.add_territory_deployment(
-      &self.base().get_name().to_string(),
-      self.owner.player_id.clone()
-    );
-
-    self.waiting_for_deployment_following_action = true;
+    let mut virtual_planet = virtual_planet.bind_mut();
+    let mut battlefield = self.get_battlefield(&mut virtual_planet, rng);
+    self.state.receive_order(&mut battlefield, order, pace, mode);
   }
 
-  fn get_territory<'a>(&mut self, territory_id: TerritoryId, virtual_planet: &'a mut GdMut<'_, VirtualPlanet>) -> &'a mut Territory {
-    let territory = virtual_planet
-      .get_mut_territory_from_virtual_planet(&territory_id);
-
-    territory
-  }
-  
   pub fn select_troop(&mut self) {
-    self.troop_activities.insert(TroopState::Selected);
+    self.state.troop_activities.insert(TroopState::Selected);
 
     self.set_selected_sprites_visibility(true);
   }
 
   pub fn deselect_troop(&mut self) {
-    self.troop_activities.remove(&TroopState::Selected);
+    self.state.troop_activities.remove(&TroopState::Selected);
 
     self.set_selected_sprites_visibility(false);
   }
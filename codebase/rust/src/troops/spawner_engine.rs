@@ -3,29 +3,35 @@ use godot::{
   classes::{MeshInstance3D, Sprite3D, StandardMaterial3D}, prelude::*
 };
 use crate::{
-  globe::
-    territories::territory::Territory
-  ,
   player::{
-    color::PlayerColor,
-    player::{Player, PlayerStaticInfo, PlayerType}
+    color::{PlayerColor, PlayerColorPalette},
+    player::{PlayerStaticInfo, PlayerType}
   },
   root::root::RootScene
 };
 
-use super::{mesh_map::TroopMesh, troop::Troop};
-
-pub fn spawn_troop(
-  root_scene: &mut RootScene,
-  player: &mut Gd<Player>,
-  territory: &mut Territory,
-) {
-  let mut player_bind = player.bind_mut();
-  let player_static_info = player_bind.static_info.clone();
+use super::{
+  mesh_map::TroopMesh,
+  troop::{Troop, TroopId}
+};
 
+/// Builds the troop scene with the player meshes and colors and adds it under /root_scene/troops,
+/// the counters and territories bookkeeping is up to the caller, check `Realm::spawn_troop`
+///
+/// # Arguments
+/// * `troop_id` - Name of the troop node, if None Godot picks a unique one
+pub fn instantiate_troop(
+  root_scene: &Gd<RootScene>,
+  player_static_info: &PlayerStaticInfo,
+  troop_id: Option<&TroopId>,
+) -> Gd<Troop> {
   let new_troop: Gd<PackedScene> = load("res://scenes/troop_scene.tscn");
   let mut new_troop = new_troop.instantiate_as::<Troop>();
-  new_troop.bind_mut().set_ownership(&player_static_info);
+  new_troop.bind_mut().set_ownership(player_static_info);
+
+  if let Some(troop_id) = troop_id {
+    new_troop.set_name(troop_id);
+  }
 
   let mut land_node = new_troop
     .find_child("land")
@@ -35,7 +41,7 @@ pub fn spawn_troop(
     .find_child("sea")
     .expect("Expected sea to exist");
 
-  let (land_troop, sea_troop) = get_colored_troop_scenes(&player_static_info);
+  let (land_troop, sea_troop) = get_colored_troop_scenes(player_static_info);
   land_node.add_child(&land_troop);
   sea_node.add_child(&sea_troop);
 
@@ -54,36 +60,13 @@ pub fn spawn_troop(
     _ => (),
   }
 
-  // Not listening to the signal anywhere yet, wil be used for UI (HUD timeline)
-  new_troop.emit_signal(
-    Troop::EVENT_TROOP_SPAWNED,
-    &[
-    ]
-  );
-
   // For organization matter, new_troops are spawn under /root_scene/troops
   root_scene
-    .base()
-    .find_child("troops") 
+    .find_child("troops")
     .expect("troops to exist")
     .add_child(&new_troop);
 
-  player_bind.register_troop_spawning();
-
-  new_troop.set_position(territory.spawner_location);
-  new_troop.bind_mut().deployed_to_territory = territory.territory_id.to_string();
-
-  territory.add_territory_deployment(
-    &new_troop.get_name().to_string(),
-    player_static_info.player_id
-  );
-
-  // Whenever a troop is spawned in a territory, it also means it has arrived to it
-  territory.inform_troop_arrived(
-    &new_troop.get_name().to_string(),
-    player_static_info.player_id
-  );
-
+  new_troop
 }
 
 /// Returns (`land_mesh`, `sea_mesh`)
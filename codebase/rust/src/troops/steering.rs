@@ -0,0 +1 @@
+pub use simulation::troops::steering::{Steering, SteeringAgent};